@@ -0,0 +1,312 @@
+import { getBondsAddress, Network } from './network'
+import { Bonds as BondsIdl, IDL } from './idl/bonds'
+import { BN, Program, Provider } from '@project-serum/anchor'
+import { IWallet } from '.'
+import { TOKEN_PROGRAM_ID } from '@solana/spl-token'
+import {
+  Connection,
+  PublicKey,
+  ConfirmOptions,
+  SystemProgram,
+  SYSVAR_RENT_PUBKEY,
+  Transaction,
+  Keypair,
+  sendAndConfirmRawTransaction
+} from '@solana/web3.js'
+import { BONDS_SEED } from './utils'
+
+export class Bonds {
+  public connection: Connection
+  public network: Network
+  public wallet: IWallet
+  public programId: PublicKey
+  public program: Program<BondsIdl>
+  public opts?: ConfirmOptions
+
+  private constructor(
+    connection: Connection,
+    network: Network,
+    wallet: IWallet,
+    opts?: ConfirmOptions
+  ) {
+    this.connection = connection
+    this.wallet = wallet
+    this.opts = opts
+    this.programId = new PublicKey(getBondsAddress(network))
+    const provider = new Provider(connection, wallet, opts ?? Provider.defaultOptions())
+    const programAddress = new PublicKey(getBondsAddress(network))
+
+    this.network = network
+    this.program = new Program(IDL, programAddress, provider)
+  }
+
+  public static async build(
+    network: Network,
+    wallet: IWallet,
+    connection: Connection
+  ): Promise<Bonds> {
+    const instance = new Bonds(connection, network, wallet)
+
+    return instance
+  }
+
+  // frontend methods
+  public async initBondSale(initBondSale: InitBondSale) {
+    const bondSaleAccount = Keypair.generate()
+    const tokenBondReserveAccount = Keypair.generate()
+    const tokenQuoteReserveAccount = Keypair.generate()
+    const bondSale = bondSaleAccount.publicKey
+    const tokenBondReserve = tokenBondReserveAccount.publicKey
+    const tokenQuoteReserve = tokenQuoteReserveAccount.publicKey
+    const initIx = await this.initBondSaleIx(
+      initBondSale,
+      bondSale,
+      tokenBondReserve,
+      tokenQuoteReserve
+    )
+    const tx = new Transaction().add(initIx)
+    const stringTx = await this.signAndSend(tx, [
+      bondSaleAccount,
+      tokenBondReserveAccount,
+      tokenQuoteReserveAccount
+    ])
+
+    return { stringTx, bondSale, tokenBondReserve, tokenQuoteReserve }
+  }
+
+  public async buyBond(buyBond: BuyBond) {
+    const bondAccount = Keypair.generate()
+    const bond = bondAccount.publicKey
+    const buyIx = await this.buyBondIx(buyBond, bond)
+    const tx = new Transaction().add(buyIx)
+    const stringTx = await this.signAndSend(tx, [bondAccount])
+
+    return { stringTx, bond }
+  }
+
+  public async claimBond(claimBond: ClaimBond) {
+    const claimIx = await this.claimBondIx(claimBond)
+    const tx = new Transaction().add(claimIx)
+    const stringTx = await this.signAndSend(tx)
+
+    return stringTx
+  }
+
+  public async endBondSale(endBondSale: EndBondSale) {
+    const endIx = await this.endBondSaleIx(endBondSale)
+    const tx = new Transaction().add(endIx)
+    const stringTx = await this.signAndSend(tx)
+
+    return stringTx
+  }
+
+  // instructions
+  public async initBondSaleIx(
+    {
+      pool,
+      tokenBond,
+      tokenQuote,
+      payerBondAccount,
+      payer,
+      bondAmount,
+      floorPrice,
+      upBound,
+      velocity,
+      startTime,
+      endTime,
+      vestingTime
+    }: InitBondSale,
+    bondSale: PublicKey,
+    tokenBondReserve: PublicKey,
+    tokenQuoteReserve: PublicKey
+  ) {
+    const { authority, nonce } = await this.getBondsAuthority(bondSale)
+
+    return this.program.instruction.initBondSale(
+      nonce,
+      bondAmount,
+      floorPrice,
+      upBound,
+      velocity,
+      startTime,
+      endTime,
+      vestingTime,
+      {
+        accounts: {
+          bondSale,
+          pool,
+          tokenBond,
+          tokenQuote,
+          tokenBondReserve,
+          tokenQuoteReserve,
+          payerBondAccount,
+          payer: payer ?? this.wallet.publicKey,
+          bondsAuthority: authority,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+          rent: SYSVAR_RENT_PUBKEY
+        }
+      }
+    )
+  }
+
+  public async buyBondIx(
+    { bondSale, ownerQuoteAccount, owner, amount, maxQuoteAmount }: BuyBond,
+    bond: PublicKey
+  ) {
+    const { tokenQuoteReserve } = await this.getBondSale(bondSale)
+
+    return this.program.instruction.buyBond(amount, maxQuoteAmount, {
+      accounts: {
+        bondSale,
+        bond,
+        tokenQuoteReserve,
+        ownerQuoteAccount,
+        owner: owner ?? this.wallet.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+        rent: SYSVAR_RENT_PUBKEY
+      }
+    })
+  }
+
+  public async claimBondIx({ bond, ownerBondAccount, owner }: ClaimBond) {
+    const { bondSale } = await this.getBond(bond)
+    const { tokenBondReserve } = await this.getBondSale(bondSale)
+    const { authority, nonce } = await this.getBondsAuthority(bondSale)
+
+    return this.program.instruction.claimBond(nonce, {
+      accounts: {
+        bond,
+        bondSale,
+        tokenBondReserve,
+        ownerBondAccount,
+        bondsAuthority: authority,
+        owner: owner ?? this.wallet.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID
+      }
+    })
+  }
+
+  public async endBondSaleIx({
+    bondSale,
+    payerBondAccount,
+    payerQuoteAccount,
+    payer
+  }: EndBondSale) {
+    const { tokenBondReserve, tokenQuoteReserve } = await this.getBondSale(bondSale)
+    const { authority, nonce } = await this.getBondsAuthority(bondSale)
+
+    return this.program.instruction.endBondSale(nonce, {
+      accounts: {
+        bondSale,
+        tokenBondReserve,
+        tokenQuoteReserve,
+        payerBondAccount,
+        payerQuoteAccount,
+        bondsAuthority: authority,
+        payer: payer ?? this.wallet.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID
+      }
+    })
+  }
+
+  // getters
+  public async getBondsAuthority(bondSale: PublicKey) {
+    const [authority, nonce] = await PublicKey.findProgramAddress(
+      [Buffer.from(BONDS_SEED), bondSale.toBuffer()],
+      this.program.programId
+    )
+
+    return {
+      authority,
+      nonce
+    }
+  }
+
+  public async getBondSale(bondSale: PublicKey) {
+    return (await this.program.account.bondSale.fetch(bondSale)) as BondSaleStructure
+  }
+
+  public async getBond(bond: PublicKey) {
+    return (await this.program.account.bond.fetch(bond)) as BondStructure
+  }
+
+  private async signAndSend(tx: Transaction, signers?: Keypair[], opts?: ConfirmOptions) {
+    const blockhash = await this.connection.getRecentBlockhash(
+      this.opts?.commitment || Provider.defaultOptions().commitment
+    )
+    tx.feePayer = this.wallet.publicKey
+    tx.recentBlockhash = blockhash.blockhash
+
+    const signedTx = await this.wallet.signTransaction(tx)
+    if (signers) signedTx.partialSign(...signers)
+
+    const rawTx = signedTx.serialize()
+    return await sendAndConfirmRawTransaction(
+      this.connection,
+      rawTx,
+      opts ?? Provider.defaultOptions()
+    )
+  }
+}
+export interface InitBondSale {
+  pool: PublicKey
+  tokenBond: PublicKey
+  tokenQuote: PublicKey
+  payerBondAccount: PublicKey
+  payer?: PublicKey
+  bondAmount: Decimal
+  floorPrice: Decimal
+  upBound: Decimal
+  velocity: Decimal
+  startTime: Decimal
+  endTime: Decimal
+  vestingTime: Decimal
+}
+export interface BuyBond {
+  bondSale: PublicKey
+  ownerQuoteAccount: PublicKey
+  owner?: PublicKey
+  amount: Decimal
+  maxQuoteAmount: Decimal
+}
+export interface ClaimBond {
+  bond: PublicKey
+  ownerBondAccount: PublicKey
+  owner?: PublicKey
+}
+export interface EndBondSale {
+  bondSale: PublicKey
+  payerBondAccount: PublicKey
+  payerQuoteAccount: PublicKey
+  payer?: PublicKey
+}
+export interface BondSaleStructure {
+  tokenBond: PublicKey
+  tokenQuote: PublicKey
+  tokenBondReserve: PublicKey
+  tokenQuoteReserve: PublicKey
+  payer: PublicKey
+  bondAmount: Decimal
+  remainingAmount: Decimal
+  floorPrice: Decimal
+  previousPrice: Decimal
+  upBound: Decimal
+  velocity: Decimal
+  startTime: Decimal
+  endTime: Decimal
+  lastTrade: Decimal
+  vestingTime: Decimal
+}
+export interface BondStructure {
+  bondSale: PublicKey
+  owner: PublicKey
+  tokenBondAmount: Decimal
+  claimedAmount: Decimal
+  vestingStart: Decimal
+  vestingEnd: Decimal
+}
+export interface Decimal {
+  v: BN
+}
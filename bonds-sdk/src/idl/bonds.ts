@@ -0,0 +1,1085 @@
+export type Bonds = {
+  "version": "0.1.0",
+  "name": "bonds",
+  "instructions": [
+    {
+      "name": "initBondSale",
+      "accounts": [
+        {
+          "name": "bondSale",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "pool",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenBond",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenQuote",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenBondReserve",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "tokenQuoteReserve",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "payerBondAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "bondsAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "nonce",
+          "type": "u8"
+        },
+        {
+          "name": "bondAmount",
+          "type": {
+            "defined": "TokenAmount"
+          }
+        },
+        {
+          "name": "floorPrice",
+          "type": {
+            "defined": "Price"
+          }
+        },
+        {
+          "name": "upBound",
+          "type": {
+            "defined": "Portion"
+          }
+        },
+        {
+          "name": "velocity",
+          "type": {
+            "defined": "Portion"
+          }
+        },
+        {
+          "name": "startTime",
+          "type": {
+            "defined": "Seconds"
+          }
+        },
+        {
+          "name": "endTime",
+          "type": {
+            "defined": "Seconds"
+          }
+        },
+        {
+          "name": "vestingTime",
+          "type": {
+            "defined": "Seconds"
+          }
+        }
+      ]
+    },
+    {
+      "name": "buyBond",
+      "accounts": [
+        {
+          "name": "bondSale",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bond",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "tokenQuoteReserve",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "ownerQuoteAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": {
+            "defined": "TokenAmount"
+          }
+        },
+        {
+          "name": "maxQuoteAmount",
+          "type": {
+            "defined": "TokenAmount"
+          }
+        }
+      ]
+    },
+    {
+      "name": "claimBond",
+      "accounts": [
+        {
+          "name": "bond",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bondSale",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenBondReserve",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "ownerBondAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bondsAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "nonce",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "endBondSale",
+      "accounts": [
+        {
+          "name": "bondSale",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenBondReserve",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenQuoteReserve",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payerBondAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payerQuoteAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bondsAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "nonce",
+          "type": "u8"
+        }
+      ]
+    }
+  ],
+  "accounts": [
+    {
+      "name": "bond",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bondSale",
+            "type": "publicKey"
+          },
+          {
+            "name": "owner",
+            "type": "publicKey"
+          },
+          {
+            "name": "tokenBondAmount",
+            "type": {
+              "defined": "TokenAmount"
+            }
+          },
+          {
+            "name": "claimedAmount",
+            "type": {
+              "defined": "TokenAmount"
+            }
+          },
+          {
+            "name": "vestingStart",
+            "type": {
+              "defined": "Seconds"
+            }
+          },
+          {
+            "name": "vestingEnd",
+            "type": {
+              "defined": "Seconds"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "bondSale",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "tokenBond",
+            "type": "publicKey"
+          },
+          {
+            "name": "tokenQuote",
+            "type": "publicKey"
+          },
+          {
+            "name": "tokenBondReserve",
+            "type": "publicKey"
+          },
+          {
+            "name": "tokenQuoteReserve",
+            "type": "publicKey"
+          },
+          {
+            "name": "payer",
+            "type": "publicKey"
+          },
+          {
+            "name": "bondAmount",
+            "type": {
+              "defined": "TokenAmount"
+            }
+          },
+          {
+            "name": "remainingAmount",
+            "type": {
+              "defined": "TokenAmount"
+            }
+          },
+          {
+            "name": "floorPrice",
+            "type": {
+              "defined": "Price"
+            }
+          },
+          {
+            "name": "previousPrice",
+            "type": {
+              "defined": "Price"
+            }
+          },
+          {
+            "name": "upBound",
+            "type": {
+              "defined": "Portion"
+            }
+          },
+          {
+            "name": "velocity",
+            "type": {
+              "defined": "Portion"
+            }
+          },
+          {
+            "name": "startTime",
+            "type": {
+              "defined": "Seconds"
+            }
+          },
+          {
+            "name": "endTime",
+            "type": {
+              "defined": "Seconds"
+            }
+          },
+          {
+            "name": "lastTrade",
+            "type": {
+              "defined": "Seconds"
+            }
+          },
+          {
+            "name": "vestingTime",
+            "type": {
+              "defined": "Seconds"
+            }
+          }
+        ]
+      }
+    }
+  ],
+  "types": [
+    {
+      "name": "TokenAmount",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "v",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "Price",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "v",
+            "type": "u128"
+          }
+        ]
+      }
+    },
+    {
+      "name": "Portion",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "v",
+            "type": "u128"
+          }
+        ]
+      }
+    },
+    {
+      "name": "Seconds",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "v",
+            "type": "u64"
+          }
+        ]
+      }
+    }
+  ],
+  "errors": [
+    {
+      "code": 6000,
+      "name": "ZeroAmount",
+      "msg": "Amount is zero"
+    },
+    {
+      "code": 6001,
+      "name": "ZeroPrice",
+      "msg": "Floor price is zero"
+    },
+    {
+      "code": 6002,
+      "name": "StartInPast",
+      "msg": "Start in past"
+    },
+    {
+      "code": 6003,
+      "name": "TooLongDuration",
+      "msg": "Bond sale duration is too long"
+    },
+    {
+      "code": 6004,
+      "name": "InvalidSaleTime",
+      "msg": "End time is not after start time"
+    },
+    {
+      "code": 6005,
+      "name": "NotStarted",
+      "msg": "The bond sale didn't start yet!"
+    },
+    {
+      "code": 6006,
+      "name": "Ended",
+      "msg": "Bond sale is over"
+    },
+    {
+      "code": 6007,
+      "name": "TooEarly",
+      "msg": "Bond sale not ended"
+    },
+    {
+      "code": 6008,
+      "name": "InsufficientBondAmount",
+      "msg": "Not enough tokens left in the bond sale"
+    },
+    {
+      "code": 6009,
+      "name": "QuoteLimitExceeded",
+      "msg": "Quote amount exceeds the given limit"
+    },
+    {
+      "code": 6010,
+      "name": "ZeroClaimable",
+      "msg": "Nothing to claim yet"
+    },
+    {
+      "code": 6011,
+      "name": "InvalidPool",
+      "msg": "Pool tokens are different than bond sale tokens"
+    },
+    {
+      "code": 6012,
+      "name": "InvalidPayer",
+      "msg": "Payer address is different than expected"
+    },
+    {
+      "code": 6013,
+      "name": "InvalidBond",
+      "msg": "Provided bond doesn't belong to bond sale"
+    },
+    {
+      "code": 6014,
+      "name": "InvalidTokenAccount",
+      "msg": "Provided token account is different than expected"
+    },
+    {
+      "code": 6015,
+      "name": "InvalidOwner",
+      "msg": "Provided token owner is different than expected"
+    },
+    {
+      "code": 6016,
+      "name": "InvalidMint",
+      "msg": "Provided token account mint is different than expected mint token"
+    }
+  ]
+};
+
+export const IDL: Bonds = {
+  "version": "0.1.0",
+  "name": "bonds",
+  "instructions": [
+    {
+      "name": "initBondSale",
+      "accounts": [
+        {
+          "name": "bondSale",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "pool",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenBond",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenQuote",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenBondReserve",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "tokenQuoteReserve",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "payerBondAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "bondsAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "nonce",
+          "type": "u8"
+        },
+        {
+          "name": "bondAmount",
+          "type": {
+            "defined": "TokenAmount"
+          }
+        },
+        {
+          "name": "floorPrice",
+          "type": {
+            "defined": "Price"
+          }
+        },
+        {
+          "name": "upBound",
+          "type": {
+            "defined": "Portion"
+          }
+        },
+        {
+          "name": "velocity",
+          "type": {
+            "defined": "Portion"
+          }
+        },
+        {
+          "name": "startTime",
+          "type": {
+            "defined": "Seconds"
+          }
+        },
+        {
+          "name": "endTime",
+          "type": {
+            "defined": "Seconds"
+          }
+        },
+        {
+          "name": "vestingTime",
+          "type": {
+            "defined": "Seconds"
+          }
+        }
+      ]
+    },
+    {
+      "name": "buyBond",
+      "accounts": [
+        {
+          "name": "bondSale",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bond",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "tokenQuoteReserve",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "ownerQuoteAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": {
+            "defined": "TokenAmount"
+          }
+        },
+        {
+          "name": "maxQuoteAmount",
+          "type": {
+            "defined": "TokenAmount"
+          }
+        }
+      ]
+    },
+    {
+      "name": "claimBond",
+      "accounts": [
+        {
+          "name": "bond",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bondSale",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenBondReserve",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "ownerBondAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bondsAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "nonce",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "endBondSale",
+      "accounts": [
+        {
+          "name": "bondSale",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenBondReserve",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenQuoteReserve",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payerBondAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payerQuoteAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bondsAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "nonce",
+          "type": "u8"
+        }
+      ]
+    }
+  ],
+  "accounts": [
+    {
+      "name": "bond",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bondSale",
+            "type": "publicKey"
+          },
+          {
+            "name": "owner",
+            "type": "publicKey"
+          },
+          {
+            "name": "tokenBondAmount",
+            "type": {
+              "defined": "TokenAmount"
+            }
+          },
+          {
+            "name": "claimedAmount",
+            "type": {
+              "defined": "TokenAmount"
+            }
+          },
+          {
+            "name": "vestingStart",
+            "type": {
+              "defined": "Seconds"
+            }
+          },
+          {
+            "name": "vestingEnd",
+            "type": {
+              "defined": "Seconds"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "bondSale",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "tokenBond",
+            "type": "publicKey"
+          },
+          {
+            "name": "tokenQuote",
+            "type": "publicKey"
+          },
+          {
+            "name": "tokenBondReserve",
+            "type": "publicKey"
+          },
+          {
+            "name": "tokenQuoteReserve",
+            "type": "publicKey"
+          },
+          {
+            "name": "payer",
+            "type": "publicKey"
+          },
+          {
+            "name": "bondAmount",
+            "type": {
+              "defined": "TokenAmount"
+            }
+          },
+          {
+            "name": "remainingAmount",
+            "type": {
+              "defined": "TokenAmount"
+            }
+          },
+          {
+            "name": "floorPrice",
+            "type": {
+              "defined": "Price"
+            }
+          },
+          {
+            "name": "previousPrice",
+            "type": {
+              "defined": "Price"
+            }
+          },
+          {
+            "name": "upBound",
+            "type": {
+              "defined": "Portion"
+            }
+          },
+          {
+            "name": "velocity",
+            "type": {
+              "defined": "Portion"
+            }
+          },
+          {
+            "name": "startTime",
+            "type": {
+              "defined": "Seconds"
+            }
+          },
+          {
+            "name": "endTime",
+            "type": {
+              "defined": "Seconds"
+            }
+          },
+          {
+            "name": "lastTrade",
+            "type": {
+              "defined": "Seconds"
+            }
+          },
+          {
+            "name": "vestingTime",
+            "type": {
+              "defined": "Seconds"
+            }
+          }
+        ]
+      }
+    }
+  ],
+  "types": [
+    {
+      "name": "TokenAmount",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "v",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "Price",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "v",
+            "type": "u128"
+          }
+        ]
+      }
+    },
+    {
+      "name": "Portion",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "v",
+            "type": "u128"
+          }
+        ]
+      }
+    },
+    {
+      "name": "Seconds",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "v",
+            "type": "u64"
+          }
+        ]
+      }
+    }
+  ],
+  "errors": [
+    {
+      "code": 6000,
+      "name": "ZeroAmount",
+      "msg": "Amount is zero"
+    },
+    {
+      "code": 6001,
+      "name": "ZeroPrice",
+      "msg": "Floor price is zero"
+    },
+    {
+      "code": 6002,
+      "name": "StartInPast",
+      "msg": "Start in past"
+    },
+    {
+      "code": 6003,
+      "name": "TooLongDuration",
+      "msg": "Bond sale duration is too long"
+    },
+    {
+      "code": 6004,
+      "name": "InvalidSaleTime",
+      "msg": "End time is not after start time"
+    },
+    {
+      "code": 6005,
+      "name": "NotStarted",
+      "msg": "The bond sale didn't start yet!"
+    },
+    {
+      "code": 6006,
+      "name": "Ended",
+      "msg": "Bond sale is over"
+    },
+    {
+      "code": 6007,
+      "name": "TooEarly",
+      "msg": "Bond sale not ended"
+    },
+    {
+      "code": 6008,
+      "name": "InsufficientBondAmount",
+      "msg": "Not enough tokens left in the bond sale"
+    },
+    {
+      "code": 6009,
+      "name": "QuoteLimitExceeded",
+      "msg": "Quote amount exceeds the given limit"
+    },
+    {
+      "code": 6010,
+      "name": "ZeroClaimable",
+      "msg": "Nothing to claim yet"
+    },
+    {
+      "code": 6011,
+      "name": "InvalidPool",
+      "msg": "Pool tokens are different than bond sale tokens"
+    },
+    {
+      "code": 6012,
+      "name": "InvalidPayer",
+      "msg": "Payer address is different than expected"
+    },
+    {
+      "code": 6013,
+      "name": "InvalidBond",
+      "msg": "Provided bond doesn't belong to bond sale"
+    },
+    {
+      "code": 6014,
+      "name": "InvalidTokenAccount",
+      "msg": "Provided token account is different than expected"
+    },
+    {
+      "code": 6015,
+      "name": "InvalidOwner",
+      "msg": "Provided token owner is different than expected"
+    },
+    {
+      "code": 6016,
+      "name": "InvalidMint",
+      "msg": "Provided token account mint is different than expected mint token"
+    }
+  ]
+};
@@ -0,0 +1,18 @@
+export enum Network {
+  LOCAL,
+  DEV,
+  MAIN
+}
+
+export const getBondsAddress = (network: Network) => {
+  switch (network) {
+    case Network.LOCAL:
+      return 'AcniaXZvizbgTyp4SpWoAFzQJy2YaLR5okWnZ2SXvj7f'
+    case Network.DEV:
+      return 'AcniaXZvizbgTyp4SpWoAFzQJy2YaLR5okWnZ2SXvj7f'
+    case Network.MAIN:
+      return 'AcniaXZvizbgTyp4SpWoAFzQJy2YaLR5okWnZ2SXvj7f'
+    default:
+      throw new Error('Unknown network')
+  }
+}
@@ -0,0 +1,25 @@
+import { BN } from '@project-serum/anchor'
+
+export const BONDS_SEED = 'bonds'
+export const DECIMAL = 12
+export const DENOMINATOR = new BN(10).pow(new BN(DECIMAL))
+
+export enum BONDS_ERRORS {
+  ZERO_AMOUNT = '0x1770',
+  ZERO_PRICE = '0x1771',
+  START_IN_PAST = '0x1772',
+  TOO_LONG_DURATION = '0x1773',
+  INVALID_SALE_TIME = '0x1774',
+  NOT_STARTED = '0x1775',
+  ENDED = '0x1776',
+  TOO_EARLY = '0x1777',
+  INSUFFICIENT_BOND_AMOUNT = '0x1778',
+  QUOTE_LIMIT_EXCEEDED = '0x1779',
+  ZERO_CLAIMABLE = '0x177a',
+  INVALID_POOL = '0x177b',
+  INVALID_PAYER = '0x177c',
+  INVALID_BOND = '0x177d',
+  INVALID_TOKEN_ACCOUNT = '0x177e',
+  INVALID_OWNER = '0x177f',
+  INVALID_MINT = '0x1780'
+}
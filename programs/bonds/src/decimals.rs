@@ -0,0 +1,54 @@
+use core::convert::TryFrom;
+use core::convert::TryInto;
+pub use decimal::*;
+
+use anchor_lang::prelude::*;
+
+#[decimal(0)]
+#[zero_copy]
+#[derive(
+    Default, std::fmt::Debug, PartialEq, Eq, PartialOrd, Ord, AnchorSerialize, AnchorDeserialize,
+)]
+pub struct TokenAmount {
+    pub v: u64,
+}
+
+// price of a single bond token expressed in quote tokens
+#[decimal(12)]
+#[zero_copy]
+#[derive(
+    Default, std::fmt::Debug, PartialEq, Eq, PartialOrd, Ord, AnchorSerialize, AnchorDeserialize,
+)]
+pub struct Price {
+    pub v: u128,
+}
+
+#[decimal(12)]
+#[zero_copy]
+#[derive(
+    Default, std::fmt::Debug, PartialEq, Eq, PartialOrd, Ord, AnchorSerialize, AnchorDeserialize,
+)]
+pub struct Portion {
+    pub v: u128,
+}
+
+#[decimal(0)]
+#[zero_copy]
+#[derive(
+    Default, std::fmt::Debug, PartialEq, Eq, PartialOrd, Ord, AnchorSerialize, AnchorDeserialize,
+)]
+pub struct Seconds {
+    pub v: u64,
+}
+
+impl TokenAmount {
+    pub fn percent(self, other: TokenAmount) -> Portion {
+        Portion::from_decimal(self).big_div(Portion::from_decimal(other))
+    }
+}
+
+impl Seconds {
+    pub fn now() -> Self {
+        Seconds::new(Clock::get().unwrap().unix_timestamp.try_into().unwrap())
+    }
+}
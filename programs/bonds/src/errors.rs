@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+
+#[error]
+pub enum ErrorCode {
+    #[msg("Amount is zero")]
+    ZeroAmount = 0, // 1770
+    #[msg("Floor price is zero")]
+    ZeroPrice = 1, // 1771
+    #[msg("Start in past")]
+    StartInPast = 2, // 1772
+    #[msg("Bond sale duration is too long")]
+    TooLongDuration = 3, // 1773
+    #[msg("End time is not after start time")]
+    InvalidSaleTime = 4, // 1774
+    #[msg("The bond sale didn't start yet!")]
+    NotStarted = 5, // 1775
+    #[msg("Bond sale is over")]
+    Ended = 6, // 1776
+    #[msg("Bond sale not ended")]
+    TooEarly = 7, // 1777
+    #[msg("Not enough tokens left in the bond sale")]
+    InsufficientBondAmount = 8, // 1778
+    #[msg("Quote amount exceeds the given limit")]
+    QuoteLimitExceeded = 9, // 1779
+    #[msg("Nothing to claim yet")]
+    ZeroClaimable = 10, // 177a
+    #[msg("Pool tokens are different than bond sale tokens")]
+    InvalidPool = 11, // 177b
+    #[msg("Payer address is different than expected")]
+    InvalidPayer = 12, // 177c
+    #[msg("Provided bond doesn't belong to bond sale")]
+    InvalidBond = 13, // 177d
+    #[msg("Provided token account is different than expected")]
+    InvalidTokenAccount = 14, // 177e
+    #[msg("Provided token owner is different than expected")]
+    InvalidOwner = 15, // 177f
+    #[msg("Provided token account mint is different than expected mint token")]
+    InvalidMint = 16, // 1780
+}
@@ -0,0 +1,80 @@
+use crate::decimals::*;
+use crate::math::*;
+use crate::structs::*;
+use crate::ErrorCode::*;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::system_program;
+use anchor_spl::token::{self, TokenAccount, Transfer};
+
+#[derive(Accounts)]
+pub struct BuyBond<'info> {
+    #[account(mut)]
+    pub bond_sale: AccountLoader<'info, BondSale>,
+    #[account(init, payer = owner)]
+    pub bond: AccountLoader<'info, Bond>,
+    #[account(mut,
+        constraint = bond_sale.load()?.token_quote_reserve == token_quote_reserve.key() @ InvalidTokenAccount
+    )]
+    pub token_quote_reserve: Account<'info, TokenAccount>,
+    #[account(mut,
+        constraint = owner_quote_account.mint == bond_sale.load()?.token_quote @ InvalidMint,
+        constraint = owner_quote_account.owner == owner.key() @ InvalidOwner
+    )]
+    pub owner_quote_account: Account<'info, TokenAccount>,
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    #[account(address = token::ID)]
+    pub token_program: AccountInfo<'info>,
+    #[account(address = system_program::ID)]
+    pub system_program: AccountInfo<'info>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+impl<'info> BuyBond<'info> {
+    fn pay(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        CpiContext::new(
+            self.token_program.to_account_info(),
+            Transfer {
+                from: self.owner_quote_account.to_account_info(),
+                to: self.token_quote_reserve.to_account_info(),
+                authority: self.owner.to_account_info().clone(),
+            },
+        )
+    }
+}
+
+pub fn handler(
+    ctx: Context<BuyBond>,
+    amount: TokenAmount,
+    max_quote_amount: TokenAmount,
+) -> ProgramResult {
+    msg!("BUY BOND");
+    let mut bond_sale = ctx.accounts.bond_sale.load_mut()?;
+    let current_time = Seconds::now();
+
+    require!(!amount.is_zero(), ZeroAmount);
+    require!(current_time >= { bond_sale.start_time }, NotStarted);
+    require!(current_time < { bond_sale.end_time }, Ended);
+    require!(
+        amount <= { bond_sale.remaining_amount },
+        InsufficientBondAmount
+    );
+
+    let price = calculate_new_price(&mut bond_sale, current_time, amount);
+    let quote_amount = calculate_quote_amount(price, amount);
+    require!(quote_amount <= max_quote_amount, QuoteLimitExceeded);
+
+    let bond = &mut ctx.accounts.bond.load_init()?;
+    **bond = Bond {
+        bond_sale: ctx.accounts.bond_sale.key(),
+        owner: ctx.accounts.owner.key(),
+        token_bond_amount: amount,
+        claimed_amount: TokenAmount::new(0),
+        vesting_start: current_time,
+        vesting_end: current_time + bond_sale.vesting_time,
+    };
+
+    token::transfer(ctx.accounts.pay(), quote_amount.get())?;
+
+    Ok(())
+}
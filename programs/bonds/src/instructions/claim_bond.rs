@@ -0,0 +1,74 @@
+use crate::decimals::*;
+use crate::math::*;
+use crate::structs::*;
+use crate::util::*;
+use crate::ErrorCode::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, TokenAccount, Transfer};
+
+#[derive(Accounts)]
+#[instruction(nonce: u8)]
+pub struct ClaimBond<'info> {
+    #[account(mut,
+        constraint = bond.load()?.bond_sale == bond_sale.key() @ InvalidBond,
+        constraint = bond.load()?.owner == owner.key() @ InvalidOwner
+    )]
+    pub bond: AccountLoader<'info, Bond>,
+    pub bond_sale: AccountLoader<'info, BondSale>,
+    #[account(mut,
+        constraint = bond_sale.load()?.token_bond_reserve == token_bond_reserve.key() @ InvalidTokenAccount
+    )]
+    pub token_bond_reserve: Account<'info, TokenAccount>,
+    #[account(mut,
+        constraint = owner_bond_account.mint == bond_sale.load()?.token_bond @ InvalidMint,
+        constraint = owner_bond_account.owner == owner.key() @ InvalidOwner
+    )]
+    pub owner_bond_account: Account<'info, TokenAccount>,
+    #[account(seeds = [b"bonds".as_ref(), bond_sale.key().as_ref()], bump = nonce)]
+    pub bonds_authority: AccountInfo<'info>,
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    #[account(address = token::ID)]
+    pub token_program: AccountInfo<'info>,
+}
+
+impl<'info> ClaimBond<'info> {
+    fn claim(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        CpiContext::new(
+            self.token_program.to_account_info(),
+            Transfer {
+                from: self.token_bond_reserve.to_account_info(),
+                to: self.owner_bond_account.to_account_info(),
+                authority: self.bonds_authority.to_account_info().clone(),
+            },
+        )
+    }
+}
+
+pub fn handler(ctx: Context<ClaimBond>, nonce: u8) -> ProgramResult {
+    msg!("CLAIM BOND");
+    let fully_claimed = {
+        let bond = &mut ctx.accounts.bond.load_mut()?;
+        let claimable = calculate_claimable_amount(bond, Seconds::now());
+        require!(!claimable.is_zero(), ZeroClaimable);
+
+        let claimed_amount = bond.claimed_amount + claimable;
+        bond.claimed_amount = claimed_amount;
+
+        let bond_sale_key = ctx.accounts.bond_sale.key();
+        let seeds = &[BONDS_SEED.as_bytes(), bond_sale_key.as_ref(), &[nonce]];
+        let signer = &[&seeds[..]];
+        token::transfer(ctx.accounts.claim().with_signer(signer), claimable.get())?;
+
+        claimed_amount == { bond.token_bond_amount }
+    };
+
+    if fully_claimed {
+        close(
+            ctx.accounts.bond.to_account_info(),
+            ctx.accounts.owner.to_account_info(),
+        )?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,90 @@
+use crate::decimals::*;
+use crate::structs::*;
+use crate::util::*;
+use crate::ErrorCode::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, TokenAccount, Transfer};
+
+#[derive(Accounts)]
+#[instruction(nonce: u8)]
+pub struct EndBondSale<'info> {
+    #[account(mut,
+        constraint = bond_sale.load()?.payer == payer.key() @ InvalidPayer
+    )]
+    pub bond_sale: AccountLoader<'info, BondSale>,
+    #[account(mut,
+        constraint = bond_sale.load()?.token_bond_reserve == token_bond_reserve.key() @ InvalidTokenAccount
+    )]
+    pub token_bond_reserve: Account<'info, TokenAccount>,
+    #[account(mut,
+        constraint = bond_sale.load()?.token_quote_reserve == token_quote_reserve.key() @ InvalidTokenAccount
+    )]
+    pub token_quote_reserve: Account<'info, TokenAccount>,
+    #[account(mut,
+        constraint = payer_bond_account.mint == bond_sale.load()?.token_bond @ InvalidMint
+    )]
+    pub payer_bond_account: Account<'info, TokenAccount>,
+    #[account(mut,
+        constraint = payer_quote_account.mint == bond_sale.load()?.token_quote @ InvalidMint
+    )]
+    pub payer_quote_account: Account<'info, TokenAccount>,
+    #[account(seeds = [b"bonds".as_ref(), bond_sale.key().as_ref()], bump = nonce)]
+    pub bonds_authority: AccountInfo<'info>,
+    pub payer: Signer<'info>,
+    #[account(address = token::ID)]
+    pub token_program: AccountInfo<'info>,
+}
+
+impl<'info> EndBondSale<'info> {
+    fn return_bond(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        CpiContext::new(
+            self.token_program.to_account_info(),
+            Transfer {
+                from: self.token_bond_reserve.to_account_info(),
+                to: self.payer_bond_account.to_account_info(),
+                authority: self.bonds_authority.to_account_info().clone(),
+            },
+        )
+    }
+
+    fn withdraw_quote(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        CpiContext::new(
+            self.token_program.to_account_info(),
+            Transfer {
+                from: self.token_quote_reserve.to_account_info(),
+                to: self.payer_quote_account.to_account_info(),
+                authority: self.bonds_authority.to_account_info().clone(),
+            },
+        )
+    }
+}
+
+pub fn handler(ctx: Context<EndBondSale>, nonce: u8) -> ProgramResult {
+    msg!("END BOND SALE");
+    let mut bond_sale = ctx.accounts.bond_sale.load_mut()?;
+    require!(Seconds::now() >= { bond_sale.end_time }, TooEarly);
+
+    let remaining_amount = bond_sale.remaining_amount;
+    let quote_amount = ctx.accounts.token_quote_reserve.amount;
+    bond_sale.remaining_amount = TokenAmount::new(0);
+
+    let bond_sale_key = ctx.accounts.bond_sale.key();
+    let seeds = &[BONDS_SEED.as_bytes(), bond_sale_key.as_ref(), &[nonce]];
+    let signer = &[&seeds[..]];
+
+    // bought bonds stay in the reserve until their owners claim them
+    if !remaining_amount.is_zero() {
+        token::transfer(
+            ctx.accounts.return_bond().with_signer(signer),
+            remaining_amount.get(),
+        )?;
+    }
+    if quote_amount != 0 {
+        token::transfer(
+            ctx.accounts.withdraw_quote().with_signer(signer),
+            quote_amount,
+        )?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,113 @@
+use crate::decimals::*;
+use crate::structs::*;
+use crate::ErrorCode::*;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::system_program;
+use anchor_spl::token::{self, Mint, TokenAccount, Transfer};
+use invariant::structs::Pool;
+
+const MAX_TIME_BEFORE_START: u64 = 3_600; //hour in sec
+const MAX_DURATION: u64 = 31_556_926; //year in sec
+
+#[derive(Accounts)]
+#[instruction(nonce: u8)]
+pub struct InitBondSale<'info> {
+    #[account(init, payer = payer)]
+    pub bond_sale: AccountLoader<'info, BondSale>,
+    #[account(
+        constraint = (pool.load()?.token_x == token_bond.key() && pool.load()?.token_y == token_quote.key())
+            || (pool.load()?.token_x == token_quote.key() && pool.load()?.token_y == token_bond.key()) @ InvalidPool
+    )]
+    pub pool: AccountLoader<'info, Pool>,
+    pub token_bond: Account<'info, Mint>,
+    pub token_quote: Account<'info, Mint>,
+    #[account(init,
+        token::mint = token_bond,
+        token::authority = bonds_authority,
+        payer = payer,
+    )]
+    pub token_bond_reserve: Account<'info, TokenAccount>,
+    #[account(init,
+        token::mint = token_quote,
+        token::authority = bonds_authority,
+        payer = payer,
+    )]
+    pub token_quote_reserve: Account<'info, TokenAccount>,
+    #[account(mut,
+        constraint = payer_bond_account.key() != token_bond_reserve.key() @ InvalidTokenAccount,
+        constraint = payer_bond_account.mint == token_bond.key() @ InvalidMint,
+        constraint = payer_bond_account.owner == payer.key() @ InvalidOwner
+    )]
+    pub payer_bond_account: Account<'info, TokenAccount>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(seeds = [b"bonds".as_ref(), bond_sale.key().as_ref()], bump = nonce)]
+    pub bonds_authority: AccountInfo<'info>,
+    #[account(address = token::ID)]
+    pub token_program: AccountInfo<'info>,
+    #[account(address = system_program::ID)]
+    pub system_program: AccountInfo<'info>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+impl<'info> InitBondSale<'info> {
+    fn deposit(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        CpiContext::new(
+            self.token_program.to_account_info(),
+            Transfer {
+                from: self.payer_bond_account.to_account_info(),
+                to: self.token_bond_reserve.to_account_info(),
+                authority: self.payer.to_account_info().clone(),
+            },
+        )
+    }
+}
+
+pub fn handler(
+    ctx: Context<InitBondSale>,
+    _nonce: u8,
+    bond_amount: TokenAmount,
+    floor_price: Price,
+    up_bound: Portion,
+    velocity: Portion,
+    start_time: Seconds,
+    end_time: Seconds,
+    vesting_time: Seconds,
+) -> ProgramResult {
+    msg!("INIT BOND SALE");
+    require!(!bond_amount.is_zero(), ZeroAmount);
+    require!(!floor_price.is_zero(), ZeroPrice);
+    require!(end_time > start_time, InvalidSaleTime);
+    require!(
+        (start_time + Seconds::new(MAX_TIME_BEFORE_START)) >= Seconds::now(),
+        StartInPast
+    );
+    require!(
+        (Seconds::now() + Seconds::new(MAX_DURATION)) >= end_time,
+        TooLongDuration
+    );
+
+    let bond_sale = &mut ctx.accounts.bond_sale.load_init()?;
+
+    **bond_sale = BondSale {
+        token_bond: ctx.accounts.token_bond.key(),
+        token_quote: ctx.accounts.token_quote.key(),
+        token_bond_reserve: ctx.accounts.token_bond_reserve.key(),
+        token_quote_reserve: ctx.accounts.token_quote_reserve.key(),
+        payer: ctx.accounts.payer.key(),
+        bond_amount,
+        remaining_amount: bond_amount,
+        floor_price,
+        previous_price: floor_price,
+        up_bound,
+        velocity,
+        start_time,
+        end_time,
+        last_trade: start_time,
+        vesting_time,
+    };
+
+    token::transfer(ctx.accounts.deposit(), bond_amount.get())?;
+
+    Ok(())
+}
@@ -0,0 +1,9 @@
+pub mod buy_bond;
+pub mod claim_bond;
+pub mod end_bond_sale;
+pub mod init_bond_sale;
+
+pub use buy_bond::*;
+pub use claim_bond::*;
+pub use end_bond_sale::*;
+pub use init_bond_sale::*;
@@ -0,0 +1,60 @@
+mod decimals;
+mod errors;
+mod instructions;
+mod math;
+mod structs;
+mod util;
+
+use anchor_lang::prelude::*;
+
+use decimals::*;
+use errors::*;
+use instructions::*;
+
+declare_id!("AcniaXZvizbgTyp4SpWoAFzQJy2YaLR5okWnZ2SXvj7f");
+
+#[program]
+pub mod bonds {
+
+    use super::*;
+
+    pub fn init_bond_sale(
+        ctx: Context<InitBondSale>,
+        nonce: u8,
+        bond_amount: TokenAmount,
+        floor_price: Price,
+        up_bound: Portion,
+        velocity: Portion,
+        start_time: Seconds,
+        end_time: Seconds,
+        vesting_time: Seconds,
+    ) -> ProgramResult {
+        instructions::init_bond_sale::handler(
+            ctx,
+            nonce,
+            bond_amount,
+            floor_price,
+            up_bound,
+            velocity,
+            start_time,
+            end_time,
+            vesting_time,
+        )
+    }
+
+    pub fn buy_bond(
+        ctx: Context<BuyBond>,
+        amount: TokenAmount,
+        max_quote_amount: TokenAmount,
+    ) -> ProgramResult {
+        instructions::buy_bond::handler(ctx, amount, max_quote_amount)
+    }
+
+    pub fn claim_bond(ctx: Context<ClaimBond>, nonce: u8) -> ProgramResult {
+        instructions::claim_bond::handler(ctx, nonce)
+    }
+
+    pub fn end_bond_sale(ctx: Context<EndBondSale>, nonce: u8) -> ProgramResult {
+        instructions::end_bond_sale::handler(ctx, nonce)
+    }
+}
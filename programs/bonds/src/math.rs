@@ -0,0 +1,158 @@
+use crate::decimals::*;
+use crate::structs::*;
+
+pub fn calculate_new_price(
+    bond_sale: &mut BondSale,
+    current_time: Seconds,
+    buy_amount: TokenAmount,
+) -> Price {
+    let delta_time = current_time - bond_sale.last_trade;
+    let sale_time = bond_sale.end_time - bond_sale.start_time;
+    let time_ratio =
+        Portion::from_integer(delta_time.get()) / Portion::from_integer(sale_time.get());
+
+    let delta_price = bond_sale.floor_price * bond_sale.velocity * bond_sale.up_bound * time_ratio;
+    let supply_ratio = buy_amount.percent(bond_sale.bond_amount);
+
+    let price = match { bond_sale.previous_price } < { bond_sale.floor_price } + delta_price {
+        true => bond_sale.floor_price,
+        false => bond_sale.previous_price - delta_price,
+    };
+    let jump = bond_sale.floor_price * supply_ratio * bond_sale.up_bound;
+
+    bond_sale.previous_price = price + jump;
+    bond_sale.remaining_amount = bond_sale.remaining_amount - buy_amount;
+    bond_sale.last_trade = current_time;
+
+    price + jump * Portion::from_scale(5, 1)
+}
+
+pub fn calculate_quote_amount(price: Price, buy_amount: TokenAmount) -> TokenAmount {
+    TokenAmount::from_decimal_up(price.big_mul(buy_amount))
+}
+
+pub fn calculate_claimable_amount(bond: &Bond, current_time: Seconds) -> TokenAmount {
+    let vested = match current_time >= { bond.vesting_end } {
+        true => bond.token_bond_amount,
+        false => {
+            let elapsed = current_time - bond.vesting_start;
+            let vesting_time = bond.vesting_end - bond.vesting_start;
+            let vesting_ratio =
+                Portion::from_integer(elapsed.get()) / Portion::from_integer(vesting_time.get());
+            { bond.token_bond_amount }.big_mul(vesting_ratio)
+        }
+    };
+
+    vested - bond.claimed_amount
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    fn week_sale() -> BondSale {
+        BondSale {
+            bond_amount: TokenAmount::new(1_000_000),
+            remaining_amount: TokenAmount::new(1_000_000),
+            floor_price: Price::from_integer(2),
+            previous_price: Price::from_integer(2),
+            up_bound: Portion::from_integer(3),
+            velocity: Portion::from_integer(1),
+            start_time: Seconds::new(0),
+            end_time: Seconds::new(604_800),
+            last_trade: Seconds::new(0),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_calculate_new_price_whole_bond() {
+        // docs example: instantly buying the whole bond costs the average of 2 and 8
+        let mut bond_sale = week_sale();
+        let price =
+            calculate_new_price(&mut bond_sale, Seconds::new(0), TokenAmount::new(1_000_000));
+
+        assert_eq!(price, Price::from_integer(5));
+        assert_eq!({ bond_sale.previous_price }, Price::from_integer(8));
+        assert_eq!({ bond_sale.remaining_amount }, TokenAmount::new(0));
+        assert_eq!(
+            calculate_quote_amount(price, TokenAmount::new(1_000_000)),
+            TokenAmount::new(5_000_000)
+        );
+    }
+
+    #[test]
+    fn test_calculate_new_price_equal_trades() {
+        // docs example: 28 equal trades every 6 hours always pay the same price
+        let mut bond_sale = week_sale();
+        let buy_amount = TokenAmount::new(1_000_000 / 28);
+        let mut total = TokenAmount::new(0);
+        for i in 0..28 {
+            let price = calculate_new_price(&mut bond_sale, Seconds::new(i * 21_600), buy_amount);
+            assert_eq!(price, Price::new(2_107_142_000_000));
+            total = total + calculate_quote_amount(price, buy_amount);
+        }
+        assert_eq!(total, TokenAmount::new(2_107_140));
+    }
+
+    #[test]
+    fn test_calculate_new_price_decays_to_floor() {
+        let mut bond_sale = week_sale();
+        calculate_new_price(&mut bond_sale, Seconds::new(0), TokenAmount::new(500_000));
+        assert_eq!({ bond_sale.previous_price }, Price::from_integer(5));
+
+        // half of the sale time decays the price by 3
+        let price = calculate_new_price(&mut bond_sale, Seconds::new(302_400), TokenAmount::new(0));
+        assert_eq!(price, Price::from_integer(2));
+
+        let price = calculate_new_price(&mut bond_sale, Seconds::new(302_401), TokenAmount::new(0));
+        assert_eq!(price, Price::from_integer(2));
+    }
+
+    #[test]
+    fn test_calculate_claimable_amount() {
+        let mut bond = Bond {
+            token_bond_amount: TokenAmount::new(1000),
+            claimed_amount: TokenAmount::new(0),
+            vesting_start: Seconds::new(100),
+            vesting_end: Seconds::new(200),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            calculate_claimable_amount(&bond, Seconds::new(100)),
+            TokenAmount::new(0)
+        );
+        assert_eq!(
+            calculate_claimable_amount(&bond, Seconds::new(125)),
+            TokenAmount::new(250)
+        );
+
+        bond.claimed_amount = TokenAmount::new(250);
+        assert_eq!(
+            calculate_claimable_amount(&bond, Seconds::new(150)),
+            TokenAmount::new(250)
+        );
+        assert_eq!(
+            calculate_claimable_amount(&bond, Seconds::new(300)),
+            TokenAmount::new(750)
+        );
+    }
+
+    #[test]
+    fn test_calculate_claimable_amount_no_vesting() {
+        let bond = Bond {
+            token_bond_amount: TokenAmount::new(1000),
+            claimed_amount: TokenAmount::new(0),
+            vesting_start: Seconds::new(100),
+            vesting_end: Seconds::new(100),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            calculate_claimable_amount(&bond, Seconds::new(100)),
+            TokenAmount::new(1000)
+        );
+    }
+}
@@ -0,0 +1,14 @@
+use crate::decimals::*;
+use anchor_lang::prelude::*;
+
+#[account(zero_copy)]
+#[repr(packed)]
+#[derive(PartialEq, Default, Debug)]
+pub struct Bond {
+    pub bond_sale: Pubkey,
+    pub owner: Pubkey,
+    pub token_bond_amount: TokenAmount,
+    pub claimed_amount: TokenAmount,
+    pub vesting_start: Seconds,
+    pub vesting_end: Seconds,
+}
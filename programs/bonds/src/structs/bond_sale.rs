@@ -0,0 +1,23 @@
+use crate::decimals::*;
+use anchor_lang::prelude::*;
+
+#[account(zero_copy)]
+#[repr(packed)]
+#[derive(PartialEq, Default, Debug)]
+pub struct BondSale {
+    pub token_bond: Pubkey,
+    pub token_quote: Pubkey,
+    pub token_bond_reserve: Pubkey,
+    pub token_quote_reserve: Pubkey,
+    pub payer: Pubkey,
+    pub bond_amount: TokenAmount,
+    pub remaining_amount: TokenAmount,
+    pub floor_price: Price,
+    pub previous_price: Price,
+    pub up_bound: Portion,
+    pub velocity: Portion,
+    pub start_time: Seconds,
+    pub end_time: Seconds,
+    pub last_trade: Seconds,
+    pub vesting_time: Seconds,
+}
@@ -0,0 +1,5 @@
+pub mod bond;
+pub mod bond_sale;
+
+pub use bond::*;
+pub use bond_sale::*;
@@ -64,4 +64,34 @@ pub enum InvariantErrorCode {
     InvalidListOwner = 29, // 178d
     #[msg("Invalid tick spacing")]
     InvalidTickSpacing = 30, // 178e
+    #[msg("Swap would cross more ticks than allowed")]
+    TooManyTickCrosses = 31, // 178f
+    #[msg("Position range requires the other token")]
+    InvalidSingleSidedToken = 32, // 1790
+    #[msg("Position requires more tokens than the given maximum")]
+    MaxAmountExceeded = 33, // 1791
+    #[msg("Invalid fee distribution")]
+    InvalidFeeDistribution = 34, // 1792
+    #[msg("Flash loan is not repaid later in the transaction")]
+    FlashLoanNotRepaid = 35, // 1793
+    #[msg("Flash loan can't be invoked through CPI")]
+    FlashLoanCpi = 36, // 1794
+    #[msg("Provided token program is different than expected")]
+    InvalidTokenProgram = 37, // 1795
+    #[msg("Mint extension is not supported")]
+    UnsupportedMintExtension = 38, // 1796
+    #[msg("Position is locked by a stake")]
+    PositionLocked = 39, // 1797
+    #[msg("Amount with transfer fee overflows")]
+    TransferFeeOverflow = 40, // 1798
+    #[msg("Liquidity for the given amount overflows")]
+    LiquidityOverflow = 41, // 1799
+    #[msg("Protocol fee has to be distributed through the fee distribution")]
+    FeeDistributionExists = 42, // 179a
+    #[msg("Position is locked by too many stakes")]
+    TooManyStakes = 43, // 179b
+    #[msg("Position is not in the legacy layout")]
+    InvalidPositionLayout = 44, // 179c
+    #[msg("Position is not locked by any stake")]
+    PositionNotLocked = 45, // 179d
 }
@@ -3,6 +3,7 @@ pub mod errors;
 pub mod log;
 pub mod macros;
 pub mod math;
+pub mod return_data;
 pub mod structs;
 pub mod utils;
 
@@ -1,5 +1,5 @@
 use crate::{err, from_result, function, location, ok_or_mark_trace, trace};
-use std::{cell::RefMut, convert::TryInto};
+use std::{cell::RefMut, cmp::min, convert::TryInto};
 
 use anchor_lang::*;
 
@@ -118,7 +118,7 @@ pub fn get_closer_limit(
             }
         }
         None => {
-            let index = get_search_limit(current_tick, tick_spacing, !x_to_y);
+            let index = get_search_limit(tick_spacing, !x_to_y);
             let price = calculate_price_sqrt(index);
 
             require!(current_tick != index, InvariantErrorCode::LimitReached);
@@ -309,6 +309,148 @@ pub fn get_delta_y(
     }
 }
 
+// L = delta_x * lower_sqrt_price * upper_sqrt_price / delta_sqrt_price
+pub fn get_liquidity_by_x(
+    x: TokenAmount,
+    sqrt_price_a: Price,
+    sqrt_price_b: Price,
+    up: bool,
+) -> TrackableResult<Liquidity> {
+    let delta_price = if sqrt_price_a > sqrt_price_b {
+        sqrt_price_a - sqrt_price_b
+    } else {
+        sqrt_price_b - sqrt_price_a
+    };
+    if delta_price.is_zero() {
+        return Err(err!(TrackableError::DIV));
+    }
+
+    // log(2, 2^96 * 2^96 / 10^24 * 2^64 * 10^6) = 194.5
+    let price_product = match up {
+        true => sqrt_price_a.big_mul_to_value_up(sqrt_price_b),
+        false => sqrt_price_a.big_mul_to_value(sqrt_price_b),
+    };
+    let nominator = price_product
+        .checked_mul(U256::from(x.get()))
+        .ok_or_else(|| err!(TrackableError::MUL))?
+        .checked_mul(Liquidity::one())
+        .ok_or_else(|| err!(TrackableError::MUL))?;
+
+    ok_or_mark_trace!(div_to_liquidity(
+        nominator,
+        U256::from(delta_price.get()),
+        up
+    ))
+}
+
+// L = delta_y / delta_sqrt_price
+pub fn get_liquidity_by_y(
+    y: TokenAmount,
+    sqrt_price_a: Price,
+    sqrt_price_b: Price,
+    up: bool,
+) -> TrackableResult<Liquidity> {
+    let delta_price = if sqrt_price_a > sqrt_price_b {
+        sqrt_price_a - sqrt_price_b
+    } else {
+        sqrt_price_b - sqrt_price_a
+    };
+    if delta_price.is_zero() {
+        return Err(err!(TrackableError::DIV));
+    }
+
+    // log(2, 2^64 * 10^24 * 10^6) = 163.7
+    let nominator = U256::from(y.get())
+        .checked_mul(Price::one())
+        .ok_or_else(|| err!(TrackableError::MUL))?
+        .checked_mul(Liquidity::one())
+        .ok_or_else(|| err!(TrackableError::MUL))?;
+
+    ok_or_mark_trace!(div_to_liquidity(
+        nominator,
+        U256::from(delta_price.get()),
+        up
+    ))
+}
+
+fn div_to_liquidity(nominator: U256, denominator: U256, up: bool) -> TrackableResult<Liquidity> {
+    let nominator = match up {
+        true => nominator
+            .checked_add(denominator - U256::from(1u32))
+            .ok_or_else(|| err!(TrackableError::ADD))?,
+        false => nominator,
+    };
+    let liquidity = nominator
+        .checked_div(denominator)
+        .ok_or_else(|| err!(TrackableError::DIV))?;
+
+    Ok(Liquidity::new(liquidity.try_into().map_err(|_| {
+        err!(TrackableError::cast::<Liquidity>().as_str())
+    })?))
+}
+
+// maximal liquidity in the tick range that requires at most x and y at sqrt_price
+pub fn get_liquidity(
+    x: TokenAmount,
+    y: TokenAmount,
+    lower_tick: i32,
+    upper_tick: i32,
+    sqrt_price: Price,
+    up: bool,
+) -> TrackableResult<Liquidity> {
+    if lower_tick >= upper_tick {
+        return Err(err!("lower tick has to be smaller than upper tick"));
+    }
+    let lower_sqrt_price = calculate_price_sqrt(lower_tick);
+    let upper_sqrt_price = calculate_price_sqrt(upper_tick);
+
+    if sqrt_price <= lower_sqrt_price {
+        ok_or_mark_trace!(get_liquidity_by_x(
+            x,
+            lower_sqrt_price,
+            upper_sqrt_price,
+            up
+        ))
+    } else if sqrt_price < upper_sqrt_price {
+        // overflow on one side means that side doesn't limit the liquidity
+        match (
+            get_liquidity_by_x(x, sqrt_price, upper_sqrt_price, up),
+            get_liquidity_by_y(y, lower_sqrt_price, sqrt_price, up),
+        ) {
+            (Err(mut err), Err(_)) => Err(trace!(err)),
+            (liquidity_x, liquidity_y) => Ok(min(
+                liquidity_x.unwrap_or(Liquidity::max_instance()),
+                liquidity_y.unwrap_or(Liquidity::max_instance()),
+            )),
+        }
+    } else {
+        ok_or_mark_trace!(get_liquidity_by_y(
+            y,
+            lower_sqrt_price,
+            upper_sqrt_price,
+            up
+        ))
+    }
+}
+
+// maximal liquidity of a position that doesn't require more than given amounts at the current price
+pub fn calculate_max_liquidity(
+    pool: &Pool,
+    max_amount_x: TokenAmount,
+    max_amount_y: TokenAmount,
+    upper_tick: i32,
+    lower_tick: i32,
+) -> TrackableResult<Liquidity> {
+    ok_or_mark_trace!(get_liquidity(
+        max_amount_x,
+        max_amount_y,
+        lower_tick,
+        upper_tick,
+        pool.sqrt_price,
+        false
+    ))
+}
+
 fn get_next_sqrt_price_from_input(
     price_sqrt: Price,
     liquidity: Liquidity,
@@ -540,7 +682,8 @@ mod tests {
     use crate::{
         decimals::{FixedPoint, Liquidity, Price, TokenAmount},
         math::{
-            compute_swap_step, get_delta_x, get_delta_y, get_max_sqrt_price, get_max_tick,
+            calculate_price_sqrt, compute_swap_step, get_delta_x, get_delta_y, get_liquidity,
+            get_liquidity_by_x, get_liquidity_by_y, get_max_sqrt_price, get_max_tick,
             get_min_sqrt_price, get_min_tick, get_next_sqrt_price_x_up, get_next_sqrt_price_y_down,
             SwapResult,
         },
@@ -549,7 +692,7 @@ mod tests {
         MAX_SQRT_PRICE, MIN_SQRT_PRICE,
     };
 
-    use super::{calculate_price_sqrt, is_enough_amount_to_push_price};
+    use super::is_enough_amount_to_push_price;
 
     #[test]
     fn test_compute_swap_step() {
@@ -1560,6 +1703,253 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_get_liquidity_by_x() {
+        // equal at equal liquidity
+        {
+            let result = get_liquidity_by_x(
+                TokenAmount(1),
+                Price::from_integer(1u8),
+                Price::from_integer(2u8),
+                false,
+            )
+            .unwrap();
+            assert_eq!(result, Liquidity::from_integer(2u8));
+        }
+        // complex
+        {
+            let sqrt_price_a = Price::new(234__878_324_943_782_000000000000);
+            let sqrt_price_b = Price::new(87__854_456_421_658_000000000000);
+
+            let result_down =
+                get_liquidity_by_x(TokenAmount(7010), sqrt_price_a, sqrt_price_b, false).unwrap();
+            let result_up =
+                get_liquidity_by_x(TokenAmount(7011), sqrt_price_a, sqrt_price_b, true).unwrap();
+
+            // 983868.166929...
+            assert_eq!(result_down, Liquidity::new(983_868__166_929));
+            // 984008.519022...
+            assert_eq!(result_up, Liquidity::new(984_008__519_023));
+        }
+        // zero range
+        {
+            let (_, cause, stack) = get_liquidity_by_x(
+                TokenAmount(1),
+                Price::from_integer(1u8),
+                Price::from_integer(1u8),
+                false,
+            )
+            .unwrap_err()
+            .get();
+
+            assert_eq!(cause, TrackableError::DIV);
+            assert_eq!(stack.len(), 1);
+        }
+        // overflow
+        {
+            let (_, cause, stack) = get_liquidity_by_x(
+                TokenAmount(u64::MAX),
+                Price::from_integer(1u8),
+                Price::new(Price::one()) + Price::new(1),
+                false,
+            )
+            .unwrap_err()
+            .get();
+
+            assert_eq!(cause, TrackableError::cast::<Liquidity>());
+            assert_eq!(stack.len(), 2);
+        }
+    }
+
+    #[test]
+    fn test_get_liquidity_by_y() {
+        // equal at equal liquidity
+        {
+            let result = get_liquidity_by_y(
+                TokenAmount(2),
+                Price::from_integer(1u8),
+                Price::from_integer(2u8),
+                false,
+            )
+            .unwrap();
+            assert_eq!(result, Liquidity::from_integer(2u8));
+        }
+        // complex
+        {
+            let sqrt_price_a = Price::new(234__878_324_943_782_000000000000);
+            let sqrt_price_b = Price::new(87__854_456_421_658_000000000000);
+
+            let result_down =
+                get_liquidity_by_y(TokenAmount(144669023), sqrt_price_a, sqrt_price_b, false)
+                    .unwrap();
+            let result_up =
+                get_liquidity_by_y(TokenAmount(144669024), sqrt_price_a, sqrt_price_b, true)
+                    .unwrap();
+
+            // 983983.243361...
+            assert_eq!(result_down, Liquidity::new(983_983__243_361));
+            // 983983.250163...
+            assert_eq!(result_up, Liquidity::new(983_983__250_164));
+        }
+        // zero range
+        {
+            let (_, cause, stack) = get_liquidity_by_y(
+                TokenAmount(1),
+                Price::from_integer(1u8),
+                Price::from_integer(1u8),
+                false,
+            )
+            .unwrap_err()
+            .get();
+
+            assert_eq!(cause, TrackableError::DIV);
+            assert_eq!(stack.len(), 1);
+        }
+        // overflow
+        {
+            let (_, cause, stack) = get_liquidity_by_y(
+                TokenAmount(u64::MAX),
+                Price::from_integer(1u8),
+                Price::new(Price::one()) + Price::new(1),
+                false,
+            )
+            .unwrap_err()
+            .get();
+
+            assert_eq!(cause, TrackableError::cast::<Liquidity>());
+            assert_eq!(stack.len(), 2);
+        }
+    }
+
+    #[test]
+    fn test_get_liquidity_inverse_of_delta() {
+        let ranges = [
+            (-221818, -100),
+            (-10, 10),
+            (0, 1),
+            (100, 221818),
+            (-50000, 50000),
+            (5, 23000),
+        ];
+        let liquidities = [
+            Liquidity::new(1),
+            Liquidity::from_integer(1),
+            Liquidity::new(123456789),
+            Liquidity::new(983_983__249_092),
+            Liquidity::from_integer(u64::MAX),
+        ];
+        let amounts = [1, 1000, 1_000_000_000, u64::MAX];
+
+        for &(lower_tick, upper_tick) in ranges.iter() {
+            let sqrt_price_a = calculate_price_sqrt(lower_tick);
+            let sqrt_price_b = calculate_price_sqrt(upper_tick);
+
+            // liquidity -> amount -> liquidity doesn't gain with rounding down and doesn't lose with rounding up
+            for &liquidity in liquidities.iter() {
+                if let Some(x) = get_delta_x(sqrt_price_a, sqrt_price_b, liquidity, false) {
+                    let result = get_liquidity_by_x(x, sqrt_price_a, sqrt_price_b, false).unwrap();
+                    assert!(result <= liquidity);
+                }
+                if let Some(x) = get_delta_x(sqrt_price_a, sqrt_price_b, liquidity, true) {
+                    let result = get_liquidity_by_x(x, sqrt_price_a, sqrt_price_b, true).unwrap();
+                    assert!(result >= liquidity);
+                }
+                if let Some(y) = get_delta_y(sqrt_price_a, sqrt_price_b, liquidity, false) {
+                    let result = get_liquidity_by_y(y, sqrt_price_a, sqrt_price_b, false).unwrap();
+                    assert!(result <= liquidity);
+                }
+                if let Some(y) = get_delta_y(sqrt_price_a, sqrt_price_b, liquidity, true) {
+                    let result = get_liquidity_by_y(y, sqrt_price_a, sqrt_price_b, true).unwrap();
+                    assert!(result >= liquidity);
+                }
+            }
+
+            // amount -> liquidity rounded down never requires more than the amount
+            for &amount in amounts.iter() {
+                let amount = TokenAmount(amount);
+                if let Ok(liquidity) = get_liquidity_by_x(amount, sqrt_price_a, sqrt_price_b, false)
+                {
+                    let x = get_delta_x(sqrt_price_a, sqrt_price_b, liquidity, true).unwrap();
+                    assert!(x <= amount);
+                }
+                if let Ok(liquidity) = get_liquidity_by_y(amount, sqrt_price_a, sqrt_price_b, false)
+                {
+                    let y = get_delta_y(sqrt_price_a, sqrt_price_b, liquidity, true).unwrap();
+                    assert!(y <= amount);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_get_liquidity() {
+        let x = TokenAmount(1_000_000);
+        let y = TokenAmount(2_000_000);
+        let lower_tick = -100;
+        let upper_tick = 100;
+        let lower_sqrt_price = calculate_price_sqrt(lower_tick);
+        let upper_sqrt_price = calculate_price_sqrt(upper_tick);
+
+        // price inside the range
+        {
+            let sqrt_price = Price::from_integer(1);
+            let liquidity = get_liquidity(x, y, lower_tick, upper_tick, sqrt_price, false).unwrap();
+
+            let needed_x = get_delta_x(sqrt_price, upper_sqrt_price, liquidity, true).unwrap();
+            let needed_y = get_delta_y(lower_sqrt_price, sqrt_price, liquidity, true).unwrap();
+            assert!(needed_x <= x);
+            assert!(needed_y <= y);
+            // x is the limiting token
+            assert_eq!(
+                liquidity,
+                get_liquidity_by_x(x, sqrt_price, upper_sqrt_price, false).unwrap()
+            );
+        }
+        // price below the range requires only x
+        {
+            let sqrt_price = calculate_price_sqrt(-200);
+            let liquidity =
+                get_liquidity(x, TokenAmount(0), lower_tick, upper_tick, sqrt_price, false)
+                    .unwrap();
+            assert_eq!(
+                liquidity,
+                get_liquidity_by_x(x, lower_sqrt_price, upper_sqrt_price, false).unwrap()
+            );
+        }
+        // price above the range requires only y
+        {
+            let sqrt_price = calculate_price_sqrt(200);
+            let liquidity =
+                get_liquidity(TokenAmount(0), y, lower_tick, upper_tick, sqrt_price, false)
+                    .unwrap();
+            assert_eq!(
+                liquidity,
+                get_liquidity_by_y(y, lower_sqrt_price, upper_sqrt_price, false).unwrap()
+            );
+        }
+        // price right below the upper tick, liquidity by x overflows and only y limits it
+        {
+            let x = TokenAmount(u64::MAX);
+            let sqrt_price = Price::new(upper_sqrt_price.get() - 1);
+            assert!(get_liquidity_by_x(x, sqrt_price, upper_sqrt_price, false).is_err());
+
+            let liquidity = get_liquidity(x, y, lower_tick, upper_tick, sqrt_price, false).unwrap();
+            assert_eq!(
+                liquidity,
+                get_liquidity_by_y(y, lower_sqrt_price, sqrt_price, false).unwrap()
+            );
+        }
+        // invalid range
+        {
+            let (_, cause, stack) =
+                get_liquidity(x, y, upper_tick, lower_tick, Price::from_integer(1), false)
+                    .unwrap_err()
+                    .get();
+            assert_eq!(cause, "lower tick has to be smaller than upper tick");
+            assert_eq!(stack.len(), 1);
+        }
+    }
+
     #[test]
     fn test_get_next_sqrt_price_x_up() {
         // basic samples
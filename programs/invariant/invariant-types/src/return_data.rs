@@ -0,0 +1,99 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::get_return_data;
+
+use crate::{
+    decimals::Price,
+    err, from_result, function, location,
+    utils::{TrackableError, TrackableResult},
+    ID,
+};
+
+// Mirrors data set by invariant program after swap, remove_position and claim_fee
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq)]
+pub struct SwapReturnData {
+    pub amount_in: u64,
+    pub amount_out: u64,
+    pub sqrt_price: Price, // price after swap
+    pub current_tick_index: i32,
+    pub initialized_crosses: u16,
+    pub virtual_crosses: u16,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq)]
+pub struct RemovePositionReturnData {
+    pub amount_x: u64,
+    pub amount_y: u64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq)]
+pub struct ClaimFeeReturnData {
+    pub amount_x: u64,
+    pub amount_y: u64,
+}
+
+pub fn decode_return_data<T: AnchorDeserialize>(
+    program_id: &Pubkey,
+    data: &[u8],
+) -> TrackableResult<T> {
+    if *program_id != ID {
+        return Err(err!("return data was set by different program"));
+    }
+    from_result!(T::try_from_slice(data).map_err(|_| "invalid return data"))
+}
+
+// to be called right after CPI into invariant
+pub fn read_return_data<T: AnchorDeserialize>() -> TrackableResult<T> {
+    let (program_id, data) = get_return_data().ok_or_else(|| err!("no return data"))?;
+    decode_return_data(&program_id, &data)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use decimal::Decimal;
+
+    #[test]
+    fn test_decode_return_data() {
+        // swap
+        {
+            let result = SwapReturnData {
+                amount_in: 1000,
+                amount_out: 995,
+                sqrt_price: Price::new(1_000_500_000_000_000_000_000_000),
+                current_tick_index: -10,
+                initialized_crosses: 2,
+                virtual_crosses: 7,
+            };
+            let data = result.try_to_vec().unwrap();
+            let decoded: SwapReturnData = decode_return_data(&ID, &data).unwrap();
+            assert_eq!(decoded, result);
+        }
+        // remove position
+        {
+            let result = RemovePositionReturnData {
+                amount_x: 7,
+                amount_y: u64::MAX,
+            };
+            let data = result.try_to_vec().unwrap();
+            let decoded: RemovePositionReturnData = decode_return_data(&ID, &data).unwrap();
+            assert_eq!(decoded, result);
+        }
+        // different program
+        {
+            let data = ClaimFeeReturnData {
+                amount_x: 1,
+                amount_y: 2,
+            }
+            .try_to_vec()
+            .unwrap();
+            let result = decode_return_data::<ClaimFeeReturnData>(&Pubkey::default(), &data);
+            assert!(result.is_err());
+        }
+        // too short data
+        {
+            let result = decode_return_data::<SwapReturnData>(&ID, &[0u8; 8]);
+            assert!(result.is_err());
+        }
+    }
+}
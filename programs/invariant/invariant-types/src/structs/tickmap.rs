@@ -19,10 +19,10 @@ impl Default for Tickmap {
 size!(Tickmap);
 
 pub const TICK_LIMIT: i32 = 44_364; // If you change it update length of array as well!
-pub const TICK_SEARCH_RANGE: i32 = 256;
 pub const MAX_TICK: i32 = 221_818; // log(1.0001, sqrt(2^64-1))
 pub const TICK_CROSSES_PER_IX: usize = 19;
 pub const TICKMAP_SIZE: i32 = 2 * TICK_LIMIT - 1;
+const WORD_SIZE: usize = 64; // bits scanned at once during search
 
 fn tick_to_position(tick: i32, tick_spacing: u16) -> (usize, u8) {
     assert_eq!(
@@ -43,137 +43,131 @@ fn tick_to_position(tick: i32, tick_spacing: u16) -> (usize, u8) {
     (byte, bit)
 }
 
-// tick_spacing - spacing already scaled by tick_spacing
-pub fn get_search_limit(tick: i32, tick_spacing: u16, up: bool) -> i32 {
-    let index = tick / tick_spacing as i32;
+fn tick_to_index(tick: i32, tick_spacing: u16) -> usize {
+    assert_eq!(
+        (tick % tick_spacing as i32),
+        0,
+        "tick not divisible by spacing"
+    );
+
+    tick.checked_div(tick_spacing.try_into().unwrap())
+        .unwrap()
+        .checked_add(TICK_LIMIT)
+        .unwrap()
+        .try_into()
+        .unwrap()
+}
 
-    // limit unsclaed
+fn index_to_tick(index: usize, tick_spacing: u16) -> i32 {
+    // no possibility to overflow
+    let index: i32 = index.try_into().unwrap();
+
+    index
+        .checked_sub(TICK_LIMIT)
+        .unwrap()
+        .checked_mul(tick_spacing.try_into().unwrap())
+        .unwrap()
+}
+
+// search isn't limited to a range around the current tick, searches during a swap move in one
+// direction so a swap reads each of ~1387 words of the bitmap about once however sparse it is
+pub fn get_search_limit(tick_spacing: u16, up: bool) -> i32 {
     let limit = if up {
         // ticks are limited by amount of space in the bitmap...
         let array_limit = TICK_LIMIT.checked_sub(1).unwrap();
-        // ...search range is limited to 256 at the time ...
-        let range_limit = index.checked_add(TICK_SEARCH_RANGE).unwrap();
         // ...also ticks for prices over 2^64 aren't needed
         let price_limit = MAX_TICK.checked_div(tick_spacing as i32).unwrap();
 
-        array_limit.min(range_limit).min(price_limit)
+        array_limit.min(price_limit)
     } else {
         let array_limit = (-TICK_LIMIT).checked_add(1).unwrap();
-        let range_limit = index.checked_sub(TICK_SEARCH_RANGE).unwrap();
         let price_limit = -MAX_TICK.checked_div(tick_spacing as i32).unwrap();
 
-        array_limit.max(range_limit).max(price_limit)
+        array_limit.max(price_limit)
     };
 
-    // scaled by tick_spacing
     limit.checked_mul(tick_spacing as i32).unwrap()
 }
 
 impl Tickmap {
     pub fn next_initialized(&self, tick: i32, tick_spacing: u16) -> Option<i32> {
-        let limit = get_search_limit(tick, tick_spacing, true);
+        let limit = get_search_limit(tick_spacing, true);
 
         // add 1 to not check current tick
-        let (mut byte, mut bit) =
-            tick_to_position(tick.checked_add(tick_spacing as i32).unwrap(), tick_spacing);
-        let (limiting_byte, limiting_bit) = tick_to_position(limit, tick_spacing);
-
-        while byte < limiting_byte || (byte == limiting_byte && bit <= limiting_bit) {
-            // ignore some bits on first loop
-            let mut shifted = self.bitmap[byte] >> bit;
-
-            // go through all bits in byte until it is zero
-            if shifted != 0 {
-                while shifted.checked_rem(2).unwrap() == 0 {
-                    shifted >>= 1;
-                    bit = bit.checked_add(1).unwrap();
-                }
-
-                return if byte < limiting_byte || (byte == limiting_byte && bit <= limiting_bit) {
-                    let index: i32 = byte
-                        .checked_mul(8)
-                        .unwrap()
-                        .checked_add(bit.into())
-                        .unwrap()
-                        .try_into()
-                        .unwrap();
-                    Some(
-                        index
-                            .checked_sub(TICK_LIMIT)
-                            .unwrap()
-                            .checked_mul(tick_spacing.try_into().unwrap())
-                            .unwrap(),
-                    )
-                } else {
-                    None
+        let index = tick_to_index(tick.checked_add(tick_spacing as i32).unwrap(), tick_spacing);
+        let limiting_index = tick_to_index(limit, tick_spacing);
+
+        if index > limiting_index {
+            return None;
+        }
+
+        let mut word = index / WORD_SIZE;
+        // ignore lower bits on first word
+        let mut value = self.get_word(word) & (u64::MAX << (index % WORD_SIZE));
+
+        loop {
+            if value != 0 {
+                let found = word * WORD_SIZE + value.trailing_zeros() as usize;
+
+                // return first initalized tick if limit is not exceeded, otherwise return None
+                return match found <= limiting_index {
+                    true => Some(index_to_tick(found, tick_spacing)),
+                    false => None,
                 };
             }
 
-            // go to the text byte
-            if let Some(value) = byte.checked_add(1) {
-                byte = value;
-            } else {
+            // go to the next word
+            word = word.checked_add(1)?;
+            if word * WORD_SIZE > limiting_index {
                 return None;
             }
-            bit = 0;
+            value = self.get_word(word);
         }
-
-        None
     }
 
     // tick_spacing - spacing already scaled by tick_spacing
     pub fn prev_initialized(&self, tick: i32, tick_spacing: u16) -> Option<i32> {
         // don't subtract 1 to check the current tick
-        let limit = get_search_limit(tick, tick_spacing, false); // limit scaled by tick_spacing
-        let (mut byte, mut bit) = tick_to_position(tick as i32, tick_spacing);
-        let (limiting_byte, limiting_bit) = tick_to_position(limit, tick_spacing);
-
-        while byte > limiting_byte || (byte == limiting_byte && bit >= limiting_bit) {
-            // always safe due to limitated domain of bit variable
-            let mut mask = 1u16.checked_shl(bit.try_into().unwrap()).unwrap(); // left = MSB direction (increase value)
-            let value = self.bitmap[byte] as u16;
-
-            // enter if some of previous bits are initialized in current byte
-            if value.checked_rem(mask.checked_shl(1).unwrap()).unwrap() > 0 {
-                // skip uninitalized ticks
-                while value & mask == 0 {
-                    mask >>= 1;
-                    bit = bit.checked_sub(1).unwrap();
-                }
-
-                // return first initalized tick if limiit is not exceeded, otherswise return None
-                return if byte > limiting_byte || (byte == limiting_byte && bit >= limiting_bit) {
-                    // no possibility to overflow
-                    let index: i32 = byte
-                        .checked_mul(8)
-                        .unwrap()
-                        .checked_add(bit.into())
-                        .unwrap()
-                        .try_into()
-                        .unwrap();
-
-                    Some(
-                        index
-                            .checked_sub(TICK_LIMIT)
-                            .unwrap()
-                            .checked_mul(tick_spacing.try_into().unwrap())
-                            .unwrap(),
-                    )
-                } else {
-                    None
+        let limit = get_search_limit(tick_spacing, false); // limit scaled by tick_spacing
+        let index = tick_to_index(tick, tick_spacing);
+        let limiting_index = tick_to_index(limit, tick_spacing);
+
+        if index < limiting_index {
+            return None;
+        }
+
+        let mut word = index / WORD_SIZE;
+        // ignore higher bits on first word (MSB direction)
+        let mut value = self.get_word(word) & (u64::MAX >> (WORD_SIZE - 1 - index % WORD_SIZE));
+
+        loop {
+            if value != 0 {
+                let found = word * WORD_SIZE + (WORD_SIZE - 1) - value.leading_zeros() as usize;
+
+                // return first initalized tick if limit is not exceeded, otherwise return None
+                return match found >= limiting_index {
+                    true => Some(index_to_tick(found, tick_spacing)),
+                    false => None,
                 };
             }
 
-            // go to the next byte
-            if let Some(value) = byte.checked_sub(1) {
-                byte = value;
-            } else {
+            // go to the previous word
+            if word == 0 || word * WORD_SIZE <= limiting_index {
                 return None;
             }
-            bit = 7;
+            word -= 1;
+            value = self.get_word(word);
         }
+    }
+
+    // reads 64 consecutive bits of bitmap, missing bytes past the end are zeros
+    fn get_word(&self, word: usize) -> u64 {
+        let start = word * WORD_SIZE / 8;
+        let end = (start + 8).min(self.bitmap.len());
+        let mut bytes = [0u8; 8];
+        bytes[..end - start].copy_from_slice(&self.bitmap[start..end]);
 
-        None
+        u64::from_le_bytes(bytes)
     }
 
     pub fn get(&self, tick: i32, tick_spacing: u16) -> bool {
@@ -199,6 +193,42 @@ impl Tickmap {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_search_across_words() {
+        // wide gap
+        {
+            let mut map = Tickmap::default();
+            map.flip(true, -8_000, 1);
+            map.flip(true, 8_000, 1);
+            assert_eq!(map.next_initialized(-8_000, 1), Some(8_000));
+            assert_eq!(map.prev_initialized(7_999, 1), Some(-8_000));
+        }
+        // edges of bitmap
+        {
+            let mut map = Tickmap::default();
+            map.flip(true, TICK_LIMIT - 1, 1);
+            map.flip(true, -TICK_LIMIT + 1, 1);
+            assert_eq!(
+                map.next_initialized(TICK_LIMIT - 100, 1),
+                Some(TICK_LIMIT - 1)
+            );
+            assert_eq!(
+                map.prev_initialized(-TICK_LIMIT + 100, 1),
+                Some(-TICK_LIMIT + 1)
+            );
+            assert_eq!(map.next_initialized(TICK_LIMIT - 1, 1), None);
+        }
+        // bits of the starting word
+        {
+            let mut map = Tickmap::default();
+            map.flip(true, 3, 1);
+            map.flip(true, 5, 1);
+            assert_eq!(map.next_initialized(3, 1), Some(5));
+            assert_eq!(map.prev_initialized(4, 1), Some(3));
+            assert_eq!(map.prev_initialized(5, 1), Some(5));
+        }
+    }
+
     #[test]
     fn test_next_and_prev_initialized() {
         // initalized edges
@@ -217,17 +247,12 @@ mod tests {
                 map.flip(true, max_index, spacing as u16);
                 map.flip(true, min_index, spacing as u16);
 
-                let tick_edge_diff = TICK_SEARCH_RANGE / spacing * spacing;
-
-                let prev = map.prev_initialized(min_index + tick_edge_diff, spacing as u16);
-                let next = map.next_initialized(max_index - tick_edge_diff, spacing as u16);
+                // search reaches the opposite edge of the bitmap
+                let prev = map.prev_initialized(max_index - spacing, spacing as u16);
+                let next = map.next_initialized(min_index, spacing as u16);
 
-                if prev.is_some() {
-                    println!("found prev = {}", prev.unwrap());
-                }
-                if next.is_some() {
-                    println!("found next = {}", next.unwrap());
-                }
+                assert_eq!(prev, Some(min_index));
+                assert_eq!(next, Some(max_index));
             }
         }
         // unintalized edges
@@ -239,17 +264,11 @@ mod tests {
                 false => (MAX_TICK / spacing) * spacing,
             };
             let min_index = -max_index;
-            let tick_edge_diff = TICK_SEARCH_RANGE / spacing * spacing;
-
-            let prev = map.prev_initialized(min_index + tick_edge_diff, spacing as u16);
-            let next = map.next_initialized(max_index - tick_edge_diff, spacing as u16);
+            let prev = map.prev_initialized(max_index, spacing as u16);
+            let next = map.next_initialized(min_index, spacing as u16);
 
-            if prev.is_some() {
-                println!("found prev = {}", prev.unwrap());
-            }
-            if next.is_some() {
-                println!("found next = {}", next.unwrap());
-            }
+            assert_eq!(prev, None);
+            assert_eq!(next, None);
         }
     }
 }
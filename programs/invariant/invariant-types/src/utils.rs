@@ -2,7 +2,12 @@ use std::cmp::Ordering;
 
 use anchor_lang::prelude::Pubkey;
 
-use crate::ID;
+use crate::{
+    decimals::Price,
+    math::calculate_price_sqrt,
+    structs::{get_search_limit, Tickmap, TICK_CROSSES_PER_IX},
+    ID, TICK_SEED,
+};
 
 pub type TrackableResult<T> = Result<T, TrackableError>;
 
@@ -78,6 +83,63 @@ pub fn get_pool_address(
     pool_address
 }
 
+pub fn get_tick_address(pool: Pubkey, index: i32) -> Pubkey {
+    let (tick_address, _) = Pubkey::find_program_address(
+        &[TICK_SEED.as_bytes(), pool.as_ref(), &index.to_le_bytes()],
+        &ID,
+    );
+    tick_address
+}
+
+// Predicts initialized ticks crossed by swap up to sqrt_price_limit
+// returns their addresses in order of crossing (capped at TICK_CROSSES_PER_IX)
+pub fn get_swap_tick_addresses(
+    tickmap: &Tickmap,
+    pool: Pubkey,
+    current_tick_index: i32,
+    tick_spacing: u16,
+    x_to_y: bool,
+    sqrt_price_limit: Price,
+) -> Vec<Pubkey> {
+    let mut addresses = Vec::new();
+    let mut current = current_tick_index;
+
+    while addresses.len() < TICK_CROSSES_PER_IX {
+        let (index, initialized) = match x_to_y {
+            true => tickmap.prev_initialized(current, tick_spacing),
+            false => tickmap.next_initialized(current, tick_spacing),
+        }
+        .map(|index| (index, true))
+        // nothing initialized up to the edge of the bitmap, continue to it as a virtual tick
+        .unwrap_or_else(|| (get_search_limit(tick_spacing, !x_to_y), false));
+
+        if index == current && !initialized {
+            break;
+        }
+
+        let price = calculate_price_sqrt(index);
+        let is_before_limit = match x_to_y {
+            true => price > sqrt_price_limit,
+            false => price < sqrt_price_limit,
+        };
+        if !is_before_limit {
+            break;
+        }
+
+        if initialized {
+            addresses.push(get_tick_address(pool, index));
+        }
+
+        // below crossed tick if price is going down
+        current = match x_to_y && initialized {
+            true => index.checked_sub(tick_spacing as i32).unwrap(),
+            false => index,
+        };
+    }
+
+    addresses
+}
+
 #[macro_use]
 pub mod trackable_result {
     #[macro_export]
@@ -223,4 +285,90 @@ mod tests {
         assert_eq!(pool_address_1, expected);
         assert_eq!(pool_address_2, expected);
     }
+
+    #[test]
+    fn test_get_swap_tick_addresses() {
+        use super::*;
+        use crate::structs::TICK_LIMIT;
+        use decimal::Decimal;
+
+        let pool = Pubkey::from_str("BRt1iVYDNoohkL1upEb8UfHE8yji6gEDAmuN9Y4yekyc").unwrap();
+        let mut tickmap = Tickmap::default();
+        let tick_spacing = 10;
+        for index in [-100, -10, 0, 50] {
+            tickmap.flip(true, index, tick_spacing);
+        }
+        // up to the limit
+        {
+            let result = get_swap_tick_addresses(
+                &tickmap,
+                pool,
+                0,
+                tick_spacing,
+                false,
+                calculate_price_sqrt(100),
+            );
+            assert_eq!(result, vec![get_tick_address(pool, 50)]);
+        }
+        // down including current tick
+        {
+            let result = get_swap_tick_addresses(
+                &tickmap,
+                pool,
+                0,
+                tick_spacing,
+                true,
+                calculate_price_sqrt(-1000),
+            );
+            let expected = vec![
+                get_tick_address(pool, 0),
+                get_tick_address(pool, -10),
+                get_tick_address(pool, -100),
+            ];
+            assert_eq!(result, expected);
+        }
+        // limit equal to tick price is not crossed
+        {
+            let result = get_swap_tick_addresses(
+                &tickmap,
+                pool,
+                0,
+                tick_spacing,
+                false,
+                calculate_price_sqrt(50),
+            );
+            assert!(result.is_empty());
+        }
+        // at the edge of the bitmap
+        {
+            let mut tickmap = Tickmap::default();
+            tickmap.flip(true, TICK_LIMIT - 1, 1);
+            let result = get_swap_tick_addresses(
+                &tickmap,
+                pool,
+                0,
+                1,
+                false,
+                Price::new(crate::MAX_SQRT_PRICE),
+            );
+            assert_eq!(result, vec![get_tick_address(pool, TICK_LIMIT - 1)]);
+        }
+        // capped at TICK_CROSSES_PER_IX
+        {
+            let mut tickmap = Tickmap::default();
+            for i in 0..(TICK_CROSSES_PER_IX as i32 + 5) {
+                tickmap.flip(true, i * tick_spacing as i32, tick_spacing);
+            }
+            let result = get_swap_tick_addresses(
+                &tickmap,
+                pool,
+                -10,
+                tick_spacing,
+                false,
+                Price::new(crate::MAX_SQRT_PRICE),
+            );
+            assert_eq!(result.len(), TICK_CROSSES_PER_IX);
+            assert_eq!(result[0], get_tick_address(pool, 0));
+        }
+    }
 }
@@ -0,0 +1,70 @@
+use crate::decimals::*;
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct SwapEvent {
+    pub pool: Pubkey,
+    pub swapper: Pubkey,
+    pub x_to_y: bool,
+    pub amount_in: u64,
+    pub amount_out: u64,
+    pub fee: u64,
+    pub referral_fee: u64,
+    pub price_before: Price, // sqrt price before swap
+    pub price_after: Price,  // sqrt price after swap
+    pub current_tick_index: i32,
+    pub liquidity: Liquidity,
+}
+
+#[event]
+pub struct TickCrossedEvent {
+    pub pool: Pubkey,
+    pub index: i32,
+    pub x_to_y: bool,
+    pub liquidity: Liquidity, // pool liquidity after crossing
+}
+
+#[event]
+pub struct PositionCreatedEvent {
+    pub owner: Pubkey,
+    pub pool: Pubkey,
+    pub id: u128,
+    pub liquidity: Liquidity,
+    pub lower_tick: i32,
+    pub upper_tick: i32,
+    pub amount_x: u64,
+    pub amount_y: u64,
+    pub current_sqrt_price: Price,
+}
+
+#[event]
+pub struct PositionRemovedEvent {
+    pub owner: Pubkey,
+    pub pool: Pubkey,
+    pub id: u128,
+    pub liquidity: Liquidity,
+    pub lower_tick: i32,
+    pub upper_tick: i32,
+    pub amount_x: u64, // including claimed fee
+    pub amount_y: u64,
+    pub current_sqrt_price: Price,
+}
+
+#[event]
+pub struct FeeClaimedEvent {
+    pub owner: Pubkey,
+    pub pool: Pubkey,
+    pub id: u128,
+    pub amount_x: u64,
+    pub amount_y: u64,
+}
+
+#[event]
+pub struct FlashLoanEvent {
+    pub pool: Pubkey,
+    pub borrower: Pubkey,
+    pub amount_x: u64,
+    pub amount_y: u64,
+    pub fee_x: u64,
+    pub fee_y: u64,
+}
@@ -0,0 +1,32 @@
+use crate::decimals::*;
+use crate::structs::{DefaultProtocolFee, State};
+use crate::ErrorCode::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct ChangeDefaultProtocolFee<'info> {
+    #[account(mut,
+        seeds = [b"defaultprotocolfeev1".as_ref()],
+        bump = default_protocol_fee.load()?.bump
+    )]
+    pub default_protocol_fee: AccountLoader<'info, DefaultProtocolFee>,
+    #[account(seeds = [b"statev1".as_ref()], bump = state.load()?.bump)]
+    pub state: AccountLoader<'info, State>,
+    #[account(constraint = &state.load()?.admin == admin.key @ InvalidAdmin)]
+    pub admin: Signer<'info>,
+}
+
+impl<'info> ChangeDefaultProtocolFee<'info> {
+    pub fn handler(&self, protocol_fee: FixedPoint) -> ProgramResult {
+        msg!("INVARIANT: CHANGE DEFAULT PROTOCOL FEE");
+
+        require!(
+            protocol_fee <= FixedPoint::from_integer(1),
+            InvalidProtocolFee
+        );
+        let default_protocol_fee = &mut self.default_protocol_fee.load_mut()?;
+        default_protocol_fee.protocol_fee = protocol_fee;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,24 @@
+use crate::structs::{FeeDistribution, State};
+use crate::ErrorCode::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct ChangeFeeDistribution<'info> {
+    #[account(mut, seeds = [b"feedistributionv1".as_ref()], bump = fee_distribution.load()?.bump)]
+    pub fee_distribution: AccountLoader<'info, FeeDistribution>,
+    #[account(seeds = [b"statev1".as_ref()], bump = state.load()?.bump)]
+    pub state: AccountLoader<'info, State>,
+    #[account(constraint = &state.load()?.admin == admin.key @ InvalidAdmin)]
+    pub admin: Signer<'info>,
+}
+
+impl<'info> ChangeFeeDistribution<'info> {
+    pub fn handler(&self, owners: Vec<Pubkey>, weights: Vec<u16>) -> ProgramResult {
+        msg!("INVARIANT: CHANGE FEE DISTRIBUTION");
+
+        let fee_distribution = &mut self.fee_distribution.load_mut()?;
+        fee_distribution.set(&owners, &weights)?;
+
+        Ok(())
+    }
+}
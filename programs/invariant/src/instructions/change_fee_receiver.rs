@@ -1,7 +1,6 @@
 use crate::structs::{Pool, State};
 use crate::ErrorCode::*;
 use anchor_lang::prelude::*;
-use anchor_spl::token::Mint;
 
 #[derive(Accounts)]
 pub struct ChangeFeeReceiver<'info> {
@@ -13,9 +12,9 @@ pub struct ChangeFeeReceiver<'info> {
     )]
     pub pool: AccountLoader<'info, Pool>,
     #[account(constraint = token_x.to_account_info().key == &pool.load()?.token_x @ InvalidTokenAccount) ]
-    pub token_x: Account<'info, Mint>,
+    pub token_x: AccountInfo<'info>,
     #[account(constraint = token_y.to_account_info().key == &pool.load()?.token_y @ InvalidTokenAccount)]
-    pub token_y: Account<'info, Mint>,
+    pub token_y: AccountInfo<'info>,
     #[account(constraint = &state.load()?.admin == admin.key @ InvalidAdmin)]
     pub admin: Signer<'info>,
     pub fee_receiver: AccountInfo<'info>,
@@ -2,7 +2,6 @@ use crate::decimals::*;
 use crate::structs::{Pool, State};
 use crate::ErrorCode::*;
 use anchor_lang::prelude::*;
-use anchor_spl::token::Mint;
 
 #[derive(Accounts)]
 pub struct ChangeProtocolFee<'info> {
@@ -14,9 +13,9 @@ pub struct ChangeProtocolFee<'info> {
     )]
     pub pool: AccountLoader<'info, Pool>,
     #[account(constraint = token_x.to_account_info().key == &pool.load()?.token_x @ InvalidTokenAccount) ]
-    pub token_x: Account<'info, Mint>,
+    pub token_x: AccountInfo<'info>,
     #[account(constraint = token_y.to_account_info().key == &pool.load()?.token_y @ InvalidTokenAccount)]
-    pub token_y: Account<'info, Mint>,
+    pub token_y: AccountInfo<'info>,
     #[account(constraint = &state.load()?.admin == admin.key @ InvalidAdmin)]
     pub admin: Signer<'info>,
     #[account(constraint = &state.load()?.authority == program_authority.key @ InvalidAuthority)]
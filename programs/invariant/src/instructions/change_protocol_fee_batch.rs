@@ -0,0 +1,35 @@
+use crate::decimals::*;
+use crate::structs::{Pool, State};
+use crate::ErrorCode::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct ChangeProtocolFeeBatch<'info> {
+    #[account(seeds = [b"statev1".as_ref()], bump = state.load()?.bump)]
+    pub state: AccountLoader<'info, State>,
+    #[account(constraint = &state.load()?.admin == admin.key @ InvalidAdmin)]
+    pub admin: Signer<'info>,
+}
+
+impl<'info> ChangeProtocolFeeBatch<'info> {
+    // pools to update are passed as writable remaining accounts
+    pub fn handler(
+        ctx: Context<'_, '_, '_, 'info, ChangeProtocolFeeBatch<'info>>,
+        protocol_fee: FixedPoint,
+    ) -> ProgramResult {
+        msg!("INVARIANT: CHANGE PROTOCOL FEE BATCH");
+
+        require!(
+            protocol_fee <= FixedPoint::from_integer(1),
+            InvalidProtocolFee
+        );
+
+        for account in ctx.remaining_accounts.iter() {
+            let loader = AccountLoader::<'_, Pool>::try_from(account)?;
+            let pool = &mut loader.load_mut()?;
+            pool.protocol_fee = protocol_fee;
+        }
+
+        Ok(())
+    }
+}
@@ -1,14 +1,17 @@
 use crate::decimals::*;
+use crate::events::FeeClaimedEvent;
 use crate::interfaces::send_tokens::SendTokens;
+use crate::return_data::{self, ClaimFeeReturnData};
 use crate::structs::pool::Pool;
 use crate::structs::position::Position;
 use crate::structs::tick::Tick;
+use crate::token_interface::{token_account, transfer_checked};
 use crate::util::*;
 use crate::ErrorCode::*;
 use crate::*;
 
 use anchor_lang::prelude::*;
-use anchor_spl::token::{Mint, TokenAccount, Transfer};
+use anchor_spl::token::Transfer;
 
 #[derive(Accounts)]
 #[instruction( index: u32, lower_tick_index: i32, upper_tick_index: i32)]
@@ -41,41 +44,39 @@ pub struct ClaimFee<'info> {
     pub upper_tick: AccountLoader<'info, Tick>,
     pub owner: Signer<'info>,
     #[account(constraint = token_x.key() == pool.load()?.token_x @ InvalidTokenAccount)]
-    pub token_x: Account<'info, Mint>,
+    pub token_x: AccountInfo<'info>,
     #[account(constraint = token_y.key() == pool.load()?.token_y @ InvalidTokenAccount)]
-    pub token_y: Account<'info, Mint>,
+    pub token_y: AccountInfo<'info>,
     #[account(mut,
-        constraint = account_x.mint == token_x.key() @ InvalidMint,
-        constraint = &account_x.owner == owner.key @ InvalidOwner,
+        constraint = token_account(&account_x)?.mint == token_x.key() @ InvalidMint,
+        constraint = token_account(&account_x)?.owner == owner.key() @ InvalidOwner
     )]
-    pub account_x: Box<Account<'info, TokenAccount>>,
+    pub account_x: AccountInfo<'info>,
     #[account(mut,
-        constraint = account_y.mint == token_y.key() @ InvalidMint,
-        constraint = &account_y.owner == owner.key @ InvalidOwner
+        constraint = token_account(&account_y)?.mint == token_y.key() @ InvalidMint,
+        constraint = token_account(&account_y)?.owner == owner.key() @ InvalidOwner
     )]
-    pub account_y: Box<Account<'info, TokenAccount>>,
+    pub account_y: AccountInfo<'info>,
     #[account(mut,
-        constraint = reserve_x.mint == token_x.key() @ InvalidMint,
-        constraint = &reserve_x.owner == program_authority.key @ InvalidAuthority,
         constraint = reserve_x.key() == pool.load()?.token_x_reserve @ InvalidTokenAccount
     )]
-    pub reserve_x: Box<Account<'info, TokenAccount>>,
+    pub reserve_x: AccountInfo<'info>,
     #[account(mut,
-        constraint = reserve_y.mint == token_y.key() @ InvalidMint,
-        constraint = &reserve_y.owner == program_authority.key @ InvalidAuthority,
         constraint = reserve_y.key() == pool.load()?.token_y_reserve @ InvalidTokenAccount
     )]
-    pub reserve_y: Box<Account<'info, TokenAccount>>,
+    pub reserve_y: AccountInfo<'info>,
     #[account(constraint = &state.load()?.authority == program_authority.key @ InvalidAuthority)]
     pub program_authority: AccountInfo<'info>,
-    #[account(address = token::ID)]
-    pub token_program: AccountInfo<'info>,
+    #[account(constraint = token_x_program.key == token_x.owner @ InvalidTokenProgram)]
+    pub token_x_program: AccountInfo<'info>,
+    #[account(constraint = token_y_program.key == token_y.owner @ InvalidTokenProgram)]
+    pub token_y_program: AccountInfo<'info>,
 }
 
 impl<'info> interfaces::SendTokens<'info> for ClaimFee<'info> {
     fn send_x(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
         CpiContext::new(
-            self.token_program.to_account_info(),
+            self.token_x_program.to_account_info(),
             Transfer {
                 from: self.reserve_x.to_account_info(),
                 to: self.account_x.to_account_info(),
@@ -86,7 +87,7 @@ impl<'info> interfaces::SendTokens<'info> for ClaimFee<'info> {
 
     fn send_y(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
         CpiContext::new(
-            self.token_program.to_account_info(),
+            self.token_y_program.to_account_info(),
             Transfer {
                 from: self.reserve_y.to_account_info(),
                 to: self.account_y.to_account_info(),
@@ -132,8 +133,21 @@ impl<'info> ClaimFee<'info> {
         let cpi_ctx_x = self.send_x().with_signer(signer);
         let cpi_ctx_y = self.send_y().with_signer(signer);
 
-        token::transfer(cpi_ctx_x, fee_to_collect_x.0)?;
-        token::transfer(cpi_ctx_y, fee_to_collect_y.0)?;
+        transfer_checked(cpi_ctx_x, &self.token_x, fee_to_collect_x.0)?;
+        transfer_checked(cpi_ctx_y, &self.token_y, fee_to_collect_y.0)?;
+
+        emit!(FeeClaimedEvent {
+            owner: position.owner,
+            pool: position.pool,
+            id: position.id,
+            amount_x: fee_to_collect_x.0,
+            amount_y: fee_to_collect_y.0,
+        });
+
+        return_data::set(&ClaimFeeReturnData {
+            amount_x: fee_to_collect_x.0,
+            amount_y: fee_to_collect_y.0,
+        });
 
         Ok(())
     }
@@ -0,0 +1,34 @@
+use crate::decimals::*;
+use crate::structs::{DefaultProtocolFee, State};
+use crate::ErrorCode::*;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::system_program;
+
+#[derive(Accounts)]
+pub struct CreateDefaultProtocolFee<'info> {
+    #[account(init, seeds = [b"defaultprotocolfeev1".as_ref()], bump, payer = admin)]
+    pub default_protocol_fee: AccountLoader<'info, DefaultProtocolFee>,
+    #[account(seeds = [b"statev1".as_ref()], bump = state.load()?.bump)]
+    pub state: AccountLoader<'info, State>,
+    #[account(mut, constraint = &state.load()?.admin == admin.key @ InvalidAdmin)]
+    pub admin: Signer<'info>,
+    pub rent: Sysvar<'info, Rent>,
+    #[account(address = system_program::ID)]
+    pub system_program: AccountInfo<'info>,
+}
+
+impl<'info> CreateDefaultProtocolFee<'info> {
+    pub fn handler(&self, protocol_fee: FixedPoint, bump: u8) -> ProgramResult {
+        msg!("INVARIANT: CREATE DEFAULT PROTOCOL FEE");
+
+        require!(
+            protocol_fee <= FixedPoint::from_integer(1),
+            InvalidProtocolFee
+        );
+        let default_protocol_fee = &mut self.default_protocol_fee.load_init()?;
+        default_protocol_fee.protocol_fee = protocol_fee;
+        default_protocol_fee.bump = bump;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,30 @@
+use crate::structs::fee_distribution::FeeDistribution;
+use crate::ErrorCode::*;
+use crate::*;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::system_program;
+
+#[derive(Accounts)]
+pub struct CreateFeeDistribution<'info> {
+    #[account(init, seeds = [b"feedistributionv1".as_ref()], bump, payer = admin)]
+    pub fee_distribution: AccountLoader<'info, FeeDistribution>,
+    #[account(seeds = [b"statev1".as_ref()], bump = state.load()?.bump)]
+    pub state: AccountLoader<'info, State>,
+    #[account(mut, constraint = &state.load()?.admin == admin.key @ InvalidAdmin)]
+    pub admin: Signer<'info>,
+    pub rent: Sysvar<'info, Rent>,
+    #[account(address = system_program::ID)]
+    pub system_program: AccountInfo<'info>,
+}
+
+impl<'info> CreateFeeDistribution<'info> {
+    pub fn handler(&self, owners: Vec<Pubkey>, weights: Vec<u16>, bump: u8) -> ProgramResult {
+        msg!("INVARIANT: CREATE FEE DISTRIBUTION");
+
+        let fee_distribution = &mut self.fee_distribution.load_init()?;
+        fee_distribution.bump = bump;
+        fee_distribution.set(&owners, &weights)?;
+
+        Ok(())
+    }
+}
@@ -1,16 +1,16 @@
 use crate::decimals::*;
 use crate::math::calculate_price_sqrt;
+use crate::structs::default_protocol_fee::DefaultProtocolFee;
 use crate::structs::fee_tier::FeeTier;
 use crate::structs::pool::Pool;
 use crate::structs::tickmap::Tickmap;
 use crate::structs::State;
+use crate::token_interface::{create_token_account, is_token_program};
 use crate::util::check_tick;
 use crate::util::get_current_timestamp;
 use crate::ErrorCode::*;
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::system_program;
-use anchor_spl::token::Token;
-use anchor_spl::token::{Mint, TokenAccount};
 use std::cmp::Ordering;
 
 #[derive(Accounts)]
@@ -27,27 +27,27 @@ pub struct CreatePool<'info> {
         bump = fee_tier.load()?.bump
     )]
     pub fee_tier: AccountLoader<'info, FeeTier>,
+    // may not exist yet, in which case the initial default applies
+    #[account(seeds = [b"defaultprotocolfeev1".as_ref()], bump)]
+    pub default_protocol_fee: AccountInfo<'info>,
     #[account(zero)]
     pub tickmap: AccountLoader<'info, Tickmap>,
-    pub token_x: Account<'info, Mint>,
-    pub token_y: Account<'info, Mint>,
-    #[account(init,
-        token::mint = token_x,
-        token::authority = authority,
-        payer = payer,
-    )]
-    pub token_x_reserve: Account<'info, TokenAccount>,
-    #[account(init,
-        token::mint = token_y,
-        token::authority = authority,
-        payer = payer,
-    )]
-    pub token_y_reserve: Account<'info, TokenAccount>,
+    #[account(constraint = is_token_program(token_x.owner) @ InvalidTokenProgram)]
+    pub token_x: AccountInfo<'info>,
+    #[account(constraint = is_token_program(token_y.owner) @ InvalidTokenProgram)]
+    pub token_y: AccountInfo<'info>,
+    #[account(mut)]
+    pub token_x_reserve: Signer<'info>,
+    #[account(mut)]
+    pub token_y_reserve: Signer<'info>,
     #[account(mut)]
     pub payer: Signer<'info>,
     #[account(constraint = &state.load()?.authority == authority.key @ InvalidAuthority)]
     pub authority: AccountInfo<'info>,
-    pub token_program: Program<'info, Token>,
+    #[account(constraint = token_x_program.key == token_x.owner @ InvalidTokenProgram)]
+    pub token_x_program: AccountInfo<'info>,
+    #[account(constraint = token_y_program.key == token_y.owner @ InvalidTokenProgram)]
+    pub token_y_program: AccountInfo<'info>,
     pub rent: Sysvar<'info, Rent>,
     #[account(address = system_program::ID)]
     pub system_program: AccountInfo<'info>,
@@ -67,6 +67,26 @@ impl<'info> CreatePool<'info> {
             InvalidPoolTokenAddresses
         );
 
+        // reserves are created by the token program of each mint, with space for its extensions
+        create_token_account(
+            &self.token_x_program,
+            &self.token_x_reserve,
+            &self.token_x,
+            &self.authority,
+            &self.payer,
+            &self.rent,
+            &self.system_program,
+        )?;
+        create_token_account(
+            &self.token_y_program,
+            &self.token_y_reserve,
+            &self.token_y,
+            &self.authority,
+            &self.payer,
+            &self.rent,
+            &self.system_program,
+        )?;
+
         let pool = &mut self.pool.load_init()?;
         let fee_tier = self.fee_tier.load()?;
         let current_timestamp = get_current_timestamp();
@@ -80,7 +100,7 @@ impl<'info> CreatePool<'info> {
             token_y_reserve: *self.token_y_reserve.to_account_info().key,
             tick_spacing: fee_tier.tick_spacing,
             fee: fee_tier.fee,
-            protocol_fee: FixedPoint::from_scale(1, 2),
+            protocol_fee: DefaultProtocolFee::get(&self.default_protocol_fee)?,
             liquidity: Liquidity::new(0),
             sqrt_price: calculate_price_sqrt(init_tick),
             current_tick_index: init_tick,
@@ -1,9 +1,12 @@
+use crate::events::PositionCreatedEvent;
+use crate::interfaces::send_tokens::SendTokens;
 use crate::interfaces::take_tokens::TakeTokens;
 use crate::structs::pool::Pool;
 use crate::structs::position::Position;
 use crate::structs::position_list::PositionList;
 use crate::structs::tick::Tick;
 use crate::structs::Tickmap;
+use crate::token_interface::{token_account, transfer_with_fee};
 use crate::util::check_ticks;
 use crate::ErrorCode::*;
 use crate::*;
@@ -12,7 +15,7 @@ use anchor_lang::solana_program::system_program;
 use decimals::*;
 
 use anchor_spl::token;
-use anchor_spl::token::{Mint, TokenAccount, Transfer};
+use anchor_spl::token::Transfer;
 
 #[derive(Accounts)]
 #[instruction( lower_tick_index: i32, upper_tick_index: i32)]
@@ -55,35 +58,33 @@ pub struct CreatePosition<'info> {
     )]
     pub tickmap: AccountLoader<'info, Tickmap>,
     #[account(constraint = token_x.key() == pool.load()?.token_x @ InvalidTokenAccount)]
-    pub token_x: Account<'info, Mint>,
+    pub token_x: AccountInfo<'info>,
     #[account(constraint = token_y.key() == pool.load()?.token_y @ InvalidTokenAccount)]
-    pub token_y: Account<'info, Mint>,
+    pub token_y: AccountInfo<'info>,
     #[account(mut,
-        constraint = account_x.mint == token_x.key() @ InvalidMint,
-        constraint = &account_x.owner == owner.key @ InvalidOwner,
+        constraint = token_account(&account_x)?.mint == token_x.key() @ InvalidMint,
+        constraint = token_account(&account_x)?.owner == owner.key() @ InvalidOwner
     )]
-    pub account_x: Box<Account<'info, TokenAccount>>,
+    pub account_x: AccountInfo<'info>,
     #[account(mut,
-        constraint = account_y.mint == token_y.key() @ InvalidMint,
-        constraint = &account_y.owner == owner.key @ InvalidOwner
+        constraint = token_account(&account_y)?.mint == token_y.key() @ InvalidMint,
+        constraint = token_account(&account_y)?.owner == owner.key() @ InvalidOwner
     )]
-    pub account_y: Box<Account<'info, TokenAccount>>,
+    pub account_y: AccountInfo<'info>,
     #[account(mut,
-        constraint = reserve_x.mint == token_x.key() @ InvalidMint,
-        constraint = &reserve_x.owner == program_authority.key @ InvalidOwner,
         constraint = reserve_x.key() == pool.load()?.token_x_reserve @ InvalidTokenAccount
     )]
-    pub reserve_x: Box<Account<'info, TokenAccount>>,
+    pub reserve_x: AccountInfo<'info>,
     #[account(mut,
-        constraint = reserve_y.mint == token_y.key() @ InvalidMint,
-        constraint = &reserve_y.owner == program_authority.key @ InvalidOwner,
         constraint = reserve_y.key() == pool.load()?.token_y_reserve @ InvalidTokenAccount
     )]
-    pub reserve_y: Box<Account<'info, TokenAccount>>,
+    pub reserve_y: AccountInfo<'info>,
     #[account(constraint = &state.load()?.authority == program_authority.key @ InvalidAuthority)]
     pub program_authority: AccountInfo<'info>,
-    #[account(address = token::ID)]
-    pub token_program: AccountInfo<'info>,
+    #[account(constraint = token_x_program.key == token_x.owner @ InvalidTokenProgram)]
+    pub token_x_program: AccountInfo<'info>,
+    #[account(constraint = token_y_program.key == token_y.owner @ InvalidTokenProgram)]
+    pub token_y_program: AccountInfo<'info>,
     pub rent: Sysvar<'info, Rent>,
     #[account(address = system_program::ID)]
     pub system_program: AccountInfo<'info>,
@@ -92,7 +93,7 @@ pub struct CreatePosition<'info> {
 impl<'info> TakeTokens<'info> for CreatePosition<'info> {
     fn take_x(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
         CpiContext::new(
-            self.token_program.to_account_info(),
+            self.token_x_program.to_account_info(),
             Transfer {
                 from: self.account_x.to_account_info(),
                 to: self.reserve_x.to_account_info(),
@@ -103,7 +104,7 @@ impl<'info> TakeTokens<'info> for CreatePosition<'info> {
 
     fn take_y(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
         CpiContext::new(
-            self.token_program.to_account_info(),
+            self.token_y_program.to_account_info(),
             Transfer {
                 from: self.account_y.to_account_info(),
                 to: self.reserve_y.to_account_info(),
@@ -113,6 +114,30 @@ impl<'info> TakeTokens<'info> for CreatePosition<'info> {
     }
 }
 
+impl<'info> SendTokens<'info> for CreatePosition<'info> {
+    fn send_x(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        CpiContext::new(
+            self.token_x_program.to_account_info(),
+            Transfer {
+                from: self.reserve_x.to_account_info(),
+                to: self.account_x.to_account_info(),
+                authority: self.program_authority.clone(),
+            },
+        )
+    }
+
+    fn send_y(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        CpiContext::new(
+            self.token_y_program.to_account_info(),
+            Transfer {
+                from: self.reserve_y.to_account_info(),
+                to: self.account_y.to_account_info(),
+                authority: self.program_authority.clone(),
+            },
+        )
+    }
+}
+
 impl<'info> CreatePosition<'info> {
     pub fn handler(
         &self,
@@ -123,8 +148,28 @@ impl<'info> CreatePosition<'info> {
     ) -> ProgramResult {
         msg!("INVARIANT: CREATE POSITION");
 
+        let pool = &mut self.pool.load_mut()?;
+
+        // validate price
+        let price = pool.sqrt_price;
+        require!(price >= slippage_limit_lower, PriceLimitReached);
+        require!(price <= slippage_limit_upper, PriceLimitReached);
+
+        let (amount_x, amount_y) = self.open_position(pool, liquidity_delta, bump)?;
+
+        transfer_with_fee(self.take_x(), &self.token_x, amount_x.0)?;
+        transfer_with_fee(self.take_y(), &self.token_y, amount_y.0)?;
+        Ok(())
+    }
+
+    // initializes position with liquidity at the current pool price, returns amounts it requires
+    pub fn open_position(
+        &self,
+        pool: &mut Pool,
+        liquidity_delta: Liquidity,
+        bump: u8,
+    ) -> Result<(TokenAmount, TokenAmount)> {
         let mut position = self.position.load_init()?;
-        let mut pool = &mut self.pool.load_mut()?;
         let lower_tick = &mut self.lower_tick.load_mut()?;
         let upper_tick = &mut self.upper_tick.load_mut()?;
         let mut position_list = self.position_list.load_mut()?;
@@ -132,11 +177,6 @@ impl<'info> CreatePosition<'info> {
         let mut tickmap = self.tickmap.load_mut()?;
         let slot = get_current_slot();
 
-        // validate price
-        let price = pool.sqrt_price;
-        require!(price >= slippage_limit_lower, PriceLimitReached);
-        require!(price <= slippage_limit_upper, PriceLimitReached);
-
         // validate ticks
         check_ticks(lower_tick.index, upper_tick.index, pool.tick_spacing)?;
 
@@ -149,7 +189,7 @@ impl<'info> CreatePosition<'info> {
 
         // update position_list head
         position_list.head = position_list.head.checked_add(1).unwrap();
-        position.initialized_id(&mut pool);
+        position.initialized_id(pool);
 
         // init position
         *position = Position {
@@ -166,6 +206,7 @@ impl<'info> CreatePosition<'info> {
             tokens_owed_x: FixedPoint::new(0),
             tokens_owed_y: FixedPoint::new(0),
             bump,
+            stake_count: 0,
         };
 
         let (amount_x, amount_y) = position.modify(
@@ -177,8 +218,17 @@ impl<'info> CreatePosition<'info> {
             current_timestamp,
         )?;
 
-        token::transfer(self.take_x(), amount_x.0)?;
-        token::transfer(self.take_y(), amount_y.0)?;
-        Ok(())
+        emit!(PositionCreatedEvent {
+            owner: position.owner,
+            pool: position.pool,
+            id: position.id,
+            liquidity: liquidity_delta,
+            lower_tick: lower_tick.index,
+            upper_tick: upper_tick.index,
+            amount_x: amount_x.0,
+            amount_y: amount_y.0,
+            current_sqrt_price: pool.sqrt_price,
+        });
+        Ok((amount_x, amount_y))
     }
 }
@@ -0,0 +1,44 @@
+use crate::interfaces::take_tokens::TakeTokens;
+use crate::math::calculate_max_liquidity;
+use crate::token_interface::{get_post_fee_amount, transfer_with_fee};
+use crate::util::check_ticks;
+use crate::ErrorCode::*;
+use crate::*;
+use anchor_lang::prelude::*;
+use decimals::*;
+
+impl<'info> CreatePosition<'info> {
+    pub fn by_amounts_handler(
+        &self,
+        max_amount_x: TokenAmount,
+        max_amount_y: TokenAmount,
+        bump: u8,
+    ) -> ProgramResult {
+        msg!("INVARIANT: CREATE POSITION BY AMOUNTS");
+
+        let pool = &mut self.pool.load_mut()?;
+        let lower_tick_index = self.lower_tick.load()?.index;
+        let upper_tick_index = self.upper_tick.load()?.index;
+        check_ticks(lower_tick_index, upper_tick_index, pool.tick_spacing)?;
+
+        // maximum amounts are sent by the owner, so transfer fees are withheld from them
+        let max_amount_x = TokenAmount(get_post_fee_amount(&self.token_x, max_amount_x.0)?);
+        let max_amount_y = TokenAmount(get_post_fee_amount(&self.token_y, max_amount_y.0)?);
+
+        let liquidity_delta = calculate_max_liquidity(
+            pool,
+            max_amount_x,
+            max_amount_y,
+            upper_tick_index,
+            lower_tick_index,
+        )?;
+
+        let (amount_x, amount_y) = self.open_position(pool, liquidity_delta, bump)?;
+        require!(amount_x <= max_amount_x, MaxAmountExceeded);
+        require!(amount_y <= max_amount_y, MaxAmountExceeded);
+
+        transfer_with_fee(self.take_x(), &self.token_x, amount_x.0)?;
+        transfer_with_fee(self.take_y(), &self.token_y, amount_y.0)?;
+        Ok(())
+    }
+}
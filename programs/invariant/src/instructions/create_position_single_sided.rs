@@ -0,0 +1,134 @@
+use crate::events::SwapEvent;
+use crate::interfaces::send_tokens::SendTokens;
+use crate::interfaces::take_tokens::TakeTokens;
+use crate::log::get_tick_at_sqrt_price;
+use crate::math::{
+    calculate_price_sqrt, calculate_single_sided_swap, get_liquidity_by_x, get_liquidity_by_y,
+};
+use crate::token_interface::{get_post_fee_amount, transfer_checked, transfer_with_fee};
+use crate::util::{check_ticks, get_closer_limit};
+use crate::ErrorCode::*;
+use crate::*;
+use anchor_lang::prelude::*;
+use decimals::*;
+
+impl<'info> CreatePosition<'info> {
+    pub fn single_sided_handler(
+        &self,
+        amount: TokenAmount,
+        x_to_y: bool, // whether token x is deposited and partially swapped to y
+        slippage_limit_lower: Price,
+        slippage_limit_upper: Price,
+        bump: u8,
+    ) -> ProgramResult {
+        msg!("INVARIANT: CREATE POSITION SINGLE SIDED");
+
+        // deposited amount is sent by the owner, so the transfer fee is withheld from it
+        let (token_deposited, token_other) = match x_to_y {
+            true => (&self.token_x, &self.token_y),
+            false => (&self.token_y, &self.token_x),
+        };
+        let amount = TokenAmount(get_post_fee_amount(token_deposited, amount.0)?);
+        require!(!amount.is_zero(), ZeroAmount);
+
+        let state = self.state.load()?;
+        let pool = &mut self.pool.load_mut()?;
+        let lower_tick_index = self.lower_tick.load()?.index;
+        let upper_tick_index = self.upper_tick.load()?.index;
+
+        // validate price
+        let price_before = pool.sqrt_price;
+        require!(price_before >= slippage_limit_lower, PriceLimitReached);
+        require!(price_before <= slippage_limit_upper, PriceLimitReached);
+
+        check_ticks(lower_tick_index, upper_tick_index, pool.tick_spacing)?;
+        let lower_sqrt_price = calculate_price_sqrt(lower_tick_index);
+        let upper_sqrt_price = calculate_price_sqrt(upper_tick_index);
+
+        // amount of deposited token swapped and amount of the other token received
+        let (swapped, received, liquidity) = if pool.current_tick_index < lower_tick_index {
+            require!(x_to_y, InvalidSingleSidedToken);
+            let liquidity = get_liquidity_by_x(amount, lower_sqrt_price, upper_sqrt_price, false)
+                .ok_or(LiquidityOverflow)?;
+            (TokenAmount(0), TokenAmount(0), liquidity)
+        } else if pool.current_tick_index >= upper_tick_index {
+            require!(!x_to_y, InvalidSingleSidedToken);
+            let liquidity = get_liquidity_by_y(amount, lower_sqrt_price, upper_sqrt_price, false)
+                .ok_or(LiquidityOverflow)?;
+            (TokenAmount(0), TokenAmount(0), liquidity)
+        } else {
+            // swap can't cross initialized ticks nor leave the position range
+            let (swap_limit, _) = get_closer_limit(
+                match x_to_y {
+                    true => lower_sqrt_price,
+                    false => upper_sqrt_price,
+                },
+                x_to_y,
+                pool.current_tick_index,
+                pool.tick_spacing,
+                &*self.tickmap.load()?,
+            )?;
+
+            let (swap_amount, result, liquidity) = calculate_single_sided_swap(
+                pool.sqrt_price,
+                swap_limit,
+                pool.liquidity,
+                pool.fee,
+                amount,
+                lower_sqrt_price,
+                upper_sqrt_price,
+                x_to_y,
+            );
+
+            if !swap_amount.is_zero() {
+                require!(result.next_price_sqrt != swap_limit, PriceLimitReached);
+
+                pool.add_fee(result.fee_amount, FixedPoint::from_integer(0), x_to_y);
+                pool.sqrt_price = result.next_price_sqrt;
+                pool.current_tick_index =
+                    get_tick_at_sqrt_price(result.next_price_sqrt, pool.tick_spacing);
+
+                emit!(SwapEvent {
+                    pool: self.pool.key(),
+                    swapper: self.owner.key(),
+                    x_to_y,
+                    amount_in: swap_amount.0,
+                    amount_out: result.amount_out.0,
+                    fee: result.fee_amount.0,
+                    referral_fee: 0,
+                    price_before,
+                    price_after: pool.sqrt_price,
+                    current_tick_index: pool.current_tick_index,
+                    liquidity: pool.liquidity,
+                });
+            }
+            (swap_amount, result.amount_out, liquidity)
+        };
+
+        let (amount_x, amount_y) = self.open_position(pool, liquidity, bump)?;
+
+        // swap output stays in the reserve, so only the difference is moved
+        let (deposited, other) = match x_to_y {
+            true => (amount_x, amount_y),
+            false => (amount_y, amount_x),
+        };
+        let (take_ctx, take_other_ctx, send_other_ctx) = match x_to_y {
+            true => (self.take_x(), self.take_y(), self.send_y()),
+            false => (self.take_y(), self.take_x(), self.send_x()),
+        };
+
+        transfer_with_fee(take_ctx, token_deposited, (swapped + deposited).0)?;
+        if other > received {
+            transfer_with_fee(take_other_ctx, token_other, (other - received).0)?;
+        } else if received > other {
+            let signer: &[&[&[u8]]] = get_signer!(state.nonce);
+            transfer_checked(
+                send_other_ctx.with_signer(signer),
+                token_other,
+                (received - other).0,
+            )?;
+        }
+
+        Ok(())
+    }
+}
@@ -8,7 +8,6 @@ use crate::util::get_current_timestamp;
 use crate::ErrorCode::*;
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::system_program;
-use anchor_spl::token::Mint;
 
 #[derive(Accounts)]
 #[instruction( index: i32)]
@@ -32,9 +31,9 @@ pub struct CreateTick<'info> {
     pub payer: Signer<'info>,
     #[account(
         constraint = token_x.key() == pool.load()?.token_x @ InvalidTokenAccount)]
-    pub token_x: Account<'info, Mint>,
+    pub token_x: AccountInfo<'info>,
     #[account(constraint = token_y.key() == pool.load()?.token_y @ InvalidTokenAccount)]
-    pub token_y: Account<'info, Mint>,
+    pub token_y: AccountInfo<'info>,
     pub rent: Sysvar<'info, Rent>,
     #[account(address = system_program::ID)]
     pub system_program: AccountInfo<'info>,
@@ -0,0 +1,131 @@
+use crate::structs::fee_distribution::FeeDistribution;
+use crate::structs::pool::Pool;
+use crate::structs::state::State;
+use crate::token_interface::{token_account, transfer_checked};
+use crate::ErrorCode::*;
+use crate::SEED;
+use crate::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::Transfer;
+
+#[derive(Accounts)]
+pub struct DistributeProtocolFee<'info> {
+    #[account(seeds = [b"statev1".as_ref()], bump = state.load()?.bump)]
+    pub state: AccountLoader<'info, State>,
+    #[account(seeds = [b"feedistributionv1".as_ref()], bump = fee_distribution.load()?.bump)]
+    pub fee_distribution: AccountLoader<'info, FeeDistribution>,
+    #[account(mut,
+        seeds = [b"poolv1", token_x.key().as_ref(), token_y.key().as_ref(), &pool.load()?.fee.v.to_le_bytes(), &pool.load()?.tick_spacing.to_le_bytes()],
+        bump = pool.load()?.bump
+    )]
+    pub pool: AccountLoader<'info, Pool>,
+    #[account(constraint = token_x.key() == pool.load()?.token_x @ InvalidTokenAccount)]
+    pub token_x: AccountInfo<'info>,
+    #[account(constraint = token_y.key() == pool.load()?.token_y @ InvalidTokenAccount)]
+    pub token_y: AccountInfo<'info>,
+    #[account(mut,
+        constraint = reserve_x.key() == pool.load()?.token_x_reserve @ InvalidTokenAccount
+    )]
+    pub reserve_x: AccountInfo<'info>,
+    #[account(mut,
+        constraint = reserve_y.key() == pool.load()?.token_y_reserve @ InvalidTokenAccount
+    )]
+    pub reserve_y: AccountInfo<'info>,
+    #[account(constraint = &state.load()?.authority == program_authority.key @ InvalidAuthority)]
+    pub program_authority: AccountInfo<'info>,
+    #[account(constraint = token_x_program.key == token_x.owner @ InvalidTokenProgram)]
+    pub token_x_program: AccountInfo<'info>,
+    #[account(constraint = token_y_program.key == token_y.owner @ InvalidTokenProgram)]
+    pub token_y_program: AccountInfo<'info>,
+}
+
+impl<'info> DistributeProtocolFee<'info> {
+    fn send(
+        &self,
+        token_program: &AccountInfo<'info>,
+        reserve: &AccountInfo<'info>,
+        to: &AccountInfo<'info>,
+    ) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        CpiContext::new(
+            token_program.clone(),
+            Transfer {
+                from: reserve.clone(),
+                to: to.clone(),
+                authority: self.program_authority.clone(),
+            },
+        )
+    }
+
+    // closed, frozen or foreign accounts can't block the distribution, their share stays in the pool
+    fn can_receive(
+        account: &AccountInfo<'info>,
+        token_program: &AccountInfo<'info>,
+        mint: Pubkey,
+        owner: Pubkey,
+    ) -> bool {
+        if account.owner != token_program.key {
+            return false;
+        }
+        match token_account(account) {
+            Ok(state) => state.mint == mint && state.owner == owner && !state.is_frozen,
+            Err(_) => false,
+        }
+    }
+
+    // remaining accounts are token accounts of the recipients in order: [x_0, y_0, x_1, y_1, ...]
+    pub fn handler(ctx: Context<'_, '_, '_, 'info, DistributeProtocolFee<'info>>) -> ProgramResult {
+        msg!("INVARIANT: DISTRIBUTE PROTOCOL FEE");
+
+        let state = ctx.accounts.state.load()?;
+        let fee_distribution = ctx.accounts.fee_distribution.load()?;
+        let mut pool = ctx.accounts.pool.load_mut()?;
+
+        let count = fee_distribution.count as usize;
+        require!(
+            ctx.remaining_accounts.len() == count * 2,
+            InvalidFeeDistribution
+        );
+
+        let shares_x = fee_distribution.split(pool.fee_protocol_token_x);
+        let shares_y = fee_distribution.split(pool.fee_protocol_token_y);
+        let signer: &[&[&[u8]]] = get_signer!(state.nonce);
+
+        for (i, recipient) in fee_distribution.recipients[..count].iter().enumerate() {
+            let account_x = &ctx.remaining_accounts[2 * i];
+            let account_y = &ctx.remaining_accounts[2 * i + 1];
+
+            if shares_x[i] != 0 {
+                let token_program = &ctx.accounts.token_x_program;
+                let reserve = &ctx.accounts.reserve_x;
+                if Self::can_receive(account_x, token_program, pool.token_x, recipient.owner) {
+                    let cpi_ctx = ctx.accounts.send(token_program, reserve, account_x);
+                    transfer_checked(
+                        cpi_ctx.with_signer(signer),
+                        &ctx.accounts.token_x,
+                        shares_x[i],
+                    )?;
+                    pool.fee_protocol_token_x -= shares_x[i];
+                } else {
+                    msg!("INVARIANT: SKIPPING FEE RECIPIENT {} TOKEN X", i);
+                }
+            }
+            if shares_y[i] != 0 {
+                let token_program = &ctx.accounts.token_y_program;
+                let reserve = &ctx.accounts.reserve_y;
+                if Self::can_receive(account_y, token_program, pool.token_y, recipient.owner) {
+                    let cpi_ctx = ctx.accounts.send(token_program, reserve, account_y);
+                    transfer_checked(
+                        cpi_ctx.with_signer(signer),
+                        &ctx.accounts.token_y,
+                        shares_y[i],
+                    )?;
+                    pool.fee_protocol_token_y -= shares_y[i];
+                } else {
+                    msg!("INVARIANT: SKIPPING FEE RECIPIENT {} TOKEN Y", i);
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
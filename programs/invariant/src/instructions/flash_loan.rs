@@ -0,0 +1,137 @@
+use crate::interfaces::SendTokens;
+use crate::structs::pool::Pool;
+use crate::structs::state::State;
+use crate::token_interface::{token_account, transfer_checked};
+use crate::ErrorCode::*;
+use crate::SEED;
+use crate::*;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions::{
+    self as sysvar_instructions, load_current_index_checked, load_instruction_at_checked,
+};
+use anchor_lang::InstructionData;
+use anchor_spl::token::Transfer;
+
+// position of the pool in accounts of both flash_loan and flash_repay
+pub const FLASH_LOAN_POOL_INDEX: usize = 1;
+
+#[derive(Accounts)]
+pub struct FlashLoan<'info> {
+    #[account(seeds = [b"statev1".as_ref()], bump = state.load()?.bump)]
+    pub state: AccountLoader<'info, State>,
+    #[account(
+        seeds = [b"poolv1", token_x.key().as_ref(), token_y.key().as_ref(), &pool.load()?.fee.v.to_le_bytes(), &pool.load()?.tick_spacing.to_le_bytes()],
+        bump = pool.load()?.bump
+    )]
+    pub pool: AccountLoader<'info, Pool>,
+    #[account(constraint = token_x.key() == pool.load()?.token_x @ InvalidTokenAccount)]
+    pub token_x: AccountInfo<'info>,
+    #[account(constraint = token_y.key() == pool.load()?.token_y @ InvalidTokenAccount)]
+    pub token_y: AccountInfo<'info>,
+    #[account(mut,
+        constraint = token_account(&account_x)?.mint == token_x.key() @ InvalidMint
+    )]
+    pub account_x: AccountInfo<'info>,
+    #[account(mut,
+        constraint = token_account(&account_y)?.mint == token_y.key() @ InvalidMint
+    )]
+    pub account_y: AccountInfo<'info>,
+    #[account(mut,
+        constraint = reserve_x.key() == pool.load()?.token_x_reserve @ InvalidTokenAccount
+    )]
+    pub reserve_x: AccountInfo<'info>,
+    #[account(mut,
+        constraint = reserve_y.key() == pool.load()?.token_y_reserve @ InvalidTokenAccount
+    )]
+    pub reserve_y: AccountInfo<'info>,
+    pub borrower: Signer<'info>,
+    #[account(constraint = &state.load()?.authority == program_authority.key @ InvalidAuthority)]
+    pub program_authority: AccountInfo<'info>,
+    #[account(constraint = token_x_program.key == token_x.owner @ InvalidTokenProgram)]
+    pub token_x_program: AccountInfo<'info>,
+    #[account(constraint = token_y_program.key == token_y.owner @ InvalidTokenProgram)]
+    pub token_y_program: AccountInfo<'info>,
+    #[account(address = sysvar_instructions::ID)]
+    pub instructions: AccountInfo<'info>,
+}
+
+impl<'info> SendTokens<'info> for FlashLoan<'info> {
+    fn send_x(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        CpiContext::new(
+            self.token_x_program.to_account_info(),
+            Transfer {
+                from: self.reserve_x.to_account_info(),
+                to: self.account_x.to_account_info(),
+                authority: self.program_authority.clone(),
+            },
+        )
+    }
+
+    fn send_y(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        CpiContext::new(
+            self.token_y_program.to_account_info(),
+            Transfer {
+                from: self.reserve_y.to_account_info(),
+                to: self.account_y.to_account_info(),
+                authority: self.program_authority.clone(),
+            },
+        )
+    }
+}
+
+impl<'info> FlashLoan<'info> {
+    pub fn handler(&self, amount_x: u64, amount_y: u64) -> ProgramResult {
+        msg!("INVARIANT: FLASH LOAN");
+        require!(amount_x != 0 || amount_y != 0, ZeroAmount);
+
+        self.check_repay(amount_x, amount_y)?;
+
+        let state = self.state.load()?;
+        let signer: &[&[&[u8]]] = get_signer!(state.nonce);
+
+        if amount_x != 0 {
+            transfer_checked(self.send_x().with_signer(signer), &self.token_x, amount_x)?;
+        }
+        if amount_y != 0 {
+            transfer_checked(self.send_y().with_signer(signer), &self.token_y, amount_y)?;
+        }
+
+        Ok(())
+    }
+
+    // a matching flash_repay of the same pool has to follow later in the transaction
+    fn check_repay(&self, amount_x: u64, amount_y: u64) -> ProgramResult {
+        let instructions = &self.instructions;
+        let current_index = load_current_index_checked(instructions)? as usize;
+
+        // introspection only sees top level instructions
+        let current = load_instruction_at_checked(current_index, instructions)?;
+        require!(current.program_id == crate::ID, FlashLoanCpi);
+
+        let repay_data = crate::instruction::FlashRepay { amount_x, amount_y }.data();
+        let loan_data = crate::instruction::FlashLoan { amount_x, amount_y }.data();
+        let loan_discriminator = &loan_data[..8];
+
+        let mut index = current_index + 1;
+        while let Ok(instruction) = load_instruction_at_checked(index, instructions) {
+            let is_same_pool = instruction
+                .accounts
+                .get(FLASH_LOAN_POOL_INDEX)
+                .map_or(false, |meta| meta.pubkey == self.pool.key());
+
+            if instruction.program_id == crate::ID && is_same_pool {
+                if instruction.data == repay_data {
+                    return Ok(());
+                }
+                // another loan would be covered by the same repay
+                require!(
+                    !instruction.data.starts_with(loan_discriminator),
+                    FlashLoanNotRepaid
+                );
+            }
+            index += 1;
+        }
+
+        Err(FlashLoanNotRepaid.into())
+    }
+}
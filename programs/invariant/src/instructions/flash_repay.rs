@@ -0,0 +1,114 @@
+use crate::decimals::*;
+use crate::events::FlashLoanEvent;
+use crate::interfaces::TakeTokens;
+use crate::structs::pool::Pool;
+use crate::structs::state::State;
+use crate::token_interface::{token_account, transfer_with_fee};
+use crate::ErrorCode::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::Transfer;
+
+#[derive(Accounts)]
+pub struct FlashRepay<'info> {
+    #[account(seeds = [b"statev1".as_ref()], bump = state.load()?.bump)]
+    pub state: AccountLoader<'info, State>,
+    #[account(mut,
+        seeds = [b"poolv1", token_x.key().as_ref(), token_y.key().as_ref(), &pool.load()?.fee.v.to_le_bytes(), &pool.load()?.tick_spacing.to_le_bytes()],
+        bump = pool.load()?.bump
+    )]
+    pub pool: AccountLoader<'info, Pool>,
+    #[account(constraint = token_x.key() == pool.load()?.token_x @ InvalidTokenAccount)]
+    pub token_x: AccountInfo<'info>,
+    #[account(constraint = token_y.key() == pool.load()?.token_y @ InvalidTokenAccount)]
+    pub token_y: AccountInfo<'info>,
+    #[account(mut,
+        constraint = token_account(&account_x)?.mint == token_x.key() @ InvalidMint,
+        constraint = token_account(&account_x)?.owner == borrower.key() @ InvalidOwner
+    )]
+    pub account_x: AccountInfo<'info>,
+    #[account(mut,
+        constraint = token_account(&account_y)?.mint == token_y.key() @ InvalidMint,
+        constraint = token_account(&account_y)?.owner == borrower.key() @ InvalidOwner
+    )]
+    pub account_y: AccountInfo<'info>,
+    #[account(mut,
+        constraint = reserve_x.key() == pool.load()?.token_x_reserve @ InvalidTokenAccount
+    )]
+    pub reserve_x: AccountInfo<'info>,
+    #[account(mut,
+        constraint = reserve_y.key() == pool.load()?.token_y_reserve @ InvalidTokenAccount
+    )]
+    pub reserve_y: AccountInfo<'info>,
+    pub borrower: Signer<'info>,
+    #[account(constraint = &state.load()?.authority == program_authority.key @ InvalidAuthority)]
+    pub program_authority: AccountInfo<'info>,
+    #[account(constraint = token_x_program.key == token_x.owner @ InvalidTokenProgram)]
+    pub token_x_program: AccountInfo<'info>,
+    #[account(constraint = token_y_program.key == token_y.owner @ InvalidTokenProgram)]
+    pub token_y_program: AccountInfo<'info>,
+}
+
+impl<'info> TakeTokens<'info> for FlashRepay<'info> {
+    fn take_x(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        CpiContext::new(
+            self.token_x_program.to_account_info(),
+            Transfer {
+                from: self.account_x.to_account_info(),
+                to: self.reserve_x.to_account_info(),
+                authority: self.borrower.to_account_info().clone(),
+            },
+        )
+    }
+
+    fn take_y(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        CpiContext::new(
+            self.token_y_program.to_account_info(),
+            Transfer {
+                from: self.account_y.to_account_info(),
+                to: self.reserve_y.to_account_info(),
+                authority: self.borrower.to_account_info().clone(),
+            },
+        )
+    }
+}
+
+impl<'info> FlashRepay<'info> {
+    pub fn handler(&self, amount_x: u64, amount_y: u64) -> ProgramResult {
+        msg!("INVARIANT: FLASH REPAY");
+
+        let mut pool = self.pool.load_mut()?;
+
+        // fee is charged the same way as on swap and goes to liquidity providers
+        let fee_x = TokenAmount(amount_x).big_mul_up(pool.fee);
+        let fee_y = TokenAmount(amount_y).big_mul_up(pool.fee);
+
+        if amount_x != 0 {
+            transfer_with_fee(
+                self.take_x(),
+                &self.token_x,
+                (TokenAmount(amount_x) + fee_x).0,
+            )?;
+        }
+        if amount_y != 0 {
+            transfer_with_fee(
+                self.take_y(),
+                &self.token_y,
+                (TokenAmount(amount_y) + fee_y).0,
+            )?;
+        }
+
+        pool.add_fee(fee_x, FixedPoint::from_integer(0), true);
+        pool.add_fee(fee_y, FixedPoint::from_integer(0), false);
+
+        emit!(FlashLoanEvent {
+            pool: self.pool.key(),
+            borrower: self.borrower.key(),
+            amount_x,
+            amount_y,
+            fee_x: fee_x.0,
+            fee_y: fee_y.0,
+        });
+
+        Ok(())
+    }
+}
@@ -4,7 +4,6 @@ use crate::structs::pool::Pool;
 use crate::ErrorCode::*;
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::system_program;
-use anchor_spl::token::Mint;
 
 #[derive(Accounts)]
 pub struct InitializeOracle<'info> {
@@ -16,9 +15,9 @@ pub struct InitializeOracle<'info> {
     #[account(zero)]
     pub oracle: AccountLoader<'info, Oracle>,
     #[account(constraint = token_x.key() == pool.load()?.token_x @ InvalidTokenAccount)]
-    pub token_x: Box<Account<'info, Mint>>,
+    pub token_x: AccountInfo<'info>,
     #[account(constraint = token_y.key() == pool.load()?.token_y @ InvalidTokenAccount)]
-    pub token_y: Box<Account<'info, Mint>>,
+    pub token_y: AccountInfo<'info>,
     pub payer: Signer<'info>,
     pub rent: Sysvar<'info, Rent>,
     #[account(address = system_program::ID)]
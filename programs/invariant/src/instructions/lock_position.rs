@@ -0,0 +1,39 @@
+use crate::structs::position::Position;
+use crate::ErrorCode::*;
+use anchor_lang::prelude::*;
+
+pub const STAKER_SEED: &str = "staker";
+
+pub mod staker_program {
+    use anchor_lang::declare_id;
+    declare_id!("MJ6WF1tpEJ7Gk8ULqejDJapRfqBwBEp1dH5QvAgYxu9");
+}
+
+// Staked positions are locked by the staker, so liquidity snapshotted in a stake can't be removed or transferred
+#[derive(Accounts)]
+pub struct LockPosition<'info> {
+    #[account(mut)]
+    pub position: AccountLoader<'info, Position>,
+    #[account(
+        constraint = staker_authority.key() == Pubkey::find_program_address(&[STAKER_SEED.as_bytes()], &staker_program::ID).0 @ InvalidAuthority
+    )]
+    pub staker_authority: Signer<'info>,
+}
+
+impl<'info> LockPosition<'info> {
+    pub fn handler(&self, lock: bool) -> ProgramResult {
+        msg!("INVARIANT: LOCK POSITION");
+
+        let mut position = self.position.load_mut()?;
+        position.stake_count = match lock {
+            true => { position.stake_count }
+                .checked_add(1)
+                .ok_or(TooManyStakes)?,
+            false => { position.stake_count }
+                .checked_sub(1)
+                .ok_or(PositionNotLocked)?,
+        };
+
+        Ok(())
+    }
+}
@@ -0,0 +1,59 @@
+use crate::structs::position::Position;
+use crate::ErrorCode::*;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::invoke;
+use anchor_lang::solana_program::{system_instruction, system_program};
+use anchor_lang::Discriminator;
+
+// Positions created before stake_count was appended are 2 bytes shorter
+pub const LEGACY_POSITION_LEN: usize =
+    8 + std::mem::size_of::<Position>() - std::mem::size_of::<u16>();
+
+#[derive(Accounts)]
+pub struct MigratePosition<'info> {
+    #[account(mut,
+        constraint = *position.owner == crate::ID @ InvalidPositionLayout
+    )]
+    pub position: AccountInfo<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(address = system_program::ID)]
+    pub system_program: AccountInfo<'info>,
+}
+
+impl<'info> MigratePosition<'info> {
+    pub fn handler(&self) -> ProgramResult {
+        msg!("INVARIANT: MIGRATE POSITION");
+        let position = &self.position;
+        {
+            let data = position.try_borrow_data()?;
+            require!(data.len() == LEGACY_POSITION_LEN, InvalidPositionLayout);
+            require!(
+                data[..8] == Position::discriminator(),
+                InvalidPositionLayout
+            );
+        }
+
+        let space = 8 + std::mem::size_of::<Position>();
+        let required_lamports = Rent::get()?.minimum_balance(space);
+        let current_lamports = position.lamports();
+        if required_lamports > current_lamports {
+            invoke(
+                &system_instruction::transfer(
+                    self.payer.key,
+                    position.key,
+                    required_lamports - current_lamports,
+                ),
+                &[
+                    self.payer.to_account_info(),
+                    position.clone(),
+                    self.system_program.clone(),
+                ],
+            )?;
+        }
+        // appended stake_count is zero initialized, the layout of other fields is unchanged
+        position.realloc(space, true)?;
+
+        Ok(())
+    }
+}
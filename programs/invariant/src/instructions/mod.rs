@@ -1,29 +1,53 @@
+pub mod change_default_protocol_fee;
+pub mod change_fee_distribution;
 pub mod change_fee_receiver;
 pub mod change_protocol_fee;
+pub mod change_protocol_fee_batch;
 pub mod claim_fee;
+pub mod create_default_protocol_fee;
+pub mod create_fee_distribution;
 pub mod create_fee_tier;
 pub mod create_pool;
 pub mod create_position;
+pub mod create_position_by_amounts;
 pub mod create_position_list;
+pub mod create_position_single_sided;
 pub mod create_state;
 pub mod create_tick;
+pub mod distribute_protocol_fee;
+pub mod flash_loan;
+pub mod flash_repay;
 pub mod initialize_oracle;
+pub mod lock_position;
+pub mod migrate_position;
+pub mod native_sol;
 pub mod remove_position;
 pub mod swap;
 pub mod transfer_position_ownership;
 pub mod update_seconds_per_liquidity;
 pub mod withdraw_protocol_fee;
 
+pub use change_default_protocol_fee::*;
+pub use change_fee_distribution::*;
 pub use change_fee_receiver::*;
 pub use change_protocol_fee::*;
+pub use change_protocol_fee_batch::*;
 pub use claim_fee::*;
+pub use create_default_protocol_fee::*;
+pub use create_fee_distribution::*;
 pub use create_fee_tier::*;
 pub use create_pool::*;
 pub use create_position::*;
 pub use create_position_list::*;
 pub use create_state::*;
 pub use create_tick::*;
+pub use distribute_protocol_fee::*;
+pub use flash_loan::*;
+pub use flash_repay::*;
 pub use initialize_oracle::*;
+pub use lock_position::*;
+pub use migrate_position::*;
+pub use native_sol::*;
 pub use remove_position::*;
 pub use swap::*;
 pub use transfer_position_ownership::*;
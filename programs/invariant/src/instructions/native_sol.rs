@@ -0,0 +1,144 @@
+use crate::*;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::solana_program::program::{invoke, invoke_signed};
+use anchor_lang::solana_program::{system_instruction, system_program};
+use anchor_spl::token::{self, CloseAccount, InitializeAccount, Mint};
+
+pub const NATIVE_SOL_SEED: &str = "wsolv1";
+const TOKEN_ACCOUNT_SIZE: usize = 165;
+
+pub mod native_mint {
+    use anchor_lang::declare_id;
+    declare_id!("So11111111111111111111111111111111111111112");
+}
+
+// Wrapped SOL account lives only for the duration of a single instruction.
+// Accounts of the wrapped instruction are passed as remaining accounts, with `wrapped_sol`
+// in place of the owner's token account on the native side.
+#[derive(Accounts)]
+pub struct NativeSol<'info> {
+    #[account(mut, seeds = [NATIVE_SOL_SEED.as_bytes(), owner.key().as_ref()], bump)]
+    pub wrapped_sol: AccountInfo<'info>,
+    #[account(address = native_mint::ID)]
+    pub native_mint: Account<'info, Mint>,
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    #[account(address = crate::ID)]
+    pub invariant_program: AccountInfo<'info>,
+    #[account(address = token::ID)]
+    pub token_program: AccountInfo<'info>,
+    pub rent: Sysvar<'info, Rent>,
+    #[account(address = system_program::ID)]
+    pub system_program: AccountInfo<'info>,
+}
+
+impl<'info> NativeSol<'info> {
+    pub fn handler(
+        ctx: Context<'_, '_, '_, 'info, NativeSol<'info>>,
+        amount: u64, // lamports wrapped before the instruction
+        data: Vec<u8>,
+    ) -> ProgramResult {
+        msg!("INVARIANT: NATIVE SOL");
+
+        let bump = *ctx.bumps.get("wrapped_sol").unwrap();
+        ctx.accounts.wrap(amount, bump)?;
+        ctx.accounts.invoke_self(ctx.remaining_accounts, data)?;
+        ctx.accounts.unwrap()
+    }
+
+    fn wrap(&self, amount: u64, bump: u8) -> ProgramResult {
+        let owner = self.owner.key();
+        let address = self.wrapped_sol.key();
+        let seeds: &[&[u8]] = &[NATIVE_SOL_SEED.as_bytes(), owner.as_ref(), &[bump]];
+        let accounts = [
+            self.owner.to_account_info(),
+            self.wrapped_sol.clone(),
+            self.system_program.clone(),
+        ];
+
+        // wrapped amount is counted from lamports above rent exemption on initialization
+        let required = self
+            .rent
+            .minimum_balance(TOKEN_ACCOUNT_SIZE)
+            .checked_add(amount)
+            .unwrap();
+        let current = self.wrapped_sol.lamports();
+
+        if current == 0 {
+            invoke_signed(
+                &system_instruction::create_account(
+                    &owner,
+                    &address,
+                    required,
+                    TOKEN_ACCOUNT_SIZE as u64,
+                    &token::ID,
+                ),
+                &accounts,
+                &[seeds],
+            )?;
+        } else {
+            // lamports sent to the address upfront would make create_account fail
+            if required > current {
+                invoke(
+                    &system_instruction::transfer(&owner, &address, required - current),
+                    &accounts,
+                )?;
+            }
+            invoke_signed(
+                &system_instruction::allocate(&address, TOKEN_ACCOUNT_SIZE as u64),
+                &accounts,
+                &[seeds],
+            )?;
+            invoke_signed(
+                &system_instruction::assign(&address, &token::ID),
+                &accounts,
+                &[seeds],
+            )?;
+        }
+
+        token::initialize_account(CpiContext::new(
+            self.token_program.clone(),
+            InitializeAccount {
+                account: self.wrapped_sol.clone(),
+                mint: self.native_mint.to_account_info(),
+                authority: self.owner.to_account_info(),
+                rent: self.rent.to_account_info(),
+            },
+        ))
+    }
+
+    fn invoke_self(&self, accounts: &[AccountInfo<'info>], data: Vec<u8>) -> ProgramResult {
+        let metas = accounts
+            .iter()
+            .map(|account| match account.is_writable {
+                true => AccountMeta::new(*account.key, account.is_signer),
+                false => AccountMeta::new_readonly(*account.key, account.is_signer),
+            })
+            .collect();
+
+        let mut infos = accounts.to_vec();
+        infos.push(self.invariant_program.clone());
+
+        invoke(
+            &Instruction {
+                program_id: crate::ID,
+                accounts: metas,
+                data,
+            },
+            &infos,
+        )
+    }
+
+    // closing returns both the rent and the remaining wrapped balance to the owner
+    fn unwrap(&self) -> ProgramResult {
+        token::close_account(CpiContext::new(
+            self.token_program.clone(),
+            CloseAccount {
+                account: self.wrapped_sol.clone(),
+                destination: self.owner.to_account_info(),
+                authority: self.owner.to_account_info(),
+            },
+        ))
+    }
+}
@@ -1,16 +1,19 @@
 use crate::decimals::*;
+use crate::events::PositionRemovedEvent;
 use crate::interfaces::send_tokens::SendTokens;
+use crate::return_data::{self, RemovePositionReturnData};
 use crate::structs::pool::Pool;
 use crate::structs::position::Position;
 use crate::structs::position_list::PositionList;
 use crate::structs::tick::Tick;
 use crate::structs::tickmap::Tickmap;
+use crate::token_interface::{token_account, transfer_checked};
 use crate::util::{check_ticks, close};
 use crate::ErrorCode::*;
 use crate::*;
 use anchor_lang::prelude::*;
 use anchor_spl::token;
-use anchor_spl::token::{Mint, TokenAccount, Transfer};
+use anchor_spl::token::Transfer;
 
 #[derive(Accounts)]
 #[instruction(index: i32, lower_tick_index: i32, upper_tick_index: i32)]
@@ -62,41 +65,39 @@ pub struct RemovePosition<'info> {
     #[account(mut)]
     pub owner: Signer<'info>,
     #[account(constraint = token_x.key() == pool.load()?.token_x @ InvalidTokenAccount)]
-    pub token_x: Account<'info, Mint>,
+    pub token_x: AccountInfo<'info>,
     #[account(constraint = token_y.key() == pool.load()?.token_y @ InvalidTokenAccount)]
-    pub token_y: Account<'info, Mint>,
+    pub token_y: AccountInfo<'info>,
     #[account(mut,
-        constraint = account_x.mint == token_x.key() @ InvalidMint,
-        constraint = &account_x.owner == owner.key @ InvalidOwner
+        constraint = token_account(&account_x)?.mint == token_x.key() @ InvalidMint,
+        constraint = token_account(&account_x)?.owner == owner.key() @ InvalidOwner
     )]
-    pub account_x: Box<Account<'info, TokenAccount>>,
+    pub account_x: AccountInfo<'info>,
     #[account(mut,
-        constraint = account_y.mint == token_y.key() @ InvalidMint,
-        constraint = &account_y.owner == owner.key @ InvalidOwner
+        constraint = token_account(&account_y)?.mint == token_y.key() @ InvalidMint,
+        constraint = token_account(&account_y)?.owner == owner.key() @ InvalidOwner
     )]
-    pub account_y: Box<Account<'info, TokenAccount>>,
+    pub account_y: AccountInfo<'info>,
     #[account(mut,
-        constraint = reserve_x.mint == token_x.key() @ InvalidMint,
-        constraint = &reserve_x.owner == program_authority.key @ InvalidAuthority,
         constraint = reserve_x.key() == pool.load()?.token_x_reserve @ InvalidTokenAccount
     )]
-    pub reserve_x: Box<Account<'info, TokenAccount>>,
+    pub reserve_x: AccountInfo<'info>,
     #[account(mut,
-        constraint = reserve_y.mint == token_y.key() @ InvalidMint,
-        constraint = &reserve_y.owner == program_authority.key @ InvalidAuthority,
         constraint = reserve_y.key() == pool.load()?.token_y_reserve @ InvalidTokenAccount
     )]
-    pub reserve_y: Box<Account<'info, TokenAccount>>,
+    pub reserve_y: AccountInfo<'info>,
     #[account(constraint = &state.load()?.authority == program_authority.key @ InvalidAuthority)]
     pub program_authority: AccountInfo<'info>,
-    #[account(address = token::ID)]
-    pub token_program: AccountInfo<'info>,
+    #[account(constraint = token_x_program.key == token_x.owner @ InvalidTokenProgram)]
+    pub token_x_program: AccountInfo<'info>,
+    #[account(constraint = token_y_program.key == token_y.owner @ InvalidTokenProgram)]
+    pub token_y_program: AccountInfo<'info>,
 }
 
 impl<'info> SendTokens<'info> for RemovePosition<'info> {
     fn send_x(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
         CpiContext::new(
-            self.token_program.to_account_info(),
+            self.token_x_program.to_account_info(),
             Transfer {
                 from: self.reserve_x.to_account_info(),
                 to: self.account_x.to_account_info(),
@@ -107,7 +108,7 @@ impl<'info> SendTokens<'info> for RemovePosition<'info> {
 
     fn send_y(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
         CpiContext::new(
-            self.token_program.to_account_info(),
+            self.token_y_program.to_account_info(),
             Transfer {
                 from: self.reserve_y.to_account_info(),
                 to: self.account_y.to_account_info(),
@@ -132,11 +133,15 @@ impl<'info> RemovePosition<'info> {
         let pool = &mut self.pool.load_mut()?;
         let tickmap = &mut self.tickmap.load_mut()?;
         let current_timestamp = get_current_timestamp();
+        require!({ removed_position.stake_count } == 0, PositionLocked);
 
         // closing tick can't be in the same scope as loaded tick
         let close_lower;
         let close_upper;
 
+        let removed_id = removed_position.id;
+        let removed_liquidity = removed_position.liquidity;
+
         let (amount_x, amount_y) = {
             let lower_tick = &mut self.lower_tick.load_mut()?;
             let upper_tick = &mut self.upper_tick.load_mut()?;
@@ -211,6 +216,7 @@ impl<'info> RemovePosition<'info> {
                 last_slot: last_position.last_slot,
                 tokens_owed_x: last_position.tokens_owed_x,
                 tokens_owed_y: last_position.tokens_owed_y,
+                stake_count: last_position.stake_count,
             };
 
             *last_position = Default::default();
@@ -219,8 +225,25 @@ impl<'info> RemovePosition<'info> {
         }
 
         let signer: &[&[&[u8]]] = get_signer!(state.nonce);
-        token::transfer(self.send_x().with_signer(signer), amount_x.0)?;
-        token::transfer(self.send_y().with_signer(signer), amount_y.0)?;
+        transfer_checked(self.send_x().with_signer(signer), &self.token_x, amount_x.0)?;
+        transfer_checked(self.send_y().with_signer(signer), &self.token_y, amount_y.0)?;
+
+        emit!(PositionRemovedEvent {
+            owner: self.owner.key(),
+            pool: self.pool.key(),
+            id: removed_id,
+            liquidity: removed_liquidity,
+            lower_tick: lower_tick_index,
+            upper_tick: upper_tick_index,
+            amount_x: amount_x.0,
+            amount_y: amount_y.0,
+            current_sqrt_price: pool.sqrt_price,
+        });
+
+        return_data::set(&RemovePositionReturnData {
+            amount_x: amount_x.0,
+            amount_y: amount_y.0,
+        });
 
         Ok(())
     }
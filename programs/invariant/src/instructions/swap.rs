@@ -1,24 +1,43 @@
+use crate::events::{SwapEvent, TickCrossedEvent};
 use crate::interfaces::send_tokens::SendTokens;
 use crate::interfaces::take_ref_tokens::TakeRefTokens;
 use crate::interfaces::take_tokens::TakeTokens;
 use crate::log::get_tick_at_sqrt_price;
 use crate::math::compute_swap_step;
+use crate::return_data::{self, SwapReturnData};
 use crate::structs::pool::Pool;
 use crate::structs::tick::Tick;
 use crate::structs::tickmap::Tickmap;
+use crate::token_interface::{is_token_program, mint, token_account, transfer_checked};
 use crate::util::get_closer_limit;
 use crate::ErrorCode::*;
 use crate::*;
 use crate::{decimals::*, referral::whitelist::contains_owner};
 use anchor_lang::prelude::*;
-use anchor_spl::token::{TokenAccount, Transfer};
+use anchor_spl::token::Transfer;
+
+// Bounds number of ticks crossed in a single swap, so compute usage can be predicted
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq)]
+pub struct CrossLimit {
+    pub max_initialized: u16,
+    pub max_virtual: u16,
+    pub partial_fill: bool, // stop at the last allowed tick instead of failing
+}
+
+impl CrossLimit {
+    pub const UNLIMITED: CrossLimit = CrossLimit {
+        max_initialized: u16::MAX,
+        max_virtual: u16::MAX,
+        partial_fill: false,
+    };
+}
 
 #[derive(Accounts)]
 pub struct Swap<'info> {
     #[account(seeds = [b"statev1".as_ref()], bump = state.load()?.bump)]
     pub state: AccountLoader<'info, State>,
     #[account(mut,
-        seeds = [b"poolv1", account_x.mint.as_ref(), account_y.mint.as_ref(), &pool.load()?.fee.v.to_le_bytes(), &pool.load()?.tick_spacing.to_le_bytes()],
+        seeds = [b"poolv1", token_x.key().as_ref(), token_y.key().as_ref(), &pool.load()?.fee.v.to_le_bytes(), &pool.load()?.tick_spacing.to_le_bytes()],
         bump = pool.load()?.bump
     )]
     pub pool: AccountLoader<'info, Pool>,
@@ -27,37 +46,40 @@ pub struct Swap<'info> {
         constraint = tickmap.to_account_info().owner == program_id @ InvalidTickmapOwner
     )]
     pub tickmap: AccountLoader<'info, Tickmap>,
+    #[account(constraint = token_x.key() == pool.load()?.token_x @ InvalidTokenAccount)]
+    pub token_x: AccountInfo<'info>,
+    #[account(constraint = token_y.key() == pool.load()?.token_y @ InvalidTokenAccount)]
+    pub token_y: AccountInfo<'info>,
+    // owners are checked in the handler, as the output account is unused when swapping to a recipient
     #[account(mut,
-        constraint = &account_x.owner == owner.key @ InvalidOwner
+        constraint = token_account(&account_x)?.mint == token_x.key() @ InvalidMint
     )]
-    pub account_x: Account<'info, TokenAccount>,
+    pub account_x: AccountInfo<'info>,
     #[account(mut,
-        constraint = &account_y.owner == owner.key @ InvalidOwner
+        constraint = token_account(&account_y)?.mint == token_y.key() @ InvalidMint
     )]
-    pub account_y: Account<'info, TokenAccount>,
+    pub account_y: AccountInfo<'info>,
     #[account(mut,
-        constraint = reserve_x.mint == account_x.mint @ InvalidMint,
-        constraint = &reserve_x.owner == program_authority.key @ InvalidAuthority,
-        constraint = reserve_x.to_account_info().key == &pool.load()?.token_x_reserve @ InvalidTokenAccount
+        constraint = reserve_x.key() == pool.load()?.token_x_reserve @ InvalidTokenAccount
     )]
-    pub reserve_x: Box<Account<'info, TokenAccount>>,
+    pub reserve_x: AccountInfo<'info>,
     #[account(mut,
-        constraint = reserve_y.mint == account_y.mint @ InvalidMint,
-        constraint = &reserve_y.owner == program_authority.key @ InvalidAuthority,
-        constraint = reserve_y.to_account_info().key == &pool.load()?.token_y_reserve @ InvalidTokenAccount
+        constraint = reserve_y.key() == pool.load()?.token_y_reserve @ InvalidTokenAccount
     )]
-    pub reserve_y: Box<Account<'info, TokenAccount>>,
+    pub reserve_y: AccountInfo<'info>,
     pub owner: Signer<'info>,
     #[account(constraint = &state.load()?.authority == program_authority.key @ InvalidAuthority)]
     pub program_authority: AccountInfo<'info>,
-    #[account(address = token::ID)]
-    pub token_program: AccountInfo<'info>,
+    #[account(constraint = token_x_program.key == token_x.owner @ InvalidTokenProgram)]
+    pub token_x_program: AccountInfo<'info>,
+    #[account(constraint = token_y_program.key == token_y.owner @ InvalidTokenProgram)]
+    pub token_y_program: AccountInfo<'info>,
 }
 
 impl<'info> TakeTokens<'info> for Swap<'info> {
     fn take_x(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
         CpiContext::new(
-            self.token_program.to_account_info(),
+            self.token_x_program.to_account_info(),
             Transfer {
                 from: self.account_x.to_account_info(),
                 to: self.reserve_x.to_account_info(),
@@ -68,7 +90,7 @@ impl<'info> TakeTokens<'info> for Swap<'info> {
 
     fn take_y(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
         CpiContext::new(
-            self.token_program.to_account_info(),
+            self.token_y_program.to_account_info(),
             Transfer {
                 from: self.account_y.to_account_info(),
                 to: self.reserve_y.to_account_info(),
@@ -80,7 +102,7 @@ impl<'info> TakeTokens<'info> for Swap<'info> {
 impl<'info> SendTokens<'info> for Swap<'info> {
     fn send_x(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
         CpiContext::new(
-            self.token_program.to_account_info(),
+            self.token_x_program.to_account_info(),
             Transfer {
                 from: self.reserve_x.to_account_info(),
                 to: self.account_x.to_account_info(),
@@ -91,7 +113,7 @@ impl<'info> SendTokens<'info> for Swap<'info> {
 
     fn send_y(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
         CpiContext::new(
-            self.token_program.to_account_info(),
+            self.token_y_program.to_account_info(),
             Transfer {
                 from: self.reserve_y.to_account_info(),
                 to: self.account_y.to_account_info(),
@@ -104,7 +126,7 @@ impl<'info> SendTokens<'info> for Swap<'info> {
 impl<'info> TakeRefTokens<'info> for Swap<'info> {
     fn take_ref_x(&self, to: AccountInfo<'info>) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
         CpiContext::new(
-            self.token_program.to_account_info(),
+            self.token_x_program.to_account_info(),
             Transfer {
                 from: self.account_x.to_account_info(),
                 to: to.to_account_info(),
@@ -115,7 +137,7 @@ impl<'info> TakeRefTokens<'info> for Swap<'info> {
 
     fn take_ref_y(&self, to: AccountInfo<'info>) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
         CpiContext::new(
-            self.token_program.to_account_info(),
+            self.token_y_program.to_account_info(),
             Transfer {
                 from: self.account_y.to_account_info(),
                 to: to.to_account_info(),
@@ -126,12 +148,36 @@ impl<'info> TakeRefTokens<'info> for Swap<'info> {
 }
 
 impl<'info> Swap<'info> {
+    fn send_x_to(&self, to: AccountInfo<'info>) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        CpiContext::new(
+            self.token_x_program.to_account_info(),
+            Transfer {
+                from: self.reserve_x.to_account_info(),
+                to,
+                authority: self.program_authority.clone(),
+            },
+        )
+    }
+
+    fn send_y_to(&self, to: AccountInfo<'info>) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        CpiContext::new(
+            self.token_y_program.to_account_info(),
+            Transfer {
+                from: self.reserve_y.to_account_info(),
+                to,
+                authority: self.program_authority.clone(),
+            },
+        )
+    }
+
     pub fn handler(
         ctx: Context<'_, '_, '_, 'info, Swap<'info>>,
         x_to_y: bool,
         amount: u64,
         by_amount_in: bool, // whether amount specifies input or output
         sqrt_price_limit: u128,
+        cross_limit: CrossLimit,
+        recipient: Option<Pubkey>, // token account receiving the output, account of the owner by default
     ) -> ProgramResult {
         msg!("INVARIANT: SWAP");
         require!(amount != 0, ZeroAmount);
@@ -144,14 +190,14 @@ impl<'info> Swap<'info> {
         let ref_account = match ctx
             .remaining_accounts
             .iter()
-            .find(|account| *account.owner == token::ID)
+            .find(|account| is_token_program(account.owner) && Some(*account.key) != recipient)
         {
-            Some(account) => match Account::<'_, TokenAccount>::try_from(account) {
+            Some(account) => match token_account(account) {
                 Ok(token) => {
                     let is_valid_mint = token.mint
                         == match x_to_y {
-                            true => ctx.accounts.account_x.mint,
-                            false => ctx.accounts.account_y.mint,
+                            true => pool.token_x,
+                            false => pool.token_y,
                         };
                     let is_on_whitelist = contains_owner(token.owner);
                     match is_valid_mint && is_on_whitelist {
@@ -164,6 +210,41 @@ impl<'info> Swap<'info> {
             None => None,
         };
 
+        let recipient_account = match recipient {
+            Some(recipient) => {
+                let account = match ctx
+                    .remaining_accounts
+                    .iter()
+                    .find(|account| *account.key == recipient)
+                {
+                    Some(account) => account,
+                    None => return Err(ErrorCode::InvalidTokenAccount.into()),
+                };
+                let token_out = match x_to_y {
+                    true => pool.token_y,
+                    false => pool.token_x,
+                };
+                require!(token_account(account)?.mint == token_out, InvalidMint);
+                Some(account)
+            }
+            None => None,
+        };
+
+        let (account_in, account_out) = match x_to_y {
+            true => (&ctx.accounts.account_x, &ctx.accounts.account_y),
+            false => (&ctx.accounts.account_y, &ctx.accounts.account_x),
+        };
+        require!(
+            token_account(account_in)?.owner == ctx.accounts.owner.key(),
+            InvalidOwner
+        );
+        if recipient_account.is_none() {
+            require!(
+                token_account(account_out)?.owner == ctx.accounts.owner.key(),
+                InvalidOwner
+            );
+        }
+
         // limit is on the right side of price
         if x_to_y {
             require!(
@@ -179,11 +260,31 @@ impl<'info> Swap<'info> {
             );
         }
 
+        // swap is computed on amounts the pool actually receives and sends
+        let (token_in, token_out) = match x_to_y {
+            true => (&ctx.accounts.token_x, &ctx.accounts.token_y),
+            false => (&ctx.accounts.token_y, &ctx.accounts.token_x),
+        };
+        let (mint_in, mint_out) = (mint(token_in)?, mint(token_out)?);
+        let epoch = Clock::get()?.epoch;
+        let amount = match by_amount_in {
+            true => amount - mint_in.get_fee(amount, epoch),
+            false => mint_out
+                .get_pre_fee_amount(amount, epoch)
+                .ok_or(TransferFeeOverflow)?,
+        };
+        require!(amount != 0, ZeroAmount);
+
+        let price_before = pool.sqrt_price;
         let mut remaining_amount = TokenAmount(amount);
 
         let mut total_amount_in = TokenAmount(0);
         let mut total_amount_out = TokenAmount(0);
         let mut total_amount_referral = TokenAmount(0);
+        let mut total_fee = TokenAmount(0);
+
+        let mut initialized_crosses: u16 = 0;
+        let mut virtual_crosses: u16 = 0;
 
         while !remaining_amount.is_zero() {
             let (swap_limit, limiting_tick) = get_closer_limit(
@@ -218,6 +319,7 @@ impl<'info> Swap<'info> {
 
             total_amount_in += result.amount_in + result.fee_amount;
             total_amount_out += result.amount_out;
+            total_fee += result.fee_amount;
 
             // Fail if price would go over swap limit
             if { pool.sqrt_price } == sqrt_price_limit && !remaining_amount.is_zero() {
@@ -238,6 +340,30 @@ impl<'info> Swap<'info> {
                     x_to_y,
                 );
 
+                let will_cross = !x_to_y || is_enough_amount_to_cross;
+
+                // limits are checked before the next cross, so a swap ending right at the limit succeeds
+                let (crosses, max_crosses) = match initialized {
+                    true => (initialized_crosses, cross_limit.max_initialized),
+                    false => (virtual_crosses, cross_limit.max_virtual),
+                };
+                if will_cross && crosses >= max_crosses {
+                    if !remaining_amount.is_zero() {
+                        msg!(
+                            "INVARIANT: CROSSES LIMIT REACHED {} {}",
+                            initialized_crosses,
+                            virtual_crosses
+                        );
+                        require!(cross_limit.partial_fill, TooManyTickCrosses);
+                    }
+                    // stop at the price of the tick without crossing it
+                    pool.current_tick_index = match x_to_y {
+                        true => tick_index,
+                        false => tick_index.checked_sub(pool.tick_spacing as i32).unwrap(),
+                    };
+                    break;
+                }
+
                 if initialized {
                     // Calculating address of the crossed tick
                     let (tick_address, _) = Pubkey::find_program_address(
@@ -261,16 +387,26 @@ impl<'info> Swap<'info> {
                     let mut tick = loader.load_mut().unwrap();
 
                     // crossing tick
-                    if !x_to_y || is_enough_amount_to_cross {
+                    if will_cross {
                         msg!("INVARIANT: CROSSING TICK {} ", { tick.index });
                         cross_tick(&mut tick, &mut pool, get_current_timestamp())?;
+                        initialized_crosses += 1;
+                        emit!(TickCrossedEvent {
+                            pool: ctx.accounts.pool.key(),
+                            index: tick.index,
+                            x_to_y,
+                            liquidity: pool.liquidity,
+                        });
                     } else if !remaining_amount.is_zero() {
                         if by_amount_in {
                             pool.add_fee(remaining_amount, FixedPoint::from_integer(0), x_to_y);
                             total_amount_in += remaining_amount;
+                            total_fee += remaining_amount;
                         }
                         remaining_amount = TokenAmount(0);
                     }
+                } else {
+                    virtual_crosses += 1;
                 }
                 // set tick to limit (below if price is going down, because current tick should always be below price)
                 pool.current_tick_index = if x_to_y && is_enough_amount_to_cross {
@@ -296,28 +432,73 @@ impl<'info> Swap<'info> {
         }
 
         // Execute swap
-        let (take_ctx, send_ctx) = match x_to_y {
-            true => (ctx.accounts.take_x(), ctx.accounts.send_y()),
-            false => (ctx.accounts.take_y(), ctx.accounts.send_x()),
+        let (take_ctx, send_ctx) = match (x_to_y, recipient_account) {
+            (true, Some(to)) => (ctx.accounts.take_x(), ctx.accounts.send_y_to(to.clone())),
+            (false, Some(to)) => (ctx.accounts.take_y(), ctx.accounts.send_x_to(to.clone())),
+            (true, None) => (ctx.accounts.take_x(), ctx.accounts.send_y()),
+            (false, None) => (ctx.accounts.take_y(), ctx.accounts.send_x()),
         };
 
         let signer: &[&[&[u8]]] = get_signer!(state.nonce);
-        token::transfer(send_ctx.with_signer(signer), total_amount_out.0)?;
+        transfer_checked(send_ctx.with_signer(signer), token_out, total_amount_out.0)?;
 
+        // swapper covers the transfer fee, so reserve and referral receive their whole share
         match ref_account.is_some() && !total_amount_referral.is_zero() {
             true => {
                 let take_ref_ctx = match x_to_y {
                     true => ctx.accounts.take_ref_x(ref_account.unwrap().clone()),
                     false => ctx.accounts.take_ref_y(ref_account.unwrap().clone()),
                 };
-                token::transfer(take_ctx, total_amount_in.0 - total_amount_referral.0)?;
-                token::transfer(take_ref_ctx, total_amount_referral.0)?;
+                let amount_to_reserve = total_amount_in.0 - total_amount_referral.0;
+                transfer_checked(
+                    take_ctx,
+                    token_in,
+                    mint_in
+                        .get_pre_fee_amount(amount_to_reserve, epoch)
+                        .ok_or(TransferFeeOverflow)?,
+                )?;
+                transfer_checked(
+                    take_ref_ctx,
+                    token_in,
+                    mint_in
+                        .get_pre_fee_amount(total_amount_referral.0, epoch)
+                        .ok_or(TransferFeeOverflow)?,
+                )?;
             }
             false => {
-                token::transfer(take_ctx, total_amount_in.0)?;
+                transfer_checked(
+                    take_ctx,
+                    token_in,
+                    mint_in
+                        .get_pre_fee_amount(total_amount_in.0, epoch)
+                        .ok_or(TransferFeeOverflow)?,
+                )?;
             }
         }
 
+        emit!(SwapEvent {
+            pool: ctx.accounts.pool.key(),
+            swapper: ctx.accounts.owner.key(),
+            x_to_y,
+            amount_in: total_amount_in.0,
+            amount_out: total_amount_out.0,
+            fee: total_fee.0,
+            referral_fee: total_amount_referral.0,
+            price_before,
+            price_after: pool.sqrt_price,
+            current_tick_index: pool.current_tick_index,
+            liquidity: pool.liquidity,
+        });
+
+        return_data::set(&SwapReturnData {
+            amount_in: total_amount_in.0,
+            amount_out: total_amount_out.0,
+            sqrt_price: pool.sqrt_price,
+            current_tick_index: pool.current_tick_index,
+            initialized_crosses,
+            virtual_crosses,
+        });
+
         Ok(())
     }
 }
@@ -56,6 +56,7 @@ impl<'info> TransferPositionOwnership<'info> {
         let mut recipient_list = self.recipient_list.load_mut()?;
         let new_position = &mut self.new_position.load_init()?;
         let removed_position = &mut self.removed_position.load_mut()?;
+        require!({ removed_position.stake_count } == 0, PositionLocked);
 
         owner_list.head = owner_list.head.checked_sub(1).unwrap();
         recipient_list.head = recipient_list.head.checked_add(1).unwrap();
@@ -76,6 +77,7 @@ impl<'info> TransferPositionOwnership<'info> {
                 tokens_owed_y: removed_position.tokens_owed_y,
                 last_slot: removed_position.last_slot,
                 bump, // assign new bump
+                stake_count: removed_position.stake_count,
             };
         }
 
@@ -97,6 +99,7 @@ impl<'info> TransferPositionOwnership<'info> {
                 tokens_owed_y: last_position.tokens_owed_y,
                 last_slot: last_position.last_slot,
                 bump: removed_position.bump, // stay with the same bump
+                stake_count: last_position.stake_count,
             };
             *last_position = Default::default();
         } else {
@@ -6,7 +6,6 @@ use crate::util::{get_current_slot, get_current_timestamp};
 use crate::ErrorCode::*;
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::system_program;
-use anchor_spl::token::Mint;
 use math::*;
 
 #[derive(Accounts)]
@@ -37,9 +36,9 @@ pub struct UpdateSecondsPerLiquidity<'info> {
     )]
     pub position: AccountLoader<'info, Position>,
     #[account(constraint = token_x.key() == pool.load()?.token_x @ InvalidTokenAccount)]
-    pub token_x: Account<'info, Mint>,
+    pub token_x: AccountInfo<'info>,
     #[account(constraint = token_y.key() == pool.load()?.token_y @ InvalidTokenAccount)]
-    pub token_y: Account<'info, Mint>,
+    pub token_y: AccountInfo<'info>,
     pub owner: AccountInfo<'info>,
     #[account(mut)]
     pub signer: Signer<'info>,
@@ -1,11 +1,12 @@
 use crate::interfaces::SendTokens;
 use crate::structs::pool::Pool;
 use crate::structs::state::State;
+use crate::token_interface::{token_account, transfer_checked};
 use crate::ErrorCode::*;
 use crate::SEED;
 use crate::*;
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Mint, TokenAccount, Transfer};
+use anchor_spl::token::Transfer;
 
 #[derive(Accounts)]
 pub struct WithdrawProtocolFee<'info> {
@@ -16,42 +17,45 @@ pub struct WithdrawProtocolFee<'info> {
         bump = pool.load()?.bump
     )]
     pub pool: AccountLoader<'info, Pool>,
+    // once created, the fee distribution is the only way to withdraw protocol fee
+    #[account(seeds = [b"feedistributionv1".as_ref()], bump,
+        constraint = fee_distribution.data_is_empty() @ FeeDistributionExists
+    )]
+    pub fee_distribution: AccountInfo<'info>,
     #[account(constraint = token_x.key() == pool.load()?.token_x @ InvalidTokenAccount)]
-    pub token_x: Account<'info, Mint>,
+    pub token_x: AccountInfo<'info>,
     #[account(constraint = token_y.key() == pool.load()?.token_y @ InvalidTokenAccount)]
-    pub token_y: Account<'info, Mint>,
+    pub token_y: AccountInfo<'info>,
     #[account(mut,
-        constraint = account_x.mint == token_x.key() @ InvalidMint
+        constraint = token_account(&account_x)?.mint == token_x.key() @ InvalidMint
     )]
-    pub account_x: Box<Account<'info, TokenAccount>>,
+    pub account_x: AccountInfo<'info>,
     #[account(mut,
-        constraint = account_y.mint == token_y.key() @ InvalidMint
+        constraint = token_account(&account_y)?.mint == token_y.key() @ InvalidMint
     )]
-    pub account_y: Box<Account<'info, TokenAccount>>,
+    pub account_y: AccountInfo<'info>,
     #[account(mut,
-        constraint = reserve_x.mint == token_x.key() @ InvalidMint,
-        constraint = &reserve_x.owner == program_authority.key @ InvalidAuthority,
         constraint = reserve_x.key() == pool.load()?.token_x_reserve @ InvalidTokenAccount
     )]
-    pub reserve_x: Account<'info, TokenAccount>,
+    pub reserve_x: AccountInfo<'info>,
     #[account(mut,
-        constraint = reserve_y.mint == token_y.key() @ InvalidMint,
-        constraint = &reserve_y.owner == program_authority.key @ InvalidAuthority,
         constraint = reserve_y.key() == pool.load()?.token_y_reserve @ InvalidTokenAccount
     )]
-    pub reserve_y: Account<'info, TokenAccount>,
+    pub reserve_y: AccountInfo<'info>,
     #[account(constraint = &pool.load()?.fee_receiver == authority.key @ InvalidAuthority)]
     pub authority: Signer<'info>,
     #[account(constraint = &state.load()?.authority == program_authority.key @ InvalidAuthority)]
     pub program_authority: AccountInfo<'info>,
-    #[account(address = token::ID)]
-    pub token_program: AccountInfo<'info>,
+    #[account(constraint = token_x_program.key == token_x.owner @ InvalidTokenProgram)]
+    pub token_x_program: AccountInfo<'info>,
+    #[account(constraint = token_y_program.key == token_y.owner @ InvalidTokenProgram)]
+    pub token_y_program: AccountInfo<'info>,
 }
 
 impl<'info> SendTokens<'info> for WithdrawProtocolFee<'info> {
     fn send_x(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
         CpiContext::new(
-            self.token_program.to_account_info(),
+            self.token_x_program.to_account_info(),
             Transfer {
                 from: self.reserve_x.to_account_info(),
                 to: self.account_x.to_account_info(),
@@ -62,7 +66,7 @@ impl<'info> SendTokens<'info> for WithdrawProtocolFee<'info> {
 
     fn send_y(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
         CpiContext::new(
-            self.token_program.to_account_info(),
+            self.token_y_program.to_account_info(),
             Transfer {
                 from: self.reserve_y.to_account_info(),
                 to: self.account_y.to_account_info(),
@@ -84,8 +88,8 @@ impl<'info> WithdrawProtocolFee<'info> {
         let cpi_ctx_x = self.send_x().with_signer(signer);
         let cpi_ctx_y = self.send_y().with_signer(signer);
 
-        token::transfer(cpi_ctx_x, pool.fee_protocol_token_x)?;
-        token::transfer(cpi_ctx_y, pool.fee_protocol_token_y)?;
+        transfer_checked(cpi_ctx_x, &self.token_x, pool.fee_protocol_token_x)?;
+        transfer_checked(cpi_ctx_y, &self.token_y, pool.fee_protocol_token_y)?;
 
         pool.fee_protocol_token_x = 0;
         pool.fee_protocol_token_y = 0;
@@ -1,16 +1,20 @@
 mod decimals;
 mod errors;
+pub mod events;
 mod instructions;
 mod interfaces;
 mod log;
 mod macros;
 mod math;
 mod referral;
+pub mod return_data;
 pub mod structs;
+mod token_interface;
 mod uint;
 mod util;
 
 use anchor_lang::prelude::*;
+use anchor_lang::InstructionData;
 use anchor_spl::token;
 
 use crate::decimals::*;
@@ -55,7 +59,61 @@ pub mod invariant {
         by_amount_in: bool, // whether amount specifies input or output
         sqrt_price_limit: u128,
     ) -> ProgramResult {
-        Swap::handler(ctx, x_to_y, amount, by_amount_in, sqrt_price_limit)
+        Swap::handler(
+            ctx,
+            x_to_y,
+            amount,
+            by_amount_in,
+            sqrt_price_limit,
+            CrossLimit::UNLIMITED,
+            None,
+        )
+    }
+
+    pub fn swap_with_cross_limit<'info>(
+        ctx: Context<'_, '_, '_, 'info, Swap<'info>>,
+        x_to_y: bool,
+        amount: u64,
+        by_amount_in: bool,
+        sqrt_price_limit: u128,
+        cross_limit: CrossLimit,
+    ) -> ProgramResult {
+        Swap::handler(
+            ctx,
+            x_to_y,
+            amount,
+            by_amount_in,
+            sqrt_price_limit,
+            cross_limit,
+            None,
+        )
+    }
+
+    pub fn swap_to<'info>(
+        ctx: Context<'_, '_, '_, 'info, Swap<'info>>,
+        x_to_y: bool,
+        amount: u64,
+        by_amount_in: bool,
+        sqrt_price_limit: u128,
+        recipient: Pubkey, // passed in remaining accounts
+    ) -> ProgramResult {
+        Swap::handler(
+            ctx,
+            x_to_y,
+            amount,
+            by_amount_in,
+            sqrt_price_limit,
+            CrossLimit::UNLIMITED,
+            Some(recipient),
+        )
+    }
+
+    pub fn flash_loan(ctx: Context<FlashLoan>, amount_x: u64, amount_y: u64) -> ProgramResult {
+        ctx.accounts.handler(amount_x, amount_y)
+    }
+
+    pub fn flash_repay(ctx: Context<FlashRepay>, amount_x: u64, amount_y: u64) -> ProgramResult {
+        ctx.accounts.handler(amount_x, amount_y)
     }
 
     pub fn initialize_oracle(ctx: Context<InitializeOracle>) -> ProgramResult {
@@ -87,6 +145,38 @@ pub mod invariant {
         )
     }
 
+    pub fn create_position_by_amounts(
+        ctx: Context<CreatePosition>,
+        _lower_tick_index: i32,
+        _upper_tick_index: i32,
+        max_amount_x: u64,
+        max_amount_y: u64,
+    ) -> ProgramResult {
+        ctx.accounts.by_amounts_handler(
+            TokenAmount(max_amount_x),
+            TokenAmount(max_amount_y),
+            *ctx.bumps.get("position").unwrap(),
+        )
+    }
+
+    pub fn create_position_single_sided(
+        ctx: Context<CreatePosition>,
+        _lower_tick_index: i32,
+        _upper_tick_index: i32,
+        amount: u64,
+        x_to_y: bool, // whether token x is deposited and partially swapped to y
+        slippage_limit_lower: Price,
+        slippage_limit_upper: Price,
+    ) -> ProgramResult {
+        ctx.accounts.single_sided_handler(
+            TokenAmount(amount),
+            x_to_y,
+            slippage_limit_lower,
+            slippage_limit_upper,
+            *ctx.bumps.get("position").unwrap(),
+        )
+    }
+
     pub fn remove_position(
         ctx: Context<RemovePosition>,
         index: u32,
@@ -114,6 +204,86 @@ pub mod invariant {
         ctx.accounts.handler()
     }
 
+    pub fn swap_native<'info>(
+        ctx: Context<'_, '_, '_, 'info, NativeSol<'info>>,
+        native_amount: u64, // lamports wrapped for the swap, the rest is returned
+        x_to_y: bool,
+        amount: u64,
+        by_amount_in: bool,
+        sqrt_price_limit: u128,
+    ) -> ProgramResult {
+        let data = crate::instruction::Swap {
+            x_to_y,
+            amount,
+            by_amount_in,
+            sqrt_price_limit,
+        }
+        .data();
+        NativeSol::handler(ctx, native_amount, data)
+    }
+
+    pub fn create_position_native<'info>(
+        ctx: Context<'_, '_, '_, 'info, NativeSol<'info>>,
+        native_amount: u64,
+        lower_tick_index: i32,
+        upper_tick_index: i32,
+        liquidity_delta: Liquidity,
+        slippage_limit_lower: Price,
+        slippage_limit_upper: Price,
+    ) -> ProgramResult {
+        let data = crate::instruction::CreatePosition {
+            _lower_tick_index: lower_tick_index,
+            _upper_tick_index: upper_tick_index,
+            liquidity_delta,
+            slippage_limit_lower,
+            slippage_limit_upper,
+        }
+        .data();
+        NativeSol::handler(ctx, native_amount, data)
+    }
+
+    pub fn remove_position_native<'info>(
+        ctx: Context<'_, '_, '_, 'info, NativeSol<'info>>,
+        index: u32,
+        lower_tick_index: i32,
+        upper_tick_index: i32,
+    ) -> ProgramResult {
+        let data = crate::instruction::RemovePosition {
+            index,
+            lower_tick_index,
+            upper_tick_index,
+        }
+        .data();
+        NativeSol::handler(ctx, 0, data)
+    }
+
+    pub fn claim_fee_native<'info>(
+        ctx: Context<'_, '_, '_, 'info, NativeSol<'info>>,
+        index: u32,
+        lower_tick_index: i32,
+        upper_tick_index: i32,
+    ) -> ProgramResult {
+        let data = crate::instruction::ClaimFee {
+            _index: index,
+            _lower_tick_index: lower_tick_index,
+            _upper_tick_index: upper_tick_index,
+        }
+        .data();
+        NativeSol::handler(ctx, 0, data)
+    }
+
+    pub fn lock_position(ctx: Context<LockPosition>) -> ProgramResult {
+        ctx.accounts.handler(true)
+    }
+
+    pub fn unlock_position(ctx: Context<LockPosition>) -> ProgramResult {
+        ctx.accounts.handler(false)
+    }
+
+    pub fn migrate_position(ctx: Context<MigratePosition>) -> ProgramResult {
+        ctx.accounts.handler()
+    }
+
     pub fn update_seconds_per_liquidity(
         ctx: Context<UpdateSecondsPerLiquidity>,
         _lower_tick_index: i32,
@@ -136,10 +306,62 @@ pub mod invariant {
         ctx.accounts.handler(protocol_fee)
     }
 
+    #[access_control(admin(&ctx.accounts.state, &ctx.accounts.admin))]
+    pub fn create_default_protocol_fee(
+        ctx: Context<CreateDefaultProtocolFee>,
+        protocol_fee: FixedPoint,
+    ) -> ProgramResult {
+        ctx.accounts.handler(
+            protocol_fee,
+            *ctx.bumps.get("default_protocol_fee").unwrap(),
+        )
+    }
+
+    #[access_control(admin(&ctx.accounts.state, &ctx.accounts.admin))]
+    pub fn change_default_protocol_fee(
+        ctx: Context<ChangeDefaultProtocolFee>,
+        protocol_fee: FixedPoint,
+    ) -> ProgramResult {
+        ctx.accounts.handler(protocol_fee)
+    }
+
+    #[access_control(admin(&ctx.accounts.state, &ctx.accounts.admin))]
+    pub fn change_protocol_fee_batch<'info>(
+        ctx: Context<'_, '_, '_, 'info, ChangeProtocolFeeBatch<'info>>,
+        protocol_fee: FixedPoint,
+    ) -> ProgramResult {
+        ChangeProtocolFeeBatch::handler(ctx, protocol_fee)
+    }
+
     #[access_control(admin(&ctx.accounts.state, &ctx.accounts.admin))]
     pub fn change_fee_receiver(ctx: Context<ChangeFeeReceiver>) -> ProgramResult {
         ctx.accounts.handler()
     }
+
+    #[access_control(admin(&ctx.accounts.state, &ctx.accounts.admin))]
+    pub fn create_fee_distribution(
+        ctx: Context<CreateFeeDistribution>,
+        owners: Vec<Pubkey>,
+        weights: Vec<u16>,
+    ) -> ProgramResult {
+        ctx.accounts
+            .handler(owners, weights, *ctx.bumps.get("fee_distribution").unwrap())
+    }
+
+    #[access_control(admin(&ctx.accounts.state, &ctx.accounts.admin))]
+    pub fn change_fee_distribution(
+        ctx: Context<ChangeFeeDistribution>,
+        owners: Vec<Pubkey>,
+        weights: Vec<u16>,
+    ) -> ProgramResult {
+        ctx.accounts.handler(owners, weights)
+    }
+
+    pub fn distribute_protocol_fee<'info>(
+        ctx: Context<'_, '_, '_, 'info, DistributeProtocolFee<'info>>,
+    ) -> ProgramResult {
+        DistributeProtocolFee::handler(ctx)
+    }
 }
 
 fn admin(state_loader: &AccountLoader<State>, signer: &AccountInfo) -> Result<()> {
@@ -1,3 +1,4 @@
+use std::cmp::min;
 use std::convert::TryInto;
 
 use crate::decimals::*;
@@ -9,6 +10,8 @@ use crate::*;
 
 pub const MAX_SQRT_PRICE: u128 = 65535383934512647000000000000;
 pub const MIN_SQRT_PRICE: u128 = 15258932000000000000;
+// every step simulates a swap, so the search is bounded to fit in the compute budget
+pub const SINGLE_SIDED_SWAP_ITERATIONS: u32 = 24;
 
 #[derive(PartialEq, Debug)]
 pub struct SwapResult {
@@ -245,6 +248,88 @@ pub fn get_delta_y(
     }
 }
 
+// L = delta_x * lower_sqrt_price * upper_sqrt_price / delta_sqrt_price
+pub fn get_liquidity_by_x(
+    x: TokenAmount,
+    sqrt_price_a: Price,
+    sqrt_price_b: Price,
+    up: bool,
+) -> Option<Liquidity> {
+    let delta_price = if sqrt_price_a > sqrt_price_b {
+        sqrt_price_a - sqrt_price_b
+    } else {
+        sqrt_price_b - sqrt_price_a
+    };
+    if delta_price.is_zero() {
+        return None;
+    }
+
+    // log(2, 2^96 * 2^96 / 10^24 * 2^64 * 10^6) = 194.5
+    let price_product = match up {
+        true => sqrt_price_a.big_mul_to_value_up(sqrt_price_b),
+        false => sqrt_price_a.big_mul_to_value(sqrt_price_b),
+    };
+
+    let nominator = price_product
+        .checked_mul(U256::from(x.get()))
+        .unwrap()
+        .checked_mul(Liquidity::one())
+        .unwrap();
+    let denominator = U256::from(delta_price.get());
+
+    match match up {
+        true => nominator
+            .checked_add(denominator - U256::from(1u32))
+            .unwrap()
+            .checked_div(denominator)
+            .unwrap(),
+        false => nominator.checked_div(denominator).unwrap(),
+    }
+    .try_into()
+    {
+        Ok(v) => Some(Liquidity::new(v)),
+        Err(_) => None,
+    }
+}
+
+// L = delta_y / delta_sqrt_price
+pub fn get_liquidity_by_y(
+    y: TokenAmount,
+    sqrt_price_a: Price,
+    sqrt_price_b: Price,
+    up: bool,
+) -> Option<Liquidity> {
+    let delta_price = if sqrt_price_a > sqrt_price_b {
+        sqrt_price_a - sqrt_price_b
+    } else {
+        sqrt_price_b - sqrt_price_a
+    };
+    if delta_price.is_zero() {
+        return None;
+    }
+
+    let nominator = U256::from(y.get())
+        .checked_mul(Price::one())
+        .unwrap()
+        .checked_mul(Liquidity::one())
+        .unwrap();
+    let denominator = U256::from(delta_price.get());
+
+    match match up {
+        true => nominator
+            .checked_add(denominator - U256::from(1u32))
+            .unwrap()
+            .checked_div(denominator)
+            .unwrap(),
+        false => nominator.checked_div(denominator).unwrap(),
+    }
+    .try_into()
+    {
+        Ok(v) => Some(Liquidity::new(v)),
+        Err(_) => None,
+    }
+}
+
 fn get_next_sqrt_price_from_input(
     price_sqrt: Price,
     liquidity: Liquidity,
@@ -423,6 +508,173 @@ pub fn calculate_amount_delta(
     Ok((amount_x, amount_y))
 }
 
+// maximal liquidity of a position that doesn't require more than given amounts at the current price
+pub fn calculate_max_liquidity(
+    pool: &Pool,
+    max_amount_x: TokenAmount,
+    max_amount_y: TokenAmount,
+    upper_tick: i32,
+    lower_tick: i32,
+) -> Result<Liquidity> {
+    // assume that upper_tick > lower_tick
+    let liquidity = if pool.current_tick_index < lower_tick {
+        get_liquidity_by_x(
+            max_amount_x,
+            calculate_price_sqrt(lower_tick),
+            calculate_price_sqrt(upper_tick),
+            false,
+        )
+        .ok_or(ErrorCode::LiquidityOverflow)?
+    } else if pool.current_tick_index < upper_tick {
+        // price at the lower bound doesn't require y, so only x limits liquidity
+        let liquidity_x = get_liquidity_by_x(
+            max_amount_x,
+            pool.sqrt_price,
+            calculate_price_sqrt(upper_tick),
+            false,
+        )
+        .unwrap_or(Liquidity::new(u128::MAX));
+        let liquidity_y = get_liquidity_by_y(
+            max_amount_y,
+            calculate_price_sqrt(lower_tick),
+            pool.sqrt_price,
+            false,
+        )
+        .unwrap_or(Liquidity::new(u128::MAX));
+
+        min(liquidity_x, liquidity_y)
+    } else {
+        get_liquidity_by_y(
+            max_amount_y,
+            calculate_price_sqrt(lower_tick),
+            calculate_price_sqrt(upper_tick),
+            false,
+        )
+        .ok_or(ErrorCode::LiquidityOverflow)?
+    };
+
+    Ok(liquidity)
+}
+
+// Finds how much of a single deposited token has to be swapped, so that the rest
+// together with the swap output mint the most liquidity in the position range.
+// Swap is simulated at constant liquidity, so it never reaches `swap_limit` (the closest
+// initialized tick or the range bound). When the best ratio lies beyond it, the swap stops
+// just before the limit and the position gets less liquidity, the rest stays with the owner.
+// Search takes at most SINGLE_SIDED_SWAP_ITERATIONS steps, so up to 2^-24 of the deposit
+// can be left unused on top of rounding.
+pub fn calculate_single_sided_swap(
+    current_sqrt_price: Price,
+    swap_limit: Price,
+    pool_liquidity: Liquidity,
+    fee: FixedPoint,
+    amount: TokenAmount,
+    lower_sqrt_price: Price,
+    upper_sqrt_price: Price,
+    x_to_y: bool,
+) -> (TokenAmount, SwapResult, Liquidity) {
+    // liquidity that can be minted from the kept and the received token
+    let evaluate = |swap_amount: TokenAmount| -> (SwapResult, Liquidity, Liquidity) {
+        let result = match swap_amount.is_zero() {
+            true => SwapResult {
+                next_price_sqrt: current_sqrt_price,
+                amount_in: TokenAmount(0),
+                amount_out: TokenAmount(0),
+                fee_amount: TokenAmount(0),
+            },
+            false => compute_swap_step(
+                current_sqrt_price,
+                swap_limit,
+                pool_liquidity,
+                swap_amount,
+                true,
+                fee,
+            ),
+        };
+        let kept = amount - result.amount_in - result.fee_amount;
+        let price = result.next_price_sqrt;
+
+        // range on the side of a missing token does not limit liquidity
+        let (liquidity_kept, liquidity_received) = match x_to_y {
+            true => (
+                get_liquidity_by_x(kept, price, upper_sqrt_price, false),
+                get_liquidity_by_y(result.amount_out, lower_sqrt_price, price, false),
+            ),
+            false => (
+                get_liquidity_by_y(kept, lower_sqrt_price, price, false),
+                get_liquidity_by_x(result.amount_out, price, upper_sqrt_price, false),
+            ),
+        };
+        (
+            result,
+            liquidity_kept.unwrap_or(Liquidity::new(u128::MAX)),
+            liquidity_received.unwrap_or(Liquidity::new(u128::MAX)),
+        )
+    };
+
+    // search is limited to amounts that keep the price before the limit
+    let max_swap_amount = match current_sqrt_price == swap_limit {
+        true => TokenAmount(0),
+        false => {
+            let full = compute_swap_step(
+                current_sqrt_price,
+                swap_limit,
+                pool_liquidity,
+                amount,
+                true,
+                fee,
+            );
+            // smallest amount reaching the limit leaves `amount_in` to the limit after the fee
+            match full.next_price_sqrt == swap_limit {
+                true => {
+                    let reaching = full
+                        .amount_in
+                        .big_div_up(FixedPoint::from_integer(1u8) - fee);
+                    TokenAmount(reaching.0.saturating_sub(1))
+                }
+                false => amount,
+            }
+        }
+    };
+
+    // liquidity from the received token grows with the swapped amount and from the kept one falls
+    let mut low = TokenAmount(0);
+    let mut high = max_swap_amount;
+    for _ in 0..SINGLE_SIDED_SWAP_ITERATIONS {
+        if low >= high {
+            break;
+        }
+        let mid = low + TokenAmount((high - low).0 / 2);
+        let (_, liquidity_kept, liquidity_received) = evaluate(mid);
+        if liquidity_received >= liquidity_kept {
+            high = mid;
+        } else {
+            low = mid + TokenAmount(1);
+        }
+    }
+
+    // best amount lies between the last one limited by the received token and `high`
+    let candidate = |swap_amount: TokenAmount| {
+        let (result, liquidity_kept, liquidity_received) = evaluate(swap_amount);
+        (swap_amount, result, min(liquidity_kept, liquidity_received))
+    };
+    let mut best = candidate(low);
+    if !low.is_zero() {
+        let previous = candidate(low - TokenAmount(1));
+        if previous.2 > best.2 {
+            best = previous;
+        }
+    }
+    if high > low {
+        let upper = candidate(high);
+        if upper.2 > best.2 {
+            best = upper;
+        }
+    }
+
+    best
+}
+
 pub fn calculate_seconds_per_liquidity_inside(
     tick_lower: Tick,
     tick_upper: Tick,
@@ -1004,6 +1256,98 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_get_liquidity_by_x() {
+        // equal at equal liquidity
+        {
+            let result = get_liquidity_by_x(
+                TokenAmount(1),
+                Price::from_integer(1u8),
+                Price::from_integer(2u8),
+                false,
+            )
+            .unwrap();
+            assert_eq!(result, Liquidity::from_integer(2u8));
+        }
+        // complex
+        {
+            let sqrt_price_a = Price::new(234__878_324_943_782_000000000000);
+            let sqrt_price_b = Price::new(87__854_456_421_658_000000000000);
+            let liquidity = Liquidity::new(983_983__249_092);
+
+            let x_down = get_delta_x(sqrt_price_a, sqrt_price_b, liquidity, false).unwrap();
+            let x_up = get_delta_x(sqrt_price_a, sqrt_price_b, liquidity, true).unwrap();
+
+            let result_down =
+                get_liquidity_by_x(x_down, sqrt_price_a, sqrt_price_b, false).unwrap();
+            let result_up = get_liquidity_by_x(x_up, sqrt_price_a, sqrt_price_b, true).unwrap();
+
+            assert_eq!(result_down, Liquidity::new(983_868__166_929));
+            assert_eq!(result_up, Liquidity::new(984_008__519_023));
+            assert!(result_down <= liquidity && liquidity <= result_up);
+        }
+        // zero range
+        {
+            let result = get_liquidity_by_x(
+                TokenAmount(1),
+                Price::from_integer(1u8),
+                Price::from_integer(1u8),
+                false,
+            );
+            assert!(result.is_none());
+        }
+        // overflow
+        {
+            let sqrt_price_a = Price::from_integer(1u8);
+            let sqrt_price_b = Price::new(Price::one()) + Price::new(1);
+
+            let result =
+                get_liquidity_by_x(TokenAmount(u64::MAX), sqrt_price_a, sqrt_price_b, false);
+            assert!(result.is_none());
+        }
+    }
+
+    #[test]
+    fn test_get_liquidity_by_y() {
+        // equal at equal liquidity
+        {
+            let result = get_liquidity_by_y(
+                TokenAmount(2),
+                Price::from_integer(1u8),
+                Price::from_integer(2u8),
+                false,
+            )
+            .unwrap();
+            assert_eq!(result, Liquidity::from_integer(2u8));
+        }
+        // complex
+        {
+            let sqrt_price_a = Price::new(234__878_324_943_782_000000000000);
+            let sqrt_price_b = Price::new(87__854_456_421_658_000000000000);
+            let liquidity = Liquidity::new(983_983__249_092);
+
+            let y_down = get_delta_y(sqrt_price_a, sqrt_price_b, liquidity, false).unwrap();
+            let y_up = get_delta_y(sqrt_price_a, sqrt_price_b, liquidity, true).unwrap();
+
+            let result_down =
+                get_liquidity_by_y(y_down, sqrt_price_a, sqrt_price_b, false).unwrap();
+            let result_up = get_liquidity_by_y(y_up, sqrt_price_a, sqrt_price_b, true).unwrap();
+
+            assert_eq!(result_down, Liquidity::new(983_983__243_361));
+            assert_eq!(result_up, Liquidity::new(983_983__250_164));
+            assert!(result_down <= liquidity && liquidity <= result_up);
+        }
+        // overflow
+        {
+            let sqrt_price_a = Price::from_integer(1u8);
+            let sqrt_price_b = Price::new(Price::one()) + Price::new(1);
+
+            let result =
+                get_liquidity_by_y(TokenAmount(u64::MAX), sqrt_price_a, sqrt_price_b, false);
+            assert!(result.is_none());
+        }
+    }
+
     #[test]
     fn test_calculate_price_sqrt() {
         {
@@ -1464,6 +1808,208 @@ mod tests {
         pool.update_seconds_per_liquidity_global(current_timestamp);
         assert_eq!({ pool.seconds_per_liquidity_global }.get(), 100000000000);
     }
+    #[test]
+    fn test_calculate_max_liquidity() {
+        // current tick between lower tick and upper tick
+        {
+            let mut pool = Pool {
+                liquidity: Liquidity::from_integer(0),
+                sqrt_price: Price::new(1000140000000_000000000000),
+                current_tick_index: 2,
+                ..Default::default()
+            };
+            let upper_tick = 3;
+            let lower_tick = 0;
+
+            let liquidity = calculate_max_liquidity(
+                &pool,
+                TokenAmount(51),
+                TokenAmount(700),
+                upper_tick,
+                lower_tick,
+            )
+            .unwrap();
+            assert_eq!(liquidity, Liquidity::from_integer(5_000_000));
+
+            let (x, y) =
+                calculate_amount_delta(&mut pool, liquidity, true, upper_tick, lower_tick).unwrap();
+            assert_eq!(x, TokenAmount(51));
+            assert_eq!(y, TokenAmount(700));
+        }
+        // current tick smaller than lower tick
+        {
+            let mut pool = Pool {
+                liquidity: Liquidity::from_integer(0),
+                current_tick_index: 0,
+                ..Default::default()
+            };
+            let upper_tick = 4;
+            let lower_tick = 2;
+
+            let liquidity = calculate_max_liquidity(
+                &pool,
+                TokenAmount(100),
+                TokenAmount(0),
+                upper_tick,
+                lower_tick,
+            )
+            .unwrap();
+            assert_eq!(liquidity, Liquidity::new(1_000_200__010000));
+
+            let (x, y) =
+                calculate_amount_delta(&mut pool, liquidity, true, upper_tick, lower_tick).unwrap();
+            assert_eq!(x, TokenAmount(100));
+            assert_eq!(y, TokenAmount(0));
+        }
+        // current tick greater than upper tick
+        {
+            let mut pool = Pool {
+                liquidity: Liquidity::from_integer(0),
+                current_tick_index: 0,
+                ..Default::default()
+            };
+            let upper_tick = -2;
+            let lower_tick = -4;
+
+            let liquidity = calculate_max_liquidity(
+                &pool,
+                TokenAmount(0),
+                TokenAmount(100),
+                upper_tick,
+                lower_tick,
+            )
+            .unwrap();
+            assert_eq!(liquidity, Liquidity::new(1_000_200__009995));
+
+            let (x, y) =
+                calculate_amount_delta(&mut pool, liquidity, true, upper_tick, lower_tick).unwrap();
+            assert_eq!(x, TokenAmount(0));
+            assert_eq!(y, TokenAmount(100));
+        }
+    }
+
+    #[test]
+    fn test_calculate_single_sided_swap() {
+        let current_sqrt_price = Price::from_integer(1);
+        let pool_liquidity = Liquidity::from_integer(1_000_000_000);
+        let fee = FixedPoint::from_scale(6, 3);
+        let lower_sqrt_price = calculate_price_sqrt(-1000);
+        let upper_sqrt_price = calculate_price_sqrt(1000);
+        let amount = TokenAmount(1_000_000);
+
+        // x to y
+        {
+            let (swap_amount, result, liquidity) = calculate_single_sided_swap(
+                current_sqrt_price,
+                lower_sqrt_price,
+                pool_liquidity,
+                fee,
+                amount,
+                lower_sqrt_price,
+                upper_sqrt_price,
+                true,
+            );
+            let next_price = result.next_price_sqrt;
+            assert!(next_price < current_sqrt_price && next_price > lower_sqrt_price);
+            assert_eq!(swap_amount, result.amount_in + result.fee_amount);
+
+            let needed_x = get_delta_x(next_price, upper_sqrt_price, liquidity, true).unwrap();
+            let needed_y = get_delta_y(lower_sqrt_price, next_price, liquidity, true).unwrap();
+            let kept = amount - swap_amount;
+            assert!(needed_x <= kept);
+            assert!(needed_y <= result.amount_out);
+            // only dust is left
+            assert!(kept - needed_x <= TokenAmount(10));
+            assert!(result.amount_out - needed_y <= TokenAmount(10));
+        }
+        // y to x
+        {
+            let (swap_amount, result, liquidity) = calculate_single_sided_swap(
+                current_sqrt_price,
+                upper_sqrt_price,
+                pool_liquidity,
+                fee,
+                amount,
+                lower_sqrt_price,
+                upper_sqrt_price,
+                false,
+            );
+            let next_price = result.next_price_sqrt;
+            assert!(next_price > current_sqrt_price && next_price < upper_sqrt_price);
+            assert_eq!(swap_amount, result.amount_in + result.fee_amount);
+
+            let needed_x = get_delta_x(next_price, upper_sqrt_price, liquidity, true).unwrap();
+            let needed_y = get_delta_y(lower_sqrt_price, next_price, liquidity, true).unwrap();
+            let kept = amount - swap_amount;
+            assert!(needed_y <= kept);
+            assert!(needed_x <= result.amount_out);
+            assert!(kept - needed_y <= TokenAmount(10));
+            assert!(result.amount_out - needed_x <= TokenAmount(10));
+        }
+        // price at the lower bound needs only x
+        {
+            let (swap_amount, result, liquidity) = calculate_single_sided_swap(
+                lower_sqrt_price,
+                lower_sqrt_price,
+                pool_liquidity,
+                fee,
+                amount,
+                lower_sqrt_price,
+                upper_sqrt_price,
+                true,
+            );
+            assert_eq!(swap_amount, TokenAmount(0));
+            assert_eq!(result.next_price_sqrt, lower_sqrt_price);
+            assert_eq!(
+                liquidity,
+                get_liquidity_by_x(amount, lower_sqrt_price, upper_sqrt_price, false).unwrap()
+            );
+        }
+        // initialized tick before the best ratio stops the swap right before it
+        {
+            let swap_limit = calculate_price_sqrt(-2);
+            let (_, _, unlimited_liquidity) = calculate_single_sided_swap(
+                current_sqrt_price,
+                lower_sqrt_price,
+                pool_liquidity,
+                fee,
+                amount,
+                lower_sqrt_price,
+                upper_sqrt_price,
+                true,
+            );
+            let (swap_amount, result, liquidity) = calculate_single_sided_swap(
+                current_sqrt_price,
+                swap_limit,
+                pool_liquidity,
+                fee,
+                amount,
+                lower_sqrt_price,
+                upper_sqrt_price,
+                true,
+            );
+            let next_price = result.next_price_sqrt;
+            assert!(next_price > swap_limit);
+            assert!(liquidity < unlimited_liquidity);
+
+            // one more token would reach the limit
+            let reaching = compute_swap_step(
+                current_sqrt_price,
+                swap_limit,
+                pool_liquidity,
+                swap_amount + TokenAmount(1),
+                true,
+                fee,
+            );
+            assert_eq!(reaching.next_price_sqrt, swap_limit);
+
+            // received token limits liquidity, the rest of the deposit isn't needed
+            let needed_y = get_delta_y(lower_sqrt_price, next_price, liquidity, true).unwrap();
+            assert!(needed_y <= result.amount_out);
+            assert!(result.amount_out - needed_y <= TokenAmount(10));
+        }
+    }
+
     #[test]
     fn test_calculate_seconds_per_liquidity_inside() {
         let mut tick_lower = Tick {
@@ -0,0 +1,31 @@
+use crate::decimals::*;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::set_return_data;
+
+// Results readable by programs calling invariant through CPI (get_return_data)
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq)]
+pub struct SwapReturnData {
+    pub amount_in: u64,
+    pub amount_out: u64,
+    pub sqrt_price: Price, // price after swap
+    pub current_tick_index: i32,
+    pub initialized_crosses: u16,
+    pub virtual_crosses: u16,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq)]
+pub struct RemovePositionReturnData {
+    pub amount_x: u64,
+    pub amount_y: u64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq)]
+pub struct ClaimFeeReturnData {
+    pub amount_x: u64,
+    pub amount_y: u64,
+}
+
+pub fn set<T: AnchorSerialize>(data: &T) {
+    set_return_data(&data.try_to_vec().unwrap());
+}
@@ -0,0 +1,27 @@
+use crate::decimals::FixedPoint;
+use anchor_lang::prelude::*;
+
+// Protocol fee of newly created pools, kept out of State so its layout doesn't change
+#[account(zero_copy)]
+#[repr(packed)]
+#[derive(PartialEq, Default, Debug)]
+pub struct DefaultProtocolFee {
+    pub protocol_fee: FixedPoint,
+    pub bump: u8,
+}
+
+impl DefaultProtocolFee {
+    // fee of pools created before the admin sets the default
+    pub fn initial() -> FixedPoint {
+        FixedPoint::from_scale(1, 2)
+    }
+
+    pub fn get(account: &AccountInfo) -> Result<FixedPoint> {
+        if account.data_is_empty() {
+            return Ok(Self::initial());
+        }
+        let loader = AccountLoader::<DefaultProtocolFee>::try_from(account)?;
+        let default_protocol_fee = loader.load()?;
+        Ok(default_protocol_fee.protocol_fee)
+    }
+}
@@ -0,0 +1,135 @@
+use crate::ErrorCode::*;
+use anchor_lang::prelude::*;
+
+pub const MAX_FEE_RECIPIENTS: usize = 8; // UPDATE IN ARRAYS AS WELL!
+pub const FEE_DISTRIBUTION_DENOMINATOR: u16 = 10000; // weights are in basis points
+
+#[account(zero_copy)]
+#[repr(packed)]
+#[derive(PartialEq, Default, Debug)]
+pub struct FeeDistribution {
+    pub recipients: [FeeRecipient; 8],
+    pub count: u8,
+    pub bump: u8,
+}
+
+#[zero_copy]
+#[repr(packed)]
+#[derive(PartialEq, Default, Debug)]
+pub struct FeeRecipient {
+    pub owner: Pubkey,
+    pub weight: u16,
+}
+
+impl FeeDistribution {
+    pub fn set(&mut self, owners: &[Pubkey], weights: &[u16]) -> Result<()> {
+        require!(owners.len() == weights.len(), InvalidFeeDistribution);
+        require!(
+            !owners.is_empty() && owners.len() <= MAX_FEE_RECIPIENTS,
+            InvalidFeeDistribution
+        );
+        require!(
+            weights.iter().all(|weight| *weight != 0),
+            InvalidFeeDistribution
+        );
+        require!(
+            weights.iter().map(|weight| *weight as u32).sum::<u32>()
+                == FEE_DISTRIBUTION_DENOMINATOR as u32,
+            InvalidFeeDistribution
+        );
+
+        self.recipients = Default::default();
+        for (i, (owner, weight)) in owners.iter().zip(weights.iter()).enumerate() {
+            self.recipients[i] = FeeRecipient {
+                owner: *owner,
+                weight: *weight,
+            };
+        }
+        self.count = owners.len() as u8;
+
+        Ok(())
+    }
+
+    // rounding dust goes to the first recipient
+    pub fn split(&self, amount: u64) -> Vec<u64> {
+        let mut shares: Vec<u64> = self.recipients[..self.count as usize]
+            .iter()
+            .map(|recipient| {
+                (amount as u128 * { recipient.weight } as u128
+                    / FEE_DISTRIBUTION_DENOMINATOR as u128) as u64
+            })
+            .collect();
+
+        let distributed: u64 = shares.iter().sum();
+        if let Some(first) = shares.first_mut() {
+            *first += amount - distributed;
+        }
+        shares
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_set() {
+        let mut distribution = FeeDistribution::default();
+        let owners = [Pubkey::new_unique(), Pubkey::new_unique()];
+
+        // valid
+        {
+            distribution.set(&owners, &[6000, 4000]).unwrap();
+            assert_eq!({ distribution.count }, 2);
+            assert_eq!({ distribution.recipients[0].owner }, owners[0]);
+            assert_eq!({ distribution.recipients[1].weight }, 4000);
+        }
+        // shorter list clears previous recipients
+        {
+            distribution.set(&owners[..1], &[10000]).unwrap();
+            assert_eq!({ distribution.count }, 1);
+            assert_eq!(distribution.recipients[1], FeeRecipient::default());
+        }
+        // weights not summing to denominator
+        assert!(distribution.set(&owners, &[6000, 3999]).is_err());
+        // zero weight
+        assert!(distribution.set(&owners, &[10000, 0]).is_err());
+        // length mismatch
+        assert!(distribution.set(&owners, &[10000]).is_err());
+        // empty
+        assert!(distribution.set(&[], &[]).is_err());
+        // too many recipients
+        {
+            let owners = [Pubkey::new_unique(); MAX_FEE_RECIPIENTS + 1];
+            let mut weights = [1000; MAX_FEE_RECIPIENTS + 1];
+            weights[0] = 2000;
+            assert!(distribution.set(&owners, &weights).is_err());
+        }
+    }
+
+    #[test]
+    fn test_split() {
+        let mut distribution = FeeDistribution::default();
+        let owners = [
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        ];
+        distribution.set(&owners, &[5000, 3000, 2000]).unwrap();
+
+        // exact
+        assert_eq!(distribution.split(1000), vec![500, 300, 200]);
+        // dust to first recipient
+        assert_eq!(distribution.split(7), vec![4, 2, 1]);
+        // zero
+        assert_eq!(distribution.split(0), vec![0, 0, 0]);
+        // max
+        {
+            let shares = distribution.split(u64::MAX);
+            assert_eq!(
+                shares.iter().map(|s| *s as u128).sum::<u128>(),
+                u64::MAX as u128
+            );
+        }
+    }
+}
@@ -1,3 +1,5 @@
+pub mod default_protocol_fee;
+pub mod fee_distribution;
 pub mod fee_tier;
 pub mod oracle;
 pub mod pool;
@@ -7,6 +9,8 @@ pub mod state;
 pub mod tick;
 pub mod tickmap;
 
+pub use default_protocol_fee::*;
+pub use fee_distribution::*;
 pub use fee_tier::*;
 pub use oracle::*;
 pub use pool::*;
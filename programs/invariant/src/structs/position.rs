@@ -21,6 +21,7 @@ pub struct Position {
     pub tokens_owed_x: FixedPoint,
     pub tokens_owed_y: FixedPoint,
     pub bump: u8,
+    pub stake_count: u16, // number of stakes locking the position, appended after bump to keep the layout
 }
 
 impl Position {
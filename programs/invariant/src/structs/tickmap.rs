@@ -2,8 +2,8 @@ use anchor_lang::prelude::*;
 use std::convert::TryInto;
 
 pub const TICK_LIMIT: i32 = 44_364; // If you change it update length of array as well!
-pub const TICK_SEARCH_RANGE: i32 = 256;
 pub const MAX_TICK: i32 = 221_818; // log(1.0001, sqrt(2^64-1))
+const WORD_SIZE: usize = 64; // bits scanned at once during search
 
 #[account(zero_copy)]
 #[repr(packed)]
@@ -17,6 +17,30 @@ impl Default for Tickmap {
     }
 }
 
+fn tick_to_index(tick: i32, tick_spacing: u16) -> usize {
+    assert!(
+        (tick % tick_spacing as i32) == 0,
+        "tick not divisible by spacing"
+    );
+
+    tick.checked_div(tick_spacing.try_into().unwrap())
+        .unwrap()
+        .checked_add(TICK_LIMIT)
+        .unwrap()
+        .try_into()
+        .unwrap()
+}
+
+fn index_to_tick(index: usize, tick_spacing: u16) -> i32 {
+    let index: i32 = index.try_into().unwrap();
+
+    index
+        .checked_sub(TICK_LIMIT)
+        .unwrap()
+        .checked_mul(tick_spacing.try_into().unwrap())
+        .unwrap()
+}
+
 fn tick_to_position(tick: i32, tick_spacing: u16) -> (usize, u8) {
     assert!(
         (tick % tick_spacing as i32) == 0,
@@ -35,24 +59,21 @@ fn tick_to_position(tick: i32, tick_spacing: u16) -> (usize, u8) {
     (byte, bit)
 }
 
-pub fn get_search_limit(tick: i32, tick_spacing: u16, up: bool) -> i32 {
-    let index = tick / tick_spacing as i32;
-
+// search isn't limited to a range around the current tick, searches during a swap move in one
+// direction so a swap reads each of ~1387 words of the bitmap about once however sparse it is
+pub fn get_search_limit(tick_spacing: u16, up: bool) -> i32 {
     let limit = if up {
         // ticks are limited by amount of space in the bitmap...
         let array_limit = TICK_LIMIT.checked_sub(1).unwrap();
-        // ...search range is limited to 256 at the time ...
-        let range_limit = index.checked_add(TICK_SEARCH_RANGE).unwrap();
         // ...also ticks for prices over 2^64 aren't needed
         let price_limit = MAX_TICK.checked_div(tick_spacing as i32).unwrap();
 
-        array_limit.min(range_limit).min(price_limit)
+        array_limit.min(price_limit)
     } else {
         let array_limit = (-TICK_LIMIT).checked_add(1).unwrap();
-        let range_limit = index.checked_sub(TICK_SEARCH_RANGE).unwrap();
         let price_limit = -MAX_TICK.checked_div(tick_spacing as i32).unwrap();
 
-        array_limit.max(range_limit).max(price_limit)
+        array_limit.max(price_limit)
     };
 
     limit.checked_mul(tick_spacing as i32).unwrap()
@@ -78,95 +99,80 @@ impl Tickmap {
     }
 
     pub fn next_initialized(&self, tick: i32, tick_spacing: u16) -> Option<i32> {
-        let limit = get_search_limit(tick, tick_spacing, true);
+        let limit = get_search_limit(tick_spacing, true);
 
         // add 1 to not check current tick
-        let (mut byte, mut bit) =
-            tick_to_position(tick.checked_add(tick_spacing as i32).unwrap(), tick_spacing);
-        let (limiting_byte, limiting_bit) = tick_to_position(limit, tick_spacing);
-
-        while byte < limiting_byte || (byte == limiting_byte && bit <= limiting_bit) {
-            // ignore some bits on first loop
-            let mut shifted = self.bitmap[byte] >> bit;
-
-            // go through all bits in byte until it is zero
-            if shifted != 0 {
-                while shifted.checked_rem(2).unwrap() == 0 {
-                    shifted >>= 1;
-                    bit = bit.checked_add(1).unwrap();
-                }
-
-                return if byte < limiting_byte || (byte == limiting_byte && bit <= limiting_bit) {
-                    let index: i32 = byte
-                        .checked_mul(8)
-                        .unwrap()
-                        .checked_add(bit.into())
-                        .unwrap()
-                        .try_into()
-                        .unwrap();
-                    Some(
-                        index
-                            .checked_sub(TICK_LIMIT)
-                            .unwrap()
-                            .checked_mul(tick_spacing.try_into().unwrap())
-                            .unwrap(),
-                    )
-                } else {
-                    None
+        let index = tick_to_index(tick.checked_add(tick_spacing as i32).unwrap(), tick_spacing);
+        let limiting_index = tick_to_index(limit, tick_spacing);
+
+        if index > limiting_index {
+            return None;
+        }
+
+        let mut word = index / WORD_SIZE;
+        // ignore lower bits on first word
+        let mut value = self.get_word(word) & (u64::MAX << (index % WORD_SIZE));
+
+        loop {
+            if value != 0 {
+                let found = word * WORD_SIZE + value.trailing_zeros() as usize;
+
+                return match found <= limiting_index {
+                    true => Some(index_to_tick(found, tick_spacing)),
+                    false => None,
                 };
             }
 
-            // go to the text byte
-            byte = byte.checked_add(1).unwrap();
-            bit = 0;
+            // go to the next word
+            word = word.checked_add(1).unwrap();
+            if word * WORD_SIZE > limiting_index {
+                return None;
+            }
+            value = self.get_word(word);
         }
-
-        None
     }
 
     pub fn prev_initialized(&self, tick: i32, tick_spacing: u16) -> Option<i32> {
         // don't subtract 1 to check the current tick
-        let limit = get_search_limit(tick, tick_spacing, false);
-        let (mut byte, mut bit) = tick_to_position(tick as i32, tick_spacing);
-        let (limiting_byte, limiting_bit) = tick_to_position(limit, tick_spacing);
-
-        while byte > limiting_byte || (byte == limiting_byte && bit >= limiting_bit) {
-            let mut mask = 1u16.checked_shl(bit.try_into().unwrap()).unwrap();
-            let value = self.bitmap[byte] as u16;
-
-            if value.checked_rem(mask.checked_shl(1).unwrap()).unwrap() > 0 {
-                while value & mask == 0 {
-                    mask >>= 1;
-                    bit = bit.checked_sub(1).unwrap();
-                }
-
-                return if byte > limiting_byte || (byte == limiting_byte && bit >= limiting_bit) {
-                    let index: i32 = byte
-                        .checked_mul(8)
-                        .unwrap()
-                        .checked_add(bit.into())
-                        .unwrap()
-                        .try_into()
-                        .unwrap();
-
-                    Some(
-                        index
-                            .checked_sub(TICK_LIMIT)
-                            .unwrap()
-                            .checked_mul(tick_spacing.try_into().unwrap())
-                            .unwrap(),
-                    )
-                } else {
-                    None
+        let limit = get_search_limit(tick_spacing, false);
+        let index = tick_to_index(tick, tick_spacing);
+        let limiting_index = tick_to_index(limit, tick_spacing);
+
+        if index < limiting_index {
+            return None;
+        }
+
+        let mut word = index / WORD_SIZE;
+        // ignore higher bits on first word
+        let mut value = self.get_word(word) & (u64::MAX >> (WORD_SIZE - 1 - index % WORD_SIZE));
+
+        loop {
+            if value != 0 {
+                let found = word * WORD_SIZE + (WORD_SIZE - 1) - value.leading_zeros() as usize;
+
+                return match found >= limiting_index {
+                    true => Some(index_to_tick(found, tick_spacing)),
+                    false => None,
                 };
             }
 
-            // go to the text byte
-            byte = byte.checked_sub(1).unwrap();
-            bit = 7;
+            // go to the previous word
+            if word == 0 || word * WORD_SIZE <= limiting_index {
+                return None;
+            }
+            word -= 1;
+            value = self.get_word(word);
         }
+    }
 
-        None
+    // reads 64 consecutive bits of bitmap, missing bytes past the end are zeros
+    fn get_word(&self, word: usize) -> u64 {
+        let start = word * WORD_SIZE / 8;
+        let end = (start + 8).min(self.bitmap.len());
+        let mut bytes = [0u8; 8];
+        bytes[..end - start].copy_from_slice(&self.bitmap[start..end]);
+
+        u64::from_le_bytes(bytes)
     }
 }
 
@@ -279,26 +285,22 @@ mod tests {
             map.flip(true, 0, 10);
             assert_eq!(map.next_initialized(0, 10), None);
         }
-        // Just below limit
+        // Across the whole bitmap
         {
             let mut map = Tickmap::default();
 
-            map.flip(true, 0, 1);
-            assert_eq!(map.next_initialized(-TICK_SEARCH_RANGE, 1), Some(0));
+            map.flip(true, TICK_LIMIT - 1, 1);
+            assert_eq!(
+                map.next_initialized(-TICK_LIMIT + 1, 1),
+                Some(TICK_LIMIT - 1)
+            );
         }
-        // At limit
+        // Past the edge of the bitmap
         {
-            let mut map = Tickmap::default();
-
-            map.flip(true, 0, 1);
-            assert_eq!(map.next_initialized(-TICK_SEARCH_RANGE - 1, 1), None);
-        }
-        // Further than limit
-        {
-            let mut map = Tickmap::default();
+            let map = Tickmap::default();
 
-            map.flip(true, TICK_LIMIT - 10, 1);
             assert_eq!(map.next_initialized(-TICK_LIMIT + 1, 1), None);
+            assert_eq!(map.next_initialized(TICK_LIMIT - 2, 1), None);
         }
         // Hitting the limit
         {
@@ -344,83 +346,109 @@ mod tests {
             map.flip(true, 10, 10);
             assert_eq!(map.prev_initialized(0, 10), None);
         }
-        // Just below limit
+        // Across the whole bitmap
         {
             let mut map = Tickmap::default();
 
-            map.flip(true, 0, 1);
-            assert_eq!(map.prev_initialized(TICK_SEARCH_RANGE, 1), Some(0));
-        }
-        // At limit
-        {
-            let mut map = Tickmap::default();
-
-            map.flip(true, 0, 1);
-            assert_eq!(map.prev_initialized(TICK_SEARCH_RANGE + 1, 1), None);
+            map.flip(true, -TICK_LIMIT + 1, 1);
+            assert_eq!(
+                map.prev_initialized(TICK_LIMIT - 1, 1),
+                Some(-TICK_LIMIT + 1)
+            );
         }
-        // Farther than limit
+        // Past the edge of the bitmap
         {
-            let mut map = Tickmap::default();
+            let map = Tickmap::default();
 
-            map.flip(true, -TICK_LIMIT + 1, 1);
             assert_eq!(map.prev_initialized(TICK_LIMIT - 1, 1), None);
+            assert_eq!(map.prev_initialized(-TICK_LIMIT + 1, 1), None);
         }
     }
 
     #[test]
-    fn test_get_search_limit() {
-        // Simple up
+    fn test_search_across_words() {
+        // Next in the following word
         {
-            let result = get_search_limit(0, 1, true);
-            assert_eq!(result, TICK_SEARCH_RANGE);
+            let mut map = Tickmap::default();
+            map.flip(true, 100, 1);
+            assert_eq!(map.next_initialized(0, 1), Some(100));
+            assert_eq!(map.prev_initialized(200, 1), Some(100));
         }
-        // Simple down
+        // Wide gap
         {
-            let result = get_search_limit(0, 1, false);
-            assert_eq!(result, -TICK_SEARCH_RANGE);
+            let mut map = Tickmap::default();
+            map.flip(true, -8_000, 1);
+            map.flip(true, 8_000, 1);
+            assert_eq!(map.next_initialized(-8_000, 1), Some(8_000));
+            assert_eq!(map.prev_initialized(7_999, 1), Some(-8_000));
         }
-        // Less simple up
+        // Last byte of bitmap
         {
-            let start = 60;
-            let step = 12;
-            let result = get_search_limit(start, step, true);
-            let expected = start + TICK_SEARCH_RANGE * step as i32;
-            assert_eq!(result, expected);
+            let mut map = Tickmap::default();
+            map.flip(true, TICK_LIMIT - 1, 1);
+            assert_eq!(
+                map.next_initialized(TICK_LIMIT - 100, 1),
+                Some(TICK_LIMIT - 1)
+            );
+            assert_eq!(
+                map.prev_initialized(TICK_LIMIT - 1, 1),
+                Some(TICK_LIMIT - 1)
+            );
         }
-        // Less simple down
+        // First byte of bitmap
         {
-            let start = 60;
-            let step = 12;
-            let result = get_search_limit(start, step, false);
-            let expected = start - TICK_SEARCH_RANGE * step as i32;
-            assert_eq!(result, expected);
+            let mut map = Tickmap::default();
+            map.flip(true, -TICK_LIMIT + 1, 1);
+            assert_eq!(
+                map.prev_initialized(-TICK_LIMIT + 100, 1),
+                Some(-TICK_LIMIT + 1)
+            );
+            assert_eq!(map.next_initialized(-TICK_LIMIT + 1, 1), None);
         }
+        // Bits of the starting word are skipped in the right direction
+        {
+            let mut map = Tickmap::default();
+            map.flip(true, 3, 1);
+            map.flip(true, 5, 1);
+            assert_eq!(map.next_initialized(3, 1), Some(5));
+            assert_eq!(map.prev_initialized(4, 1), Some(3));
+            assert_eq!(map.prev_initialized(5, 1), Some(5));
+        }
+    }
+
+    #[test]
+    fn test_get_search_limit() {
         // Up to array limit
         {
-            let step = 2u16;
-            let result = get_search_limit(step as i32 * TICK_LIMIT - 10, step, true);
-            let expected = step as i32 * (TICK_LIMIT - 1);
-            assert_eq!(result, expected);
+            let result = get_search_limit(1, true);
+            assert_eq!(result, TICK_LIMIT - 1);
         }
         // Down to array limit
+        {
+            let result = get_search_limit(1, false);
+            assert_eq!(result, -TICK_LIMIT + 1);
+        }
+        // Array limit scaled by spacing
         {
             let step = 2u16;
-            let result = get_search_limit(step as i32 * (-TICK_LIMIT + 1), step, false);
-            let expected = step as i32 * -(TICK_LIMIT - 1);
-            assert_eq!(result, expected);
+            assert_eq!(get_search_limit(step, true), step as i32 * (TICK_LIMIT - 1));
+            assert_eq!(
+                get_search_limit(step, false),
+                step as i32 * -(TICK_LIMIT - 1)
+            );
         }
         // Up to price limit
         {
             let step = 5u16;
-            let result = get_search_limit(MAX_TICK - 22, step, true);
+            let result = get_search_limit(step, true);
             let expected = MAX_TICK - 3;
             assert_eq!(result, expected);
         }
-        // At the price limit
+        // Down to price limit
         {
             let step = 5u16;
-            let result = get_search_limit(MAX_TICK - 3, step, true);
-            let expected = MAX_TICK - 3;
+            let result = get_search_limit(step, false);
+            let expected = -MAX_TICK + 3;
             assert_eq!(result, expected);
         }
     }
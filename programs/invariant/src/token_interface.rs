@@ -0,0 +1,521 @@
+use crate::ErrorCode::*;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::solana_program::program::invoke_signed;
+use anchor_lang::solana_program::system_instruction;
+use anchor_spl::token::{self, Transfer};
+use std::cmp::min;
+use std::convert::TryInto;
+
+// Both token programs share instruction layouts and the base account layouts,
+// Token-2022 appends extensions after the base data padded to the account length.
+pub mod token_2022 {
+    use anchor_lang::declare_id;
+    declare_id!("TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb");
+}
+
+const MINT_LEN: usize = 82;
+const ACCOUNT_LEN: usize = 165;
+const ACCOUNT_TYPE_MINT: u8 = 1;
+const ACCOUNT_TYPE_ACCOUNT: u8 = 2;
+const ACCOUNT_WITH_TRANSFER_FEE_LEN: usize = ACCOUNT_LEN + 1 + 4 + 8; // TransferFeeAmount extension
+
+const EXTENSION_UNINITIALIZED: u16 = 0;
+const EXTENSION_TRANSFER_FEE_CONFIG: u16 = 1;
+const EXTENSION_MINT_CLOSE_AUTHORITY: u16 = 3;
+const EXTENSION_METADATA_POINTER: u16 = 18;
+const EXTENSION_TOKEN_METADATA: u16 = 19;
+const TRANSFER_FEE_CONFIG_LEN: usize = 108;
+
+const INITIALIZE_ACCOUNT_TAG: u8 = 1;
+const TRANSFER_CHECKED_TAG: u8 = 12;
+
+pub const ONE_IN_BASIS_POINTS: u128 = 10000;
+
+pub fn is_token_program(key: &Pubkey) -> bool {
+    *key == token::ID || *key == token_2022::ID
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TokenAccountState {
+    pub mint: Pubkey,
+    pub owner: Pubkey,
+    pub amount: u64,
+    pub is_frozen: bool,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Default)]
+pub struct TransferFee {
+    pub epoch: u64,
+    pub maximum_fee: u64,
+    pub basis_points: u16,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Default)]
+pub struct TransferFeeConfig {
+    pub older: TransferFee,
+    pub newer: TransferFee,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct MintState {
+    pub decimals: u8,
+    pub transfer_fee: Option<TransferFeeConfig>,
+}
+
+impl TransferFee {
+    // fee withheld when `amount` is sent, rounded up as in Token-2022
+    pub fn calculate_fee(&self, amount: u64) -> u64 {
+        if self.basis_points == 0 || amount == 0 {
+            return 0;
+        }
+        let numerator = amount as u128 * self.basis_points as u128;
+        let fee = (numerator + ONE_IN_BASIS_POINTS - 1) / ONE_IN_BASIS_POINTS;
+        min(fee, self.maximum_fee as u128) as u64
+    }
+
+    // amount that has to be sent so that `post_fee_amount` is received
+    pub fn calculate_pre_fee_amount(&self, post_fee_amount: u64) -> Option<u64> {
+        let basis_points = self.basis_points as u128;
+        match (basis_points, post_fee_amount) {
+            (0, _) => Some(post_fee_amount),
+            (_, 0) => Some(0),
+            (ONE_IN_BASIS_POINTS, _) => post_fee_amount.checked_add(self.maximum_fee),
+            _ => {
+                let numerator = post_fee_amount as u128 * ONE_IN_BASIS_POINTS;
+                let denominator = ONE_IN_BASIS_POINTS - basis_points;
+                let raw_pre_fee_amount = (numerator + denominator - 1) / denominator;
+
+                if raw_pre_fee_amount - post_fee_amount as u128 >= self.maximum_fee as u128 {
+                    post_fee_amount.checked_add(self.maximum_fee)
+                } else {
+                    raw_pre_fee_amount.try_into().ok()
+                }
+            }
+        }
+    }
+}
+
+impl TransferFeeConfig {
+    pub fn get_epoch_fee(&self, epoch: u64) -> &TransferFee {
+        match epoch >= self.newer.epoch {
+            true => &self.newer,
+            false => &self.older,
+        }
+    }
+}
+
+impl MintState {
+    pub fn get_fee(&self, amount: u64, epoch: u64) -> u64 {
+        match self.transfer_fee {
+            Some(config) => config.get_epoch_fee(epoch).calculate_fee(amount),
+            None => 0,
+        }
+    }
+
+    pub fn get_pre_fee_amount(&self, post_fee_amount: u64, epoch: u64) -> Option<u64> {
+        match self.transfer_fee {
+            Some(config) => config
+                .get_epoch_fee(epoch)
+                .calculate_pre_fee_amount(post_fee_amount),
+            None => Some(post_fee_amount),
+        }
+    }
+
+    pub fn get_account_len(&self) -> usize {
+        match self.transfer_fee.is_some() {
+            true => ACCOUNT_WITH_TRANSFER_FEE_LEN,
+            false => ACCOUNT_LEN,
+        }
+    }
+}
+
+fn read_u16(data: &[u8], offset: usize) -> u16 {
+    u16::from_le_bytes(data[offset..offset + 2].try_into().unwrap())
+}
+
+fn read_u64(data: &[u8], offset: usize) -> u64 {
+    u64::from_le_bytes(data[offset..offset + 8].try_into().unwrap())
+}
+
+fn unpack_transfer_fee(data: &[u8]) -> TransferFee {
+    TransferFee {
+        epoch: read_u64(data, 0),
+        maximum_fee: read_u64(data, 8),
+        basis_points: read_u16(data, 16),
+    }
+}
+
+pub fn unpack_token_account(data: &[u8]) -> Result<TokenAccountState> {
+    let is_account = data.len() == ACCOUNT_LEN
+        || (data.len() > ACCOUNT_LEN && data[ACCOUNT_LEN] == ACCOUNT_TYPE_ACCOUNT);
+    // state byte: 0 - uninitialized, 1 - initialized, 2 - frozen
+    require!(is_account && data[108] != 0, InvalidTokenAccount);
+
+    Ok(TokenAccountState {
+        mint: Pubkey::new(&data[0..32]),
+        owner: Pubkey::new(&data[32..64]),
+        amount: read_u64(data, 64),
+        is_frozen: data[108] == 2,
+    })
+}
+
+pub fn unpack_mint(data: &[u8]) -> Result<MintState> {
+    let is_mint = data.len() == MINT_LEN
+        || (data.len() > ACCOUNT_LEN && data[ACCOUNT_LEN] == ACCOUNT_TYPE_MINT);
+    require!(is_mint && data[45] != 0, InvalidMint);
+
+    let mut transfer_fee = None;
+    let mut offset = ACCOUNT_LEN + 1;
+    while offset + 4 <= data.len() {
+        let extension = read_u16(data, offset);
+        let length = read_u16(data, offset + 2) as usize;
+        let value = offset + 4;
+        require!(value + length <= data.len(), InvalidMint);
+
+        match extension {
+            EXTENSION_UNINITIALIZED => break,
+            EXTENSION_TRANSFER_FEE_CONFIG => {
+                require!(length == TRANSFER_FEE_CONFIG_LEN, InvalidMint);
+                // skips both authorities and the withheld amount
+                transfer_fee = Some(TransferFeeConfig {
+                    older: unpack_transfer_fee(&data[value + 72..value + 90]),
+                    newer: unpack_transfer_fee(&data[value + 90..value + 108]),
+                });
+            }
+            // extensions that don't change how tokens move
+            EXTENSION_MINT_CLOSE_AUTHORITY
+            | EXTENSION_METADATA_POINTER
+            | EXTENSION_TOKEN_METADATA => {}
+            _ => return Err(UnsupportedMintExtension.into()),
+        }
+        offset = value + length;
+    }
+
+    Ok(MintState {
+        decimals: data[44],
+        transfer_fee,
+    })
+}
+
+pub fn token_account(info: &AccountInfo) -> Result<TokenAccountState> {
+    require!(is_token_program(info.owner), InvalidTokenProgram);
+    unpack_token_account(&info.try_borrow_data()?)
+}
+
+pub fn mint(info: &AccountInfo) -> Result<MintState> {
+    require!(is_token_program(info.owner), InvalidTokenProgram);
+    unpack_mint(&info.try_borrow_data()?)
+}
+
+// works with contexts built for `token::transfer`, program of the context decides which token program is called
+pub fn transfer_checked<'a, 'b, 'c, 'info>(
+    ctx: CpiContext<'a, 'b, 'c, 'info, Transfer<'info>>,
+    mint_info: &AccountInfo<'info>,
+    amount: u64,
+) -> ProgramResult {
+    let decimals = mint(mint_info)?.decimals;
+
+    let mut data = vec![TRANSFER_CHECKED_TAG];
+    data.extend_from_slice(&amount.to_le_bytes());
+    data.push(decimals);
+
+    let instruction = Instruction {
+        program_id: ctx.program.key(),
+        accounts: vec![
+            AccountMeta::new(ctx.accounts.from.key(), false),
+            AccountMeta::new_readonly(mint_info.key(), false),
+            AccountMeta::new(ctx.accounts.to.key(), false),
+            AccountMeta::new_readonly(ctx.accounts.authority.key(), true),
+        ],
+        data,
+    };
+
+    invoke_signed(
+        &instruction,
+        &[
+            ctx.accounts.from,
+            mint_info.clone(),
+            ctx.accounts.to,
+            ctx.accounts.authority,
+            ctx.program,
+        ],
+        ctx.signer_seeds,
+    )
+}
+
+// grosses up the amount by the transfer fee, so the destination receives exactly `amount`
+pub fn transfer_with_fee<'a, 'b, 'c, 'info>(
+    ctx: CpiContext<'a, 'b, 'c, 'info, Transfer<'info>>,
+    mint_info: &AccountInfo<'info>,
+    amount: u64,
+) -> ProgramResult {
+    let epoch = Clock::get()?.epoch;
+    let pre_fee_amount = mint(mint_info)?
+        .get_pre_fee_amount(amount, epoch)
+        .ok_or(TransferFeeOverflow)?;
+    transfer_checked(ctx, mint_info, pre_fee_amount)
+}
+
+// amount left after the transfer fee is withheld from `amount`
+pub fn get_post_fee_amount(mint_info: &AccountInfo, amount: u64) -> Result<u64> {
+    let epoch = Clock::get()?.epoch;
+    Ok(amount - mint(mint_info)?.get_fee(amount, epoch))
+}
+
+// creates token account for the mint under the given token program with space for its required extensions
+pub fn create_token_account<'info>(
+    token_program: &AccountInfo<'info>,
+    account: &AccountInfo<'info>,
+    mint_info: &AccountInfo<'info>,
+    authority: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    rent: &Sysvar<'info, Rent>,
+    system_program: &AccountInfo<'info>,
+) -> ProgramResult {
+    require!(mint_info.owner == token_program.key, InvalidTokenProgram);
+    let len = mint(mint_info)?.get_account_len();
+
+    invoke_signed(
+        &system_instruction::create_account(
+            payer.key,
+            account.key,
+            rent.minimum_balance(len),
+            len as u64,
+            token_program.key,
+        ),
+        &[payer.clone(), account.clone(), system_program.clone()],
+        &[],
+    )?;
+
+    invoke_signed(
+        &Instruction {
+            program_id: token_program.key(),
+            accounts: vec![
+                AccountMeta::new(account.key(), false),
+                AccountMeta::new_readonly(mint_info.key(), false),
+                AccountMeta::new_readonly(authority.key(), false),
+                AccountMeta::new_readonly(rent.key(), false),
+            ],
+            data: vec![INITIALIZE_ACCOUNT_TAG],
+        },
+        &[
+            account.clone(),
+            mint_info.clone(),
+            authority.clone(),
+            rent.to_account_info(),
+            token_program.clone(),
+        ],
+        &[],
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mint_data(extensions: &[(u16, Vec<u8>)]) -> Vec<u8> {
+        let mut data = vec![0u8; MINT_LEN];
+        data[44] = 6; // decimals
+        data[45] = 1; // is_initialized
+        if extensions.is_empty() {
+            return data;
+        }
+        data.resize(ACCOUNT_LEN, 0);
+        data.push(ACCOUNT_TYPE_MINT);
+        for (extension, value) in extensions {
+            data.extend_from_slice(&extension.to_le_bytes());
+            data.extend_from_slice(&(value.len() as u16).to_le_bytes());
+            data.extend_from_slice(value);
+        }
+        data
+    }
+
+    fn transfer_fee_config_data(older: TransferFee, newer: TransferFee) -> Vec<u8> {
+        let mut data = vec![0u8; 72];
+        for fee in [older, newer].iter() {
+            data.extend_from_slice(&fee.epoch.to_le_bytes());
+            data.extend_from_slice(&fee.maximum_fee.to_le_bytes());
+            data.extend_from_slice(&fee.basis_points.to_le_bytes());
+        }
+        data
+    }
+
+    #[test]
+    fn test_calculate_fee() {
+        let fee = TransferFee {
+            epoch: 0,
+            maximum_fee: 5_000,
+            basis_points: 100,
+        };
+        // rounded up
+        assert_eq!(fee.calculate_fee(1), 1);
+        assert_eq!(fee.calculate_fee(100), 1);
+        assert_eq!(fee.calculate_fee(101), 2);
+        // capped
+        assert_eq!(fee.calculate_fee(1_000_000), 5_000);
+        // zero
+        assert_eq!(fee.calculate_fee(0), 0);
+        assert_eq!(
+            TransferFee {
+                basis_points: 0,
+                ..fee
+            }
+            .calculate_fee(1_000),
+            0
+        );
+    }
+
+    #[test]
+    fn test_calculate_pre_fee_amount() {
+        let fees = [
+            TransferFee {
+                epoch: 0,
+                maximum_fee: 5_000,
+                basis_points: 100,
+            },
+            TransferFee {
+                epoch: 0,
+                maximum_fee: u64::MAX,
+                basis_points: 1,
+            },
+            TransferFee {
+                epoch: 0,
+                maximum_fee: 3,
+                basis_points: 9_999,
+            },
+        ];
+        let amounts = [0, 1, 99, 100, 12345, 1_000_000, u64::MAX / 2];
+
+        for fee in fees.iter() {
+            for &amount in amounts.iter() {
+                let pre_fee_amount = fee.calculate_pre_fee_amount(amount).unwrap();
+                assert_eq!(pre_fee_amount - fee.calculate_fee(pre_fee_amount), amount);
+            }
+        }
+        // whole transfer is taken
+        {
+            let fee = TransferFee {
+                epoch: 0,
+                maximum_fee: 10,
+                basis_points: 10_000,
+            };
+            assert_eq!(fee.calculate_pre_fee_amount(5), Some(15));
+            assert_eq!(fee.calculate_pre_fee_amount(u64::MAX), None);
+        }
+    }
+
+    #[test]
+    fn test_get_epoch_fee() {
+        let older = TransferFee {
+            epoch: 0,
+            maximum_fee: 1,
+            basis_points: 1,
+        };
+        let newer = TransferFee {
+            epoch: 10,
+            maximum_fee: 2,
+            basis_points: 2,
+        };
+        let config = TransferFeeConfig { older, newer };
+
+        assert_eq!(*config.get_epoch_fee(9), older);
+        assert_eq!(*config.get_epoch_fee(10), newer);
+        assert_eq!(*config.get_epoch_fee(11), newer);
+    }
+
+    #[test]
+    fn test_unpack_mint() {
+        // without extensions
+        {
+            let mint = unpack_mint(&mint_data(&[])).unwrap();
+            assert_eq!(mint.decimals, 6);
+            assert_eq!(mint.transfer_fee, None);
+            assert_eq!(mint.get_account_len(), ACCOUNT_LEN);
+        }
+        // with transfer fee
+        {
+            let older = TransferFee {
+                epoch: 3,
+                maximum_fee: 100,
+                basis_points: 50,
+            };
+            let newer = TransferFee {
+                epoch: 7,
+                maximum_fee: 200,
+                basis_points: 75,
+            };
+            let data = mint_data(&[
+                (EXTENSION_MINT_CLOSE_AUTHORITY, vec![0u8; 32]),
+                (
+                    EXTENSION_TRANSFER_FEE_CONFIG,
+                    transfer_fee_config_data(older, newer),
+                ),
+            ]);
+            let mint = unpack_mint(&data).unwrap();
+            assert_eq!(mint.transfer_fee, Some(TransferFeeConfig { older, newer }));
+            assert_eq!(mint.get_account_len(), ACCOUNT_WITH_TRANSFER_FEE_LEN);
+            assert_eq!(mint.get_fee(10_000, 5), 50);
+            assert_eq!(mint.get_fee(10_000, 7), 75);
+        }
+        // unsupported extension
+        {
+            // transfer hook
+            let data = mint_data(&[(14, vec![0u8; 64])]);
+            assert!(unpack_mint(&data).is_err());
+        }
+        // uninitialized
+        {
+            let mut data = mint_data(&[]);
+            data[45] = 0;
+            assert!(unpack_mint(&data).is_err());
+        }
+        // token account
+        assert!(unpack_mint(&vec![1u8; ACCOUNT_LEN]).is_err());
+    }
+
+    #[test]
+    fn test_unpack_token_account() {
+        let mint = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+        let mut data = vec![0u8; ACCOUNT_LEN];
+        data[0..32].copy_from_slice(mint.as_ref());
+        data[32..64].copy_from_slice(owner.as_ref());
+        data[64..72].copy_from_slice(&42u64.to_le_bytes());
+        data[108] = 1;
+
+        let expected = TokenAccountState {
+            mint,
+            owner,
+            amount: 42,
+            is_frozen: false,
+        };
+        assert_eq!(unpack_token_account(&data).unwrap(), expected);
+
+        // with extensions
+        {
+            let mut data = data.clone();
+            data.push(ACCOUNT_TYPE_ACCOUNT);
+            data.extend_from_slice(&[2, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+            assert_eq!(unpack_token_account(&data).unwrap(), expected);
+        }
+        // mint with extensions
+        {
+            let mut data = data.clone();
+            data.push(ACCOUNT_TYPE_MINT);
+            assert!(unpack_token_account(&data).is_err());
+        }
+        // frozen
+        {
+            let mut data = data.clone();
+            data[108] = 2;
+            assert!(unpack_token_account(&data).unwrap().is_frozen);
+        }
+        // uninitialized
+        {
+            let mut data = data.clone();
+            data[108] = 0;
+            assert!(unpack_token_account(&data).is_err());
+        }
+    }
+}
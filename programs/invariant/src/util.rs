@@ -66,7 +66,7 @@ pub fn get_closer_limit(
             }
         }
         None => {
-            let index = get_search_limit(current_tick, tick_spacing, !x_to_y);
+            let index = get_search_limit(tick_spacing, !x_to_y);
             let price = calculate_price_sqrt(index);
 
             require!(current_tick != index, LimitReached);
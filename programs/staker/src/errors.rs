@@ -48,4 +48,22 @@ pub enum ErrorCode {
     InvalidMint = 21, // 1785
     #[msg("Incentive pool is different from stake pool")]
     DifferentIncentivePool = 22, // 1786
+    #[msg("Claim time of the incentive is over")]
+    ClaimEnded = 23, // 1787
+    #[msg("Incentive can't hold more reward tokens")]
+    TooManyRewardTokens = 24, // 1788
+    #[msg("New end time has to be later than the current one")]
+    InvalidEndTime = 25, // 1789
+    #[msg("Invalid tick band")]
+    InvalidTickBand = 26, // 178a
+    #[msg("Position is outside of the incentive tick band")]
+    PositionOutsideBand = 27, // 178b
+    #[msg("Tick doesn't belong to the position")]
+    InvalidTick = 28, // 178c
+    #[msg("Incentive has already started")]
+    AlreadyStarted = 29, // 178d
+    #[msg("Invalid vesting schedule")]
+    InvalidVesting = 30, // 178e
+    #[msg("Reward for extending the incentive doesn't fit into the token amount")]
+    ExtensionRewardOverflow = 31, // 178f
 }
@@ -0,0 +1,63 @@
+use crate::decimals::*;
+use crate::instructions::create_incentive::DepositToken;
+use crate::structs::*;
+use crate::ErrorCode::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, TokenAccount, Transfer};
+
+#[derive(Accounts)]
+pub struct AddIncentiveReward<'info> {
+    #[account(mut)]
+    pub incentive: AccountLoader<'info, Incentive>,
+    #[account(mut,
+        constraint = incentive.load()?.rewards().iter().any(|reward| reward.token_account == incentive_token_account.key()) @ InvalidTokenAccount
+    )]
+    pub incentive_token_account: Account<'info, TokenAccount>,
+    #[account(mut,
+        constraint = funder_token_account.key() != incentive_token_account.key() @ InvalidTokenAccount,
+        constraint = funder_token_account.mint == incentive_token_account.mint @ InvalidMint,
+        constraint = funder_token_account.owner == funder.key() @ InvalidOwner
+    )]
+    pub funder_token_account: Account<'info, TokenAccount>,
+    pub funder: Signer<'info>,
+    #[account(address = token::ID)]
+    pub token_program: AccountInfo<'info>,
+}
+
+impl<'info> DepositToken<'info> for AddIncentiveReward<'info> {
+    fn deposit(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        CpiContext::new(
+            self.token_program.to_account_info(),
+            Transfer {
+                from: self.funder_token_account.to_account_info(),
+                to: self.incentive_token_account.to_account_info(),
+                authority: self.funder.to_account_info().clone(),
+            },
+        )
+    }
+}
+
+pub fn handler(ctx: Context<AddIncentiveReward>, reward: TokenAmount) -> ProgramResult {
+    msg!("ADD INCENTIVE REWARD");
+    require!(reward != TokenAmount::new(0), ZeroAmount);
+
+    {
+        let mut incentive = ctx.accounts.incentive.load_mut()?;
+        require!(Seconds::now() < { incentive.end_time }, Ended);
+
+        // added reward is distributed over all liquidity-seconds not claimed yet
+        let token_account = ctx.accounts.incentive_token_account.key();
+        let index = incentive
+            .rewards()
+            .iter()
+            .position(|incentive_reward| incentive_reward.token_account == token_account)
+            .unwrap();
+        incentive.rewards[index].total_reward_unclaimed =
+            incentive.rewards[index].total_reward_unclaimed + reward;
+    }
+
+    let cpi_ctx = ctx.accounts.deposit();
+    token::transfer(cpi_ctx, reward.get())?;
+
+    Ok(())
+}
@@ -0,0 +1,68 @@
+use crate::decimals::*;
+use crate::instructions::create_incentive::DepositToken;
+use crate::structs::*;
+use crate::ErrorCode::*;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::system_program;
+use anchor_spl::token::Mint;
+use anchor_spl::token::{self, TokenAccount, Transfer};
+
+#[derive(Accounts)]
+#[instruction(nonce: u8)]
+pub struct AddRewardToken<'info> {
+    #[account(mut,
+        constraint = incentive.load()?.founder == founder.key() @ InvalidFounder
+    )]
+    pub incentive: AccountLoader<'info, Incentive>,
+    #[account(init,
+        token::mint = incentive_token,
+        token::authority = staker_authority,
+        payer = founder,
+    )]
+    pub incentive_token_account: Account<'info, TokenAccount>,
+    #[account(mut,
+        constraint = founder_token_account.key() != incentive_token_account.key() @ InvalidTokenAccount,
+        constraint = founder_token_account.mint == incentive_token.key() @ InvalidMint,
+        constraint = founder_token_account.owner == founder.key() @ InvalidOwner
+    )]
+    pub founder_token_account: Account<'info, TokenAccount>,
+    #[account(mut)]
+    pub founder: Signer<'info>,
+    #[account(seeds = [b"staker".as_ref()], bump = nonce)]
+    pub staker_authority: AccountInfo<'info>,
+    pub incentive_token: Account<'info, Mint>,
+    #[account(address = token::ID)]
+    pub token_program: AccountInfo<'info>,
+    #[account(address = system_program::ID)]
+    pub system_program: AccountInfo<'info>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+impl<'info> DepositToken<'info> for AddRewardToken<'info> {
+    fn deposit(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        CpiContext::new(
+            self.token_program.to_account_info(),
+            Transfer {
+                from: self.founder_token_account.to_account_info(),
+                to: self.incentive_token_account.to_account_info(),
+                authority: self.founder.to_account_info().clone(),
+            },
+        )
+    }
+}
+
+pub fn handler(ctx: Context<AddRewardToken>, _nonce: u8, reward: TokenAmount) -> ProgramResult {
+    msg!("ADD REWARD TOKEN");
+    require!(reward != TokenAmount::new(0), ZeroAmount);
+
+    {
+        let mut incentive = ctx.accounts.incentive.load_mut()?;
+        require!(Seconds::now() < { incentive.end_time }, Ended);
+        incentive.add_reward(ctx.accounts.incentive_token_account.key(), reward)?;
+    }
+
+    let cpi_ctx = ctx.accounts.deposit();
+    token::transfer(cpi_ctx, reward.get())?;
+
+    Ok(())
+}
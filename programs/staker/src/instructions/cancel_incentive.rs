@@ -0,0 +1,115 @@
+use crate::decimals::*;
+use crate::structs::*;
+use crate::util;
+use crate::ErrorCode::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, CloseAccount, TokenAccount, Transfer};
+use util::STAKER_SEED;
+
+#[derive(Accounts)]
+#[instruction(nonce: u8)]
+pub struct CancelIncentive<'info> {
+    #[account(mut,
+        close = founder,
+        constraint = incentive.load()?.founder == founder.key() @ InvalidFounder
+    )]
+    pub incentive: AccountLoader<'info, Incentive>,
+    #[account(mut,
+        constraint = incentive_token_account.owner == staker_authority.key() @ InvalidTokenAccount,
+        constraint = incentive.load()?.rewards[0].token_account == incentive_token_account.key() @ InvalidTokenAccount
+    )]
+    pub incentive_token_account: Account<'info, TokenAccount>,
+    #[account(mut,
+        constraint = founder_token_account.key() != incentive_token_account.key() @ InvalidTokenAccount
+    )]
+    pub founder_token_account: Account<'info, TokenAccount>,
+    #[account(seeds = [b"staker".as_ref()], bump = nonce)]
+    pub staker_authority: AccountInfo<'info>,
+    #[account(mut)]
+    pub founder: Signer<'info>,
+    #[account(address = token::ID)]
+    pub token_program: AccountInfo<'info>,
+}
+
+impl<'info> CancelIncentive<'info> {
+    fn return_from(
+        &self,
+        vault: &AccountInfo<'info>,
+        to: &AccountInfo<'info>,
+    ) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        CpiContext::new(
+            self.token_program.to_account_info(),
+            Transfer {
+                from: vault.clone(),
+                to: to.clone(),
+                authority: self.staker_authority.to_account_info(),
+            },
+        )
+    }
+
+    // rent of the vault goes back to the founder who paid for it
+    fn close_vault(
+        &self,
+        vault: &AccountInfo<'info>,
+    ) -> CpiContext<'_, '_, '_, 'info, CloseAccount<'info>> {
+        CpiContext::new(
+            self.token_program.to_account_info(),
+            CloseAccount {
+                account: vault.clone(),
+                destination: self.founder.to_account_info(),
+                authority: self.staker_authority.to_account_info(),
+            },
+        )
+    }
+}
+
+// remaining accounts are pairs of incentive and founder token accounts of additional rewards: [vault_1, founder_1, ...]
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, CancelIncentive<'info>>,
+    nonce: u8,
+) -> ProgramResult {
+    msg!("CANCEL INCENTIVE");
+    let incentive = ctx.accounts.incentive.load()?;
+    require!(Seconds::now() < { incentive.start_time }, AlreadyStarted);
+    require!(incentive.num_of_stakes == 0, StakeExist);
+
+    let reward_count = incentive.reward_count as usize;
+    require!(
+        ctx.remaining_accounts.len() == (reward_count - 1) * 2,
+        InvalidTokenAccount
+    );
+
+    let mut vaults = vec![(
+        ctx.accounts.incentive_token_account.to_account_info(),
+        ctx.accounts.founder_token_account.to_account_info(),
+    )];
+    for i in 1..reward_count {
+        let vault = &ctx.remaining_accounts[2 * (i - 1)];
+        let founder_token_account = &ctx.remaining_accounts[2 * (i - 1) + 1];
+        require!(
+            *vault.key == incentive.rewards[i].token_account,
+            InvalidTokenAccount
+        );
+        require!(vault.key != founder_token_account.key, InvalidTokenAccount);
+        vaults.push((vault.clone(), founder_token_account.clone()));
+    }
+
+    let seeds = &[STAKER_SEED.as_bytes(), &[nonce]];
+    let signer = &[&seeds[..]];
+
+    // the whole balance is returned, so the vault can be closed even if tokens were sent to it directly
+    for (vault, founder_token_account) in vaults.iter() {
+        let amount = Account::<'_, TokenAccount>::try_from(vault)?.amount;
+        if amount != 0 {
+            let cpi_ctx = ctx
+                .accounts
+                .return_from(vault, founder_token_account)
+                .with_signer(signer);
+            token::transfer(cpi_ctx, amount)?;
+        }
+
+        token::close_account(ctx.accounts.close_vault(vault).with_signer(signer))?;
+    }
+
+    Ok(())
+}
@@ -1,20 +1,24 @@
 use crate::structs::{Incentive, UserStake};
+use crate::util::STAKER_SEED;
 use crate::ErrorCode::*;
 use anchor_lang::prelude::*;
+use invariant::cpi::accounts::LockPosition;
+use invariant::program::Invariant;
 use invariant::structs::Position;
 
 #[derive(Accounts)]
 #[instruction(index: u32)]
 pub struct CloseStakeByOwner<'info> {
+    // incentive can be already closed, when it was ended after the claim time
     #[account(mut, constraint = user_stake.load()?.incentive == incentive.key() @ InvalidFounder)]
-    pub incentive: AccountLoader<'info, Incentive>,
+    pub incentive: AccountInfo<'info>,
     #[account(mut,
         close = owner,
         seeds = [b"staker", incentive.key().as_ref(), position.load()?.pool.as_ref(), &position.load()?.id.to_le_bytes()],
         bump = user_stake.load()?.bump,
     )]
     pub user_stake: AccountLoader<'info, UserStake>,
-    #[account(
+    #[account(mut,
         seeds = [b"positionv1",
         owner.key.as_ref(),
         &index.to_le_bytes(),],
@@ -23,14 +27,39 @@ pub struct CloseStakeByOwner<'info> {
     )]
     pub position: AccountLoader<'info, Position>,
     pub owner: Signer<'info>,
+    #[account(seeds = [b"staker".as_ref()], bump)]
+    pub staker_authority: AccountInfo<'info>,
+    #[account(address = invariant::ID)]
+    pub invariant: Program<'info, Invariant>,
+}
+
+impl<'info> CloseStakeByOwner<'info> {
+    fn unlock_position(&self) -> CpiContext<'_, '_, '_, 'info, LockPosition<'info>> {
+        CpiContext::new(
+            self.invariant.to_account_info(),
+            LockPosition {
+                position: self.position.to_account_info(),
+                staker_authority: self.staker_authority.to_account_info(),
+            },
+        )
+    }
 }
 
 pub fn handler(ctx: Context<CloseStakeByOwner>, _index: i32) -> ProgramResult {
-    let mut incentive = ctx.accounts.incentive.load_mut()?;
-    require!(incentive.num_of_stakes > 0, NoStakes);
+    if let Ok(incentive) = AccountLoader::<Incentive>::try_from(&ctx.accounts.incentive) {
+        let mut incentive = incentive.load_mut()?;
+        require!(incentive.num_of_stakes > 0, NoStakes);
+
+        // decrease number of stakes by 1
+        incentive.num_of_stakes -= 1;
+    }
 
-    // decrease number of stakes by 1
-    incentive.num_of_stakes -= 1;
+    if ctx.accounts.user_stake.load()?.locked {
+        let bump = *ctx.bumps.get("staker_authority").unwrap();
+        let seeds = &[STAKER_SEED.as_bytes(), &[bump]];
+        let signer = &[&seeds[..]];
+        invariant::cpi::unlock_position(ctx.accounts.unlock_position().with_signer(signer))?;
+    }
 
     Ok(())
 }
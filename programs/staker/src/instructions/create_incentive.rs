@@ -9,8 +9,8 @@ use invariant::program::Invariant;
 use invariant::structs::Pool;
 
 const MAX_TIME_BEFORE_START: u64 = 3_600; //hour in sec
-const MAX_DURATION: u64 = 31_556_926; //year in sec
-const WEEK: u64 = 604_800; //week in sec
+pub const MAX_DURATION: u64 = 31_556_926; //year in sec
+pub const WEEK: u64 = 604_800; //week in sec
 
 #[derive(Accounts)]
 #[instruction(nonce: u8)]
@@ -67,10 +67,22 @@ pub fn handler(
     reward: TokenAmount,
     start_time: Seconds,
     end_time: Seconds,
+    band: Option<TickBand>,
+    vesting: Option<Vesting>,
 ) -> ProgramResult {
     msg!("CREATE INCENTIVE");
     require!((reward) != TokenAmount::new(0), ZeroAmount);
 
+    let band = band.unwrap_or(TickBand::FULL_RANGE);
+    require!(band.is_valid(), InvalidTickBand);
+
+    let vesting = vesting.unwrap_or_default();
+    let (cliff, duration) = ({ vesting.cliff }.get(), { vesting.duration }.get());
+    require!(
+        cliff <= MAX_DURATION && duration <= MAX_DURATION - cliff,
+        InvalidVesting
+    );
+
     require!(
         (start_time + Seconds::new(MAX_TIME_BEFORE_START)) >= Seconds::now(),
         StartInPast
@@ -84,15 +96,18 @@ pub fn handler(
     **incentive = Incentive {
         founder: ctx.accounts.founder.key(),
         pool: ctx.accounts.pool.key(),
-        token_account: ctx.accounts.incentive_token_account.key(),
-        total_reward_unclaimed: reward,
+        rewards: Default::default(),
+        reward_count: 0,
         total_seconds_claimed: Seconds::new(0),
         num_of_stakes: 0,
         start_time,
         end_time,
         end_claim_time: end_time + Seconds::new(WEEK),
+        band,
+        vesting,
         nonce,
     };
+    incentive.add_reward(ctx.accounts.incentive_token_account.key(), reward)?;
 
     //send tokens to incentive
     let cpi_ctx = ctx.accounts.deposit();
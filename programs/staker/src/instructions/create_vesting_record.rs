@@ -0,0 +1,38 @@
+use crate::structs::*;
+use crate::ErrorCode::*;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::system_program;
+
+#[derive(Accounts)]
+pub struct CreateVestingRecord<'info> {
+    #[account(init,
+        seeds = [b"vesting", incentive.key().as_ref(), owner.key.as_ref()],
+        payer = signer,
+        bump)]
+    pub vesting_record: AccountLoader<'info, VestingRecord>,
+    pub incentive: AccountLoader<'info, Incentive>,
+    pub owner: AccountInfo<'info>,
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    #[account(address = system_program::ID)]
+    pub system_program: AccountInfo<'info>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+pub fn handler(ctx: Context<CreateVestingRecord>) -> ProgramResult {
+    msg!("CREATE VESTING RECORD");
+    let incentive = ctx.accounts.incentive.load()?;
+    require!(incentive.vesting.is_enabled(), InvalidVesting);
+
+    let vesting_record = &mut ctx.accounts.vesting_record.load_init()?;
+    **vesting_record = VestingRecord {
+        incentive: ctx.accounts.incentive.key(),
+        owner: ctx.accounts.owner.key(),
+        vesting: incentive.vesting,
+        start_time: incentive.end_time,
+        bump: *ctx.bumps.get("vesting_record").unwrap(),
+        ..Default::default()
+    };
+
+    Ok(())
+}
@@ -16,7 +16,7 @@ pub struct ReturnFounds<'info> {
     pub incentive: AccountLoader<'info, Incentive>,
     #[account(mut,
         constraint = incentive_token_account.owner == staker_authority.key() @ InvalidTokenAccount,
-        constraint = incentive.load()?.token_account == incentive_token_account.key() @ InvalidTokenAccount,
+        constraint = incentive.load()?.rewards[0].token_account == incentive_token_account.key() @ InvalidTokenAccount,
         constraint = incentive_token_account.mint == incentive_token.key() @ InvalidMint
     )]
     pub incentive_token_account: Account<'info, TokenAccount>,
@@ -41,20 +41,68 @@ impl<'info> ReturnFounds<'info> {
             },
         )
     }
+
+    fn return_from(
+        &self,
+        vault: &AccountInfo<'info>,
+        to: &AccountInfo<'info>,
+    ) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        CpiContext::new(
+            self.token_program.to_account_info(),
+            Transfer {
+                from: vault.clone(),
+                to: to.clone(),
+                authority: self.staker_authority.to_account_info().clone(),
+            },
+        )
+    }
 }
 
-pub fn handler(ctx: Context<ReturnFounds>, nonce: u8) -> ProgramResult {
+// remaining accounts are pairs of incentive and founder token accounts of additional rewards: [vault_1, founder_1, ...]
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, ReturnFounds<'info>>,
+    nonce: u8,
+) -> ProgramResult {
     {
         let incentive = ctx.accounts.incentive.load()?;
         require!(Seconds::now() > { incentive.end_time }, TooEarly);
-        require!(incentive.num_of_stakes == 0, StakeExist);
-        let remaining_reward = incentive.total_reward_unclaimed;
+        // abandoned stakes can't block the return after the claim time
+        require!(
+            incentive.num_of_stakes == 0 || Seconds::now() > { incentive.end_claim_time },
+            StakeExist
+        );
+        let reward_count = incentive.reward_count as usize;
+        require!(
+            ctx.remaining_accounts.len() == (reward_count - 1) * 2,
+            InvalidTokenAccount
+        );
 
         let seeds = &[STAKER_SEED.as_bytes(), &[nonce]];
         let signer = &[&seeds[..]];
         let cpi_ctx = ctx.accounts.return_to_founder().with_signer(signer);
 
-        token::transfer(cpi_ctx, remaining_reward.get())?;
+        token::transfer(
+            cpi_ctx,
+            { incentive.rewards[0].total_reward_unclaimed }.get(),
+        )?;
+
+        for i in 1..reward_count {
+            let vault = &ctx.remaining_accounts[2 * (i - 1)];
+            let founder_token_account = &ctx.remaining_accounts[2 * (i - 1) + 1];
+            require!(
+                *vault.key == incentive.rewards[i].token_account,
+                InvalidTokenAccount
+            );
+
+            let cpi_ctx = ctx
+                .accounts
+                .return_from(vault, founder_token_account)
+                .with_signer(signer);
+            token::transfer(
+                cpi_ctx,
+                { incentive.rewards[i].total_reward_unclaimed }.get(),
+            )?;
+        }
     }
 
     Ok(())
@@ -0,0 +1,109 @@
+use crate::decimals::*;
+use crate::instructions::create_incentive::{MAX_DURATION, WEEK};
+use crate::math::calculate_extension_reward;
+use crate::structs::*;
+use crate::ErrorCode::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, TokenAccount, Transfer};
+
+#[derive(Accounts)]
+pub struct ExtendIncentive<'info> {
+    #[account(mut,
+        constraint = incentive.load()?.founder == founder.key() @ InvalidFounder
+    )]
+    pub incentive: AccountLoader<'info, Incentive>,
+    #[account(mut,
+        constraint = incentive.load()?.rewards[0].token_account == incentive_token_account.key() @ InvalidTokenAccount
+    )]
+    pub incentive_token_account: Account<'info, TokenAccount>,
+    #[account(mut,
+        constraint = founder_token_account.key() != incentive_token_account.key() @ InvalidTokenAccount,
+        constraint = founder_token_account.mint == incentive_token_account.mint @ InvalidMint,
+        constraint = founder_token_account.owner == founder.key() @ InvalidOwner
+    )]
+    pub founder_token_account: Account<'info, TokenAccount>,
+    pub founder: Signer<'info>,
+    #[account(address = token::ID)]
+    pub token_program: AccountInfo<'info>,
+}
+
+impl<'info> ExtendIncentive<'info> {
+    fn deposit_to(
+        &self,
+        from: &AccountInfo<'info>,
+        vault: &AccountInfo<'info>,
+    ) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        CpiContext::new(
+            self.token_program.to_account_info(),
+            Transfer {
+                from: from.clone(),
+                to: vault.clone(),
+                authority: self.founder.to_account_info(),
+            },
+        )
+    }
+}
+
+// remaining accounts are pairs of incentive and founder token accounts of additional rewards: [vault_1, founder_1, ...]
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, ExtendIncentive<'info>>,
+    end_time: Seconds,
+) -> ProgramResult {
+    msg!("EXTEND INCENTIVE");
+    let mut incentive = ctx.accounts.incentive.load_mut()?;
+
+    require!(Seconds::now() < { incentive.end_time }, Ended);
+    require!(end_time > { incentive.end_time }, InvalidEndTime);
+    require!(
+        (incentive.start_time + Seconds::new(MAX_DURATION)) >= end_time,
+        TooLongDuration
+    );
+
+    let reward_count = incentive.reward_count as usize;
+    require!(
+        ctx.remaining_accounts.len() == (reward_count - 1) * 2,
+        InvalidTokenAccount
+    );
+
+    let mut vaults = vec![(
+        ctx.accounts.incentive_token_account.to_account_info(),
+        ctx.accounts.founder_token_account.to_account_info(),
+    )];
+    for i in 1..reward_count {
+        let vault = &ctx.remaining_accounts[2 * (i - 1)];
+        let founder_token_account = &ctx.remaining_accounts[2 * (i - 1) + 1];
+        require!(
+            *vault.key == incentive.rewards[i].token_account,
+            InvalidTokenAccount
+        );
+        require!(vault.key != founder_token_account.key, InvalidTokenAccount);
+        vaults.push((vault.clone(), founder_token_account.clone()));
+    }
+
+    // founder funds the added time at the current rate, so rewards earned until now stay the same
+    let mut deposits = Vec::with_capacity(reward_count);
+    for i in 0..reward_count {
+        let deposit = calculate_extension_reward(
+            incentive.rewards[i].total_reward_unclaimed,
+            incentive.total_seconds_claimed,
+            incentive.start_time,
+            incentive.end_time,
+            end_time,
+        )?;
+        incentive.rewards[i].total_reward_unclaimed =
+            incentive.rewards[i].total_reward_unclaimed + deposit;
+        deposits.push(deposit);
+    }
+    incentive.end_time = end_time;
+    incentive.end_claim_time = end_time + Seconds::new(WEEK);
+    drop(incentive);
+
+    for ((vault, founder_token_account), deposit) in vaults.iter().zip(deposits) {
+        if !deposit.is_zero() {
+            let cpi_ctx = ctx.accounts.deposit_to(founder_token_account, vault);
+            token::transfer(cpi_ctx, deposit.get())?;
+        }
+    }
+
+    Ok(())
+}
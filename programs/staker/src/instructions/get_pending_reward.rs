@@ -0,0 +1,49 @@
+use crate::decimals::*;
+use crate::math::calculate_pending_reward;
+use crate::return_data::{self, PendingRewardReturnData};
+use crate::structs::*;
+use crate::ErrorCode::*;
+use anchor_lang::prelude::*;
+use invariant::structs::{Pool, Position, Tick};
+
+// Read only, meant to be simulated
+#[derive(Accounts)]
+pub struct GetPendingReward<'info> {
+    #[account(constraint = user_stake.load()?.incentive == incentive.key() @ InvalidIncentive)]
+    pub incentive: AccountLoader<'info, Incentive>,
+    #[account(
+        seeds = [b"staker", incentive.key().as_ref(), position.load()?.pool.as_ref(), &position.load()?.id.to_le_bytes()],
+        bump = user_stake.load()?.bump,
+    )]
+    pub user_stake: AccountLoader<'info, UserStake>,
+    pub position: AccountLoader<'info, Position>,
+    #[account(constraint = pool.key() == position.load()?.pool @ DifferentIncentivePool)]
+    pub pool: AccountLoader<'info, Pool>,
+    #[account(
+        constraint = lower_tick.load()?.pool == pool.key() @ InvalidTick,
+        constraint = lower_tick.load()?.index == position.load()?.lower_tick_index @ InvalidTick
+    )]
+    pub lower_tick: AccountLoader<'info, Tick>,
+    #[account(
+        constraint = upper_tick.load()?.pool == pool.key() @ InvalidTick,
+        constraint = upper_tick.load()?.index == position.load()?.upper_tick_index @ InvalidTick
+    )]
+    pub upper_tick: AccountLoader<'info, Tick>,
+}
+
+pub fn handler(ctx: Context<GetPendingReward>) -> ProgramResult {
+    let rewards = calculate_pending_reward(
+        &*ctx.accounts.incentive.load()?,
+        &*ctx.accounts.user_stake.load()?,
+        &*ctx.accounts.pool.load()?,
+        &*ctx.accounts.lower_tick.load()?,
+        &*ctx.accounts.upper_tick.load()?,
+        Seconds::now(),
+    )?;
+
+    return_data::set(&PendingRewardReturnData {
+        rewards: rewards.iter().map(|reward| reward.get()).collect(),
+    });
+
+    Ok(())
+}
@@ -0,0 +1,55 @@
+use crate::structs::*;
+use crate::ErrorCode::*;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::invoke;
+use anchor_lang::solana_program::{system_instruction, system_program};
+use anchor_lang::Discriminator;
+
+#[derive(Accounts)]
+pub struct MigrateIncentive<'info> {
+    #[account(mut,
+        constraint = *incentive.owner == crate::ID @ InvalidIncentive
+    )]
+    pub incentive: AccountInfo<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(address = system_program::ID)]
+    pub system_program: AccountInfo<'info>,
+}
+
+// Stakes are derived from the incentive address, so the account is resized in place instead of recreated
+pub fn handler(ctx: Context<MigrateIncentive>) -> ProgramResult {
+    msg!("MIGRATE INCENTIVE");
+    let incentive_info = &ctx.accounts.incentive;
+    let legacy = {
+        let data = incentive_info.try_borrow_data()?;
+        require!(data.len() == LegacyIncentive::LEN, InvalidIncentive);
+        require!(data[..8] == Incentive::discriminator(), InvalidIncentive);
+        LegacyIncentive::try_from_slice(&data[8..])?
+    };
+
+    let space = 8 + std::mem::size_of::<Incentive>();
+    let required_lamports = Rent::get()?.minimum_balance(space);
+    let current_lamports = incentive_info.lamports();
+    if required_lamports > current_lamports {
+        invoke(
+            &system_instruction::transfer(
+                ctx.accounts.payer.key,
+                incentive_info.key,
+                required_lamports - current_lamports,
+            ),
+            &[
+                ctx.accounts.payer.to_account_info(),
+                incentive_info.clone(),
+                ctx.accounts.system_program.clone(),
+            ],
+        )?;
+    }
+    incentive_info.realloc(space, true)?;
+
+    let incentive_loader = AccountLoader::<Incentive>::try_from(incentive_info)?;
+    let mut incentive = incentive_loader.load_mut()?;
+    *incentive = legacy.migrate();
+
+    Ok(())
+}
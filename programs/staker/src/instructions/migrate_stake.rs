@@ -0,0 +1,50 @@
+use crate::structs::*;
+use crate::ErrorCode::*;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::invoke;
+use anchor_lang::solana_program::{system_instruction, system_program};
+use anchor_lang::Discriminator;
+
+#[derive(Accounts)]
+pub struct MigrateStake<'info> {
+    #[account(mut,
+        constraint = *user_stake.owner == crate::ID @ InvalidStake
+    )]
+    pub user_stake: AccountInfo<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(address = system_program::ID)]
+    pub system_program: AccountInfo<'info>,
+}
+
+// Appended locked flag is zero initialized, so closing a legacy stake never unlocks the position
+pub fn handler(ctx: Context<MigrateStake>) -> ProgramResult {
+    msg!("MIGRATE STAKE");
+    let user_stake_info = &ctx.accounts.user_stake;
+    {
+        let data = user_stake_info.try_borrow_data()?;
+        require!(data.len() == UserStake::LEGACY_LEN, InvalidStake);
+        require!(data[..8] == UserStake::discriminator(), InvalidStake);
+    }
+
+    let space = 8 + std::mem::size_of::<UserStake>();
+    let required_lamports = Rent::get()?.minimum_balance(space);
+    let current_lamports = user_stake_info.lamports();
+    if required_lamports > current_lamports {
+        invoke(
+            &system_instruction::transfer(
+                ctx.accounts.payer.key,
+                user_stake_info.key,
+                required_lamports - current_lamports,
+            ),
+            &[
+                ctx.accounts.payer.to_account_info(),
+                user_stake_info.clone(),
+                ctx.accounts.system_program.clone(),
+            ],
+        )?;
+    }
+    user_stake_info.realloc(space, true)?;
+
+    Ok(())
+}
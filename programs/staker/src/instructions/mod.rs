@@ -1,13 +1,33 @@
+pub mod add_incentive_reward;
+pub mod add_reward_token;
+pub mod cancel_incentive;
 pub mod close_stake_by_owner;
 pub mod create_incentive;
+pub mod create_vesting_record;
 pub mod end_incentive;
+pub mod extend_incentive;
+pub mod get_pending_reward;
+pub mod migrate_incentive;
+pub mod migrate_stake;
+pub mod release_vested;
 pub mod remove_stake;
 pub mod stake;
 pub mod withdraw;
+pub mod withdraw_many;
 
+pub use add_incentive_reward::*;
+pub use add_reward_token::*;
+pub use cancel_incentive::*;
 pub use close_stake_by_owner::*;
 pub use create_incentive::*;
+pub use create_vesting_record::*;
 pub use end_incentive::*;
+pub use extend_incentive::*;
+pub use get_pending_reward::*;
+pub use migrate_incentive::*;
+pub use migrate_stake::*;
+pub use release_vested::*;
 pub use remove_stake::*;
 pub use stake::*;
 pub use withdraw::*;
+pub use withdraw_many::*;
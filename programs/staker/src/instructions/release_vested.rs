@@ -0,0 +1,98 @@
+use crate::decimals::*;
+use crate::structs::*;
+use crate::util::STAKER_SEED;
+use crate::ErrorCode::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, TokenAccount, Transfer};
+
+#[derive(Accounts)]
+#[instruction(nonce: u8)]
+pub struct ReleaseVested<'info> {
+    #[account(mut,
+        seeds = [b"vesting", vesting_record.load()?.incentive.as_ref(), vesting_record.load()?.owner.as_ref()],
+        bump = vesting_record.load()?.bump
+    )]
+    pub vesting_record: AccountLoader<'info, VestingRecord>,
+    #[account(mut,
+        constraint = incentive_token_account.owner == staker_authority.key() @ InvalidTokenAccount,
+        constraint = vesting_record.load()?.rewards[0].token_account == incentive_token_account.key() @ InvalidTokenAccount
+    )]
+    pub incentive_token_account: Account<'info, TokenAccount>,
+    #[account(mut,
+        constraint = owner_token_account.key() != incentive_token_account.key() @ InvalidTokenAccount,
+        constraint = owner_token_account.owner == vesting_record.load()?.owner @ InvalidOwner
+    )]
+    pub owner_token_account: Account<'info, TokenAccount>,
+    #[account(seeds = [b"staker".as_ref()], bump = nonce)]
+    pub staker_authority: AccountInfo<'info>,
+    #[account(address = token::ID)]
+    pub token_program: AccountInfo<'info>,
+}
+
+impl<'info> ReleaseVested<'info> {
+    fn release_from(
+        &self,
+        vault: &AccountInfo<'info>,
+        to: &AccountInfo<'info>,
+    ) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        CpiContext::new(
+            self.token_program.to_account_info(),
+            Transfer {
+                from: vault.clone(),
+                to: to.clone(),
+                authority: self.staker_authority.to_account_info(),
+            },
+        )
+    }
+}
+
+// remaining accounts are pairs of incentive and owner token accounts of additional rewards: [vault_1, owner_1, ...]
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, ReleaseVested<'info>>,
+    nonce: u8,
+) -> ProgramResult {
+    msg!("RELEASE VESTED");
+    let mut vesting_record = ctx.accounts.vesting_record.load_mut()?;
+    let reward_count = vesting_record.reward_count as usize;
+    require!(reward_count != 0, ZeroAmount);
+    require!(
+        ctx.remaining_accounts.len() == (reward_count - 1) * 2,
+        InvalidTokenAccount
+    );
+
+    let mut token_accounts = vec![(
+        ctx.accounts.incentive_token_account.to_account_info(),
+        ctx.accounts.owner_token_account.to_account_info(),
+    )];
+    for i in 1..reward_count {
+        let vault = &ctx.remaining_accounts[2 * (i - 1)];
+        let owner_token_account = &ctx.remaining_accounts[2 * (i - 1) + 1];
+        require!(
+            *vault.key == vesting_record.rewards[i].token_account,
+            InvalidTokenAccount
+        );
+        require!(vault.key != owner_token_account.key, InvalidTokenAccount);
+        require!(
+            Account::<'_, TokenAccount>::try_from(owner_token_account)?.owner
+                == vesting_record.owner,
+            InvalidOwner
+        );
+        token_accounts.push((vault.clone(), owner_token_account.clone()));
+    }
+
+    let amounts = vesting_record.release(Seconds::now());
+
+    let seeds = &[STAKER_SEED.as_bytes(), &[nonce]];
+    let signer = &[&seeds[..]];
+    for ((vault, owner_token_account), amount) in token_accounts.iter().zip(amounts) {
+        if !amount.is_zero() {
+            let cpi_ctx = ctx
+                .accounts
+                .release_from(vault, owner_token_account)
+                .with_signer(signer);
+            token::transfer(cpi_ctx, amount.get())?;
+        }
+    }
+
+    Ok(())
+}
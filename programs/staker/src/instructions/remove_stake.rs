@@ -1,7 +1,11 @@
 use crate::decimals::*;
 use crate::structs::{Incentive, UserStake};
+use crate::util::STAKER_SEED;
 use crate::ErrorCode::*;
 use anchor_lang::prelude::*;
+use invariant::cpi::accounts::LockPosition;
+use invariant::program::Invariant;
+use invariant::structs::Position;
 
 #[derive(Accounts)]
 pub struct RemoveStake<'info> {
@@ -9,19 +13,48 @@ pub struct RemoveStake<'info> {
     pub incentive: AccountLoader<'info, Incentive>,
     #[account(mut,
         close = founder,
+        seeds = [b"staker", incentive.key().as_ref(), position.load()?.pool.as_ref(), &position.load()?.id.to_le_bytes()],
+        bump = user_stake.load()?.bump,
         constraint = user_stake.load()?.incentive == incentive.key() @ InvalidStake
     )]
     pub user_stake: AccountLoader<'info, UserStake>,
+    #[account(mut)]
+    pub position: AccountLoader<'info, Position>,
     pub founder: Signer<'info>,
+    #[account(seeds = [b"staker".as_ref()], bump)]
+    pub staker_authority: AccountInfo<'info>,
+    #[account(address = invariant::ID)]
+    pub invariant: Program<'info, Invariant>,
+}
+
+impl<'info> RemoveStake<'info> {
+    fn unlock_position(&self) -> CpiContext<'_, '_, '_, 'info, LockPosition<'info>> {
+        CpiContext::new(
+            self.invariant.to_account_info(),
+            LockPosition {
+                position: self.position.to_account_info(),
+                staker_authority: self.staker_authority.to_account_info(),
+            },
+        )
+    }
 }
 
 pub fn handler(ctx: Context<RemoveStake>) -> ProgramResult {
-    let mut incentive = ctx.accounts.incentive.load_mut()?;
-    require!(Seconds::now() > { incentive.end_time }, TooEarly);
-    require!(incentive.num_of_stakes > 0, NoStakes);
+    {
+        let mut incentive = ctx.accounts.incentive.load_mut()?;
+        require!(Seconds::now() > { incentive.end_time }, TooEarly);
+        require!(incentive.num_of_stakes > 0, NoStakes);
+
+        // decrease number of stakes by 1
+        incentive.num_of_stakes -= 1;
+    }
 
-    // decrease number of stakes by 1
-    incentive.num_of_stakes -= 1;
+    if ctx.accounts.user_stake.load()?.locked {
+        let bump = *ctx.bumps.get("staker_authority").unwrap();
+        let seeds = &[STAKER_SEED.as_bytes(), &[bump]];
+        let signer = &[&seeds[..]];
+        invariant::cpi::unlock_position(ctx.accounts.unlock_position().with_signer(signer))?;
+    }
 
     Ok(())
 }
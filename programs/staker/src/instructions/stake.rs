@@ -1,10 +1,11 @@
 use crate::decimals::*;
 use crate::structs::*;
-use crate::util::get_current_slot;
+use crate::util::STAKER_SEED;
 use crate::ErrorCode::*;
 
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::system_program;
+use invariant::cpi::accounts::{LockPosition, UpdateSecondsPerLiquidity};
 use invariant::program::Invariant;
 use invariant::structs::Position;
 
@@ -16,7 +17,7 @@ pub struct CreateUserStake<'info> {
         payer = signer,
         bump)]
     pub user_stake: AccountLoader<'info, UserStake>,
-    #[account(
+    #[account(mut,
         seeds = [b"positionv1",
         owner.key.as_ref(),
         &index.to_le_bytes(),],
@@ -24,11 +25,21 @@ pub struct CreateUserStake<'info> {
         seeds::program = invariant::ID
     )]
     pub position: AccountLoader<'info, Position>,
+    #[account(mut,
+        constraint = pool.key() == position.load()?.pool @ DifferentIncentivePool
+    )]
+    pub pool: AccountInfo<'info>,
+    pub lower_tick: AccountInfo<'info>,
+    pub upper_tick: AccountInfo<'info>,
+    pub token_x: AccountInfo<'info>,
+    pub token_y: AccountInfo<'info>,
     #[account(mut,
         constraint = incentive.load()?.pool == position.load()?.pool @ DifferentIncentivePool
     )]
     pub incentive: AccountLoader<'info, Incentive>,
     pub owner: AccountInfo<'info>,
+    #[account(seeds = [b"staker".as_ref()], bump)]
+    pub staker_authority: AccountInfo<'info>,
     #[account(mut)]
     pub signer: Signer<'info>,
     #[account(address = invariant::ID)]
@@ -38,30 +49,86 @@ pub struct CreateUserStake<'info> {
     pub rent: Sysvar<'info, Rent>,
 }
 
-pub fn handler(ctx: Context<CreateUserStake>) -> ProgramResult {
-    msg!("STAKE");
-    let mut incentive = ctx.accounts.incentive.load_mut()?;
-    require!(Seconds::now() >= { incentive.start_time }, NotStarted);
-    require!(Seconds::now() < { incentive.end_time }, Ended);
-    require!(incentive.num_of_stakes < u64::MAX, NoStakes);
+impl<'info> CreateUserStake<'info> {
+    fn update_seconds_per_liquidity(
+        &self,
+    ) -> CpiContext<'_, '_, '_, 'info, UpdateSecondsPerLiquidity<'info>> {
+        CpiContext::new(
+            self.invariant.to_account_info(),
+            UpdateSecondsPerLiquidity {
+                pool: self.pool.to_account_info(),
+                lower_tick: self.lower_tick.to_account_info(),
+                upper_tick: self.upper_tick.to_account_info(),
+                position: self.position.to_account_info(),
+                token_x: self.token_x.to_account_info(),
+                token_y: self.token_y.to_account_info(),
+                owner: self.owner.to_account_info(),
+                signer: self.signer.to_account_info(),
+                rent: self.rent.to_account_info(),
+                system_program: self.system_program.to_account_info(),
+            },
+        )
+    }
 
-    let user_stake = &mut ctx.accounts.user_stake.load_init()?;
-    let position = ctx.accounts.position.load()?;
-    let update_slot = position.last_slot;
-    let slot = get_current_slot();
-    require!(slot == update_slot, SlotsAreNotEqual);
+    fn lock_position(&self) -> CpiContext<'_, '_, '_, 'info, LockPosition<'info>> {
+        CpiContext::new(
+            self.invariant.to_account_info(),
+            LockPosition {
+                position: self.position.to_account_info(),
+                staker_authority: self.staker_authority.to_account_info(),
+            },
+        )
+    }
+}
 
-    **user_stake = UserStake {
-        position: ctx.accounts.position.key(),
-        liquidity: Liquidity::new({ position.liquidity }.get()),
-        incentive: ctx.accounts.incentive.key(),
-        bump: *ctx.bumps.get("user_stake").unwrap(),
-        seconds_per_liquidity_initial: SecondsPerLiquidity::from_decimal(
-            position.seconds_per_liquidity_inside,
-        ),
+pub fn handler(ctx: Context<CreateUserStake>, index: i32) -> ProgramResult {
+    msg!("STAKE");
+    let (lower_tick_index, upper_tick_index) = {
+        let position = ctx.accounts.position.load()?;
+        (position.lower_tick_index, position.upper_tick_index)
     };
-    incentive.num_of_stakes += 1;
-    let liquidity = user_stake.liquidity;
-    require!(!liquidity.is_zero(), ZeroLiquidity);
+    invariant::cpi::update_seconds_per_liquidity(
+        ctx.accounts.update_seconds_per_liquidity(),
+        lower_tick_index,
+        upper_tick_index,
+        index,
+    )?;
+
+    {
+        let mut incentive = ctx.accounts.incentive.load_mut()?;
+        require!(Seconds::now() >= { incentive.start_time }, NotStarted);
+        require!(Seconds::now() < { incentive.end_time }, Ended);
+        require!(incentive.num_of_stakes < u64::MAX, NoStakes);
+
+        let user_stake = &mut ctx.accounts.user_stake.load_init()?;
+        let position = ctx.accounts.position.load()?;
+        require!(
+            incentive
+                .band
+                .contains(position.lower_tick_index, position.upper_tick_index),
+            PositionOutsideBand
+        );
+
+        **user_stake = UserStake {
+            position: ctx.accounts.position.key(),
+            liquidity: Liquidity::new({ position.liquidity }.get()),
+            incentive: ctx.accounts.incentive.key(),
+            bump: *ctx.bumps.get("user_stake").unwrap(),
+            seconds_per_liquidity_initial: SecondsPerLiquidity::from_decimal(
+                position.seconds_per_liquidity_inside,
+            ),
+            locked: true,
+        };
+        incentive.num_of_stakes += 1;
+        let liquidity = user_stake.liquidity;
+        require!(!liquidity.is_zero(), ZeroLiquidity);
+    }
+
+    // staked liquidity can't be removed until the stake is closed
+    let bump = *ctx.bumps.get("staker_authority").unwrap();
+    let seeds = &[STAKER_SEED.as_bytes(), &[bump]];
+    let signer = &[&seeds[..]];
+    invariant::cpi::lock_position(ctx.accounts.lock_position().with_signer(signer))?;
+
     Ok(())
 }
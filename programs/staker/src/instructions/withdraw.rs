@@ -4,7 +4,10 @@ use crate::structs::*;
 use crate::util::*;
 use crate::ErrorCode::*;
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::system_program;
 use anchor_spl::token::{self, TokenAccount, Transfer};
+use invariant::cpi::accounts::{LockPosition, UpdateSecondsPerLiquidity};
+use invariant::program::Invariant;
 use invariant::structs::Position;
 
 #[derive(Accounts)]
@@ -20,10 +23,11 @@ pub struct Withdraw<'info> {
     )]
     pub incentive: AccountLoader<'info, Incentive>,
     #[account(mut,
-        constraint = incentive_token_account.owner == staker_authority.key() @ InvalidTokenAccount
+        constraint = incentive_token_account.owner == staker_authority.key() @ InvalidTokenAccount,
+        constraint = incentive.load()?.rewards[0].token_account == incentive_token_account.key() @ InvalidTokenAccount
     )]
     pub incentive_token_account: Account<'info, TokenAccount>,
-    #[account(
+    #[account(mut,
         seeds = [b"positionv1",
         owner.key.as_ref(),
         &index.to_le_bytes(),],
@@ -31,6 +35,14 @@ pub struct Withdraw<'info> {
         seeds::program = invariant::ID
     )]
     pub position: AccountLoader<'info, Position>,
+    #[account(mut,
+        constraint = pool.key() == position.load()?.pool @ DifferentIncentivePool
+    )]
+    pub pool: AccountInfo<'info>,
+    pub lower_tick: AccountInfo<'info>,
+    pub upper_tick: AccountInfo<'info>,
+    pub token_x: AccountInfo<'info>,
+    pub token_y: AccountInfo<'info>,
     #[account(mut,
         constraint = owner_token_account.key() != incentive_token_account.key() @ InvalidTokenAccount,
         constraint = owner_token_account.owner == position.load()?.owner @ InvalidOwner
@@ -40,45 +52,144 @@ pub struct Withdraw<'info> {
     pub staker_authority: AccountInfo<'info>,
     #[account(mut)]
     pub owner: AccountInfo<'info>,
+    #[account(mut)]
+    pub signer: Signer<'info>,
     #[account(address = token::ID)]
     pub token_program: AccountInfo<'info>,
+    #[account(address = invariant::ID)]
+    pub invariant: Program<'info, Invariant>,
+    #[account(address = system_program::ID)]
+    pub system_program: AccountInfo<'info>,
+    pub rent: Sysvar<'info, Rent>,
 }
 
 impl<'info> Withdraw<'info> {
-    fn withdraw(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+    fn update_seconds_per_liquidity(
+        &self,
+    ) -> CpiContext<'_, '_, '_, 'info, UpdateSecondsPerLiquidity<'info>> {
+        CpiContext::new(
+            self.invariant.to_account_info(),
+            UpdateSecondsPerLiquidity {
+                pool: self.pool.to_account_info(),
+                lower_tick: self.lower_tick.to_account_info(),
+                upper_tick: self.upper_tick.to_account_info(),
+                position: self.position.to_account_info(),
+                token_x: self.token_x.to_account_info(),
+                token_y: self.token_y.to_account_info(),
+                owner: self.owner.to_account_info(),
+                signer: self.signer.to_account_info(),
+                rent: self.rent.to_account_info(),
+                system_program: self.system_program.to_account_info(),
+            },
+        )
+    }
+
+    fn lock_position(&self) -> CpiContext<'_, '_, '_, 'info, LockPosition<'info>> {
         CpiContext::new(
-            self.token_program.to_account_info(),
-            Transfer {
-                from: self.incentive_token_account.to_account_info(),
-                to: self.owner_token_account.to_account_info(),
-                authority: self.staker_authority.to_account_info().clone(),
+            self.invariant.to_account_info(),
+            LockPosition {
+                position: self.position.to_account_info(),
+                staker_authority: self.staker_authority.to_account_info(),
             },
         )
     }
 }
 
-pub fn handler(ctx: Context<Withdraw>, _index: i32, nonce: u8) -> ProgramResult {
+// remaining accounts are pairs of incentive and owner token accounts of additional rewards: [vault_1, owner_1, ...]
+// followed by the vesting record of the owner if the incentive vests rewards
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, Withdraw<'info>>,
+    index: i32,
+    nonce: u8,
+) -> ProgramResult {
     msg!("WITHDRAW");
+    let (lower_tick_index, upper_tick_index) = {
+        let position = ctx.accounts.position.load()?;
+        (position.lower_tick_index, position.upper_tick_index)
+    };
+    invariant::cpi::update_seconds_per_liquidity(
+        ctx.accounts.update_seconds_per_liquidity(),
+        lower_tick_index,
+        upper_tick_index,
+        index,
+    )?;
 
     let mut incentive = ctx.accounts.incentive.load_mut()?;
     {
-        let user_stake = &mut ctx.accounts.user_stake.load_mut()?;
+        let mut user_stake = ctx.accounts.user_stake.load_mut()?;
         let position = ctx.accounts.position.load()?;
 
-        let update_slot = position.last_slot;
-        let slot = get_current_slot();
+        let mut token_accounts = vec![
+            ctx.accounts.incentive_token_account.to_account_info(),
+            ctx.accounts.owner_token_account.to_account_info(),
+        ];
+        token_accounts.extend_from_slice(ctx.remaining_accounts);
 
-        require!(slot == update_slot, SlotsAreNotEqual);
-        require!(user_stake.liquidity.v != 0, ZeroSecondsStaked);
+        let seeds = &[STAKER_SEED.as_bytes(), &[nonce]];
+        let signer = &[&seeds[..]];
+        claim_rewards(
+            &mut incentive,
+            &mut user_stake,
+            &position,
+            &token_accounts,
+            &ctx.accounts.token_program,
+            &ctx.accounts.staker_authority,
+            signer,
+        )?;
+    }
+
+    if Seconds::now() > { incentive.end_time } {
+        require!(incentive.num_of_stakes > 0, NoStakes);
+        let locked = ctx.accounts.user_stake.load()?.locked;
+        close(
+            ctx.accounts.user_stake.to_account_info(),
+            ctx.accounts.owner.to_account_info(),
+        )
+        .unwrap();
 
-        let seconds_per_liquidity_inside =
-            SecondsPerLiquidity::new(position.seconds_per_liquidity_inside.v);
+        incentive.num_of_stakes -= 1;
 
-        let reward_unclaimed = incentive.total_reward_unclaimed;
+        if locked {
+            let seeds = &[STAKER_SEED.as_bytes(), &[nonce]];
+            let signer = &[&seeds[..]];
+            invariant::cpi::unlock_position(ctx.accounts.lock_position().with_signer(signer))?;
+        }
+    }
 
-        require!(reward_unclaimed != TokenAmount::new(0), ZeroAmount);
+    Ok(())
+}
 
-        let (seconds_inside, reward) = calculate_reward(
+// token accounts are pairs of incentive vault and owner token account of every reward token: [vault_0, owner_0, vault_1, owner_1, ...]
+// followed by the vesting record of the owner if the incentive vests rewards
+pub fn claim_rewards<'info>(
+    incentive: &mut Incentive,
+    user_stake: &mut UserStake,
+    position: &Position,
+    token_accounts: &[AccountInfo<'info>],
+    token_program: &AccountInfo<'info>,
+    staker_authority: &AccountInfo<'info>,
+    signer: &[&[&[u8]]],
+) -> ProgramResult {
+    require!(Seconds::now() <= { incentive.end_claim_time }, ClaimEnded);
+    require!(user_stake.liquidity.v != 0, ZeroSecondsStaked);
+    require!(incentive.has_unclaimed_reward(), ZeroAmount);
+
+    let reward_count = incentive.reward_count as usize;
+    let vesting = incentive.vesting.is_enabled();
+    require!(
+        token_accounts.len() == reward_count * 2 + vesting as usize,
+        InvalidTokenAccount
+    );
+
+    let seconds_per_liquidity_inside =
+        SecondsPerLiquidity::new(position.seconds_per_liquidity_inside.v);
+
+    // every reward token is split by the same share of unclaimed seconds
+    let mut seconds_inside = Seconds::new(0);
+    let mut rewards = Vec::with_capacity(reward_count);
+    for i in 0..reward_count {
+        let reward_unclaimed = incentive.rewards[i].total_reward_unclaimed;
+        let (seconds, reward) = calculate_reward(
             reward_unclaimed,
             incentive.total_seconds_claimed,
             incentive.start_time,
@@ -90,29 +201,52 @@ pub fn handler(ctx: Context<Withdraw>, _index: i32, nonce: u8) -> ProgramResult
         )
         .unwrap();
 
-        incentive.total_seconds_claimed = incentive.total_seconds_claimed + seconds_inside;
-        incentive.total_reward_unclaimed = reward_unclaimed - reward;
-        user_stake.seconds_per_liquidity_initial = seconds_per_liquidity_inside;
-
-        let seeds = &[STAKER_SEED.as_bytes(), &[nonce]];
-        let signer = &[&seeds[..]];
-
-        let cpi_ctx = ctx.accounts.withdraw().with_signer(signer);
+        seconds_inside = seconds;
+        incentive.rewards[i].total_reward_unclaimed = reward_unclaimed - reward;
+        rewards.push(reward);
+    }
 
-        if !reward.is_zero() {
-            token::transfer(cpi_ctx, reward.get())?;
+    incentive.total_seconds_claimed = incentive.total_seconds_claimed + seconds_inside;
+    user_stake.seconds_per_liquidity_initial = seconds_per_liquidity_inside;
+
+    for i in 0..reward_count {
+        let vault = &token_accounts[2 * i];
+        let owner_token_account = &token_accounts[2 * i + 1];
+        require!(
+            *vault.key == incentive.rewards[i].token_account,
+            InvalidTokenAccount
+        );
+        require!(vault.key != owner_token_account.key, InvalidTokenAccount);
+        require!(
+            Account::<'_, TokenAccount>::try_from(owner_token_account)?.owner == position.owner,
+            InvalidOwner
+        );
+
+        if !vesting && !rewards[i].is_zero() {
+            let cpi_ctx = CpiContext::new(
+                token_program.clone(),
+                Transfer {
+                    from: vault.clone(),
+                    to: owner_token_account.clone(),
+                    authority: staker_authority.clone(),
+                },
+            )
+            .with_signer(signer);
+            token::transfer(cpi_ctx, rewards[i].get())?;
         }
     }
 
-    if Seconds::now() > { incentive.end_time } {
-        require!(incentive.num_of_stakes > 0, NoStakes);
-        close(
-            ctx.accounts.user_stake.to_account_info(),
-            ctx.accounts.owner.to_account_info(),
-        )
-        .unwrap();
-
-        incentive.num_of_stakes -= 1;
+    // vested rewards stay in the vaults until they are released
+    if vesting {
+        let vesting_record_loader =
+            AccountLoader::<VestingRecord>::try_from(&token_accounts[reward_count * 2])?;
+        let mut vesting_record = vesting_record_loader.load_mut()?;
+        require!(
+            vesting_record.incentive == user_stake.incentive,
+            InvalidIncentive
+        );
+        require!(vesting_record.owner == position.owner, InvalidOwner);
+        vesting_record.credit(incentive, &rewards);
     }
 
     Ok(())
@@ -0,0 +1,170 @@
+use crate::decimals::*;
+use crate::instructions::withdraw::claim_rewards;
+use crate::structs::*;
+use crate::util::*;
+use crate::ErrorCode::*;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::system_program;
+use anchor_spl::token;
+use invariant::cpi::accounts::{LockPosition, UpdateSecondsPerLiquidity};
+use invariant::program::Invariant;
+use invariant::structs::Position;
+
+#[derive(Accounts)]
+#[instruction(index: u32, nonce: u8)]
+pub struct WithdrawMany<'info> {
+    #[account(mut,
+        seeds = [b"positionv1",
+        owner.key.as_ref(),
+        &index.to_le_bytes(),],
+        bump = position.load()?.bump,
+        seeds::program = invariant::ID
+    )]
+    pub position: AccountLoader<'info, Position>,
+    #[account(mut,
+        constraint = pool.key() == position.load()?.pool @ DifferentIncentivePool
+    )]
+    pub pool: AccountInfo<'info>,
+    pub lower_tick: AccountInfo<'info>,
+    pub upper_tick: AccountInfo<'info>,
+    pub token_x: AccountInfo<'info>,
+    pub token_y: AccountInfo<'info>,
+    #[account(seeds = [b"staker".as_ref()], bump = nonce)]
+    pub staker_authority: AccountInfo<'info>,
+    #[account(mut)]
+    pub owner: AccountInfo<'info>,
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    #[account(address = token::ID)]
+    pub token_program: AccountInfo<'info>,
+    #[account(address = invariant::ID)]
+    pub invariant: Program<'info, Invariant>,
+    #[account(address = system_program::ID)]
+    pub system_program: AccountInfo<'info>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+impl<'info> WithdrawMany<'info> {
+    fn update_seconds_per_liquidity(
+        &self,
+    ) -> CpiContext<'_, '_, '_, 'info, UpdateSecondsPerLiquidity<'info>> {
+        CpiContext::new(
+            self.invariant.to_account_info(),
+            UpdateSecondsPerLiquidity {
+                pool: self.pool.to_account_info(),
+                lower_tick: self.lower_tick.to_account_info(),
+                upper_tick: self.upper_tick.to_account_info(),
+                position: self.position.to_account_info(),
+                token_x: self.token_x.to_account_info(),
+                token_y: self.token_y.to_account_info(),
+                owner: self.owner.to_account_info(),
+                signer: self.signer.to_account_info(),
+                rent: self.rent.to_account_info(),
+                system_program: self.system_program.to_account_info(),
+            },
+        )
+    }
+
+    fn lock_position(&self) -> CpiContext<'_, '_, '_, 'info, LockPosition<'info>> {
+        CpiContext::new(
+            self.invariant.to_account_info(),
+            LockPosition {
+                position: self.position.to_account_info(),
+                staker_authority: self.staker_authority.to_account_info(),
+            },
+        )
+    }
+}
+
+// remaining accounts are consecutive groups of incentive, user stake and pairs of incentive and owner token accounts of every reward token:
+// [incentive_a, user_stake_a, vault_a0, owner_a0, ..., incentive_b, user_stake_b, vault_b0, owner_b0, ...]
+// with the vesting record of the owner closing the group of an incentive that vests rewards
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, WithdrawMany<'info>>,
+    index: i32,
+    nonce: u8,
+) -> ProgramResult {
+    msg!("WITHDRAW MANY");
+    let (lower_tick_index, upper_tick_index) = {
+        let position = ctx.accounts.position.load()?;
+        (position.lower_tick_index, position.upper_tick_index)
+    };
+    invariant::cpi::update_seconds_per_liquidity(
+        ctx.accounts.update_seconds_per_liquidity(),
+        lower_tick_index,
+        upper_tick_index,
+        index,
+    )?;
+
+    let seeds = &[STAKER_SEED.as_bytes(), &[nonce]];
+    let signer = &[&seeds[..]];
+
+    let remaining_accounts = ctx.remaining_accounts;
+    require!(!remaining_accounts.is_empty(), InvalidIncentive);
+
+    let mut offset = 0;
+    while offset < remaining_accounts.len() {
+        require!(offset + 2 <= remaining_accounts.len(), InvalidStake);
+        let incentive_info = &remaining_accounts[offset];
+        let user_stake_info = &remaining_accounts[offset + 1];
+
+        let incentive_loader = AccountLoader::<Incentive>::try_from(incentive_info)?;
+        let user_stake_loader = AccountLoader::<UserStake>::try_from(user_stake_info)?;
+
+        let (close_stake, locked) = {
+            let mut incentive = incentive_loader.load_mut()?;
+            let mut user_stake = user_stake_loader.load_mut()?;
+            let position = ctx.accounts.position.load()?;
+            require!(
+                user_stake.incentive == *incentive_info.key,
+                InvalidIncentive
+            );
+            require!(
+                user_stake.position == ctx.accounts.position.key(),
+                InvalidStake
+            );
+            require!(
+                { incentive.pool } == { position.pool },
+                DifferentIncentivePool
+            );
+
+            let token_accounts_end = offset
+                + 2
+                + incentive.reward_count as usize * 2
+                + incentive.vesting.is_enabled() as usize;
+            require!(
+                token_accounts_end <= remaining_accounts.len(),
+                InvalidTokenAccount
+            );
+            claim_rewards(
+                &mut incentive,
+                &mut user_stake,
+                &position,
+                &remaining_accounts[offset + 2..token_accounts_end],
+                &ctx.accounts.token_program,
+                &ctx.accounts.staker_authority,
+                signer,
+            )?;
+            offset = token_accounts_end;
+
+            let close_stake = Seconds::now() > { incentive.end_time };
+            if close_stake {
+                require!(incentive.num_of_stakes > 0, NoStakes);
+                incentive.num_of_stakes -= 1;
+            }
+            (close_stake, user_stake.locked)
+        };
+
+        if close_stake {
+            close(
+                user_stake_info.clone(),
+                ctx.accounts.owner.to_account_info(),
+            )?;
+            if locked {
+                invariant::cpi::unlock_position(ctx.accounts.lock_position().with_signer(signer))?;
+            }
+        }
+    }
+
+    Ok(())
+}
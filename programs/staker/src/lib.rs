@@ -2,6 +2,7 @@ mod decimals;
 mod errors;
 mod instructions;
 mod math;
+pub mod return_data;
 mod structs;
 mod uint;
 mod util;
@@ -11,6 +12,7 @@ use anchor_lang::prelude::*;
 use decimals::*;
 use errors::*;
 use instructions::*;
+use structs::{TickBand, Vesting};
 
 declare_id!("MJ6WF1tpEJ7Gk8ULqejDJapRfqBwBEp1dH5QvAgYxu9");
 
@@ -25,22 +27,89 @@ pub mod staker {
         reward: TokenAmount,
         start_time: Seconds,
         end_time: Seconds,
+        band: Option<TickBand>,
+        vesting: Option<Vesting>,
     ) -> ProgramResult {
-        instructions::create_incentive::handler(ctx, nonce, reward, start_time, end_time)
+        instructions::create_incentive::handler(
+            ctx, nonce, reward, start_time, end_time, band, vesting,
+        )
     }
 
-    pub fn stake(ctx: Context<CreateUserStake>, _index: i32) -> ProgramResult {
-        instructions::stake::handler(ctx)
+    pub fn add_reward_token(
+        ctx: Context<AddRewardToken>,
+        nonce: u8,
+        reward: TokenAmount,
+    ) -> ProgramResult {
+        instructions::add_reward_token::handler(ctx, nonce, reward)
+    }
+
+    pub fn migrate_incentive(ctx: Context<MigrateIncentive>) -> ProgramResult {
+        instructions::migrate_incentive::handler(ctx)
     }
 
-    pub fn withdraw(ctx: Context<Withdraw>, _index: i32, nonce: u8) -> ProgramResult {
-        instructions::withdraw::handler(ctx, _index, nonce)
+    pub fn add_incentive_reward(
+        ctx: Context<AddIncentiveReward>,
+        reward: TokenAmount,
+    ) -> ProgramResult {
+        instructions::add_incentive_reward::handler(ctx, reward)
     }
 
-    pub fn end_incentive(ctx: Context<ReturnFounds>, nonce: u8) -> ProgramResult {
+    pub fn extend_incentive<'info>(
+        ctx: Context<'_, '_, '_, 'info, ExtendIncentive<'info>>,
+        end_time: Seconds,
+    ) -> ProgramResult {
+        instructions::extend_incentive::handler(ctx, end_time)
+    }
+
+    pub fn stake(ctx: Context<CreateUserStake>, index: i32) -> ProgramResult {
+        instructions::stake::handler(ctx, index)
+    }
+
+    pub fn withdraw<'info>(
+        ctx: Context<'_, '_, '_, 'info, Withdraw<'info>>,
+        index: i32,
+        nonce: u8,
+    ) -> ProgramResult {
+        instructions::withdraw::handler(ctx, index, nonce)
+    }
+
+    pub fn withdraw_many<'info>(
+        ctx: Context<'_, '_, '_, 'info, WithdrawMany<'info>>,
+        index: i32,
+        nonce: u8,
+    ) -> ProgramResult {
+        instructions::withdraw_many::handler(ctx, index, nonce)
+    }
+
+    pub fn end_incentive<'info>(
+        ctx: Context<'_, '_, '_, 'info, ReturnFounds<'info>>,
+        nonce: u8,
+    ) -> ProgramResult {
         instructions::end_incentive::handler(ctx, nonce)
     }
 
+    pub fn create_vesting_record(ctx: Context<CreateVestingRecord>) -> ProgramResult {
+        instructions::create_vesting_record::handler(ctx)
+    }
+
+    pub fn release_vested<'info>(
+        ctx: Context<'_, '_, '_, 'info, ReleaseVested<'info>>,
+        nonce: u8,
+    ) -> ProgramResult {
+        instructions::release_vested::handler(ctx, nonce)
+    }
+
+    pub fn cancel_incentive<'info>(
+        ctx: Context<'_, '_, '_, 'info, CancelIncentive<'info>>,
+        nonce: u8,
+    ) -> ProgramResult {
+        instructions::cancel_incentive::handler(ctx, nonce)
+    }
+
+    pub fn get_pending_reward(ctx: Context<GetPendingReward>) -> ProgramResult {
+        instructions::get_pending_reward::handler(ctx)
+    }
+
     pub fn remove_stake(ctx: Context<RemoveStake>) -> ProgramResult {
         instructions::remove_stake::handler(ctx)
     }
@@ -48,4 +117,8 @@ pub mod staker {
     pub fn close_stake_by_owner(ctx: Context<CloseStakeByOwner>, _index: i32) -> ProgramResult {
         instructions::close_stake_by_owner::handler(ctx, _index)
     }
+
+    pub fn migrate_stake(ctx: Context<MigrateStake>) -> ProgramResult {
+        instructions::migrate_stake::handler(ctx)
+    }
 }
@@ -1,7 +1,10 @@
 use crate::decimals::*;
+use crate::structs::{Incentive, UserStake};
 use crate::ErrorCode;
 use crate::Result;
+use invariant::structs::{Pool, Tick};
 use std::cmp;
+use std::convert::TryFrom;
 
 pub fn calculate_reward(
     total_reward_unclaimed: TokenAmount,
@@ -29,10 +32,100 @@ pub fn calculate_reward(
     Ok((seconds_inside, result))
 }
 
+// reward keeping the reward per unclaimed second when end time is moved, rounded up in favor of stakers
+pub fn calculate_extension_reward(
+    total_reward_unclaimed: TokenAmount,
+    total_seconds_claimed: Seconds,
+    start_time: Seconds,
+    end_time: Seconds,
+    new_end_time: Seconds,
+) -> Result<TokenAmount> {
+    let total_seconds_unclaimed = (end_time - start_time - total_seconds_claimed).get() as u128;
+    let extension = (new_end_time - end_time).get() as u128;
+
+    let reward = (total_reward_unclaimed.get() as u128 * extension + total_seconds_unclaimed - 1)
+        / total_seconds_unclaimed;
+    match u64::try_from(reward) {
+        Ok(reward) => Ok(TokenAmount::new(reward)),
+        Err(_) => Err(ErrorCode::ExtensionRewardOverflow.into()),
+    }
+}
+
+// same as in invariant, but on a copy of the pool, so the position doesn't have to be updated first
+pub fn calculate_seconds_per_liquidity_inside(
+    lower_tick: &Tick,
+    upper_tick: &Tick,
+    pool: &Pool,
+    current_time: Seconds,
+) -> SecondsPerLiquidity {
+    let mut pool = *pool;
+    if !{ pool.liquidity }.is_zero() {
+        pool.update_seconds_per_liquidity_global(current_time.get());
+    }
+
+    let global = { pool.seconds_per_liquidity_global }.v;
+    let current_tick_index = pool.current_tick_index;
+    let (lower_tick_index, lower_outside) = (
+        lower_tick.index,
+        { lower_tick.seconds_per_liquidity_outside }.v,
+    );
+    let (upper_tick_index, upper_outside) = (
+        upper_tick.index,
+        { upper_tick.seconds_per_liquidity_outside }.v,
+    );
+
+    let below = match current_tick_index >= lower_tick_index {
+        true => lower_outside,
+        false => global.wrapping_sub(lower_outside),
+    };
+    let above = match current_tick_index < upper_tick_index {
+        true => upper_outside,
+        false => global.wrapping_sub(upper_outside),
+    };
+
+    SecondsPerLiquidity::new(global.wrapping_sub(below).wrapping_sub(above))
+}
+
+// rewards withdraw would pay out now, in order of incentive reward tokens
+pub fn calculate_pending_reward(
+    incentive: &Incentive,
+    user_stake: &UserStake,
+    pool: &Pool,
+    lower_tick: &Tick,
+    upper_tick: &Tick,
+    current_time: Seconds,
+) -> Result<Vec<TokenAmount>> {
+    let rewards = incentive.rewards();
+    if current_time <= { incentive.start_time } || current_time > { incentive.end_claim_time } {
+        return Ok(vec![TokenAmount::new(0); rewards.len()]);
+    }
+
+    let seconds_per_liquidity_inside =
+        calculate_seconds_per_liquidity_inside(lower_tick, upper_tick, pool, current_time);
+
+    rewards
+        .iter()
+        .map(|reward| {
+            let (_, reward) = calculate_reward(
+                reward.total_reward_unclaimed,
+                incentive.total_seconds_claimed,
+                incentive.start_time,
+                incentive.end_time,
+                user_stake.liquidity,
+                user_stake.seconds_per_liquidity_initial,
+                seconds_per_liquidity_inside,
+                current_time,
+            )?;
+            Ok(reward)
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use anchor_lang::prelude::Pubkey;
 
     #[test]
     fn test_calculate_reward_1() {
@@ -235,6 +328,86 @@ mod tests {
         assert_eq!(result, TokenAmount::new(0));
         assert_eq!(seconds_inside, Seconds::new(6));
     }
+    #[test]
+    fn test_calculate_reward_extended() {
+        // same stake as in test 3 gets the same reward after doubling the duration with the extension reward
+        let extension_reward = calculate_extension_reward(
+            TokenAmount::new(1000),
+            Seconds::new(0),
+            Seconds::new(100),
+            Seconds::new(200),
+            Seconds::new(300),
+        )
+        .unwrap();
+        assert_eq!(extension_reward, TokenAmount::new(1000));
+
+        let (seconds_inside, result) = calculate_reward(
+            TokenAmount::new(1000) + extension_reward,
+            Seconds::new(0),
+            Seconds::new(100),
+            Seconds::new(300),
+            Liquidity::from_integer(10),
+            SecondsPerLiquidity::new(0),
+            SecondsPerLiquidity::from_integer(2),
+            Seconds::new(120),
+        )
+        .unwrap();
+
+        assert_eq!(result, TokenAmount::new(200));
+        assert_eq!(seconds_inside, Seconds::new(20));
+    }
+
+    #[test]
+    fn test_calculate_extension_reward() {
+        // claimed seconds are excluded from the rate
+        {
+            let result = calculate_extension_reward(
+                TokenAmount::new(900),
+                Seconds::new(10),
+                Seconds::new(100),
+                Seconds::new(200),
+                Seconds::new(230),
+            )
+            .unwrap();
+            assert_eq!(result, TokenAmount::new(300));
+        }
+        // rounded up
+        {
+            let result = calculate_extension_reward(
+                TokenAmount::new(1000),
+                Seconds::new(0),
+                Seconds::new(0),
+                Seconds::new(300),
+                Seconds::new(301),
+            )
+            .unwrap();
+            assert_eq!(result, TokenAmount::new(4));
+        }
+        // nothing left to distribute
+        {
+            let result = calculate_extension_reward(
+                TokenAmount::new(0),
+                Seconds::new(0),
+                Seconds::new(0),
+                Seconds::new(300),
+                Seconds::new(600),
+            )
+            .unwrap();
+            assert_eq!(result, TokenAmount::new(0));
+        }
+        // overflow
+        {
+            let result = calculate_extension_reward(
+                TokenAmount::new(u64::MAX),
+                Seconds::new(0),
+                Seconds::new(0),
+                Seconds::new(1),
+                Seconds::new(3),
+            );
+            assert!(result.is_err());
+        }
+    }
+
     // test underflow
     #[test]
     fn test_calculate_reward_12() {
@@ -273,6 +446,93 @@ mod tests {
         assert_eq!(seconds_inside, Seconds::new(0));
     }
 
+    #[test]
+    fn test_calculate_seconds_per_liquidity_inside() {
+        let mut pool = Pool::default();
+        pool.liquidity.v = Liquidity::from_integer(1).v;
+        pool.last_timestamp = 100;
+        let mut lower_tick = Tick::default();
+        lower_tick.index = -10;
+        let mut upper_tick = Tick::default();
+        upper_tick.index = 10;
+
+        // price inside the range
+        {
+            let result = calculate_seconds_per_liquidity_inside(
+                &lower_tick,
+                &upper_tick,
+                &pool,
+                Seconds::new(150),
+            );
+            assert_eq!(result, SecondsPerLiquidity::from_integer(50));
+            // pool is not modified
+            assert_eq!({ pool.last_timestamp }, 100);
+        }
+        // price below the range
+        {
+            pool.current_tick_index = -20;
+            lower_tick.seconds_per_liquidity_outside.v = SecondsPerLiquidity::from_integer(20).v;
+            let result = calculate_seconds_per_liquidity_inside(
+                &lower_tick,
+                &upper_tick,
+                &pool,
+                Seconds::new(150),
+            );
+            assert_eq!(result, SecondsPerLiquidity::from_integer(20));
+        }
+    }
+
+    #[test]
+    fn test_calculate_pending_reward() {
+        let mut pool = Pool::default();
+        pool.liquidity.v = Liquidity::from_integer(1).v;
+        pool.last_timestamp = 100;
+        let mut lower_tick = Tick::default();
+        lower_tick.index = -10;
+        let mut upper_tick = Tick::default();
+        upper_tick.index = 10;
+
+        let mut incentive = Incentive {
+            start_time: Seconds::new(100),
+            end_time: Seconds::new(200),
+            end_claim_time: Seconds::new(300),
+            ..Default::default()
+        };
+        incentive
+            .add_reward(Pubkey::new_unique(), TokenAmount::new(1000))
+            .unwrap();
+        incentive
+            .add_reward(Pubkey::new_unique(), TokenAmount::new(10))
+            .unwrap();
+        let user_stake = UserStake {
+            liquidity: Liquidity::from_integer(1),
+            seconds_per_liquidity_initial: SecondsPerLiquidity::new(0),
+            ..Default::default()
+        };
+
+        let pending = |current_time: u64| {
+            calculate_pending_reward(
+                &incentive,
+                &user_stake,
+                &pool,
+                &lower_tick,
+                &upper_tick,
+                Seconds::new(current_time),
+            )
+            .unwrap()
+        };
+
+        // half of the duration in range
+        assert_eq!(
+            pending(150),
+            vec![TokenAmount::new(500), TokenAmount::new(5)]
+        );
+        // not started
+        assert_eq!(pending(100), vec![TokenAmount::new(0); 2]);
+        // after claim time
+        assert_eq!(pending(301), vec![TokenAmount::new(0); 2]);
+    }
+
     //data from real case
     #[test]
     fn test_calculate_reward_14() {
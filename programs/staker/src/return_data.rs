@@ -0,0 +1,13 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::set_return_data;
+
+// Results readable by simulating a transaction or by programs calling staker through CPI (get_return_data)
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
+pub struct PendingRewardReturnData {
+    pub rewards: Vec<u64>, // in order of incentive reward tokens
+}
+
+pub fn set<T: AnchorSerialize>(data: &T) {
+    set_return_data(&data.try_to_vec().unwrap());
+}
@@ -1,10 +1,75 @@
 use crate::decimals::{Seconds, TokenAmount};
+use crate::structs::Vesting;
+use crate::ErrorCode::*;
 use anchor_lang::prelude::*;
+use invariant::structs::MAX_TICK;
+
+pub const MAX_REWARD_TOKENS: usize = 4; // UPDATE IN ARRAYS AS WELL!
 
 #[account(zero_copy)]
 #[repr(packed)]
 #[derive(PartialEq, Default, Debug)]
 pub struct Incentive {
+    pub founder: Pubkey,
+    pub rewards: [IncentiveReward; 4],
+    pub reward_count: u8,
+    pub total_seconds_claimed: Seconds,
+    pub start_time: Seconds,
+    pub end_time: Seconds,
+    pub end_claim_time: Seconds,
+    pub num_of_stakes: u64,
+    pub pool: Pubkey,
+    pub band: TickBand,
+    pub vesting: Vesting,
+    pub nonce: u8,
+}
+
+#[zero_copy]
+#[repr(packed)]
+#[derive(PartialEq, Default, Debug)]
+pub struct IncentiveReward {
+    pub token_account: Pubkey,
+    pub total_reward_unclaimed: TokenAmount,
+}
+
+// Only positions inside the band and not wider than max_width (0 for no limit) can be staked
+#[zero_copy]
+#[repr(packed)]
+#[derive(PartialEq, Default, Debug, AnchorSerialize, AnchorDeserialize)]
+pub struct TickBand {
+    pub lower_tick_index: i32,
+    pub upper_tick_index: i32,
+    pub max_width: u32,
+}
+
+impl TickBand {
+    pub const FULL_RANGE: TickBand = TickBand {
+        lower_tick_index: -MAX_TICK,
+        upper_tick_index: MAX_TICK,
+        max_width: 0,
+    };
+
+    pub fn is_valid(&self) -> bool {
+        let (lower, upper) = (self.lower_tick_index, self.upper_tick_index);
+        -MAX_TICK <= lower && lower < upper && upper <= MAX_TICK
+    }
+
+    pub fn contains(&self, lower_tick_index: i32, upper_tick_index: i32) -> bool {
+        let (lower, upper, max_width) = (
+            self.lower_tick_index,
+            self.upper_tick_index,
+            self.max_width as i64,
+        );
+        let width = upper_tick_index as i64 - lower_tick_index as i64;
+        lower <= lower_tick_index
+            && upper_tick_index <= upper
+            && (max_width == 0 || width <= max_width)
+    }
+}
+
+// Layout of incentives created before multiple reward tokens, converted by migrate_incentive
+#[derive(PartialEq, Default, Debug, AnchorSerialize, AnchorDeserialize)]
+pub struct LegacyIncentive {
     pub founder: Pubkey,
     pub token_account: Pubkey,
     pub total_reward_unclaimed: TokenAmount,
@@ -16,3 +81,169 @@ pub struct Incentive {
     pub pool: Pubkey,
     pub nonce: u8,
 }
+
+impl LegacyIncentive {
+    pub const LEN: usize = 8 + 32 + 32 + 8 + 8 + 8 + 8 + 8 + 8 + 32 + 1;
+
+    pub fn migrate(&self) -> Incentive {
+        let mut rewards: [IncentiveReward; MAX_REWARD_TOKENS] = Default::default();
+        rewards[0] = IncentiveReward {
+            token_account: self.token_account,
+            total_reward_unclaimed: self.total_reward_unclaimed,
+        };
+
+        Incentive {
+            founder: self.founder,
+            rewards,
+            reward_count: 1,
+            total_seconds_claimed: self.total_seconds_claimed,
+            start_time: self.start_time,
+            end_time: self.end_time,
+            end_claim_time: self.end_claim_time,
+            num_of_stakes: self.num_of_stakes,
+            pool: self.pool,
+            band: TickBand::FULL_RANGE,
+            vesting: Vesting::default(),
+            nonce: self.nonce,
+        }
+    }
+}
+
+impl Incentive {
+    pub fn rewards(&self) -> &[IncentiveReward] {
+        &self.rewards[..self.reward_count as usize]
+    }
+
+    pub fn add_reward(&mut self, token_account: Pubkey, reward: TokenAmount) -> Result<()> {
+        let index = self.reward_count as usize;
+        require!(index < MAX_REWARD_TOKENS, TooManyRewardTokens);
+
+        self.rewards[index] = IncentiveReward {
+            token_account,
+            total_reward_unclaimed: reward,
+        };
+        self.reward_count += 1;
+        Ok(())
+    }
+
+    pub fn has_unclaimed_reward(&self) -> bool {
+        self.rewards()
+            .iter()
+            .any(|reward| !{ reward.total_reward_unclaimed }.is_zero())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_add_reward() {
+        let mut incentive = Incentive::default();
+        assert!(!incentive.has_unclaimed_reward());
+
+        let token_account = Pubkey::new_unique();
+        incentive
+            .add_reward(token_account, TokenAmount::new(0))
+            .unwrap();
+        assert_eq!(incentive.rewards().len(), 1);
+        assert_eq!({ incentive.rewards[0].token_account }, token_account);
+        assert!(!incentive.has_unclaimed_reward());
+
+        for _ in 1..MAX_REWARD_TOKENS {
+            incentive
+                .add_reward(Pubkey::new_unique(), TokenAmount::new(100))
+                .unwrap();
+        }
+        assert_eq!(incentive.rewards().len(), MAX_REWARD_TOKENS);
+        assert!(incentive.has_unclaimed_reward());
+
+        // no space left
+        assert!(incentive
+            .add_reward(Pubkey::new_unique(), TokenAmount::new(100))
+            .is_err());
+    }
+
+    #[test]
+    fn test_migrate_legacy_incentive() {
+        let legacy = LegacyIncentive {
+            founder: Pubkey::new_unique(),
+            token_account: Pubkey::new_unique(),
+            total_reward_unclaimed: TokenAmount::new(1000),
+            total_seconds_claimed: Seconds::new(20),
+            start_time: Seconds::new(100),
+            end_time: Seconds::new(200),
+            end_claim_time: Seconds::new(300),
+            num_of_stakes: 3,
+            pool: Pubkey::new_unique(),
+            nonce: 254,
+        };
+        let data = legacy.try_to_vec().unwrap();
+        assert_eq!(data.len() + 8, LegacyIncentive::LEN);
+        assert_eq!(LegacyIncentive::try_from_slice(&data).unwrap(), legacy);
+
+        let incentive = legacy.migrate();
+        assert_eq!(incentive.rewards().len(), 1);
+        assert_eq!({ incentive.rewards[0].token_account }, legacy.token_account);
+        assert_eq!(
+            { incentive.rewards[0].total_reward_unclaimed },
+            TokenAmount::new(1000)
+        );
+        assert_eq!({ incentive.total_seconds_claimed }, Seconds::new(20));
+        assert_eq!({ incentive.end_claim_time }, Seconds::new(300));
+        assert_eq!({ incentive.num_of_stakes }, 3);
+        assert_eq!({ incentive.pool }, legacy.pool);
+        assert_eq!({ incentive.band }, TickBand::FULL_RANGE);
+        assert!(!incentive.vesting.is_enabled());
+        assert_eq!({ incentive.nonce }, 254);
+    }
+
+    #[test]
+    fn test_tick_band() {
+        // full range
+        {
+            let band = TickBand::FULL_RANGE;
+            assert!(band.is_valid());
+            assert!(band.contains(-MAX_TICK, MAX_TICK));
+            assert!(band.contains(-10, 10));
+        }
+        // band around a peg
+        {
+            let band = TickBand {
+                lower_tick_index: -100,
+                upper_tick_index: 100,
+                max_width: 0,
+            };
+            assert!(band.is_valid());
+            assert!(band.contains(-100, 100));
+            assert!(band.contains(-10, 20));
+            assert!(!band.contains(-110, 20));
+            assert!(!band.contains(-10, 110));
+            assert!(!band.contains(-200, -110));
+        }
+        // max width
+        {
+            let band = TickBand {
+                max_width: 20,
+                ..TickBand::FULL_RANGE
+            };
+            assert!(band.contains(-10, 10));
+            assert!(band.contains(1000, 1020));
+            assert!(!band.contains(-10, 11));
+        }
+        // invalid bands
+        {
+            let band = TickBand {
+                lower_tick_index: 10,
+                upper_tick_index: 10,
+                max_width: 0,
+            };
+            assert!(!band.is_valid());
+            let band = TickBand {
+                lower_tick_index: -MAX_TICK - 1,
+                ..TickBand::FULL_RANGE
+            };
+            assert!(!band.is_valid());
+        }
+    }
+}
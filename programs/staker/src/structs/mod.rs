@@ -1,5 +1,7 @@
 pub mod incentive;
 pub mod user_stake;
+pub mod vesting;
 
 pub use incentive::*;
 pub use user_stake::*;
+pub use vesting::*;
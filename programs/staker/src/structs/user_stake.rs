@@ -9,4 +9,11 @@ pub struct UserStake {
     pub seconds_per_liquidity_initial: SecondsPerLiquidity,
     pub liquidity: Liquidity,
     pub bump: u8,
+    pub locked: bool, // stakes created before locking was introduced never locked the position
+}
+
+impl UserStake {
+    // Size of stakes created before the locked flag, resized by migrate_stake
+    pub const LEGACY_LEN: usize =
+        8 + std::mem::size_of::<UserStake>() - std::mem::size_of::<bool>();
 }
@@ -0,0 +1,194 @@
+use crate::decimals::{Seconds, TokenAmount};
+use crate::structs::Incentive;
+use anchor_lang::prelude::*;
+
+// Rewards vest linearly over duration after the cliff, both counted from the end of the incentive
+#[zero_copy]
+#[repr(packed)]
+#[derive(PartialEq, Default, Debug, AnchorSerialize, AnchorDeserialize)]
+pub struct Vesting {
+    pub cliff: Seconds,
+    pub duration: Seconds,
+}
+
+impl Vesting {
+    pub fn is_enabled(&self) -> bool {
+        let (cliff, duration) = (self.cliff, self.duration);
+        !cliff.is_zero() || !duration.is_zero()
+    }
+
+    pub fn vested_amount(
+        &self,
+        total: TokenAmount,
+        start_time: Seconds,
+        current_time: Seconds,
+    ) -> TokenAmount {
+        let (cliff, duration) = (self.cliff, self.duration);
+        let cliff_end = start_time + cliff;
+        if current_time < cliff_end {
+            return TokenAmount::new(0);
+        }
+
+        let elapsed = (current_time - cliff_end).get();
+        if elapsed >= duration.get() {
+            return total;
+        }
+        TokenAmount::new((total.get() as u128 * elapsed as u128 / duration.get() as u128) as u64)
+    }
+}
+
+#[account(zero_copy)]
+#[repr(packed)]
+#[derive(PartialEq, Default, Debug)]
+pub struct VestingRecord {
+    pub incentive: Pubkey,
+    pub owner: Pubkey,
+    pub rewards: [VestedReward; 4],
+    pub reward_count: u8,
+    pub vesting: Vesting,
+    pub start_time: Seconds,
+    pub bump: u8,
+}
+
+#[zero_copy]
+#[repr(packed)]
+#[derive(PartialEq, Default, Debug)]
+pub struct VestedReward {
+    pub token_account: Pubkey,
+    pub credited: TokenAmount,
+    pub released: TokenAmount,
+}
+
+impl VestingRecord {
+    pub fn rewards(&self) -> &[VestedReward] {
+        &self.rewards[..self.reward_count as usize]
+    }
+
+    // reward tokens added to the incentive after the record was created are picked up here
+    pub fn credit(&mut self, incentive: &Incentive, rewards: &[TokenAmount]) {
+        self.vesting = incentive.vesting;
+        self.start_time = incentive.end_time;
+        self.reward_count = incentive.reward_count;
+
+        for (i, reward) in incentive.rewards().iter().enumerate() {
+            self.rewards[i].token_account = reward.token_account;
+            self.rewards[i].credited = self.rewards[i].credited + rewards[i];
+        }
+    }
+
+    // returns amounts vested since the last release and marks them as released
+    pub fn release(&mut self, current_time: Seconds) -> Vec<TokenAmount> {
+        let (vesting, start_time) = (self.vesting, self.start_time);
+        let reward_count = self.reward_count as usize;
+
+        self.rewards[..reward_count]
+            .iter_mut()
+            .map(|reward| {
+                let vested = vesting.vested_amount(reward.credited, start_time, current_time);
+                let released = reward.released;
+                if vested <= released {
+                    return TokenAmount::new(0);
+                }
+                reward.released = vested;
+                vested - released
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_vested_amount() {
+        let vesting = Vesting {
+            cliff: Seconds::new(100),
+            duration: Seconds::new(1000),
+        };
+        assert!(vesting.is_enabled());
+        assert!(!Vesting::default().is_enabled());
+
+        let total = TokenAmount::new(5000);
+        let start_time = Seconds::new(1000);
+        let vested = |current_time: u64| {
+            vesting.vested_amount(total, start_time, Seconds::new(current_time))
+        };
+
+        // before the end of the incentive
+        assert_eq!(vested(500), TokenAmount::new(0));
+        // during the cliff
+        assert_eq!(vested(1099), TokenAmount::new(0));
+        assert_eq!(vested(1100), TokenAmount::new(0));
+        // linear after the cliff
+        assert_eq!(vested(1101), TokenAmount::new(5));
+        assert_eq!(vested(1600), TokenAmount::new(2500));
+        // fully vested
+        assert_eq!(vested(2100), total);
+        assert_eq!(vested(10000), total);
+
+        // only cliff
+        let cliff_only = Vesting {
+            cliff: Seconds::new(100),
+            duration: Seconds::new(0),
+        };
+        assert_eq!(
+            cliff_only.vested_amount(total, start_time, Seconds::new(1099)),
+            TokenAmount::new(0)
+        );
+        assert_eq!(
+            cliff_only.vested_amount(total, start_time, Seconds::new(1100)),
+            total
+        );
+    }
+
+    #[test]
+    fn test_credit_and_release() {
+        let mut incentive = Incentive {
+            end_time: Seconds::new(1000),
+            vesting: Vesting {
+                cliff: Seconds::new(0),
+                duration: Seconds::new(100),
+            },
+            ..Default::default()
+        };
+        incentive
+            .add_reward(Pubkey::new_unique(), TokenAmount::new(1000))
+            .unwrap();
+
+        let mut record = VestingRecord::default();
+        record.credit(&incentive, &[TokenAmount::new(100)]);
+        assert_eq!(record.rewards().len(), 1);
+        assert_eq!({ record.rewards[0].token_account }, {
+            incentive.rewards[0].token_account
+        });
+
+        // nothing vested before the end
+        assert_eq!(record.release(Seconds::new(900)), vec![TokenAmount::new(0)]);
+        assert_eq!(
+            record.release(Seconds::new(1050)),
+            vec![TokenAmount::new(50)]
+        );
+        // already released part is not paid again
+        assert_eq!(
+            record.release(Seconds::new(1050)),
+            vec![TokenAmount::new(0)]
+        );
+
+        // a token added later is vested on the same schedule
+        incentive
+            .add_reward(Pubkey::new_unique(), TokenAmount::new(1000))
+            .unwrap();
+        record.credit(&incentive, &[TokenAmount::new(100), TokenAmount::new(10)]);
+        assert_eq!(
+            record.release(Seconds::new(1075)),
+            vec![TokenAmount::new(100), TokenAmount::new(7)]
+        );
+        assert_eq!(
+            record.release(Seconds::new(2000)),
+            vec![TokenAmount::new(50), TokenAmount::new(3)]
+        );
+        assert_eq!({ record.rewards[0].released }, TokenAmount::new(200));
+        assert_eq!({ record.rewards[1].released }, TokenAmount::new(10));
+    }
+}
@@ -6,10 +6,6 @@ use anchor_lang::prelude::*;
 
 pub const STAKER_SEED: &str = "staker";
 
-pub fn get_current_slot() -> u64 {
-    Clock::get().unwrap().slot
-}
-
 pub fn close<'info>(
     info: AccountInfo<'info>,
     sol_destination: AccountInfo<'info>,
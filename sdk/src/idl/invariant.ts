@@ -96,6 +96,11 @@ export type Invariant = {
           "isMut": false,
           "isSigner": false
         },
+        {
+          "name": "defaultProtocolFee",
+          "isMut": false,
+          "isSigner": false
+        },
         {
           "name": "tickmap",
           "isMut": true,
@@ -132,7 +137,12 @@ export type Invariant = {
           "isSigner": false
         },
         {
-          "name": "tokenProgram",
+          "name": "tokenXProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenYProgram",
           "isMut": false,
           "isSigner": false
         },
@@ -172,6 +182,16 @@ export type Invariant = {
           "isMut": true,
           "isSigner": false
         },
+        {
+          "name": "tokenX",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenY",
+          "isMut": false,
+          "isSigner": false
+        },
         {
           "name": "accountX",
           "isMut": true,
@@ -203,7 +223,12 @@ export type Invariant = {
           "isSigner": false
         },
         {
-          "name": "tokenProgram",
+          "name": "tokenXProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenYProgram",
           "isMut": false,
           "isSigner": false
         }
@@ -228,15 +253,20 @@ export type Invariant = {
       ]
     },
     {
-      "name": "initializeOracle",
+      "name": "swapWithCrossLimit",
       "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
         {
           "name": "pool",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "oracle",
+          "name": "tickmap",
           "isMut": true,
           "isSigner": false
         },
@@ -251,148 +281,84 @@ export type Invariant = {
           "isSigner": false
         },
         {
-          "name": "payer",
-          "isMut": false,
-          "isSigner": true
-        },
-        {
-          "name": "rent",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
-        }
-      ],
-      "args": []
-    },
-    {
-      "name": "createTick",
-      "accounts": [
-        {
-          "name": "tick",
+          "name": "accountX",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "pool",
-          "isMut": false,
+          "name": "accountY",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "tickmap",
+          "name": "reserveX",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "payer",
+          "name": "reserveY",
           "isMut": true,
-          "isSigner": true
+          "isSigner": false
         },
         {
-          "name": "tokenX",
+          "name": "owner",
           "isMut": false,
-          "isSigner": false
+          "isSigner": true
         },
         {
-          "name": "tokenY",
+          "name": "programAuthority",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "rent",
+          "name": "tokenXProgram",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "systemProgram",
+          "name": "tokenYProgram",
           "isMut": false,
           "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "index",
-          "type": "i32"
-        }
-      ]
-    },
-    {
-      "name": "createPositionList",
-      "accounts": [
-        {
-          "name": "positionList",
-          "isMut": true,
-          "isSigner": false
+          "name": "xToY",
+          "type": "bool"
         },
         {
-          "name": "owner",
-          "isMut": false,
-          "isSigner": false
+          "name": "amount",
+          "type": "u64"
         },
         {
-          "name": "signer",
-          "isMut": true,
-          "isSigner": true
+          "name": "byAmountIn",
+          "type": "bool"
         },
         {
-          "name": "rent",
-          "isMut": false,
-          "isSigner": false
+          "name": "sqrtPriceLimit",
+          "type": "u128"
         },
         {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
+          "name": "crossLimit",
+          "type": {
+            "defined": "CrossLimit"
+          }
         }
-      ],
-      "args": []
+      ]
     },
     {
-      "name": "createPosition",
+      "name": "swapTo",
       "accounts": [
         {
           "name": "state",
           "isMut": false,
           "isSigner": false
         },
-        {
-          "name": "position",
-          "isMut": true,
-          "isSigner": false
-        },
         {
           "name": "pool",
           "isMut": true,
           "isSigner": false
         },
-        {
-          "name": "positionList",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "payer",
-          "isMut": true,
-          "isSigner": true
-        },
-        {
-          "name": "owner",
-          "isMut": false,
-          "isSigner": true
-        },
-        {
-          "name": "lowerTick",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "upperTick",
-          "isMut": true,
-          "isSigner": false
-        },
         {
           "name": "tickmap",
           "isMut": true,
@@ -429,57 +395,51 @@ export type Invariant = {
           "isSigner": false
         },
         {
-          "name": "programAuthority",
+          "name": "owner",
           "isMut": false,
-          "isSigner": false
+          "isSigner": true
         },
         {
-          "name": "tokenProgram",
+          "name": "programAuthority",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "rent",
+          "name": "tokenXProgram",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "systemProgram",
+          "name": "tokenYProgram",
           "isMut": false,
           "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "lowerTickIndex",
-          "type": "i32"
+          "name": "xToY",
+          "type": "bool"
         },
         {
-          "name": "upperTickIndex",
-          "type": "i32"
+          "name": "amount",
+          "type": "u64"
         },
         {
-          "name": "liquidityDelta",
-          "type": {
-            "defined": "Liquidity"
-          }
+          "name": "byAmountIn",
+          "type": "bool"
         },
         {
-          "name": "slippageLimitLower",
-          "type": {
-            "defined": "Price"
-          }
+          "name": "sqrtPriceLimit",
+          "type": "u128"
         },
         {
-          "name": "slippageLimitUpper",
-          "type": {
-            "defined": "Price"
-          }
+          "name": "recipient",
+          "type": "publicKey"
         }
       ]
     },
     {
-      "name": "removePosition",
+      "name": "flashLoan",
       "accounts": [
         {
           "name": "state",
@@ -487,45 +447,90 @@ export type Invariant = {
           "isSigner": false
         },
         {
-          "name": "removedPosition",
-          "isMut": true,
+          "name": "pool",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "positionList",
-          "isMut": true,
+          "name": "tokenX",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "lastPosition",
-          "isMut": true,
+          "name": "tokenY",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "pool",
+          "name": "accountX",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "tickmap",
+          "name": "accountY",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "lowerTick",
+          "name": "reserveX",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "upperTick",
+          "name": "reserveY",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "owner",
-          "isMut": true,
+          "name": "borrower",
+          "isMut": false,
           "isSigner": true
         },
+        {
+          "name": "programAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenXProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenYProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "instructions",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "amountX",
+          "type": "u64"
+        },
+        {
+          "name": "amountY",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "flashRepay",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
         {
           "name": "tokenX",
           "isMut": false,
@@ -556,67 +561,109 @@ export type Invariant = {
           "isMut": true,
           "isSigner": false
         },
+        {
+          "name": "borrower",
+          "isMut": false,
+          "isSigner": true
+        },
         {
           "name": "programAuthority",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "tokenProgram",
+          "name": "tokenXProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenYProgram",
           "isMut": false,
           "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "index",
-          "type": "u32"
-        },
-        {
-          "name": "lowerTickIndex",
-          "type": "i32"
+          "name": "amountX",
+          "type": "u64"
         },
         {
-          "name": "upperTickIndex",
-          "type": "i32"
+          "name": "amountY",
+          "type": "u64"
         }
       ]
     },
     {
-      "name": "transferPositionOwnership",
+      "name": "initializeOracle",
       "accounts": [
         {
-          "name": "ownerList",
+          "name": "pool",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "recipientList",
+          "name": "oracle",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "newPosition",
-          "isMut": true,
+          "name": "tokenX",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "removedPosition",
+          "name": "tokenY",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "createTick",
+      "accounts": [
+        {
+          "name": "tick",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "lastPosition",
+          "name": "pool",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tickmap",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "owner",
+          "name": "payer",
           "isMut": true,
           "isSigner": true
         },
         {
-          "name": "recipient",
+          "name": "tokenX",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenY",
           "isMut": false,
           "isSigner": false
         },
@@ -634,21 +681,47 @@ export type Invariant = {
       "args": [
         {
           "name": "index",
-          "type": "u32"
+          "type": "i32"
         }
       ]
     },
     {
-      "name": "claimFee",
+      "name": "createPositionList",
       "accounts": [
         {
-          "name": "state",
+          "name": "positionList",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "pool",
+          "name": "signer",
           "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "createPosition",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
           "isSigner": false
         },
         {
@@ -657,20 +730,40 @@ export type Invariant = {
           "isSigner": false
         },
         {
-          "name": "lowerTick",
+          "name": "pool",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "upperTick",
+          "name": "positionList",
           "isMut": true,
           "isSigner": false
         },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
         {
           "name": "owner",
           "isMut": false,
           "isSigner": true
         },
+        {
+          "name": "lowerTick",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "upperTick",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tickmap",
+          "isMut": true,
+          "isSigner": false
+        },
         {
           "name": "tokenX",
           "isMut": false,
@@ -707,16 +800,27 @@ export type Invariant = {
           "isSigner": false
         },
         {
-          "name": "tokenProgram",
+          "name": "tokenXProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenYProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
           "isMut": false,
           "isSigner": false
         }
       ],
       "args": [
-        {
-          "name": "index",
-          "type": "u32"
-        },
         {
           "name": "lowerTickIndex",
           "type": "i32"
@@ -724,29 +828,72 @@ export type Invariant = {
         {
           "name": "upperTickIndex",
           "type": "i32"
+        },
+        {
+          "name": "liquidityDelta",
+          "type": {
+            "defined": "Liquidity"
+          }
+        },
+        {
+          "name": "slippageLimitLower",
+          "type": {
+            "defined": "Price"
+          }
+        },
+        {
+          "name": "slippageLimitUpper",
+          "type": {
+            "defined": "Price"
+          }
         }
       ]
     },
     {
-      "name": "updateSecondsPerLiquidity",
+      "name": "createPositionByAmounts",
       "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "position",
+          "isMut": true,
+          "isSigner": false
+        },
         {
           "name": "pool",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "lowerTick",
+          "name": "positionList",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "owner",
           "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "lowerTick",
+          "isMut": true,
           "isSigner": false
         },
         {
           "name": "upperTick",
-          "isMut": false,
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "position",
+          "name": "tickmap",
           "isMut": true,
           "isSigner": false
         },
@@ -761,14 +908,39 @@ export type Invariant = {
           "isSigner": false
         },
         {
-          "name": "owner",
-          "isMut": false,
+          "name": "accountX",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "signer",
+          "name": "accountY",
           "isMut": true,
-          "isSigner": true
+          "isSigner": false
+        },
+        {
+          "name": "reserveX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "programAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenXProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenYProgram",
+          "isMut": false,
+          "isSigner": false
         },
         {
           "name": "rent",
@@ -791,24 +963,63 @@ export type Invariant = {
           "type": "i32"
         },
         {
-          "name": "index",
-          "type": "i32"
+          "name": "maxAmountX",
+          "type": "u64"
+        },
+        {
+          "name": "maxAmountY",
+          "type": "u64"
         }
       ]
     },
     {
-      "name": "withdrawProtocolFee",
+      "name": "createPositionSingleSided",
       "accounts": [
         {
           "name": "state",
           "isMut": false,
           "isSigner": false
         },
+        {
+          "name": "position",
+          "isMut": true,
+          "isSigner": false
+        },
         {
           "name": "pool",
           "isMut": true,
           "isSigner": false
         },
+        {
+          "name": "positionList",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "lowerTick",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "upperTick",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tickmap",
+          "isMut": true,
+          "isSigner": false
+        },
         {
           "name": "tokenX",
           "isMut": false,
@@ -840,36 +1051,110 @@ export type Invariant = {
           "isSigner": false
         },
         {
-          "name": "authority",
+          "name": "programAuthority",
           "isMut": false,
-          "isSigner": true
+          "isSigner": false
         },
         {
-          "name": "programAuthority",
+          "name": "tokenXProgram",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "tokenProgram",
+          "name": "tokenYProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
           "isMut": false,
           "isSigner": false
         }
       ],
-      "args": []
+      "args": [
+        {
+          "name": "lowerTickIndex",
+          "type": "i32"
+        },
+        {
+          "name": "upperTickIndex",
+          "type": "i32"
+        },
+        {
+          "name": "amount",
+          "type": "u64"
+        },
+        {
+          "name": "xToY",
+          "type": "bool"
+        },
+        {
+          "name": "slippageLimitLower",
+          "type": {
+            "defined": "Price"
+          }
+        },
+        {
+          "name": "slippageLimitUpper",
+          "type": {
+            "defined": "Price"
+          }
+        }
+      ]
     },
     {
-      "name": "changeProtocolFee",
+      "name": "removePosition",
       "accounts": [
         {
           "name": "state",
           "isMut": false,
           "isSigner": false
         },
+        {
+          "name": "removedPosition",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "positionList",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lastPosition",
+          "isMut": true,
+          "isSigner": false
+        },
         {
           "name": "pool",
           "isMut": true,
           "isSigner": false
         },
+        {
+          "name": "tickmap",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lowerTick",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "upperTick",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
         {
           "name": "tokenX",
           "isMut": false,
@@ -881,27 +1166,114 @@ export type Invariant = {
           "isSigner": false
         },
         {
-          "name": "admin",
+          "name": "accountX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "accountY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "programAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenXProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenYProgram",
           "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "index",
+          "type": "u32"
+        },
+        {
+          "name": "lowerTickIndex",
+          "type": "i32"
+        },
+        {
+          "name": "upperTickIndex",
+          "type": "i32"
+        }
+      ]
+    },
+    {
+      "name": "transferPositionOwnership",
+      "accounts": [
+        {
+          "name": "ownerList",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "recipientList",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "newPosition",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "removedPosition",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lastPosition",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": true,
           "isSigner": true
         },
         {
-          "name": "programAuthority",
+          "name": "recipient",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
           "isMut": false,
           "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "protocolFee",
-          "type": {
-            "defined": "FixedPoint"
-          }
+          "name": "index",
+          "type": "u32"
         }
       ]
     },
     {
-      "name": "changeFeeReceiver",
+      "name": "claimFee",
       "accounts": [
         {
           "name": "state",
@@ -913,6 +1285,26 @@ export type Invariant = {
           "isMut": true,
           "isSigner": false
         },
+        {
+          "name": "position",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lowerTick",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "upperTick",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
         {
           "name": "tokenX",
           "isMut": false,
@@ -924,28 +1316,848 @@ export type Invariant = {
           "isSigner": false
         },
         {
-          "name": "admin",
+          "name": "accountX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "accountY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "programAuthority",
           "isMut": false,
-          "isSigner": true
+          "isSigner": false
         },
         {
-          "name": "feeReceiver",
+          "name": "tokenXProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenYProgram",
           "isMut": false,
           "isSigner": false
         }
       ],
-      "args": []
-    }
-  ],
-  "accounts": [
+      "args": [
+        {
+          "name": "index",
+          "type": "u32"
+        },
+        {
+          "name": "lowerTickIndex",
+          "type": "i32"
+        },
+        {
+          "name": "upperTickIndex",
+          "type": "i32"
+        }
+      ]
+    },
     {
-      "name": "feeTier",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "fee",
-            "type": {
+      "name": "swapNative",
+      "accounts": [
+        {
+          "name": "wrappedSol",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "nativeMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "invariantProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "nativeAmount",
+          "type": "u64"
+        },
+        {
+          "name": "xToY",
+          "type": "bool"
+        },
+        {
+          "name": "amount",
+          "type": "u64"
+        },
+        {
+          "name": "byAmountIn",
+          "type": "bool"
+        },
+        {
+          "name": "sqrtPriceLimit",
+          "type": "u128"
+        }
+      ]
+    },
+    {
+      "name": "createPositionNative",
+      "accounts": [
+        {
+          "name": "wrappedSol",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "nativeMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "invariantProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "nativeAmount",
+          "type": "u64"
+        },
+        {
+          "name": "lowerTickIndex",
+          "type": "i32"
+        },
+        {
+          "name": "upperTickIndex",
+          "type": "i32"
+        },
+        {
+          "name": "liquidityDelta",
+          "type": {
+            "defined": "Liquidity"
+          }
+        },
+        {
+          "name": "slippageLimitLower",
+          "type": {
+            "defined": "Price"
+          }
+        },
+        {
+          "name": "slippageLimitUpper",
+          "type": {
+            "defined": "Price"
+          }
+        }
+      ]
+    },
+    {
+      "name": "removePositionNative",
+      "accounts": [
+        {
+          "name": "wrappedSol",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "nativeMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "invariantProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "index",
+          "type": "u32"
+        },
+        {
+          "name": "lowerTickIndex",
+          "type": "i32"
+        },
+        {
+          "name": "upperTickIndex",
+          "type": "i32"
+        }
+      ]
+    },
+    {
+      "name": "claimFeeNative",
+      "accounts": [
+        {
+          "name": "wrappedSol",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "nativeMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "invariantProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "index",
+          "type": "u32"
+        },
+        {
+          "name": "lowerTickIndex",
+          "type": "i32"
+        },
+        {
+          "name": "upperTickIndex",
+          "type": "i32"
+        }
+      ]
+    },
+    {
+      "name": "lockPosition",
+      "accounts": [
+        {
+          "name": "position",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "stakerAuthority",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "unlockPosition",
+      "accounts": [
+        {
+          "name": "position",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "stakerAuthority",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "migratePosition",
+      "accounts": [
+        {
+          "name": "position",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "updateSecondsPerLiquidity",
+      "accounts": [
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lowerTick",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "upperTick",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "position",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenX",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenY",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "signer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "lowerTickIndex",
+          "type": "i32"
+        },
+        {
+          "name": "upperTickIndex",
+          "type": "i32"
+        },
+        {
+          "name": "index",
+          "type": "i32"
+        }
+      ]
+    },
+    {
+      "name": "withdrawProtocolFee",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "feeDistribution",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenX",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenY",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "accountX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "accountY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "programAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenXProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenYProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "changeProtocolFee",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenX",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenY",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "programAuthority",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "protocolFee",
+          "type": {
+            "defined": "FixedPoint"
+          }
+        }
+      ]
+    },
+    {
+      "name": "createDefaultProtocolFee",
+      "accounts": [
+        {
+          "name": "defaultProtocolFee",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "protocolFee",
+          "type": {
+            "defined": "FixedPoint"
+          }
+        }
+      ]
+    },
+    {
+      "name": "changeDefaultProtocolFee",
+      "accounts": [
+        {
+          "name": "defaultProtocolFee",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "protocolFee",
+          "type": {
+            "defined": "FixedPoint"
+          }
+        }
+      ]
+    },
+    {
+      "name": "changeProtocolFeeBatch",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "protocolFee",
+          "type": {
+            "defined": "FixedPoint"
+          }
+        }
+      ]
+    },
+    {
+      "name": "changeFeeReceiver",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenX",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenY",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "feeReceiver",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "createFeeDistribution",
+      "accounts": [
+        {
+          "name": "feeDistribution",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "owners",
+          "type": {
+            "vec": "publicKey"
+          }
+        },
+        {
+          "name": "weights",
+          "type": {
+            "vec": "u16"
+          }
+        }
+      ]
+    },
+    {
+      "name": "changeFeeDistribution",
+      "accounts": [
+        {
+          "name": "feeDistribution",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "owners",
+          "type": {
+            "vec": "publicKey"
+          }
+        },
+        {
+          "name": "weights",
+          "type": {
+            "vec": "u16"
+          }
+        }
+      ]
+    },
+    {
+      "name": "distributeProtocolFee",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "feeDistribution",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenX",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenY",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "reserveX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "programAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenXProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenYProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    }
+  ],
+  "accounts": [
+    {
+      "name": "defaultProtocolFee",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "protocolFee",
+            "type": {
+              "defined": "FixedPoint"
+            }
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "feeDistribution",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "recipients",
+            "type": {
+              "array": [
+                {
+                  "defined": "FeeRecipient"
+                },
+                8
+              ]
+            }
+          },
+          {
+            "name": "count",
+            "type": "u8"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "feeTier",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "fee",
+            "type": {
               "defined": "FixedPoint"
             }
           },
@@ -1173,6 +2385,10 @@ export type Invariant = {
           {
             "name": "bump",
             "type": "u8"
+          },
+          {
+            "name": "stakeCount",
+            "type": "u16"
           }
         ]
       }
@@ -1325,46 +2541,416 @@ export type Invariant = {
       }
     },
     {
-      "name": "FeeGrowth",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "v",
-            "type": "u128"
-          }
-        ]
-      }
+      "name": "FeeGrowth",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "v",
+            "type": "u128"
+          }
+        ]
+      }
+    },
+    {
+      "name": "FixedPoint",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "v",
+            "type": "u128"
+          }
+        ]
+      }
+    },
+    {
+      "name": "CrossLimit",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "maxInitialized",
+            "type": "u16"
+          },
+          {
+            "name": "maxVirtual",
+            "type": "u16"
+          },
+          {
+            "name": "partialFill",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SwapReturnData",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "amountIn",
+            "type": "u64"
+          },
+          {
+            "name": "amountOut",
+            "type": "u64"
+          },
+          {
+            "name": "sqrtPrice",
+            "type": {
+              "defined": "Price"
+            }
+          },
+          {
+            "name": "currentTickIndex",
+            "type": "i32"
+          },
+          {
+            "name": "initializedCrosses",
+            "type": "u16"
+          },
+          {
+            "name": "virtualCrosses",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "RemovePositionReturnData",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "amountX",
+            "type": "u64"
+          },
+          {
+            "name": "amountY",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ClaimFeeReturnData",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "amountX",
+            "type": "u64"
+          },
+          {
+            "name": "amountY",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "FeeRecipient",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "owner",
+            "type": "publicKey"
+          },
+          {
+            "name": "weight",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "Record",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "timestamp",
+            "type": "u64"
+          },
+          {
+            "name": "price",
+            "type": {
+              "defined": "Price"
+            }
+          }
+        ]
+      }
+    }
+  ],
+  "events": [
+    {
+      "name": "SwapEvent",
+      "fields": [
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "swapper",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "xToY",
+          "type": "bool",
+          "index": false
+        },
+        {
+          "name": "amountIn",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "amountOut",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "fee",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "referralFee",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "priceBefore",
+          "type": {
+            "defined": "Price"
+          },
+          "index": false
+        },
+        {
+          "name": "priceAfter",
+          "type": {
+            "defined": "Price"
+          },
+          "index": false
+        },
+        {
+          "name": "currentTickIndex",
+          "type": "i32",
+          "index": false
+        },
+        {
+          "name": "liquidity",
+          "type": {
+            "defined": "Liquidity"
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "TickCrossedEvent",
+      "fields": [
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "index",
+          "type": "i32",
+          "index": false
+        },
+        {
+          "name": "xToY",
+          "type": "bool",
+          "index": false
+        },
+        {
+          "name": "liquidity",
+          "type": {
+            "defined": "Liquidity"
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "PositionCreatedEvent",
+      "fields": [
+        {
+          "name": "owner",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "id",
+          "type": "u128",
+          "index": false
+        },
+        {
+          "name": "liquidity",
+          "type": {
+            "defined": "Liquidity"
+          },
+          "index": false
+        },
+        {
+          "name": "lowerTick",
+          "type": "i32",
+          "index": false
+        },
+        {
+          "name": "upperTick",
+          "type": "i32",
+          "index": false
+        },
+        {
+          "name": "amountX",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "amountY",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "currentSqrtPrice",
+          "type": {
+            "defined": "Price"
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "PositionRemovedEvent",
+      "fields": [
+        {
+          "name": "owner",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "id",
+          "type": "u128",
+          "index": false
+        },
+        {
+          "name": "liquidity",
+          "type": {
+            "defined": "Liquidity"
+          },
+          "index": false
+        },
+        {
+          "name": "lowerTick",
+          "type": "i32",
+          "index": false
+        },
+        {
+          "name": "upperTick",
+          "type": "i32",
+          "index": false
+        },
+        {
+          "name": "amountX",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "amountY",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "currentSqrtPrice",
+          "type": {
+            "defined": "Price"
+          },
+          "index": false
+        }
+      ]
     },
     {
-      "name": "FixedPoint",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "v",
-            "type": "u128"
-          }
-        ]
-      }
+      "name": "FeeClaimedEvent",
+      "fields": [
+        {
+          "name": "owner",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "id",
+          "type": "u128",
+          "index": false
+        },
+        {
+          "name": "amountX",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "amountY",
+          "type": "u64",
+          "index": false
+        }
+      ]
     },
     {
-      "name": "Record",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "timestamp",
-            "type": "u64"
-          },
-          {
-            "name": "price",
-            "type": {
-              "defined": "Price"
-            }
-          }
-        ]
-      }
+      "name": "FlashLoanEvent",
+      "fields": [
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "borrower",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "amountX",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "amountY",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "feeX",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "feeY",
+          "type": "u64",
+          "index": false
+        }
+      ]
     }
   ],
   "errors": [
@@ -1459,95 +3045,1215 @@ export type Invariant = {
       "msg": "Time cannot be negative"
     },
     {
-      "code": 6018,
-      "name": "OracleAlreadyInitialized",
-      "msg": "Oracle is already initialized"
+      "code": 6018,
+      "name": "OracleAlreadyInitialized",
+      "msg": "Oracle is already initialized"
+    },
+    {
+      "code": 6019,
+      "name": "LimitReached",
+      "msg": "Absolute price limit was reached"
+    },
+    {
+      "code": 6020,
+      "name": "InvalidProtocolFee",
+      "msg": "Invalid protocol fee"
+    },
+    {
+      "code": 6021,
+      "name": "NoGainSwap",
+      "msg": "Swap amount out is 0"
+    },
+    {
+      "code": 6022,
+      "name": "InvalidTokenAccount",
+      "msg": "Provided token account is different than expected"
+    },
+    {
+      "code": 6023,
+      "name": "InvalidAdmin",
+      "msg": "Admin address is different than expected"
+    },
+    {
+      "code": 6024,
+      "name": "InvalidAuthority",
+      "msg": "Provided authority is different than expected"
+    },
+    {
+      "code": 6025,
+      "name": "InvalidOwner",
+      "msg": "Provided token owner is different than expected"
+    },
+    {
+      "code": 6026,
+      "name": "InvalidMint",
+      "msg": "Provided token account mint is different than expected mint token"
+    },
+    {
+      "code": 6027,
+      "name": "InvalidTickmap",
+      "msg": "Provided tickmap is different than expected"
+    },
+    {
+      "code": 6028,
+      "name": "InvalidTickmapOwner",
+      "msg": "Provided tickmap owner is different than program ID"
+    },
+    {
+      "code": 6029,
+      "name": "InvalidListOwner",
+      "msg": "Recipient list address and owner list address should be different"
+    },
+    {
+      "code": 6030,
+      "name": "InvalidTickSpacing",
+      "msg": "Invalid tick spacing"
+    },
+    {
+      "code": 6031,
+      "name": "TooManyTickCrosses",
+      "msg": "Swap would cross more ticks than allowed"
+    },
+    {
+      "code": 6032,
+      "name": "InvalidSingleSidedToken",
+      "msg": "Position range requires the other token"
+    },
+    {
+      "code": 6033,
+      "name": "MaxAmountExceeded",
+      "msg": "Position requires more tokens than the given maximum"
+    },
+    {
+      "code": 6034,
+      "name": "InvalidFeeDistribution",
+      "msg": "Invalid fee distribution"
+    },
+    {
+      "code": 6035,
+      "name": "FlashLoanNotRepaid",
+      "msg": "Flash loan is not repaid later in the transaction"
+    },
+    {
+      "code": 6036,
+      "name": "FlashLoanCpi",
+      "msg": "Flash loan can't be invoked through CPI"
+    },
+    {
+      "code": 6037,
+      "name": "InvalidTokenProgram",
+      "msg": "Provided token program is different than expected"
+    },
+    {
+      "code": 6038,
+      "name": "UnsupportedMintExtension",
+      "msg": "Mint extension is not supported"
+    },
+    {
+      "code": 6039,
+      "name": "PositionLocked",
+      "msg": "Position is locked by a stake"
+    },
+    {
+      "code": 6040,
+      "name": "TransferFeeOverflow",
+      "msg": "Amount with transfer fee overflows"
+    },
+    {
+      "code": 6041,
+      "name": "LiquidityOverflow",
+      "msg": "Liquidity for the given amount overflows"
+    },
+    {
+      "code": 6042,
+      "name": "FeeDistributionExists",
+      "msg": "Protocol fee has to be distributed through the fee distribution"
+    },
+    {
+      "code": 6043,
+      "name": "TooManyStakes",
+      "msg": "Position is locked by too many stakes"
+    },
+    {
+      "code": 6044,
+      "name": "InvalidPositionLayout",
+      "msg": "Position is not in the legacy layout"
+    },
+    {
+      "code": 6045,
+      "name": "PositionNotLocked",
+      "msg": "Position is not locked by any stake"
+    }
+  ]
+};
+
+export const IDL: Invariant = {
+  "version": "0.1.0",
+  "name": "invariant",
+  "instructions": [
+    {
+      "name": "createState",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "programAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "nonce",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "createFeeTier",
+      "accounts": [
+        {
+          "name": "feeTier",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "fee",
+          "type": "u128"
+        },
+        {
+          "name": "tickSpacing",
+          "type": "u16"
+        }
+      ]
     },
     {
-      "code": 6019,
-      "name": "LimitReached",
-      "msg": "Absolute price limit was reached"
+      "name": "createPool",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "feeTier",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "defaultProtocolFee",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tickmap",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenX",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenY",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenXReserve",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "tokenYReserve",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenXProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenYProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "initTick",
+          "type": "i32"
+        }
+      ]
     },
     {
-      "code": 6020,
-      "name": "InvalidProtocolFee",
-      "msg": "Invalid protocol fee"
+      "name": "swap",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tickmap",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenX",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenY",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "accountX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "accountY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "programAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenXProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenYProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "xToY",
+          "type": "bool"
+        },
+        {
+          "name": "amount",
+          "type": "u64"
+        },
+        {
+          "name": "byAmountIn",
+          "type": "bool"
+        },
+        {
+          "name": "sqrtPriceLimit",
+          "type": "u128"
+        }
+      ]
     },
     {
-      "code": 6021,
-      "name": "NoGainSwap",
-      "msg": "Swap amount out is 0"
+      "name": "swapWithCrossLimit",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tickmap",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenX",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenY",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "accountX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "accountY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "programAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenXProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenYProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "xToY",
+          "type": "bool"
+        },
+        {
+          "name": "amount",
+          "type": "u64"
+        },
+        {
+          "name": "byAmountIn",
+          "type": "bool"
+        },
+        {
+          "name": "sqrtPriceLimit",
+          "type": "u128"
+        },
+        {
+          "name": "crossLimit",
+          "type": {
+            "defined": "CrossLimit"
+          }
+        }
+      ]
     },
     {
-      "code": 6022,
-      "name": "InvalidTokenAccount",
-      "msg": "Provided token account is different than expected"
+      "name": "swapTo",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tickmap",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenX",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenY",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "accountX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "accountY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "programAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenXProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenYProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "xToY",
+          "type": "bool"
+        },
+        {
+          "name": "amount",
+          "type": "u64"
+        },
+        {
+          "name": "byAmountIn",
+          "type": "bool"
+        },
+        {
+          "name": "sqrtPriceLimit",
+          "type": "u128"
+        },
+        {
+          "name": "recipient",
+          "type": "publicKey"
+        }
+      ]
     },
     {
-      "code": 6023,
-      "name": "InvalidAdmin",
-      "msg": "Admin address is different than expected"
+      "name": "flashLoan",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenX",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenY",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "accountX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "accountY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "borrower",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "programAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenXProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenYProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "instructions",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "amountX",
+          "type": "u64"
+        },
+        {
+          "name": "amountY",
+          "type": "u64"
+        }
+      ]
     },
     {
-      "code": 6024,
-      "name": "InvalidAuthority",
-      "msg": "Provided authority is different than expected"
+      "name": "flashRepay",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenX",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenY",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "accountX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "accountY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "borrower",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "programAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenXProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenYProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "amountX",
+          "type": "u64"
+        },
+        {
+          "name": "amountY",
+          "type": "u64"
+        }
+      ]
     },
     {
-      "code": 6025,
-      "name": "InvalidOwner",
-      "msg": "Provided token owner is different than expected"
+      "name": "initializeOracle",
+      "accounts": [
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "oracle",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenX",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenY",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
     },
     {
-      "code": 6026,
-      "name": "InvalidMint",
-      "msg": "Provided token account mint is different than expected mint token"
+      "name": "createTick",
+      "accounts": [
+        {
+          "name": "tick",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tickmap",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "tokenX",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenY",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "index",
+          "type": "i32"
+        }
+      ]
     },
     {
-      "code": 6027,
-      "name": "InvalidTickmap",
-      "msg": "Provided tickmap is different than expected"
+      "name": "createPositionList",
+      "accounts": [
+        {
+          "name": "positionList",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "signer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
     },
     {
-      "code": 6028,
-      "name": "InvalidTickmapOwner",
-      "msg": "Provided tickmap owner is different than program ID"
+      "name": "createPosition",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "position",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "positionList",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "lowerTick",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "upperTick",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tickmap",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenX",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenY",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "accountX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "accountY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "programAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenXProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenYProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "lowerTickIndex",
+          "type": "i32"
+        },
+        {
+          "name": "upperTickIndex",
+          "type": "i32"
+        },
+        {
+          "name": "liquidityDelta",
+          "type": {
+            "defined": "Liquidity"
+          }
+        },
+        {
+          "name": "slippageLimitLower",
+          "type": {
+            "defined": "Price"
+          }
+        },
+        {
+          "name": "slippageLimitUpper",
+          "type": {
+            "defined": "Price"
+          }
+        }
+      ]
     },
     {
-      "code": 6029,
-      "name": "InvalidListOwner",
-      "msg": "Recipient list address and owner list address should be different"
+      "name": "createPositionByAmounts",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "position",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "positionList",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "lowerTick",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "upperTick",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tickmap",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenX",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenY",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "accountX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "accountY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "programAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenXProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenYProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "lowerTickIndex",
+          "type": "i32"
+        },
+        {
+          "name": "upperTickIndex",
+          "type": "i32"
+        },
+        {
+          "name": "maxAmountX",
+          "type": "u64"
+        },
+        {
+          "name": "maxAmountY",
+          "type": "u64"
+        }
+      ]
     },
     {
-      "code": 6030,
-      "name": "InvalidTickSpacing",
-      "msg": "Invalid tick spacing"
-    }
-  ]
-};
-
-export const IDL: Invariant = {
-  "version": "0.1.0",
-  "name": "invariant",
-  "instructions": [
-    {
-      "name": "createState",
+      "name": "createPositionSingleSided",
       "accounts": [
         {
           "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "position",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "admin",
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "positionList",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
           "isMut": true,
           "isSigner": true
         },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "lowerTick",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "upperTick",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tickmap",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenX",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenY",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "accountX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "accountY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveY",
+          "isMut": true,
+          "isSigner": false
+        },
         {
           "name": "programAuthority",
           "isMut": false,
           "isSigner": false
         },
+        {
+          "name": "tokenXProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenYProgram",
+          "isMut": false,
+          "isSigner": false
+        },
         {
           "name": "rent",
           "isMut": false,
@@ -1561,74 +4267,83 @@ export const IDL: Invariant = {
       ],
       "args": [
         {
-          "name": "nonce",
-          "type": "u8"
+          "name": "lowerTickIndex",
+          "type": "i32"
+        },
+        {
+          "name": "upperTickIndex",
+          "type": "i32"
+        },
+        {
+          "name": "amount",
+          "type": "u64"
+        },
+        {
+          "name": "xToY",
+          "type": "bool"
+        },
+        {
+          "name": "slippageLimitLower",
+          "type": {
+            "defined": "Price"
+          }
+        },
+        {
+          "name": "slippageLimitUpper",
+          "type": {
+            "defined": "Price"
+          }
         }
       ]
     },
     {
-      "name": "createFeeTier",
+      "name": "removePosition",
       "accounts": [
-        {
-          "name": "feeTier",
-          "isMut": true,
-          "isSigner": false
-        },
         {
           "name": "state",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "admin",
+          "name": "removedPosition",
           "isMut": true,
-          "isSigner": true
+          "isSigner": false
         },
         {
-          "name": "rent",
-          "isMut": false,
+          "name": "positionList",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "systemProgram",
-          "isMut": false,
+          "name": "lastPosition",
+          "isMut": true,
           "isSigner": false
-        }
-      ],
-      "args": [
-        {
-          "name": "fee",
-          "type": "u128"
         },
         {
-          "name": "tickSpacing",
-          "type": "u16"
-        }
-      ]
-    },
-    {
-      "name": "createPool",
-      "accounts": [
-        {
-          "name": "state",
-          "isMut": false,
+          "name": "pool",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "pool",
+          "name": "tickmap",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "feeTier",
-          "isMut": false,
+          "name": "lowerTick",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "tickmap",
+          "name": "upperTick",
           "isMut": true,
           "isSigner": false
         },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
         {
           "name": "tokenX",
           "isMut": false,
@@ -1640,202 +4355,187 @@ export const IDL: Invariant = {
           "isSigner": false
         },
         {
-          "name": "tokenXReserve",
+          "name": "accountX",
           "isMut": true,
-          "isSigner": true
+          "isSigner": false
         },
         {
-          "name": "tokenYReserve",
+          "name": "accountY",
           "isMut": true,
-          "isSigner": true
+          "isSigner": false
         },
         {
-          "name": "payer",
+          "name": "reserveX",
           "isMut": true,
-          "isSigner": true
+          "isSigner": false
         },
         {
-          "name": "authority",
-          "isMut": false,
+          "name": "reserveY",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "tokenProgram",
+          "name": "programAuthority",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "rent",
+          "name": "tokenXProgram",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "systemProgram",
+          "name": "tokenYProgram",
           "isMut": false,
           "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "initTick",
+          "name": "index",
+          "type": "u32"
+        },
+        {
+          "name": "lowerTickIndex",
+          "type": "i32"
+        },
+        {
+          "name": "upperTickIndex",
           "type": "i32"
         }
       ]
     },
     {
-      "name": "swap",
+      "name": "transferPositionOwnership",
       "accounts": [
         {
-          "name": "state",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "pool",
+          "name": "ownerList",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "tickmap",
+          "name": "recipientList",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "accountX",
+          "name": "newPosition",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "accountY",
+          "name": "removedPosition",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "reserveX",
+          "name": "lastPosition",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "reserveY",
+          "name": "owner",
           "isMut": true,
-          "isSigner": false
+          "isSigner": true
         },
         {
-          "name": "owner",
+          "name": "recipient",
           "isMut": false,
-          "isSigner": true
+          "isSigner": false
         },
         {
-          "name": "programAuthority",
+          "name": "rent",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "tokenProgram",
+          "name": "systemProgram",
           "isMut": false,
           "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "xToY",
-          "type": "bool"
-        },
-        {
-          "name": "amount",
-          "type": "u64"
-        },
-        {
-          "name": "byAmountIn",
-          "type": "bool"
-        },
-        {
-          "name": "sqrtPriceLimit",
-          "type": "u128"
+          "name": "index",
+          "type": "u32"
         }
       ]
     },
     {
-      "name": "initializeOracle",
+      "name": "claimFee",
       "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
         {
           "name": "pool",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "oracle",
+          "name": "position",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "tokenX",
-          "isMut": false,
+          "name": "lowerTick",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "tokenY",
-          "isMut": false,
+          "name": "upperTick",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "payer",
+          "name": "owner",
           "isMut": false,
           "isSigner": true
         },
         {
-          "name": "rent",
+          "name": "tokenX",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "systemProgram",
+          "name": "tokenY",
           "isMut": false,
           "isSigner": false
-        }
-      ],
-      "args": []
-    },
-    {
-      "name": "createTick",
-      "accounts": [
+        },
         {
-          "name": "tick",
+          "name": "accountX",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "pool",
-          "isMut": false,
+          "name": "accountY",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "tickmap",
+          "name": "reserveX",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "payer",
+          "name": "reserveY",
           "isMut": true,
-          "isSigner": true
-        },
-        {
-          "name": "tokenX",
-          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "tokenY",
+          "name": "programAuthority",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "rent",
+          "name": "tokenXProgram",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "systemProgram",
+          "name": "tokenYProgram",
           "isMut": false,
           "isSigner": false
         }
@@ -1843,121 +4543,100 @@ export const IDL: Invariant = {
       "args": [
         {
           "name": "index",
+          "type": "u32"
+        },
+        {
+          "name": "lowerTickIndex",
+          "type": "i32"
+        },
+        {
+          "name": "upperTickIndex",
           "type": "i32"
         }
       ]
     },
     {
-      "name": "createPositionList",
+      "name": "swapNative",
       "accounts": [
         {
-          "name": "positionList",
+          "name": "wrappedSol",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "owner",
+          "name": "nativeMint",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "signer",
+          "name": "owner",
           "isMut": true,
           "isSigner": true
         },
         {
-          "name": "rent",
+          "name": "invariantProgram",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
-        }
-      ],
-      "args": []
-    },
-    {
-      "name": "createPosition",
-      "accounts": [
-        {
-          "name": "state",
+          "name": "tokenProgram",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "position",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "pool",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "positionList",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "payer",
-          "isMut": true,
-          "isSigner": true
-        },
-        {
-          "name": "owner",
+          "name": "rent",
           "isMut": false,
-          "isSigner": true
-        },
-        {
-          "name": "lowerTick",
-          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "upperTick",
-          "isMut": true,
+          "name": "systemProgram",
+          "isMut": false,
           "isSigner": false
-        },
+        }
+      ],
+      "args": [
         {
-          "name": "tickmap",
-          "isMut": true,
-          "isSigner": false
+          "name": "nativeAmount",
+          "type": "u64"
         },
         {
-          "name": "tokenX",
-          "isMut": false,
-          "isSigner": false
+          "name": "xToY",
+          "type": "bool"
         },
         {
-          "name": "tokenY",
-          "isMut": false,
-          "isSigner": false
+          "name": "amount",
+          "type": "u64"
         },
         {
-          "name": "accountX",
-          "isMut": true,
-          "isSigner": false
+          "name": "byAmountIn",
+          "type": "bool"
         },
         {
-          "name": "accountY",
+          "name": "sqrtPriceLimit",
+          "type": "u128"
+        }
+      ]
+    },
+    {
+      "name": "createPositionNative",
+      "accounts": [
+        {
+          "name": "wrappedSol",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "reserveX",
-          "isMut": true,
+          "name": "nativeMint",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "reserveY",
+          "name": "owner",
           "isMut": true,
-          "isSigner": false
+          "isSigner": true
         },
         {
-          "name": "programAuthority",
+          "name": "invariantProgram",
           "isMut": false,
           "isSigner": false
         },
@@ -1978,6 +4657,10 @@ export const IDL: Invariant = {
         }
       ],
       "args": [
+        {
+          "name": "nativeAmount",
+          "type": "u64"
+        },
         {
           "name": "lowerTickIndex",
           "type": "i32"
@@ -2007,90 +4690,94 @@ export const IDL: Invariant = {
       ]
     },
     {
-      "name": "removePosition",
+      "name": "removePositionNative",
       "accounts": [
         {
-          "name": "state",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "removedPosition",
+          "name": "wrappedSol",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "positionList",
-          "isMut": true,
+          "name": "nativeMint",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "lastPosition",
+          "name": "owner",
           "isMut": true,
-          "isSigner": false
+          "isSigner": true
         },
         {
-          "name": "pool",
-          "isMut": true,
+          "name": "invariantProgram",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "tickmap",
-          "isMut": true,
+          "name": "tokenProgram",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "lowerTick",
-          "isMut": true,
+          "name": "rent",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "upperTick",
-          "isMut": true,
+          "name": "systemProgram",
+          "isMut": false,
           "isSigner": false
-        },
+        }
+      ],
+      "args": [
         {
-          "name": "owner",
-          "isMut": true,
-          "isSigner": true
+          "name": "index",
+          "type": "u32"
         },
         {
-          "name": "tokenX",
-          "isMut": false,
-          "isSigner": false
+          "name": "lowerTickIndex",
+          "type": "i32"
         },
         {
-          "name": "tokenY",
-          "isMut": false,
-          "isSigner": false
-        },
+          "name": "upperTickIndex",
+          "type": "i32"
+        }
+      ]
+    },
+    {
+      "name": "claimFeeNative",
+      "accounts": [
         {
-          "name": "accountX",
+          "name": "wrappedSol",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "accountY",
-          "isMut": true,
+          "name": "nativeMint",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "reserveX",
+          "name": "owner",
           "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "invariantProgram",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "reserveY",
-          "isMut": true,
+          "name": "tokenProgram",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "programAuthority",
+          "name": "rent",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "tokenProgram",
+          "name": "systemProgram",
           "isMut": false,
           "isSigner": false
         }
@@ -2111,43 +4798,101 @@ export const IDL: Invariant = {
       ]
     },
     {
-      "name": "transferPositionOwnership",
+      "name": "lockPosition",
       "accounts": [
         {
-          "name": "ownerList",
+          "name": "position",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "recipientList",
+          "name": "stakerAuthority",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "unlockPosition",
+      "accounts": [
+        {
+          "name": "position",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "newPosition",
+          "name": "stakerAuthority",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "migratePosition",
+      "accounts": [
+        {
+          "name": "position",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "removedPosition",
+          "name": "payer",
           "isMut": true,
-          "isSigner": false
+          "isSigner": true
         },
         {
-          "name": "lastPosition",
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "updateSecondsPerLiquidity",
+      "accounts": [
+        {
+          "name": "pool",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "owner",
+          "name": "lowerTick",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "upperTick",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "position",
           "isMut": true,
-          "isSigner": true
+          "isSigner": false
         },
         {
-          "name": "recipient",
+          "name": "tokenX",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenY",
           "isMut": false,
           "isSigner": false
         },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "signer",
+          "isMut": true,
+          "isSigner": true
+        },
         {
           "name": "rent",
           "isMut": false,
@@ -2160,14 +4905,22 @@ export const IDL: Invariant = {
         }
       ],
       "args": [
+        {
+          "name": "lowerTickIndex",
+          "type": "i32"
+        },
+        {
+          "name": "upperTickIndex",
+          "type": "i32"
+        },
         {
           "name": "index",
-          "type": "u32"
+          "type": "i32"
         }
       ]
     },
     {
-      "name": "claimFee",
+      "name": "withdrawProtocolFee",
       "accounts": [
         {
           "name": "state",
@@ -2180,24 +4933,9 @@ export const IDL: Invariant = {
           "isSigner": false
         },
         {
-          "name": "position",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "lowerTick",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "upperTick",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "owner",
+          "name": "feeDistribution",
           "isMut": false,
-          "isSigner": true
+          "isSigner": false
         },
         {
           "name": "tokenX",
@@ -2230,102 +4968,162 @@ export const IDL: Invariant = {
           "isSigner": false
         },
         {
-          "name": "programAuthority",
+          "name": "authority",
           "isMut": false,
-          "isSigner": false
+          "isSigner": true
         },
         {
-          "name": "tokenProgram",
+          "name": "programAuthority",
           "isMut": false,
           "isSigner": false
-        }
-      ],
-      "args": [
-        {
-          "name": "index",
-          "type": "u32"
         },
         {
-          "name": "lowerTickIndex",
-          "type": "i32"
+          "name": "tokenXProgram",
+          "isMut": false,
+          "isSigner": false
         },
         {
-          "name": "upperTickIndex",
-          "type": "i32"
+          "name": "tokenYProgram",
+          "isMut": false,
+          "isSigner": false
         }
-      ]
+      ],
+      "args": []
     },
     {
-      "name": "updateSecondsPerLiquidity",
+      "name": "changeProtocolFee",
       "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
         {
           "name": "pool",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "lowerTick",
+          "name": "tokenX",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "upperTick",
+          "name": "tokenY",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "position",
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "programAuthority",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "protocolFee",
+          "type": {
+            "defined": "FixedPoint"
+          }
+        }
+      ]
+    },
+    {
+      "name": "createDefaultProtocolFee",
+      "accounts": [
+        {
+          "name": "defaultProtocolFee",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "tokenX",
+          "name": "state",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "tokenY",
+          "name": "admin",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "rent",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "owner",
+          "name": "systemProgram",
           "isMut": false,
           "isSigner": false
-        },
+        }
+      ],
+      "args": [
         {
-          "name": "signer",
+          "name": "protocolFee",
+          "type": {
+            "defined": "FixedPoint"
+          }
+        }
+      ]
+    },
+    {
+      "name": "changeDefaultProtocolFee",
+      "accounts": [
+        {
+          "name": "defaultProtocolFee",
           "isMut": true,
-          "isSigner": true
+          "isSigner": false
         },
         {
-          "name": "rent",
+          "name": "state",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "systemProgram",
+          "name": "admin",
           "isMut": false,
-          "isSigner": false
+          "isSigner": true
         }
       ],
       "args": [
         {
-          "name": "lowerTickIndex",
-          "type": "i32"
-        },
+          "name": "protocolFee",
+          "type": {
+            "defined": "FixedPoint"
+          }
+        }
+      ]
+    },
+    {
+      "name": "changeProtocolFeeBatch",
+      "accounts": [
         {
-          "name": "upperTickIndex",
-          "type": "i32"
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
         },
         {
-          "name": "index",
-          "type": "i32"
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "protocolFee",
+          "type": {
+            "defined": "FixedPoint"
+          }
         }
       ]
     },
     {
-      "name": "withdrawProtocolFee",
+      "name": "changeFeeReceiver",
       "accounts": [
         {
           "name": "state",
@@ -2348,63 +5146,72 @@ export const IDL: Invariant = {
           "isSigner": false
         },
         {
-          "name": "accountX",
-          "isMut": true,
-          "isSigner": false
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
         },
         {
-          "name": "accountY",
-          "isMut": true,
+          "name": "feeReceiver",
+          "isMut": false,
           "isSigner": false
-        },
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "createFeeDistribution",
+      "accounts": [
         {
-          "name": "reserveX",
+          "name": "feeDistribution",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "reserveY",
-          "isMut": true,
+          "name": "state",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "authority",
-          "isMut": false,
+          "name": "admin",
+          "isMut": true,
           "isSigner": true
         },
         {
-          "name": "programAuthority",
+          "name": "rent",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "tokenProgram",
+          "name": "systemProgram",
           "isMut": false,
           "isSigner": false
         }
       ],
-      "args": []
+      "args": [
+        {
+          "name": "owners",
+          "type": {
+            "vec": "publicKey"
+          }
+        },
+        {
+          "name": "weights",
+          "type": {
+            "vec": "u16"
+          }
+        }
+      ]
     },
     {
-      "name": "changeProtocolFee",
+      "name": "changeFeeDistribution",
       "accounts": [
         {
-          "name": "state",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "pool",
+          "name": "feeDistribution",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "tokenX",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "tokenY",
+          "name": "state",
           "isMut": false,
           "isSigner": false
         },
@@ -2412,30 +5219,36 @@ export const IDL: Invariant = {
           "name": "admin",
           "isMut": false,
           "isSigner": true
-        },
-        {
-          "name": "programAuthority",
-          "isMut": false,
-          "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "protocolFee",
+          "name": "owners",
           "type": {
-            "defined": "FixedPoint"
+            "vec": "publicKey"
+          }
+        },
+        {
+          "name": "weights",
+          "type": {
+            "vec": "u16"
           }
         }
       ]
     },
     {
-      "name": "changeFeeReceiver",
+      "name": "distributeProtocolFee",
       "accounts": [
         {
           "name": "state",
           "isMut": false,
           "isSigner": false
         },
+        {
+          "name": "feeDistribution",
+          "isMut": false,
+          "isSigner": false
+        },
         {
           "name": "pool",
           "isMut": true,
@@ -2452,12 +5265,27 @@ export const IDL: Invariant = {
           "isSigner": false
         },
         {
-          "name": "admin",
+          "name": "reserveX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "programAuthority",
           "isMut": false,
-          "isSigner": true
+          "isSigner": false
         },
         {
-          "name": "feeReceiver",
+          "name": "tokenXProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenYProgram",
           "isMut": false,
           "isSigner": false
         }
@@ -2466,6 +5294,51 @@ export const IDL: Invariant = {
     }
   ],
   "accounts": [
+    {
+      "name": "defaultProtocolFee",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "protocolFee",
+            "type": {
+              "defined": "FixedPoint"
+            }
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "feeDistribution",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "recipients",
+            "type": {
+              "array": [
+                {
+                  "defined": "FeeRecipient"
+                },
+                8
+              ]
+            }
+          },
+          {
+            "name": "count",
+            "type": "u8"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
     {
       "name": "feeTier",
       "type": {
@@ -2701,6 +5574,10 @@ export const IDL: Invariant = {
           {
             "name": "bump",
             "type": "u8"
+          },
+          {
+            "name": "stakeCount",
+            "type": "u16"
           }
         ]
       }
@@ -2803,75 +5680,177 @@ export const IDL: Invariant = {
             "type": "u64"
           },
           {
-            "name": "bump",
-            "type": "u8"
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "tickmap",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bitmap",
+            "type": {
+              "array": [
+                "u8",
+                11091
+              ]
+            }
+          }
+        ]
+      }
+    }
+  ],
+  "types": [
+    {
+      "name": "Price",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "v",
+            "type": "u128"
+          }
+        ]
+      }
+    },
+    {
+      "name": "Liquidity",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "v",
+            "type": "u128"
+          }
+        ]
+      }
+    },
+    {
+      "name": "FeeGrowth",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "v",
+            "type": "u128"
+          }
+        ]
+      }
+    },
+    {
+      "name": "FixedPoint",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "v",
+            "type": "u128"
           }
         ]
       }
     },
     {
-      "name": "tickmap",
+      "name": "CrossLimit",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "bitmap",
-            "type": {
-              "array": [
-                "u8",
-                11091
-              ]
-            }
+            "name": "maxInitialized",
+            "type": "u16"
+          },
+          {
+            "name": "maxVirtual",
+            "type": "u16"
+          },
+          {
+            "name": "partialFill",
+            "type": "bool"
           }
         ]
       }
-    }
-  ],
-  "types": [
+    },
     {
-      "name": "Price",
+      "name": "SwapReturnData",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "v",
-            "type": "u128"
+            "name": "amountIn",
+            "type": "u64"
+          },
+          {
+            "name": "amountOut",
+            "type": "u64"
+          },
+          {
+            "name": "sqrtPrice",
+            "type": {
+              "defined": "Price"
+            }
+          },
+          {
+            "name": "currentTickIndex",
+            "type": "i32"
+          },
+          {
+            "name": "initializedCrosses",
+            "type": "u16"
+          },
+          {
+            "name": "virtualCrosses",
+            "type": "u16"
           }
         ]
       }
     },
     {
-      "name": "Liquidity",
+      "name": "RemovePositionReturnData",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "v",
-            "type": "u128"
+            "name": "amountX",
+            "type": "u64"
+          },
+          {
+            "name": "amountY",
+            "type": "u64"
           }
         ]
       }
     },
     {
-      "name": "FeeGrowth",
+      "name": "ClaimFeeReturnData",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "v",
-            "type": "u128"
+            "name": "amountX",
+            "type": "u64"
+          },
+          {
+            "name": "amountY",
+            "type": "u64"
           }
         ]
       }
     },
     {
-      "name": "FixedPoint",
+      "name": "FeeRecipient",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "v",
-            "type": "u128"
+            "name": "owner",
+            "type": "publicKey"
+          },
+          {
+            "name": "weight",
+            "type": "u16"
           }
         ]
       }
@@ -2895,6 +5874,274 @@ export const IDL: Invariant = {
       }
     }
   ],
+  "events": [
+    {
+      "name": "SwapEvent",
+      "fields": [
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "swapper",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "xToY",
+          "type": "bool",
+          "index": false
+        },
+        {
+          "name": "amountIn",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "amountOut",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "fee",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "referralFee",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "priceBefore",
+          "type": {
+            "defined": "Price"
+          },
+          "index": false
+        },
+        {
+          "name": "priceAfter",
+          "type": {
+            "defined": "Price"
+          },
+          "index": false
+        },
+        {
+          "name": "currentTickIndex",
+          "type": "i32",
+          "index": false
+        },
+        {
+          "name": "liquidity",
+          "type": {
+            "defined": "Liquidity"
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "TickCrossedEvent",
+      "fields": [
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "index",
+          "type": "i32",
+          "index": false
+        },
+        {
+          "name": "xToY",
+          "type": "bool",
+          "index": false
+        },
+        {
+          "name": "liquidity",
+          "type": {
+            "defined": "Liquidity"
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "PositionCreatedEvent",
+      "fields": [
+        {
+          "name": "owner",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "id",
+          "type": "u128",
+          "index": false
+        },
+        {
+          "name": "liquidity",
+          "type": {
+            "defined": "Liquidity"
+          },
+          "index": false
+        },
+        {
+          "name": "lowerTick",
+          "type": "i32",
+          "index": false
+        },
+        {
+          "name": "upperTick",
+          "type": "i32",
+          "index": false
+        },
+        {
+          "name": "amountX",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "amountY",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "currentSqrtPrice",
+          "type": {
+            "defined": "Price"
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "PositionRemovedEvent",
+      "fields": [
+        {
+          "name": "owner",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "id",
+          "type": "u128",
+          "index": false
+        },
+        {
+          "name": "liquidity",
+          "type": {
+            "defined": "Liquidity"
+          },
+          "index": false
+        },
+        {
+          "name": "lowerTick",
+          "type": "i32",
+          "index": false
+        },
+        {
+          "name": "upperTick",
+          "type": "i32",
+          "index": false
+        },
+        {
+          "name": "amountX",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "amountY",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "currentSqrtPrice",
+          "type": {
+            "defined": "Price"
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "FeeClaimedEvent",
+      "fields": [
+        {
+          "name": "owner",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "id",
+          "type": "u128",
+          "index": false
+        },
+        {
+          "name": "amountX",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "amountY",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "FlashLoanEvent",
+      "fields": [
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "borrower",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "amountX",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "amountY",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "feeX",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "feeY",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    }
+  ],
   "errors": [
     {
       "code": 6000,
@@ -3050,6 +6297,81 @@ export const IDL: Invariant = {
       "code": 6030,
       "name": "InvalidTickSpacing",
       "msg": "Invalid tick spacing"
+    },
+    {
+      "code": 6031,
+      "name": "TooManyTickCrosses",
+      "msg": "Swap would cross more ticks than allowed"
+    },
+    {
+      "code": 6032,
+      "name": "InvalidSingleSidedToken",
+      "msg": "Position range requires the other token"
+    },
+    {
+      "code": 6033,
+      "name": "MaxAmountExceeded",
+      "msg": "Position requires more tokens than the given maximum"
+    },
+    {
+      "code": 6034,
+      "name": "InvalidFeeDistribution",
+      "msg": "Invalid fee distribution"
+    },
+    {
+      "code": 6035,
+      "name": "FlashLoanNotRepaid",
+      "msg": "Flash loan is not repaid later in the transaction"
+    },
+    {
+      "code": 6036,
+      "name": "FlashLoanCpi",
+      "msg": "Flash loan can't be invoked through CPI"
+    },
+    {
+      "code": 6037,
+      "name": "InvalidTokenProgram",
+      "msg": "Provided token program is different than expected"
+    },
+    {
+      "code": 6038,
+      "name": "UnsupportedMintExtension",
+      "msg": "Mint extension is not supported"
+    },
+    {
+      "code": 6039,
+      "name": "PositionLocked",
+      "msg": "Position is locked by a stake"
+    },
+    {
+      "code": 6040,
+      "name": "TransferFeeOverflow",
+      "msg": "Amount with transfer fee overflows"
+    },
+    {
+      "code": 6041,
+      "name": "LiquidityOverflow",
+      "msg": "Liquidity for the given amount overflows"
+    },
+    {
+      "code": 6042,
+      "name": "FeeDistributionExists",
+      "msg": "Protocol fee has to be distributed through the fee distribution"
+    },
+    {
+      "code": 6043,
+      "name": "TooManyStakes",
+      "msg": "Position is locked by too many stakes"
+    },
+    {
+      "code": 6044,
+      "name": "InvalidPositionLayout",
+      "msg": "Position is not in the legacy layout"
+    },
+    {
+      "code": 6045,
+      "name": "PositionNotLocked",
+      "msg": "Position is not locked by any stake"
     }
   ]
 };
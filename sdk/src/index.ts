@@ -14,8 +14,7 @@ import {
   calculatePriceSqrt,
   fromInteger,
   MAX_TICK,
-  MIN_TICK,
-  TICK_SEARCH_RANGE
+  MIN_TICK
 } from './math'
 import { PublicKey, Transaction } from '@solana/web3.js'
 import { Pair } from './pair'
@@ -42,7 +41,6 @@ export {
   MIN_TICK,
   MOCK_TOKENS,
   FEE_TIER,
-  TICK_SEARCH_RANGE,
   computeUnitsInstruction
 }
 export interface IWallet {
@@ -1,11 +1,12 @@
-import { BN, Program, utils, Provider } from '@project-serum/anchor'
-import { Token, TOKEN_PROGRAM_ID } from '@solana/spl-token'
+import { BN, EventParser, Program, utils, Provider } from '@project-serum/anchor'
+import { NATIVE_MINT, Token, TOKEN_PROGRAM_ID } from '@solana/spl-token'
 import {
   ComputeBudgetProgram,
   Connection,
   Keypair,
   PublicKey,
   SystemProgram,
+  SYSVAR_INSTRUCTIONS_PUBKEY,
   SYSVAR_RENT_PUBKEY,
   Transaction,
   TransactionInstruction
@@ -44,6 +45,9 @@ const POSITION_SEED = 'positionv1'
 const TICK_SEED = 'tickv1'
 const POSITION_LIST_SEED = 'positionlistv1'
 const STATE_SEED = 'statev1'
+const FEE_DISTRIBUTION_SEED = 'feedistributionv1'
+const DEFAULT_PROTOCOL_FEE_SEED = 'defaultprotocolfeev1'
+const NATIVE_SOL_SEED = 'wsolv1'
 export const TICK_CROSSES_PER_IX = 19
 export const FEE_TIER = 'feetierv1'
 export const DEFAULT_PUBLIC_KEY = new PublicKey(0)
@@ -114,20 +118,24 @@ export class Market {
 
     const [poolAddress] = await pair.getAddressAndBump(this.program.programId)
     const { address: feeTierAddress } = await this.getFeeTierAddress(pair.feeTier)
+    const { tokenXProgram, tokenYProgram } = await this.getTokenPrograms(pair)
+    const { address: defaultProtocolFeeAddress } = await this.getDefaultProtocolFeeAddress()
 
     const createIx = this.program.instruction.createPool(tick, {
       accounts: {
         state: stateAddress,
         pool: poolAddress,
         feeTier: feeTierAddress,
+        defaultProtocolFee: defaultProtocolFeeAddress,
         tickmap: bitmapKeypair.publicKey,
         tokenX: pair.tokenX,
         tokenY: pair.tokenY,
         tokenXReserve: tokenXReserve.publicKey,
         tokenYReserve: tokenYReserve.publicKey,
         authority: this.programAuthority,
-        tokenProgram: TOKEN_PROGRAM_ID,
         payer: payerPubkey,
+        tokenXProgram,
+        tokenYProgram,
         rent: SYSVAR_RENT_PUBKEY,
         systemProgram: SystemProgram.programId
       }
@@ -167,6 +175,18 @@ export class Market {
     }
   }
 
+  async getTokenPrograms(pair: Pair) {
+    const [tokenX, tokenY] = await Promise.all([
+      this.connection.getAccountInfo(pair.tokenX),
+      this.connection.getAccountInfo(pair.tokenY)
+    ])
+
+    return {
+      tokenXProgram: tokenX?.owner ?? TOKEN_PROGRAM_ID,
+      tokenYProgram: tokenY?.owner ?? TOKEN_PROGRAM_ID
+    }
+  }
+
   async getFeeTier(feeTier: FeeTier) {
     const { address } = await this.getFeeTierAddress(feeTier)
     return (await this.program.account.feeTier.fetch(address)) as FeeTierStructure
@@ -679,6 +699,7 @@ export class Market {
     )
     const { positionListAddress } = await this.getPositionListAddress(owner)
     const poolAddress = await pair.getAddress(this.program.programId)
+    const { tokenXProgram, tokenYProgram } = await this.getTokenPrograms(pair)
 
     return this.program.instruction.createPosition(
       lowerTickIndex,
@@ -704,7 +725,8 @@ export class Market {
           reserveX: state.tokenXReserve,
           reserveY: state.tokenYReserve,
           programAuthority: this.programAuthority,
-          tokenProgram: TOKEN_PROGRAM_ID,
+          tokenXProgram,
+          tokenYProgram,
           rent: SYSVAR_RENT_PUBKEY,
           systemProgram: SystemProgram.programId
         }
@@ -773,6 +795,154 @@ export class Market {
     await signAndSend(tx, [signer], this.connection)
   }
 
+  // ticks and position list have to exist beforehand
+  async initPositionSingleSidedInstruction({
+    pair,
+    owner,
+    userTokenX,
+    userTokenY,
+    lowerTick,
+    upperTick,
+    amount,
+    xToY,
+    knownPrice,
+    slippage
+  }: InitPositionSingleSided) {
+    const state = await this.getPool(pair)
+    owner = owner ?? this.wallet.publicKey
+
+    const slippageLimitLower = calculatePriceAfterSlippage(knownPrice, slippage, false)
+    const slippageLimitUpper = calculatePriceAfterSlippage(knownPrice, slippage, true)
+
+    const upperTickIndex = upperTick !== Infinity ? upperTick : getMaxTick(pair.tickSpacing)
+    const lowerTickIndex = lowerTick !== -Infinity ? lowerTick : getMinTick(pair.tickSpacing)
+
+    const { tickAddress: lowerTickAddress } = await this.getTickAddress(pair, lowerTickIndex)
+    const { tickAddress: upperTickAddress } = await this.getTickAddress(pair, upperTickIndex)
+    const { positionAddress } = await this.getPositionAddress(
+      owner,
+      (await this.getPositionList(owner)).head
+    )
+    const { positionListAddress } = await this.getPositionListAddress(owner)
+    const poolAddress = await pair.getAddress(this.program.programId)
+    const { tokenXProgram, tokenYProgram } = await this.getTokenPrograms(pair)
+
+    return this.program.instruction.createPositionSingleSided(
+      lowerTickIndex,
+      upperTickIndex,
+      amount,
+      xToY,
+      slippageLimitLower,
+      slippageLimitUpper,
+      {
+        accounts: {
+          state: this.stateAddress,
+          pool: poolAddress,
+          positionList: positionListAddress,
+          position: positionAddress,
+          tickmap: state.tickmap,
+          owner,
+          payer: owner,
+          lowerTick: lowerTickAddress,
+          upperTick: upperTickAddress,
+          tokenX: pair.tokenX,
+          tokenY: pair.tokenY,
+          accountX: userTokenX,
+          accountY: userTokenY,
+          reserveX: state.tokenXReserve,
+          reserveY: state.tokenYReserve,
+          programAuthority: this.programAuthority,
+          tokenXProgram,
+          tokenYProgram,
+          rent: SYSVAR_RENT_PUBKEY,
+          systemProgram: SystemProgram.programId
+        }
+      }
+    )
+  }
+
+  async initPositionSingleSidedTx(initPosition: InitPositionSingleSided) {
+    // finding the swapped amount simulates the swap several times
+    const setCuIx = computeUnitsInstruction(1_400_000, initPosition.owner ?? this.wallet.publicKey)
+    const positionIx = await this.initPositionSingleSidedInstruction(initPosition)
+    return new Transaction().add(setCuIx).add(positionIx)
+  }
+
+  async initPositionSingleSided(initPosition: InitPositionSingleSided, signer: Keypair) {
+    const tx = await this.initPositionSingleSidedTx(initPosition)
+
+    await signAndSend(tx, [signer], this.connection)
+  }
+
+  async initPositionByAmountsInstruction({
+    pair,
+    owner,
+    userTokenX,
+    userTokenY,
+    lowerTick,
+    upperTick,
+    maxAmountX,
+    maxAmountY
+  }: InitPositionByAmounts) {
+    const state = await this.getPool(pair)
+    owner = owner ?? this.wallet.publicKey
+
+    const upperTickIndex = upperTick !== Infinity ? upperTick : getMaxTick(pair.tickSpacing)
+    const lowerTickIndex = lowerTick !== -Infinity ? lowerTick : getMinTick(pair.tickSpacing)
+
+    const { tickAddress: lowerTickAddress } = await this.getTickAddress(pair, lowerTickIndex)
+    const { tickAddress: upperTickAddress } = await this.getTickAddress(pair, upperTickIndex)
+    const { positionAddress } = await this.getPositionAddress(
+      owner,
+      (await this.getPositionList(owner)).head
+    )
+    const { positionListAddress } = await this.getPositionListAddress(owner)
+    const poolAddress = await pair.getAddress(this.program.programId)
+    const { tokenXProgram, tokenYProgram } = await this.getTokenPrograms(pair)
+
+    return this.program.instruction.createPositionByAmounts(
+      lowerTickIndex,
+      upperTickIndex,
+      maxAmountX,
+      maxAmountY,
+      {
+        accounts: {
+          state: this.stateAddress,
+          pool: poolAddress,
+          positionList: positionListAddress,
+          position: positionAddress,
+          tickmap: state.tickmap,
+          owner,
+          payer: owner,
+          lowerTick: lowerTickAddress,
+          upperTick: upperTickAddress,
+          tokenX: pair.tokenX,
+          tokenY: pair.tokenY,
+          accountX: userTokenX,
+          accountY: userTokenY,
+          reserveX: state.tokenXReserve,
+          reserveY: state.tokenYReserve,
+          programAuthority: this.programAuthority,
+          tokenXProgram,
+          tokenYProgram,
+          rent: SYSVAR_RENT_PUBKEY,
+          systemProgram: SystemProgram.programId
+        }
+      }
+    )
+  }
+
+  async initPositionByAmountsTx(initPosition: InitPositionByAmounts) {
+    const positionIx = await this.initPositionByAmountsInstruction(initPosition)
+    return new Transaction().add(positionIx)
+  }
+
+  async initPositionByAmounts(initPosition: InitPositionByAmounts, signer: Keypair) {
+    const tx = await this.initPositionByAmountsTx(initPosition)
+
+    await signAndSend(tx, [signer], this.connection)
+  }
+
   async initPoolAndPositionTx(
     {
       pair,
@@ -799,6 +969,8 @@ export class Market {
 
     const [poolAddress] = await pair.getAddressAndBump(this.program.programId)
     const { address: feeTierAddress } = await this.getFeeTierAddress(pair.feeTier)
+    const { tokenXProgram, tokenYProgram } = await this.getTokenPrograms(pair)
+    const { address: defaultProtocolFeeAddress } = await this.getDefaultProtocolFeeAddress()
 
     const { positionListAddress } = await this.getPositionListAddress(payerPubkey)
     const { tickAddress } = await this.getTickAddress(pair, lowerTick)
@@ -832,14 +1004,16 @@ export class Market {
             state: stateAddress,
             pool: poolAddress,
             feeTier: feeTierAddress,
+            defaultProtocolFee: defaultProtocolFeeAddress,
             tickmap: bitmapKeypair.publicKey,
             tokenX: pair.tokenX,
             tokenY: pair.tokenY,
             tokenXReserve: tokenXReserve.publicKey,
             tokenYReserve: tokenYReserve.publicKey,
             authority: this.programAuthority,
-            tokenProgram: TOKEN_PROGRAM_ID,
             payer: payerPubkey,
+            tokenXProgram,
+            tokenYProgram,
             rent: SYSVAR_RENT_PUBKEY,
             systemProgram: SystemProgram.programId
           }
@@ -903,7 +1077,8 @@ export class Market {
             reserveX: tokenXReserve.publicKey,
             reserveY: tokenYReserve.publicKey,
             programAuthority: this.programAuthority,
-            tokenProgram: TOKEN_PROGRAM_ID,
+            tokenXProgram,
+            tokenYProgram,
             rent: SYSVAR_RENT_PUBKEY,
             systemProgram: SystemProgram.programId
           }
@@ -933,14 +1108,17 @@ export class Market {
       accountX,
       accountY,
       byAmountIn,
-      referralAccount
+      referralAccount,
+      crossLimit,
+      recipient
     } = swap
     const owner = swap.owner ?? this.wallet.publicKey
 
-    const [pool, tickmap, poolAddress] = await Promise.all([
+    const [pool, tickmap, poolAddress, { tokenXProgram, tokenYProgram }] = await Promise.all([
       this.getPool(pair),
       this.getTickmap(pair),
-      pair.getAddress(this.program.programId)
+      pair.getAddress(this.program.programId),
+      this.getTokenPrograms(pair)
     ])
 
     const priceLimit = calculatePriceAfterSlippage(estimatedPriceAfterSwap, slippage, !xToY).v
@@ -973,6 +1151,9 @@ export class Market {
     if (referralAccount) {
       remainingAccounts.unshift(referralAccount)
     }
+    if (recipient) {
+      remainingAccounts.push(recipient)
+    }
 
     // trunk-ignore(eslint)
     const ra: Array<{ pubkey: PublicKey; isWritable: boolean; isSigner: boolean }> =
@@ -982,21 +1163,39 @@ export class Market {
 
     const tx: Transaction = new Transaction()
 
-    const swapIx = this.program.instruction.swap(xToY, amount, byAmountIn, priceLimit, {
+    const ctx = {
       remainingAccounts: ra,
       accounts: {
         state: this.stateAddress,
         pool: poolAddress,
         tickmap: pool.tickmap,
+        tokenX: pool.tokenX,
+        tokenY: pool.tokenY,
         reserveX: pool.tokenXReserve,
         reserveY: pool.tokenYReserve,
         owner,
         accountX,
         accountY,
         programAuthority: this.programAuthority,
-        tokenProgram: TOKEN_PROGRAM_ID
+        tokenXProgram,
+        tokenYProgram
       }
-    })
+    }
+    let swapIx: TransactionInstruction
+    if (recipient) {
+      swapIx = this.program.instruction.swapTo(xToY, amount, byAmountIn, priceLimit, recipient, ctx)
+    } else if (crossLimit) {
+      swapIx = this.program.instruction.swapWithCrossLimit(
+        xToY,
+        amount,
+        byAmountIn,
+        priceLimit,
+        crossLimit,
+        ctx
+      )
+    } else {
+      swapIx = this.program.instruction.swap(xToY, amount, byAmountIn, priceLimit, ctx)
+    }
     tx.add(swapIx)
     return tx
   }
@@ -1013,6 +1212,60 @@ export class Market {
     return await signAndSend(tx, [signer], this.connection)
   }
 
+  async flashLoanAccounts({ pair, accountX, accountY, borrower }: FlashLoan) {
+    const pool = await this.getPool(pair)
+    const { tokenXProgram, tokenYProgram } = await this.getTokenPrograms(pair)
+
+    return {
+      state: this.stateAddress,
+      pool: await pair.getAddress(this.program.programId),
+      tokenX: pool.tokenX,
+      tokenY: pool.tokenY,
+      accountX,
+      accountY,
+      reserveX: pool.tokenXReserve,
+      reserveY: pool.tokenYReserve,
+      borrower: borrower ?? this.wallet.publicKey,
+      programAuthority: this.programAuthority,
+      tokenXProgram,
+      tokenYProgram
+    }
+  }
+
+  async flashLoanInstruction(flashLoan: FlashLoan) {
+    const { amountX, amountY } = flashLoan
+    const accounts = await this.flashLoanAccounts(flashLoan)
+
+    return this.program.instruction.flashLoan(amountX, amountY, {
+      accounts: { ...accounts, instructions: SYSVAR_INSTRUCTIONS_PUBKEY }
+    })
+  }
+
+  async flashRepayInstruction(flashLoan: FlashLoan) {
+    const { amountX, amountY } = flashLoan
+    const accounts = await this.flashLoanAccounts(flashLoan)
+
+    return this.program.instruction.flashRepay(amountX, amountY, { accounts })
+  }
+
+  // borrowed tokens can be used by instructions placed between the loan and the repay
+  async flashLoanTransaction(flashLoan: FlashLoan, instructions: TransactionInstruction[] = []) {
+    const loanIx = await this.flashLoanInstruction(flashLoan)
+    const repayIx = await this.flashRepayInstruction(flashLoan)
+
+    return new Transaction().add(loanIx).add(...instructions).add(repayIx)
+  }
+
+  async flashLoan(
+    flashLoan: FlashLoan,
+    signer: Keypair,
+    instructions: TransactionInstruction[] = []
+  ) {
+    const tx = await this.flashLoanTransaction(flashLoan, instructions)
+
+    await signAndSend(tx, [signer], this.connection)
+  }
+
   async getReserveBalances(pair: Pair, tokenX: Token, tokenY: Token) {
     const state = await this.getPool(pair)
 
@@ -1039,6 +1292,7 @@ export class Market {
       pair,
       position.upperTickIndex
     )
+    const { tokenXProgram, tokenYProgram } = await this.getTokenPrograms(pair)
 
     return this.program.instruction.claimFee(
       index,
@@ -1059,7 +1313,8 @@ export class Market {
           reserveX: state.tokenXReserve,
           reserveY: state.tokenYReserve,
           programAuthority: this.programAuthority,
-          tokenProgram: TOKEN_PROGRAM_ID
+          tokenXProgram,
+          tokenYProgram
         }
       }
     )
@@ -1081,11 +1336,14 @@ export class Market {
     const admin = withdrawProtocolFee.admin ?? this.wallet.publicKey
 
     const pool = await this.getPool(pair)
+    const { tokenXProgram, tokenYProgram } = await this.getTokenPrograms(pair)
+    const { address: feeDistributionAddress } = await this.getFeeDistributionAddress()
 
     return this.program.instruction.withdrawProtocolFee({
       accounts: {
         state: this.stateAddress,
         pool: await pair.getAddress(this.program.programId),
+        feeDistribution: feeDistributionAddress,
         tokenX: pool.tokenX,
         tokenY: pool.tokenY,
         reserveX: pool.tokenXReserve,
@@ -1094,7 +1352,8 @@ export class Market {
         accountY,
         authority: admin,
         programAuthority: this.programAuthority,
-        tokenProgram: TOKEN_PROGRAM_ID
+        tokenXProgram,
+        tokenYProgram
       }
     })
   }
@@ -1134,6 +1393,7 @@ export class Market {
       pair,
       position.upperTickIndex
     )
+    const { tokenXProgram, tokenYProgram } = await this.getTokenPrograms(pair)
 
     return this.program.instruction.removePosition(
       index,
@@ -1157,7 +1417,8 @@ export class Market {
           reserveX: state.tokenXReserve,
           reserveY: state.tokenYReserve,
           programAuthority: this.programAuthority,
-          tokenProgram: TOKEN_PROGRAM_ID
+          tokenXProgram,
+          tokenYProgram
         }
       }
     )
@@ -1174,6 +1435,190 @@ export class Market {
     await signAndSend(tx, [signer], this.connection)
   }
 
+  async getWrappedSolAddress(owner: PublicKey) {
+    const [address, bump] = await PublicKey.findProgramAddress(
+      [Buffer.from(utils.bytes.utf8.encode(NATIVE_SOL_SEED)), owner.toBuffer()],
+      this.program.programId
+    )
+
+    return {
+      address,
+      bump
+    }
+  }
+
+  // temporary wrapped SOL account takes place of the owner's token account on the SOL side
+  async getNativeSolAccounts(pair: Pair, owner: PublicKey) {
+    const { address: wrappedSol } = await this.getWrappedSolAddress(owner)
+    if (!pair.tokenX.equals(NATIVE_MINT) && !pair.tokenY.equals(NATIVE_MINT)) {
+      throw new Error('Pair does not contain native SOL')
+    }
+
+    return {
+      wrappedSol,
+      accounts: {
+        wrappedSol,
+        nativeMint: NATIVE_MINT,
+        owner,
+        invariantProgram: this.program.programId,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        rent: SYSVAR_RENT_PUBKEY,
+        systemProgram: SystemProgram.programId
+      }
+    }
+  }
+
+  async swapNativeInstruction(swap: Swap, nativeAmount: BN) {
+    const owner = swap.owner ?? this.wallet.publicKey
+    const { wrappedSol, accounts } = await this.getNativeSolAccounts(swap.pair, owner)
+    const { xToY, amount, byAmountIn, estimatedPriceAfterSwap, slippage } = swap
+
+    const swapTx = await this.swapInstruction({
+      ...swap,
+      owner,
+      accountX: swap.pair.tokenX.equals(NATIVE_MINT) ? wrappedSol : swap.accountX,
+      accountY: swap.pair.tokenY.equals(NATIVE_MINT) ? wrappedSol : swap.accountY
+    })
+    const priceLimit = calculatePriceAfterSlippage(estimatedPriceAfterSwap, slippage, !xToY).v
+
+    return this.program.instruction.swapNative(
+      nativeAmount,
+      xToY,
+      amount,
+      byAmountIn,
+      priceLimit,
+      {
+        accounts,
+        remainingAccounts: swapTx.instructions[0].keys
+      }
+    )
+  }
+
+  async swapNativeTransaction(swap: Swap, nativeAmount: BN) {
+    const setCuIx = computeUnitsInstruction(1_400_000, swap.owner ?? this.wallet.publicKey)
+    const swapIx = await this.swapNativeInstruction(swap, nativeAmount)
+    return new Transaction().add(setCuIx).add(swapIx)
+  }
+
+  async swapNative(swap: Swap, nativeAmount: BN, signer: Keypair) {
+    const tx = await this.swapNativeTransaction(swap, nativeAmount)
+
+    return await signAndSend(tx, [signer], this.connection)
+  }
+
+  // ticks and position list have to exist beforehand
+  async initPositionNativeInstruction(initPosition: InitPosition, nativeAmount: BN) {
+    const owner = initPosition.owner ?? this.wallet.publicKey
+    const { wrappedSol, accounts } = await this.getNativeSolAccounts(initPosition.pair, owner)
+    const { pair, userTokenX, userTokenY, lowerTick, upperTick, knownPrice, slippage } =
+      initPosition
+
+    const positionIx = await this.initPositionInstruction({
+      ...initPosition,
+      owner,
+      userTokenX: pair.tokenX.equals(NATIVE_MINT) ? wrappedSol : userTokenX,
+      userTokenY: pair.tokenY.equals(NATIVE_MINT) ? wrappedSol : userTokenY
+    })
+    const slippageLimitLower = calculatePriceAfterSlippage(knownPrice, slippage, false)
+    const slippageLimitUpper = calculatePriceAfterSlippage(knownPrice, slippage, true)
+    const upperTickIndex = upperTick !== Infinity ? upperTick : getMaxTick(pair.tickSpacing)
+    const lowerTickIndex = lowerTick !== -Infinity ? lowerTick : getMinTick(pair.tickSpacing)
+
+    return this.program.instruction.createPositionNative(
+      nativeAmount,
+      lowerTickIndex,
+      upperTickIndex,
+      initPosition.liquidityDelta,
+      slippageLimitLower,
+      slippageLimitUpper,
+      {
+        accounts,
+        remainingAccounts: positionIx.keys
+      }
+    )
+  }
+
+  async initPositionNativeTransaction(initPosition: InitPosition, nativeAmount: BN) {
+    const ix = await this.initPositionNativeInstruction(initPosition, nativeAmount)
+    return new Transaction().add(ix)
+  }
+
+  async initPositionNative(initPosition: InitPosition, nativeAmount: BN, signer: Keypair) {
+    const tx = await this.initPositionNativeTransaction(initPosition, nativeAmount)
+
+    await signAndSend(tx, [signer], this.connection)
+  }
+
+  async removePositionNativeInstruction(removePosition: RemovePosition) {
+    const owner = removePosition.owner ?? this.wallet.publicKey
+    const { wrappedSol, accounts } = await this.getNativeSolAccounts(removePosition.pair, owner)
+    const { pair, index, userTokenX, userTokenY } = removePosition
+
+    const position = await this.getPosition(owner, index)
+    const removeIx = await this.removePositionInstruction({
+      ...removePosition,
+      owner,
+      userTokenX: pair.tokenX.equals(NATIVE_MINT) ? wrappedSol : userTokenX,
+      userTokenY: pair.tokenY.equals(NATIVE_MINT) ? wrappedSol : userTokenY
+    })
+
+    return this.program.instruction.removePositionNative(
+      index,
+      position.lowerTickIndex,
+      position.upperTickIndex,
+      {
+        accounts,
+        remainingAccounts: removeIx.keys
+      }
+    )
+  }
+
+  async removePositionNativeTransaction(removePosition: RemovePosition) {
+    const ix = await this.removePositionNativeInstruction(removePosition)
+    return new Transaction().add(ix)
+  }
+
+  async removePositionNative(removePosition: RemovePosition, signer: Keypair) {
+    const tx = await this.removePositionNativeTransaction(removePosition)
+
+    await signAndSend(tx, [signer], this.connection)
+  }
+
+  async claimFeeNativeInstruction(claimFee: ClaimFee) {
+    const owner = claimFee.owner ?? this.wallet.publicKey
+    const { wrappedSol, accounts } = await this.getNativeSolAccounts(claimFee.pair, owner)
+    const { pair, index, userTokenX, userTokenY } = claimFee
+
+    const position = await this.getPosition(owner, index)
+    const claimIx = await this.claimFeeInstruction({
+      ...claimFee,
+      owner,
+      userTokenX: pair.tokenX.equals(NATIVE_MINT) ? wrappedSol : userTokenX,
+      userTokenY: pair.tokenY.equals(NATIVE_MINT) ? wrappedSol : userTokenY
+    })
+
+    return this.program.instruction.claimFeeNative(
+      index,
+      position.lowerTickIndex,
+      position.upperTickIndex,
+      {
+        accounts,
+        remainingAccounts: claimIx.keys
+      }
+    )
+  }
+
+  async claimFeeNativeTransaction(claimFee: ClaimFee) {
+    const ix = await this.claimFeeNativeInstruction(claimFee)
+    return new Transaction().add(ix)
+  }
+
+  async claimFeeNative(claimFee: ClaimFee, signer: Keypair) {
+    const tx = await this.claimFeeNativeTransaction(claimFee)
+
+    await signAndSend(tx, [signer], this.connection)
+  }
+
   async transferPositionOwnershipInstruction(
     transferPositionOwnership: TransferPositionOwnership
   ): Promise<TransactionInstruction> {
@@ -1265,6 +1710,29 @@ export class Market {
     await signAndSend(tx, [signer], this.connection)
   }
 
+  async migratePositionInstruction({ owner, index, payer }: MigratePosition) {
+    const { positionAddress } = await this.getPositionAddress(owner, index)
+
+    return this.program.instruction.migratePosition({
+      accounts: {
+        position: positionAddress,
+        payer: payer ?? this.wallet.publicKey,
+        systemProgram: SystemProgram.programId
+      }
+    })
+  }
+
+  async migratePositionTransaction(migratePosition: MigratePosition) {
+    const ix = await this.migratePositionInstruction(migratePosition)
+    return new Transaction().add(ix)
+  }
+
+  async migratePosition(migratePosition: MigratePosition, signer: Keypair) {
+    const tx = await this.migratePositionTransaction(migratePosition)
+
+    await signAndSend(tx, [signer], this.connection)
+  }
+
   async initializeOracle({ pair, payer }: InitializeOracle) {
     const oracleKeypair = Keypair.generate()
     const poolAddress = await pair.getAddress(this.program.programId)
@@ -1319,6 +1787,83 @@ export class Market {
     await signAndSend(tx, [signer], this.connection)
   }
 
+  async getDefaultProtocolFeeAddress() {
+    const [address, bump] = await PublicKey.findProgramAddress(
+      [Buffer.from(utils.bytes.utf8.encode(DEFAULT_PROTOCOL_FEE_SEED))],
+      this.program.programId
+    )
+
+    return {
+      address,
+      bump
+    }
+  }
+
+  async getDefaultProtocolFee() {
+    const { address } = await this.getDefaultProtocolFeeAddress()
+    return (await this.program.account.defaultProtocolFee.fetch(address)) as DefaultProtocolFee
+  }
+
+  async createDefaultProtocolFeeInstruction(changeDefaultProtocolFee: ChangeDefaultProtocolFee) {
+    const { protocolFee } = changeDefaultProtocolFee
+    const admin = changeDefaultProtocolFee.admin ?? this.wallet.publicKey
+    const { address: defaultProtocolFeeAddress } = await this.getDefaultProtocolFeeAddress()
+
+    return this.program.instruction.createDefaultProtocolFee(protocolFee, {
+      accounts: {
+        defaultProtocolFee: defaultProtocolFeeAddress,
+        state: this.stateAddress,
+        admin,
+        rent: SYSVAR_RENT_PUBKEY,
+        systemProgram: SystemProgram.programId
+      }
+    })
+  }
+
+  async createDefaultProtocolFeeTransaction(changeDefaultProtocolFee: ChangeDefaultProtocolFee) {
+    const ix = await this.createDefaultProtocolFeeInstruction(changeDefaultProtocolFee)
+    return new Transaction().add(ix)
+  }
+
+  // Admin function
+  async createDefaultProtocolFee(
+    changeDefaultProtocolFee: ChangeDefaultProtocolFee,
+    signer: Keypair
+  ) {
+    const tx = await this.createDefaultProtocolFeeTransaction(changeDefaultProtocolFee)
+
+    await signAndSend(tx, [signer], this.connection)
+  }
+
+  async changeDefaultProtocolFeeInstruction(changeDefaultProtocolFee: ChangeDefaultProtocolFee) {
+    const { protocolFee } = changeDefaultProtocolFee
+    const admin = changeDefaultProtocolFee.admin ?? this.wallet.publicKey
+    const { address: defaultProtocolFeeAddress } = await this.getDefaultProtocolFeeAddress()
+
+    return this.program.instruction.changeDefaultProtocolFee(protocolFee, {
+      accounts: {
+        defaultProtocolFee: defaultProtocolFeeAddress,
+        state: this.stateAddress,
+        admin
+      }
+    })
+  }
+
+  async changeDefaultProtocolFeeTransaction(changeDefaultProtocolFee: ChangeDefaultProtocolFee) {
+    const ix = await this.changeDefaultProtocolFeeInstruction(changeDefaultProtocolFee)
+    return new Transaction().add(ix)
+  }
+
+  // Admin function
+  async changeDefaultProtocolFee(
+    changeDefaultProtocolFee: ChangeDefaultProtocolFee,
+    signer: Keypair
+  ) {
+    const tx = await this.changeDefaultProtocolFeeTransaction(changeDefaultProtocolFee)
+
+    await signAndSend(tx, [signer], this.connection)
+  }
+
   async changeFeeReceiverInstruction(changeFeeReceiver: ChangeFeeReceiver) {
     const { pair, feeReceiver } = changeFeeReceiver
     const adminPubkey = changeFeeReceiver.admin ?? this.wallet.publicKey
@@ -1349,6 +1894,111 @@ export class Market {
     await signAndSend(tx, [signer], this.connection)
   }
 
+  async getFeeDistributionAddress() {
+    const [address, bump] = await PublicKey.findProgramAddress(
+      [Buffer.from(utils.bytes.utf8.encode(FEE_DISTRIBUTION_SEED))],
+      this.program.programId
+    )
+
+    return {
+      address,
+      bump
+    }
+  }
+
+  async getFeeDistribution() {
+    const { address } = await this.getFeeDistributionAddress()
+    return (await this.program.account.feeDistribution.fetch(address)) as FeeDistribution
+  }
+
+  async createFeeDistributionInstruction(createFeeDistribution: CreateFeeDistribution) {
+    const { owners, weights } = createFeeDistribution
+    const adminPubkey = createFeeDistribution.admin ?? this.wallet.publicKey
+    const { address: feeDistributionAddress } = await this.getFeeDistributionAddress()
+
+    return this.program.instruction.createFeeDistribution(owners, weights, {
+      accounts: {
+        feeDistribution: feeDistributionAddress,
+        state: this.stateAddress,
+        admin: adminPubkey,
+        rent: SYSVAR_RENT_PUBKEY,
+        systemProgram: SystemProgram.programId
+      }
+    })
+  }
+
+  async createFeeDistributionTransaction(createFeeDistribution: CreateFeeDistribution) {
+    const ix = await this.createFeeDistributionInstruction(createFeeDistribution)
+
+    return new Transaction().add(ix)
+  }
+
+  // Admin function
+  async createFeeDistribution(createFeeDistribution: CreateFeeDistribution, signer: Keypair) {
+    const tx = await this.createFeeDistributionTransaction(createFeeDistribution)
+
+    await signAndSend(tx, [signer], this.connection)
+  }
+
+  async distributeProtocolFeeInstruction({ pair, recipientAccounts }: DistributeProtocolFee) {
+    const pool = await this.getPool(pair)
+    const { tokenXProgram, tokenYProgram } = await this.getTokenPrograms(pair)
+    const { address: feeDistributionAddress } = await this.getFeeDistributionAddress()
+
+    const remainingAccounts: Array<{ pubkey: PublicKey; isWritable: boolean; isSigner: boolean }> =
+      []
+    for (const { accountX, accountY } of recipientAccounts) {
+      remainingAccounts.push({ pubkey: accountX, isWritable: true, isSigner: false })
+      remainingAccounts.push({ pubkey: accountY, isWritable: true, isSigner: false })
+    }
+
+    return this.program.instruction.distributeProtocolFee({
+      remainingAccounts,
+      accounts: {
+        state: this.stateAddress,
+        feeDistribution: feeDistributionAddress,
+        pool: await pair.getAddress(this.program.programId),
+        tokenX: pool.tokenX,
+        tokenY: pool.tokenY,
+        reserveX: pool.tokenXReserve,
+        reserveY: pool.tokenYReserve,
+        programAuthority: this.programAuthority,
+        tokenXProgram,
+        tokenYProgram
+      }
+    })
+  }
+
+  async distributeProtocolFeeTransaction(distributeProtocolFee: DistributeProtocolFee) {
+    const ix = await this.distributeProtocolFeeInstruction(distributeProtocolFee)
+
+    return new Transaction().add(ix)
+  }
+
+  async distributeProtocolFee(distributeProtocolFee: DistributeProtocolFee, signer: Keypair) {
+    const tx = await this.distributeProtocolFeeTransaction(distributeProtocolFee)
+
+    await signAndSend(tx, [signer], this.connection)
+  }
+
+  // events emitted by the program in logs of a transaction
+  parseEvents(logs: string[]): InvariantEvent[] {
+    const events: InvariantEvent[] = []
+    const parser = new EventParser(this.program.programId, this.program.coder)
+    parser.parseLogs(logs, event => events.push(event as InvariantEvent))
+
+    return events
+  }
+
+  async getTransactionEvents(signature: string) {
+    await this.connection.confirmTransaction(signature, 'confirmed')
+    const transaction = await this.connection.getTransaction(signature, {
+      commitment: 'confirmed'
+    })
+
+    return this.parseEvents(transaction?.meta?.logMessages ?? [])
+  }
+
   async getWholeLiquidity(pair: Pair) {
     const poolPublicKey = await pair.getAddress(this.program.programId)
     const positions: Position[] = (
@@ -1532,6 +2182,7 @@ export interface Position {
   tokensOwedX: Decimal
   tokensOwedY: Decimal
   bump: number
+  stakeCount: number
 }
 
 export interface PositionStructure {
@@ -1578,6 +2229,100 @@ export interface InitPosition {
   slippage: Decimal
 }
 
+export interface InitPositionSingleSided {
+  pair: Pair
+  owner?: PublicKey
+  userTokenX: PublicKey
+  userTokenY: PublicKey
+  lowerTick: number
+  upperTick: number
+  amount: BN
+  xToY: boolean // whether token x is deposited and partially swapped to y
+  knownPrice: Decimal
+  slippage: Decimal
+}
+
+export interface SwapEvent {
+  pool: PublicKey
+  swapper: PublicKey
+  xToY: boolean
+  amountIn: BN
+  amountOut: BN
+  fee: BN
+  referralFee: BN
+  priceBefore: Decimal // sqrt price before swap
+  priceAfter: Decimal
+  currentTickIndex: number
+  liquidity: Decimal
+}
+
+export interface TickCrossedEvent {
+  pool: PublicKey
+  index: number
+  xToY: boolean
+  liquidity: Decimal // pool liquidity after crossing
+}
+
+export interface PositionCreatedEvent {
+  owner: PublicKey
+  pool: PublicKey
+  id: BN
+  liquidity: Decimal
+  lowerTick: number
+  upperTick: number
+  amountX: BN
+  amountY: BN
+  currentSqrtPrice: Decimal
+}
+
+export interface PositionRemovedEvent {
+  owner: PublicKey
+  pool: PublicKey
+  id: BN
+  liquidity: Decimal
+  lowerTick: number
+  upperTick: number
+  amountX: BN // including claimed fee
+  amountY: BN
+  currentSqrtPrice: Decimal
+}
+
+export interface FeeClaimedEvent {
+  owner: PublicKey
+  pool: PublicKey
+  id: BN
+  amountX: BN
+  amountY: BN
+}
+
+export interface FlashLoanEvent {
+  pool: PublicKey
+  borrower: PublicKey
+  amountX: BN
+  amountY: BN
+  feeX: BN
+  feeY: BN
+}
+
+export type InvariantEvent =
+  | { name: 'SwapEvent'; data: SwapEvent }
+  | { name: 'TickCrossedEvent'; data: TickCrossedEvent }
+  | { name: 'PositionCreatedEvent'; data: PositionCreatedEvent }
+  | { name: 'PositionRemovedEvent'; data: PositionRemovedEvent }
+  | { name: 'FeeClaimedEvent'; data: FeeClaimedEvent }
+  | { name: 'FlashLoanEvent'; data: FlashLoanEvent }
+
+export interface InitPositionByAmounts {
+  pair: Pair
+  owner?: PublicKey
+  userTokenX: PublicKey
+  userTokenY: PublicKey
+  lowerTick: number
+  upperTick: number
+  maxAmountX: BN // highest amount of token x taken from the owner, transfer fee included
+  maxAmountY: BN
+}
+
 export interface InitPoolAndPosition extends InitPosition {
   initTick?: number
 }
@@ -1606,6 +2351,14 @@ export interface ClaimFee {
   userTokenY: PublicKey
   index: number
 }
+export interface FlashLoan {
+  pair: Pair
+  borrower?: PublicKey
+  accountX: PublicKey
+  accountY: PublicKey
+  amountX: BN
+  amountY: BN
+}
 export interface Swap {
   pair: Pair
   owner?: PublicKey
@@ -1617,6 +2370,13 @@ export interface Swap {
   accountY: PublicKey
   byAmountIn: boolean
   referralAccount?: PublicKey
+  crossLimit?: CrossLimit
+  recipient?: PublicKey // token account receiving the output instead of the owner's account
+}
+export interface CrossLimit {
+  maxInitialized: number
+  maxVirtual: number
+  partialFill: boolean
 }
 export interface UpdateSecondsPerLiquidity {
   pair: Pair
@@ -1632,6 +2392,14 @@ export interface ChangeProtocolFee {
   admin?: PublicKey
   protocolFee: Decimal
 }
+export interface ChangeDefaultProtocolFee {
+  admin?: PublicKey
+  protocolFee: Decimal
+}
+export interface DefaultProtocolFee {
+  protocolFee: Decimal
+  bump: number
+}
 export interface CreateFeeTier {
   feeTier: FeeTier
   admin?: PublicKey
@@ -1660,6 +2428,12 @@ export interface TransferPositionOwnership {
   index: number
 }
 
+export interface MigratePosition {
+  owner: PublicKey
+  index: number
+  payer?: PublicKey
+}
+
 export interface InitializeOracle {
   pair: Pair
   payer: Keypair
@@ -1671,6 +2445,29 @@ export interface ChangeFeeReceiver {
   feeReceiver: PublicKey
 }
 
+export interface CreateFeeDistribution {
+  owners: PublicKey[]
+  weights: number[] // basis points summing up to 10000
+  admin?: PublicKey
+}
+
+export interface DistributeProtocolFee {
+  pair: Pair
+  // token accounts of every recipient in order of the distribution
+  recipientAccounts: Array<{ accountX: PublicKey; accountY: PublicKey }>
+}
+
+export interface FeeRecipient {
+  owner: PublicKey
+  weight: number
+}
+
+export interface FeeDistribution {
+  recipients: FeeRecipient[]
+  count: number
+  bump: number
+}
+
 export interface PositionInitData {
   lowerTick: number
   upperTick: number
@@ -15,7 +15,6 @@ import {
 export const TICK_LIMIT = 44_364
 export const MAX_TICK = 221_818
 export const MIN_TICK = -MAX_TICK
-export const TICK_SEARCH_RANGE = 256
 
 export const U64_MAX = new BN('18446744073709551615')
 
@@ -1,7 +1,8 @@
+import { BN } from '@project-serum/anchor'
 import { assert } from 'chai'
-import { TICK_LIMIT } from '.'
 import { Decimal } from './market'
-import { calculatePriceSqrt, TICK_SEARCH_RANGE } from './math'
+import { calculatePriceSqrt } from './math'
+import { getSearchLimit } from './tickmap'
 
 export const getTickFromPrice = (
   currentTick: number,
@@ -14,7 +15,7 @@ export const getTickFromPrice = (
   if (xToY) {
     return priceToTickInRange(
       price,
-      Math.max(-TICK_LIMIT, currentTick - TICK_SEARCH_RANGE),
+      getSearchLimit(new BN(tickSpacing), false).toNumber(),
       currentTick,
       tickSpacing
     )
@@ -22,7 +23,7 @@ export const getTickFromPrice = (
     return priceToTickInRange(
       price,
       currentTick,
-      Math.min(TICK_LIMIT, currentTick + TICK_SEARCH_RANGE),
+      getSearchLimit(new BN(tickSpacing), true).toNumber(),
       tickSpacing
     )
   }
@@ -1,26 +1,24 @@
 import { BN } from '@project-serum/anchor'
-import { MAX_TICK, TICK_LIMIT, TICK_SEARCH_RANGE } from '.'
+import { MAX_TICK, TICK_LIMIT } from '.'
 import { Tickmap, TickPosition } from './market'
 
 export interface TickmapChange {
   [index: number]: 'added' | 'removed'
 }
 
-export const getSearchLimit = (currentTickIndex: BN, tickSpacing: BN, up: boolean): BN => {
-  const index = currentTickIndex.div(tickSpacing)
+// search covers the whole bitmap, limited only by its size and by the price range
+export const getSearchLimit = (tickSpacing: BN, up: boolean): BN => {
   let limit: BN = new BN(0)
   if (up) {
     const arrayLimit = new BN(TICK_LIMIT).subn(1)
-    const rangeLimit = index.add(new BN(TICK_SEARCH_RANGE))
     const priceLimit = new BN(MAX_TICK).div(tickSpacing)
 
-    limit = BN.min(BN.min(arrayLimit, rangeLimit), priceLimit)
+    limit = BN.min(arrayLimit, priceLimit)
   } else {
     const arrayLimit = new BN(-TICK_LIMIT).addn(1)
-    const rangeLimit = index.sub(new BN(TICK_SEARCH_RANGE))
     const priceLimit = new BN(-MAX_TICK).div(tickSpacing)
 
-    limit = BN.max(BN.max(arrayLimit, rangeLimit), priceLimit)
+    limit = BN.max(arrayLimit, priceLimit)
   }
   return limit.mul(tickSpacing)
 }
@@ -30,7 +28,7 @@ export const getPreviousTick = (
   currentTickIndex: number,
   tickSpacing: number
 ): number | null => {
-  const limit = getSearchLimit(new BN(currentTickIndex), new BN(tickSpacing), false)
+  const limit = getSearchLimit(new BN(tickSpacing), false)
   let { byte, bit } = tickToPosition(new BN(currentTickIndex), new BN(tickSpacing))
   const { byte: limitingByte, bit: limitingBit } = tickToPosition(limit, new BN(tickSpacing))
 
@@ -64,7 +62,7 @@ export const getNextTick = (
   currentTickIndex: number,
   tickSpacing: number
 ): number | null => {
-  const limit: BN = getSearchLimit(new BN(currentTickIndex), new BN(tickSpacing), true)
+  const limit: BN = getSearchLimit(new BN(tickSpacing), true)
 
   let { byte, bit } = tickToPosition(new BN(currentTickIndex + tickSpacing), new BN(tickSpacing))
   const { byte: limitingByte, bit: limitingBit } = tickToPosition(
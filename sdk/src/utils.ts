@@ -57,6 +57,7 @@ export const FEE_DENOMINATOR = 10 ** FEE_DECIMAL
 export const U128MAX = new BN('340282366920938463463374607431768211455')
 export const CONCENTRATION_FACTOR = 1.00001526069123
 export const PROTOCOL_FEE: number = 0.01
+export const TOKEN_2022_PROGRAM_ID = new PublicKey('TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb')
 
 export enum ERRORS {
   SIGNATURE = 'Error: Signature verification failed',
@@ -96,7 +97,22 @@ export enum INVARIANT_ERRORS {
   INVALID_TICKMAP = '0x178b',
   INVALID_TICKMAP_OWNER = '0x178c',
   INVALID_LIST_OWNER = '0x178d',
-  INVALID_TICK_SPACING = '0x178e'
+  INVALID_TICK_SPACING = '0x178e',
+  TOO_MANY_TICK_CROSSES = '0x178f',
+  INVALID_SINGLE_SIDED_TOKEN = '0x1790',
+  MAX_AMOUNT_EXCEEDED = '0x1791',
+  INVALID_FEE_DISTRIBUTION = '0x1792',
+  FLASH_LOAN_NOT_REPAID = '0x1793',
+  FLASH_LOAN_CPI = '0x1794',
+  INVALID_TOKEN_PROGRAM = '0x1795',
+  UNSUPPORTED_MINT_EXTENSION = '0x1796',
+  POSITION_LOCKED = '0x1797',
+  TRANSFER_FEE_OVERFLOW = '0x1798',
+  LIQUIDITY_OVERFLOW = '0x1799',
+  FEE_DISTRIBUTION_EXISTS = '0x179a',
+  TOO_MANY_STAKES = '0x179b',
+  INVALID_POSITION_LAYOUT = '0x179c',
+  POSITION_NOT_LOCKED = '0x179d'
 }
 
 export interface SimulateSwapPrice {
@@ -453,7 +469,7 @@ export const getCloserLimit = (closerLimit: CloserLimit): CloserLimitResult => {
     sqrtPrice = calculatePriceSqrt(index)
     init = true
   } else {
-    index = getSearchLimit(new BN(currentTick), new BN(tickSpacing), !xToY).toNumber()
+    index = getSearchLimit(new BN(tickSpacing), !xToY).toNumber()
     sqrtPrice = calculatePriceSqrt(index as number)
     init = false
   }
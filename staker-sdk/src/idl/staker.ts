@@ -83,39 +83,60 @@ export type Staker = {
           "type": {
             "defined": "Seconds"
           }
+        },
+        {
+          "name": "band",
+          "type": {
+            "option": {
+              "defined": "TickBand"
+            }
+          }
+        },
+        {
+          "name": "vesting",
+          "type": {
+            "option": {
+              "defined": "Vesting"
+            }
+          }
         }
       ]
     },
     {
-      "name": "stake",
+      "name": "addRewardToken",
       "accounts": [
         {
-          "name": "userStake",
+          "name": "incentive",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "position",
-          "isMut": false,
-          "isSigner": false
+          "name": "incentiveTokenAccount",
+          "isMut": true,
+          "isSigner": true
         },
         {
-          "name": "incentive",
+          "name": "founderTokenAccount",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "owner",
+          "name": "founder",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "stakerAuthority",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "signer",
-          "isMut": true,
-          "isSigner": true
+          "name": "incentiveToken",
+          "isMut": false,
+          "isSigner": false
         },
         {
-          "name": "invariant",
+          "name": "tokenProgram",
           "isMut": false,
           "isSigner": false
         },
@@ -132,19 +153,41 @@ export type Staker = {
       ],
       "args": [
         {
-          "name": "index",
-          "type": "i32"
+          "name": "nonce",
+          "type": "u8"
+        },
+        {
+          "name": "reward",
+          "type": {
+            "defined": "TokenAmount"
+          }
         }
       ]
     },
     {
-      "name": "withdraw",
+      "name": "migrateIncentive",
       "accounts": [
         {
-          "name": "userStake",
+          "name": "incentive",
           "isMut": true,
           "isSigner": false
         },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "addIncentiveReward",
+      "accounts": [
         {
           "name": "incentive",
           "isMut": true,
@@ -156,25 +199,53 @@ export type Staker = {
           "isSigner": false
         },
         {
-          "name": "position",
-          "isMut": false,
+          "name": "funderTokenAccount",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "ownerTokenAccount",
+          "name": "funder",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "reward",
+          "type": {
+            "defined": "TokenAmount"
+          }
+        }
+      ]
+    },
+    {
+      "name": "extendIncentive",
+      "accounts": [
+        {
+          "name": "incentive",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "stakerAuthority",
-          "isMut": false,
+          "name": "incentiveTokenAccount",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "owner",
+          "name": "founderTokenAccount",
           "isMut": true,
           "isSigner": false
         },
+        {
+          "name": "founder",
+          "isMut": false,
+          "isSigner": true
+        },
         {
           "name": "tokenProgram",
           "isMut": false,
@@ -183,134 +254,593 @@ export type Staker = {
       ],
       "args": [
         {
-          "name": "index",
-          "type": "i32"
-        },
-        {
-          "name": "nonce",
-          "type": "u8"
+          "name": "endTime",
+          "type": {
+            "defined": "Seconds"
+          }
         }
       ]
     },
     {
-      "name": "endIncentive",
+      "name": "stake",
       "accounts": [
         {
-          "name": "incentive",
+          "name": "userStake",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "incentiveTokenAccount",
+          "name": "position",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "founderTokenAccount",
+          "name": "pool",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "incentiveToken",
+          "name": "lowerTick",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "stakerAuthority",
+          "name": "upperTick",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "founder",
+          "name": "tokenX",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenY",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "incentive",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "stakerAuthority",
           "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "signer",
+          "isMut": true,
           "isSigner": true
         },
         {
-          "name": "tokenProgram",
+          "name": "invariant",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
           "isMut": false,
           "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "nonce",
-          "type": "u8"
+          "name": "index",
+          "type": "i32"
         }
       ]
     },
     {
-      "name": "removeStake",
+      "name": "withdraw",
       "accounts": [
+        {
+          "name": "userStake",
+          "isMut": true,
+          "isSigner": false
+        },
         {
           "name": "incentive",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "userStake",
+          "name": "incentiveTokenAccount",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "founder",
-          "isMut": false,
-          "isSigner": true
-        }
-      ],
-      "args": []
-    },
-    {
-      "name": "closeStakeByOwner",
-      "accounts": [
+          "name": "position",
+          "isMut": true,
+          "isSigner": false
+        },
         {
-          "name": "incentive",
+          "name": "pool",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "userStake",
+          "name": "lowerTick",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "upperTick",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenX",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenY",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "ownerTokenAccount",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "position",
+          "name": "stakerAuthority",
           "isMut": false,
           "isSigner": false
         },
         {
           "name": "owner",
-          "isMut": false,
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "signer",
+          "isMut": true,
           "isSigner": true
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "invariant",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
         }
       ],
       "args": [
         {
           "name": "index",
           "type": "i32"
+        },
+        {
+          "name": "nonce",
+          "type": "u8"
         }
       ]
-    }
-  ],
-  "accounts": [
+    },
     {
-      "name": "incentive",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "founder",
-            "type": "publicKey"
-          },
-          {
-            "name": "tokenAccount",
-            "type": "publicKey"
-          },
-          {
-            "name": "totalRewardUnclaimed",
-            "type": {
-              "defined": "TokenAmount"
+      "name": "withdrawMany",
+      "accounts": [
+        {
+          "name": "position",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lowerTick",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "upperTick",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenX",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenY",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "stakerAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "signer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "invariant",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "index",
+          "type": "i32"
+        },
+        {
+          "name": "nonce",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "endIncentive",
+      "accounts": [
+        {
+          "name": "incentive",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "incentiveTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "founderTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "incentiveToken",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "stakerAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "founder",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "nonce",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "createVestingRecord",
+      "accounts": [
+        {
+          "name": "vestingRecord",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "incentive",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "signer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "releaseVested",
+      "accounts": [
+        {
+          "name": "vestingRecord",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "incentiveTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "ownerTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "stakerAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "nonce",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "cancelIncentive",
+      "accounts": [
+        {
+          "name": "incentive",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "incentiveTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "founderTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "stakerAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "founder",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "nonce",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "getPendingReward",
+      "accounts": [
+        {
+          "name": "incentive",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "userStake",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "position",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "lowerTick",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "upperTick",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "removeStake",
+      "accounts": [
+        {
+          "name": "incentive",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "userStake",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "position",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "founder",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "stakerAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "invariant",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "closeStakeByOwner",
+      "accounts": [
+        {
+          "name": "incentive",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "userStake",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "position",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "stakerAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "invariant",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "index",
+          "type": "i32"
+        }
+      ]
+    },
+    {
+      "name": "migrateStake",
+      "accounts": [
+        {
+          "name": "userStake",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    }
+  ],
+  "accounts": [
+    {
+      "name": "incentive",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "founder",
+            "type": "publicKey"
+          },
+          {
+            "name": "rewards",
+            "type": {
+              "array": [
+                {
+                  "defined": "IncentiveReward"
+                },
+                4
+              ]
             }
           },
+          {
+            "name": "rewardCount",
+            "type": "u8"
+          },
           {
             "name": "totalSecondsClaimed",
             "type": {
@@ -344,14 +874,62 @@ export type Staker = {
             "type": "publicKey"
           },
           {
-            "name": "nonce",
-            "type": "u8"
+            "name": "band",
+            "type": {
+              "defined": "TickBand"
+            }
+          },
+          {
+            "name": "vesting",
+            "type": {
+              "defined": "Vesting"
+            }
+          },
+          {
+            "name": "nonce",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "userStake",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "incentive",
+            "type": "publicKey"
+          },
+          {
+            "name": "position",
+            "type": "publicKey"
+          },
+          {
+            "name": "secondsPerLiquidityInitial",
+            "type": {
+              "defined": "SecondsPerLiquidity"
+            }
+          },
+          {
+            "name": "liquidity",
+            "type": {
+              "defined": "Liquidity"
+            }
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "locked",
+            "type": "bool"
           }
         ]
       }
     },
     {
-      "name": "userStake",
+      "name": "vestingRecord",
       "type": {
         "kind": "struct",
         "fields": [
@@ -360,19 +938,34 @@ export type Staker = {
             "type": "publicKey"
           },
           {
-            "name": "position",
+            "name": "owner",
             "type": "publicKey"
           },
           {
-            "name": "secondsPerLiquidityInitial",
+            "name": "rewards",
             "type": {
-              "defined": "SecondsPerLiquidity"
+              "array": [
+                {
+                  "defined": "VestedReward"
+                },
+                4
+              ]
             }
           },
           {
-            "name": "liquidity",
+            "name": "rewardCount",
+            "type": "u8"
+          },
+          {
+            "name": "vesting",
             "type": {
-              "defined": "Liquidity"
+              "defined": "Vesting"
+            }
+          },
+          {
+            "name": "startTime",
+            "type": {
+              "defined": "Seconds"
             }
           },
           {
@@ -431,6 +1024,160 @@ export type Staker = {
           }
         ]
       }
+    },
+    {
+      "name": "PendingRewardReturnData",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "rewards",
+            "type": {
+              "vec": "u64"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "IncentiveReward",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "tokenAccount",
+            "type": "publicKey"
+          },
+          {
+            "name": "totalRewardUnclaimed",
+            "type": {
+              "defined": "TokenAmount"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "TickBand",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "lowerTickIndex",
+            "type": "i32"
+          },
+          {
+            "name": "upperTickIndex",
+            "type": "i32"
+          },
+          {
+            "name": "maxWidth",
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "LegacyIncentive",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "founder",
+            "type": "publicKey"
+          },
+          {
+            "name": "tokenAccount",
+            "type": "publicKey"
+          },
+          {
+            "name": "totalRewardUnclaimed",
+            "type": {
+              "defined": "TokenAmount"
+            }
+          },
+          {
+            "name": "totalSecondsClaimed",
+            "type": {
+              "defined": "Seconds"
+            }
+          },
+          {
+            "name": "startTime",
+            "type": {
+              "defined": "Seconds"
+            }
+          },
+          {
+            "name": "endTime",
+            "type": {
+              "defined": "Seconds"
+            }
+          },
+          {
+            "name": "endClaimTime",
+            "type": {
+              "defined": "Seconds"
+            }
+          },
+          {
+            "name": "numOfStakes",
+            "type": "u64"
+          },
+          {
+            "name": "pool",
+            "type": "publicKey"
+          },
+          {
+            "name": "nonce",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "Vesting",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "cliff",
+            "type": {
+              "defined": "Seconds"
+            }
+          },
+          {
+            "name": "duration",
+            "type": {
+              "defined": "Seconds"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "VestedReward",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "tokenAccount",
+            "type": "publicKey"
+          },
+          {
+            "name": "credited",
+            "type": {
+              "defined": "TokenAmount"
+            }
+          },
+          {
+            "name": "released",
+            "type": {
+              "defined": "TokenAmount"
+            }
+          }
+        ]
+      }
     }
   ],
   "errors": [
@@ -520,69 +1267,479 @@ export type Staker = {
       "msg": "Provided stake doesn't belong to incentive"
     },
     {
-      "code": 6017,
-      "name": "InvalidTokenAccount",
-      "msg": "Provided token account is different than expected"
+      "code": 6017,
+      "name": "InvalidTokenAccount",
+      "msg": "Provided token account is different than expected"
+    },
+    {
+      "code": 6018,
+      "name": "InvalidIncentive",
+      "msg": "Incentive address is different than expected"
+    },
+    {
+      "code": 6019,
+      "name": "InvalidAuthority",
+      "msg": "Provided authority is different than expected"
+    },
+    {
+      "code": 6020,
+      "name": "InvalidOwner",
+      "msg": "Provided token owner is different than expected"
+    },
+    {
+      "code": 6021,
+      "name": "InvalidMint",
+      "msg": "Provided token account mint is different than expected mint token"
+    },
+    {
+      "code": 6022,
+      "name": "DifferentIncentivePool",
+      "msg": "Incentive pool is different from stake pool"
+    },
+    {
+      "code": 6023,
+      "name": "ClaimEnded",
+      "msg": "Claim time of the incentive is over"
+    },
+    {
+      "code": 6024,
+      "name": "TooManyRewardTokens",
+      "msg": "Incentive can't hold more reward tokens"
+    },
+    {
+      "code": 6025,
+      "name": "InvalidEndTime",
+      "msg": "New end time has to be later than the current one"
+    },
+    {
+      "code": 6026,
+      "name": "InvalidTickBand",
+      "msg": "Invalid tick band"
+    },
+    {
+      "code": 6027,
+      "name": "PositionOutsideBand",
+      "msg": "Position is outside of the incentive tick band"
+    },
+    {
+      "code": 6028,
+      "name": "InvalidTick",
+      "msg": "Tick doesn't belong to the position"
+    },
+    {
+      "code": 6029,
+      "name": "AlreadyStarted",
+      "msg": "Incentive has already started"
+    },
+    {
+      "code": 6030,
+      "name": "InvalidVesting",
+      "msg": "Invalid vesting schedule"
+    },
+    {
+      "code": 6031,
+      "name": "ExtensionRewardOverflow",
+      "msg": "Reward for extending the incentive doesn't fit into the token amount"
+    }
+  ]
+};
+
+export const IDL: Staker = {
+  "version": "0.1.0",
+  "name": "staker",
+  "instructions": [
+    {
+      "name": "createIncentive",
+      "accounts": [
+        {
+          "name": "incentive",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "incentiveTokenAccount",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "founderTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "founder",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "stakerAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "incentiveToken",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "invariant",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "nonce",
+          "type": "u8"
+        },
+        {
+          "name": "reward",
+          "type": {
+            "defined": "TokenAmount"
+          }
+        },
+        {
+          "name": "startTime",
+          "type": {
+            "defined": "Seconds"
+          }
+        },
+        {
+          "name": "endTime",
+          "type": {
+            "defined": "Seconds"
+          }
+        },
+        {
+          "name": "band",
+          "type": {
+            "option": {
+              "defined": "TickBand"
+            }
+          }
+        },
+        {
+          "name": "vesting",
+          "type": {
+            "option": {
+              "defined": "Vesting"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "addRewardToken",
+      "accounts": [
+        {
+          "name": "incentive",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "incentiveTokenAccount",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "founderTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "founder",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "stakerAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "incentiveToken",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "nonce",
+          "type": "u8"
+        },
+        {
+          "name": "reward",
+          "type": {
+            "defined": "TokenAmount"
+          }
+        }
+      ]
     },
     {
-      "code": 6018,
-      "name": "InvalidIncentive",
-      "msg": "Incentive address is different than expected"
+      "name": "migrateIncentive",
+      "accounts": [
+        {
+          "name": "incentive",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
     },
     {
-      "code": 6019,
-      "name": "InvalidAuthority",
-      "msg": "Provided authority is different than expected"
+      "name": "addIncentiveReward",
+      "accounts": [
+        {
+          "name": "incentive",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "incentiveTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "funderTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "funder",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "reward",
+          "type": {
+            "defined": "TokenAmount"
+          }
+        }
+      ]
     },
     {
-      "code": 6020,
-      "name": "InvalidOwner",
-      "msg": "Provided token owner is different than expected"
+      "name": "extendIncentive",
+      "accounts": [
+        {
+          "name": "incentive",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "incentiveTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "founderTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "founder",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "endTime",
+          "type": {
+            "defined": "Seconds"
+          }
+        }
+      ]
     },
     {
-      "code": 6021,
-      "name": "InvalidMint",
-      "msg": "Provided token account mint is different than expected mint token"
+      "name": "stake",
+      "accounts": [
+        {
+          "name": "userStake",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "position",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lowerTick",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "upperTick",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenX",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenY",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "incentive",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "stakerAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "signer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "invariant",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "index",
+          "type": "i32"
+        }
+      ]
     },
     {
-      "code": 6022,
-      "name": "DifferentIncentivePool",
-      "msg": "Incentive pool is different from stake pool"
-    }
-  ]
-};
-
-export const IDL: Staker = {
-  "version": "0.1.0",
-  "name": "staker",
-  "instructions": [
-    {
-      "name": "createIncentive",
+      "name": "withdraw",
       "accounts": [
+        {
+          "name": "userStake",
+          "isMut": true,
+          "isSigner": false
+        },
         {
           "name": "incentive",
           "isMut": true,
-          "isSigner": true
+          "isSigner": false
         },
         {
           "name": "incentiveTokenAccount",
           "isMut": true,
-          "isSigner": true
+          "isSigner": false
         },
         {
-          "name": "founderTokenAccount",
+          "name": "position",
           "isMut": true,
           "isSigner": false
         },
         {
           "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lowerTick",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "founder",
+          "name": "upperTick",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenX",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenY",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "ownerTokenAccount",
           "isMut": true,
-          "isSigner": true
+          "isSigner": false
         },
         {
           "name": "stakerAuthority",
@@ -590,10 +1747,15 @@ export const IDL: Staker = {
           "isSigner": false
         },
         {
-          "name": "incentiveToken",
-          "isMut": false,
+          "name": "owner",
+          "isMut": true,
           "isSigner": false
         },
+        {
+          "name": "signer",
+          "isMut": true,
+          "isSigner": true
+        },
         {
           "name": "tokenProgram",
           "isMut": false,
@@ -617,57 +1779,68 @@ export const IDL: Staker = {
       ],
       "args": [
         {
-          "name": "nonce",
-          "type": "u8"
-        },
-        {
-          "name": "reward",
-          "type": {
-            "defined": "TokenAmount"
-          }
-        },
-        {
-          "name": "startTime",
-          "type": {
-            "defined": "Seconds"
-          }
+          "name": "index",
+          "type": "i32"
         },
         {
-          "name": "endTime",
-          "type": {
-            "defined": "Seconds"
-          }
+          "name": "nonce",
+          "type": "u8"
         }
       ]
     },
     {
-      "name": "stake",
+      "name": "withdrawMany",
       "accounts": [
         {
-          "name": "userStake",
+          "name": "position",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "position",
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lowerTick",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "incentive",
-          "isMut": true,
+          "name": "upperTick",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "owner",
+          "name": "tokenX",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenY",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "stakerAuthority",
           "isMut": false,
           "isSigner": false
         },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": false
+        },
         {
           "name": "signer",
           "isMut": true,
           "isSigner": true
         },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
         {
           "name": "invariant",
           "isMut": false,
@@ -688,58 +1861,125 @@ export const IDL: Staker = {
         {
           "name": "index",
           "type": "i32"
+        },
+        {
+          "name": "nonce",
+          "type": "u8"
         }
       ]
     },
     {
-      "name": "withdraw",
+      "name": "endIncentive",
       "accounts": [
         {
-          "name": "userStake",
+          "name": "incentive",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "incentive",
+          "name": "incentiveTokenAccount",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "incentiveTokenAccount",
+          "name": "founderTokenAccount",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "position",
+          "name": "incentiveToken",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "ownerTokenAccount",
+          "name": "stakerAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "founder",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "nonce",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "createVestingRecord",
+      "accounts": [
+        {
+          "name": "vestingRecord",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "stakerAuthority",
+          "name": "incentive",
           "isMut": false,
           "isSigner": false
         },
         {
           "name": "owner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "signer",
           "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "tokenProgram",
+          "name": "rent",
           "isMut": false,
           "isSigner": false
         }
       ],
-      "args": [
+      "args": []
+    },
+    {
+      "name": "releaseVested",
+      "accounts": [
         {
-          "name": "index",
-          "type": "i32"
+          "name": "vestingRecord",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "incentiveTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "ownerTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "stakerAuthority",
+          "isMut": false,
+          "isSigner": false
         },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
         {
           "name": "nonce",
           "type": "u8"
@@ -747,7 +1987,7 @@ export const IDL: Staker = {
       ]
     },
     {
-      "name": "endIncentive",
+      "name": "cancelIncentive",
       "accounts": [
         {
           "name": "incentive",
@@ -764,11 +2004,6 @@ export const IDL: Staker = {
           "isMut": true,
           "isSigner": false
         },
-        {
-          "name": "incentiveToken",
-          "isMut": false,
-          "isSigner": false
-        },
         {
           "name": "stakerAuthority",
           "isMut": false,
@@ -776,7 +2011,7 @@ export const IDL: Staker = {
         },
         {
           "name": "founder",
-          "isMut": false,
+          "isMut": true,
           "isSigner": true
         },
         {
@@ -792,6 +2027,42 @@ export const IDL: Staker = {
         }
       ]
     },
+    {
+      "name": "getPendingReward",
+      "accounts": [
+        {
+          "name": "incentive",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "userStake",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "position",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "lowerTick",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "upperTick",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
     {
       "name": "removeStake",
       "accounts": [
@@ -805,10 +2076,25 @@ export const IDL: Staker = {
           "isMut": true,
           "isSigner": false
         },
+        {
+          "name": "position",
+          "isMut": true,
+          "isSigner": false
+        },
         {
           "name": "founder",
           "isMut": false,
           "isSigner": true
+        },
+        {
+          "name": "stakerAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "invariant",
+          "isMut": false,
+          "isSigner": false
         }
       ],
       "args": []
@@ -828,13 +2114,23 @@ export const IDL: Staker = {
         },
         {
           "name": "position",
-          "isMut": false,
+          "isMut": true,
           "isSigner": false
         },
         {
           "name": "owner",
           "isMut": false,
           "isSigner": true
+        },
+        {
+          "name": "stakerAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "invariant",
+          "isMut": false,
+          "isSigner": false
         }
       ],
       "args": [
@@ -843,6 +2139,27 @@ export const IDL: Staker = {
           "type": "i32"
         }
       ]
+    },
+    {
+      "name": "migrateStake",
+      "accounts": [
+        {
+          "name": "userStake",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
     }
   ],
   "accounts": [
@@ -856,15 +2173,20 @@ export const IDL: Staker = {
             "type": "publicKey"
           },
           {
-            "name": "tokenAccount",
-            "type": "publicKey"
-          },
-          {
-            "name": "totalRewardUnclaimed",
+            "name": "rewards",
             "type": {
-              "defined": "TokenAmount"
+              "array": [
+                {
+                  "defined": "IncentiveReward"
+                },
+                4
+              ]
             }
           },
+          {
+            "name": "rewardCount",
+            "type": "u8"
+          },
           {
             "name": "totalSecondsClaimed",
             "type": {
@@ -897,6 +2219,18 @@ export const IDL: Staker = {
             "name": "pool",
             "type": "publicKey"
           },
+          {
+            "name": "band",
+            "type": {
+              "defined": "TickBand"
+            }
+          },
+          {
+            "name": "vesting",
+            "type": {
+              "defined": "Vesting"
+            }
+          },
           {
             "name": "nonce",
             "type": "u8"
@@ -929,6 +2263,57 @@ export const IDL: Staker = {
               "defined": "Liquidity"
             }
           },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "locked",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "vestingRecord",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "incentive",
+            "type": "publicKey"
+          },
+          {
+            "name": "owner",
+            "type": "publicKey"
+          },
+          {
+            "name": "rewards",
+            "type": {
+              "array": [
+                {
+                  "defined": "VestedReward"
+                },
+                4
+              ]
+            }
+          },
+          {
+            "name": "rewardCount",
+            "type": "u8"
+          },
+          {
+            "name": "vesting",
+            "type": {
+              "defined": "Vesting"
+            }
+          },
+          {
+            "name": "startTime",
+            "type": {
+              "defined": "Seconds"
+            }
+          },
           {
             "name": "bump",
             "type": "u8"
@@ -985,6 +2370,160 @@ export const IDL: Staker = {
           }
         ]
       }
+    },
+    {
+      "name": "PendingRewardReturnData",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "rewards",
+            "type": {
+              "vec": "u64"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "IncentiveReward",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "tokenAccount",
+            "type": "publicKey"
+          },
+          {
+            "name": "totalRewardUnclaimed",
+            "type": {
+              "defined": "TokenAmount"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "TickBand",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "lowerTickIndex",
+            "type": "i32"
+          },
+          {
+            "name": "upperTickIndex",
+            "type": "i32"
+          },
+          {
+            "name": "maxWidth",
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "LegacyIncentive",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "founder",
+            "type": "publicKey"
+          },
+          {
+            "name": "tokenAccount",
+            "type": "publicKey"
+          },
+          {
+            "name": "totalRewardUnclaimed",
+            "type": {
+              "defined": "TokenAmount"
+            }
+          },
+          {
+            "name": "totalSecondsClaimed",
+            "type": {
+              "defined": "Seconds"
+            }
+          },
+          {
+            "name": "startTime",
+            "type": {
+              "defined": "Seconds"
+            }
+          },
+          {
+            "name": "endTime",
+            "type": {
+              "defined": "Seconds"
+            }
+          },
+          {
+            "name": "endClaimTime",
+            "type": {
+              "defined": "Seconds"
+            }
+          },
+          {
+            "name": "numOfStakes",
+            "type": "u64"
+          },
+          {
+            "name": "pool",
+            "type": "publicKey"
+          },
+          {
+            "name": "nonce",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "Vesting",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "cliff",
+            "type": {
+              "defined": "Seconds"
+            }
+          },
+          {
+            "name": "duration",
+            "type": {
+              "defined": "Seconds"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "VestedReward",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "tokenAccount",
+            "type": "publicKey"
+          },
+          {
+            "name": "credited",
+            "type": {
+              "defined": "TokenAmount"
+            }
+          },
+          {
+            "name": "released",
+            "type": {
+              "defined": "TokenAmount"
+            }
+          }
+        ]
+      }
     }
   ],
   "errors": [
@@ -1102,6 +2641,51 @@ export const IDL: Staker = {
       "code": 6022,
       "name": "DifferentIncentivePool",
       "msg": "Incentive pool is different from stake pool"
+    },
+    {
+      "code": 6023,
+      "name": "ClaimEnded",
+      "msg": "Claim time of the incentive is over"
+    },
+    {
+      "code": 6024,
+      "name": "TooManyRewardTokens",
+      "msg": "Incentive can't hold more reward tokens"
+    },
+    {
+      "code": 6025,
+      "name": "InvalidEndTime",
+      "msg": "New end time has to be later than the current one"
+    },
+    {
+      "code": 6026,
+      "name": "InvalidTickBand",
+      "msg": "Invalid tick band"
+    },
+    {
+      "code": 6027,
+      "name": "PositionOutsideBand",
+      "msg": "Position is outside of the incentive tick band"
+    },
+    {
+      "code": 6028,
+      "name": "InvalidTick",
+      "msg": "Tick doesn't belong to the position"
+    },
+    {
+      "code": 6029,
+      "name": "AlreadyStarted",
+      "msg": "Incentive has already started"
+    },
+    {
+      "code": 6030,
+      "name": "InvalidVesting",
+      "msg": "Invalid vesting schedule"
+    },
+    {
+      "code": 6031,
+      "name": "ExtensionRewardOverflow",
+      "msg": "Reward for extending the incentive doesn't fit into the token amount"
     }
   ]
 };
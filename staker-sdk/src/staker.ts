@@ -12,9 +12,10 @@ import {
   SYSVAR_RENT_PUBKEY,
   Transaction,
   Keypair,
-  sendAndConfirmRawTransaction
+  sendAndConfirmRawTransaction,
+  AccountMeta
 } from '@solana/web3.js'
-import { STAKER_SEED } from './utils'
+import { STAKER_SEED, VESTING_SEED } from './utils'
 import { bs58 } from '@project-serum/anchor/dist/cjs/utils/bytes'
 
 export class Staker {
@@ -71,14 +72,55 @@ export class Staker {
     return { stringTx, incentive, incentiveToken }
   }
 
+  public async addRewardToken(addRewardToken: AddRewardToken) {
+    const incentiveTokenAccount = Keypair.generate()
+    const incentiveToken = incentiveTokenAccount.publicKey
+    const addIx = await this.addRewardTokenIx(addRewardToken, incentiveToken)
+    const tx = new Transaction().add(addIx)
+    const stringTx = await this.signAndSend(tx, [incentiveTokenAccount])
+
+    return { stringTx, incentiveToken }
+  }
+
+  public async addIncentiveReward(addIncentiveReward: AddIncentiveReward) {
+    const addIx = await this.addIncentiveRewardIx(addIncentiveReward)
+    const tx = new Transaction().add(addIx)
+    const stringTx = await this.signAndSend(tx)
+
+    return stringTx
+  }
+
+  public async extendIncentive(extendIncentive: ExtendIncentive) {
+    const extendIx = await this.extendIncentiveIx(extendIncentive)
+    const tx = new Transaction().add(extendIx)
+    const stringTx = await this.signAndSend(tx)
+
+    return stringTx
+  }
+
+  public async migrateIncentive(incentive: PublicKey, payer?: PublicKey) {
+    const migrateIx = await this.migrateIncentiveIx(incentive, payer)
+    const tx = new Transaction().add(migrateIx)
+    const stringTx = await this.signAndSend(tx)
+
+    return stringTx
+  }
+
+  public async migrateStake(userStake: PublicKey, payer?: PublicKey) {
+    const migrateIx = await this.migrateStakeIx(userStake, payer)
+    const tx = new Transaction().add(migrateIx)
+    const stringTx = await this.signAndSend(tx)
+
+    return stringTx
+  }
+
   public async createStake(
     market: Market,
     update: UpdateSecondsPerLiquidity,
     createStake: CreateStake
   ) {
-    const updateIx = await market.updateSecondsPerLiquidityInstruction(update)
-    const stakeIx = await this.createStakeIx(createStake)
-    const tx = new Transaction().add(updateIx).add(stakeIx)
+    const stakeIx = await this.createStakeIx(market, update, createStake)
+    const tx = new Transaction().add(stakeIx)
     const stringTx = await this.signAndSend(tx)
     const [stake] = await this.getUserStakeAddressAndBump(
       createStake.incentive,
@@ -90,9 +132,8 @@ export class Staker {
   }
 
   public async withdraw(market: Market, update: UpdateSecondsPerLiquidity, withdraw: Withdraw) {
-    const updateIx = await market.updateSecondsPerLiquidityInstruction(update)
-    const withdrawIx = await this.withdrawIx(withdraw)
-    const tx = new Transaction().add(updateIx).add(withdrawIx)
+    const withdrawIx = await this.withdrawIx(market, update, withdraw)
+    const tx = new Transaction().add(withdrawIx)
     const stringTx = await this.signAndSend(tx)
 
     return stringTx
@@ -106,10 +147,31 @@ export class Staker {
     return stringTx
   }
 
-  public async removeStake(pool: PublicKey, id: BN, incentive: PublicKey, founder: PublicKey) {
+  public async cancelIncentive(cancelIncentive: CancelIncentive) {
+    const cancelIncentiveIx = await this.cancelIncentiveIx(cancelIncentive)
+    const tx = new Transaction().add(cancelIncentiveIx)
+    const stringTx = await this.signAndSend(tx)
+
+    return stringTx
+  }
+
+  public async removeStake(
+    pool: PublicKey,
+    id: BN,
+    incentive: PublicKey,
+    founder: PublicKey,
+    invariant: PublicKey
+  ) {
     const [userStakeAddress] = await this.getUserStakeAddressAndBump(incentive, pool, id)
+    const { position } = await this.getStake(incentive, pool, id)
 
-    const removeIx = await this.removeStakeIx(userStakeAddress, incentive, founder)
+    const removeIx = await this.removeStakeIx(
+      userStakeAddress,
+      incentive,
+      founder,
+      position,
+      invariant
+    )
     const tx = new Transaction().add(removeIx)
     const stringTx = await this.signAndSend(tx)
 
@@ -117,7 +179,7 @@ export class Staker {
   }
 
   public async closeStakeByOwner(closeStake: CloseStake) {
-    const { pool, id, incentive, position, owner, index } = closeStake
+    const { pool, id, incentive, position, owner, index, invariant } = closeStake
     const [userStakeAddress] = await this.getUserStakeAddressAndBump(incentive, pool, id)
 
     const closeIx = await this.closeStakeByOwnerIx(
@@ -125,7 +187,8 @@ export class Staker {
       incentive,
       position,
       owner,
-      index
+      index,
+      invariant
     )
     const tx = new Transaction().add(closeIx)
     const stringTx = await this.signAndSend(tx)
@@ -133,14 +196,20 @@ export class Staker {
     return stringTx
   }
 
-  public async removeAllStakes(incentive: PublicKey, founder: PublicKey) {
+  public async removeAllStakes(incentive: PublicKey, founder: PublicKey, invariant: PublicKey) {
     const stakes = await this.getAllIncentiveStakes(incentive)
     let tx = new Transaction()
     const txs: Transaction[] = []
 
     // put max 18 Ix per Tx, sign and return array of tx hashes
     for (let i = 0; i < stakes.length; i++) {
-      const removeIx = await this.removeStakeIx(stakes[i].publicKey, incentive, founder)
+      const removeIx = await this.removeStakeIx(
+        stakes[i].publicKey,
+        incentive,
+        founder,
+        stakes[i].account.position,
+        invariant
+      )
       tx.add(removeIx)
       // sign and send when max Ix or last stake
       if ((i + 1) % 18 === 0 || i + 1 === stakes.length) {
@@ -163,7 +232,9 @@ export class Staker {
       pool,
       incentiveToken,
       founderTokenAccount,
-      invariant
+      invariant,
+      band,
+      vesting
     }: CreateIncentive,
     incentive: PublicKey,
     incentiveTokenAccount: PublicKey
@@ -173,6 +244,8 @@ export class Staker {
       reward,
       startTime,
       endTime,
+      band ?? null,
+      vesting ?? null,
       {
         accounts: {
           incentive: incentive,
@@ -191,24 +264,104 @@ export class Staker {
     )
   }
 
-  public async createStakeIx({
-    pool,
-    id,
-    position,
+  public async addRewardTokenIx(
+    { incentive, reward, founder, incentiveToken, founderTokenAccount }: AddRewardToken,
+    incentiveTokenAccount: PublicKey
+  ) {
+    return this.program.instruction.addRewardToken(this.programAuthority.nonce, reward, {
+      accounts: {
+        incentive,
+        incentiveTokenAccount,
+        founderTokenAccount,
+        founder,
+        stakerAuthority: this.programAuthority.authority,
+        incentiveToken,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+        rent: SYSVAR_RENT_PUBKEY
+      }
+    })
+  }
+
+  public async addIncentiveRewardIx({
     incentive,
-    owner,
-    signer,
-    index,
-    invariant
-  }: CreateStake) {
+    incentiveTokenAccount,
+    funderTokenAccount,
+    funder,
+    reward
+  }: AddIncentiveReward) {
+    return this.program.instruction.addIncentiveReward(reward, {
+      accounts: {
+        incentive,
+        incentiveTokenAccount,
+        funderTokenAccount,
+        funder: funder ?? this.wallet.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID
+      }
+    })
+  }
+
+  public async extendIncentiveIx({
+    incentive,
+    incentiveTokenAccount,
+    founderTokenAccount,
+    founder,
+    endTime,
+    rewardAccounts
+  }: ExtendIncentive) {
+    return this.program.instruction.extendIncentive(endTime, {
+      accounts: {
+        incentive,
+        incentiveTokenAccount,
+        founderTokenAccount,
+        founder: founder ?? this.wallet.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID
+      },
+      remainingAccounts: this.rewardRemainingAccounts(rewardAccounts)
+    })
+  }
+
+  public async migrateIncentiveIx(incentive: PublicKey, payer?: PublicKey) {
+    return this.program.instruction.migrateIncentive({
+      accounts: {
+        incentive,
+        payer: payer ?? this.wallet.publicKey,
+        systemProgram: SystemProgram.programId
+      }
+    })
+  }
+
+  public async migrateStakeIx(userStake: PublicKey, payer?: PublicKey) {
+    return this.program.instruction.migrateStake({
+      accounts: {
+        userStake,
+        payer: payer ?? this.wallet.publicKey,
+        systemProgram: SystemProgram.programId
+      }
+    })
+  }
+
+  public async createStakeIx(
+    market: Market,
+    update: UpdateSecondsPerLiquidity,
+    { pool, id, position, incentive, owner, signer, index, invariant }: CreateStake
+  ) {
     const [userStakeAddress] = await this.getUserStakeAddressAndBump(incentive, pool, id)
+    const { lowerTick, upperTick } = await this.getPositionTicks(market, update)
 
+    // seconds per liquidity of the position are updated by the program before staking
     return this.program.instruction.stake(index, {
       accounts: {
         userStake: userStakeAddress,
         position,
+        pool,
+        lowerTick,
+        upperTick,
+        tokenX: update.pair.tokenX,
+        tokenY: update.pair.tokenY,
         incentive,
         owner,
+        stakerAuthority: this.programAuthority.authority,
         signer: signer ?? owner,
         systemProgram: SystemProgram.programId,
         invariant,
@@ -217,29 +370,92 @@ export class Staker {
     })
   }
 
-  public async withdrawIx({
-    incentive,
-    pool,
-    id,
-    incentiveTokenAccount,
-    ownerTokenAcc,
-    position,
-    owner,
-    index
-  }: Withdraw) {
+  public async withdrawIx(
+    market: Market,
+    update: UpdateSecondsPerLiquidity,
+    {
+      incentive,
+      pool,
+      id,
+      incentiveTokenAccount,
+      ownerTokenAcc,
+      position,
+      owner,
+      signer,
+      index,
+      rewardAccounts,
+      vestingRecord
+    }: Withdraw
+  ) {
     const [userStakeAddress] = await this.getUserStakeAddressAndBump(incentive, pool, id)
+    const { lowerTick, upperTick } = await this.getPositionTicks(market, update)
 
     return this.program.instruction.withdraw(index, this.programAuthority.nonce, {
       accounts: {
         userStake: userStakeAddress,
         incentive,
         incentiveTokenAccount: incentiveTokenAccount,
+        position,
+        pool,
+        lowerTick,
+        upperTick,
+        tokenX: update.pair.tokenX,
+        tokenY: update.pair.tokenY,
         ownerTokenAccount: ownerTokenAcc,
+        stakerAuthority: this.programAuthority.authority,
+        owner,
+        signer: signer ?? owner,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        invariant: market.program.programId,
+        systemProgram: SystemProgram.programId,
+        rent: SYSVAR_RENT_PUBKEY
+      },
+      remainingAccounts: [
+        ...this.rewardRemainingAccounts(rewardAccounts),
+        ...this.vestingRemainingAccounts(vestingRecord)
+      ]
+    })
+  }
+
+  public async withdrawManyIx(
+    market: Market,
+    update: UpdateSecondsPerLiquidity,
+    { pool, id, position, owner, signer, index, incentives }: WithdrawMany
+  ) {
+    const { lowerTick, upperTick } = await this.getPositionTicks(market, update)
+
+    const remainingAccounts: AccountMeta[] = []
+    for (const withdrawIncentive of incentives) {
+      const { incentive, incentiveTokenAccount, ownerTokenAcc, rewardAccounts } = withdrawIncentive
+      const [userStakeAddress] = await this.getUserStakeAddressAndBump(incentive, pool, id)
+      remainingAccounts.push(
+        { pubkey: incentive, isWritable: true, isSigner: false },
+        { pubkey: userStakeAddress, isWritable: true, isSigner: false },
+        ...this.rewardRemainingAccounts([
+          { incentiveTokenAccount, tokenAccount: ownerTokenAcc },
+          ...(rewardAccounts ?? [])
+        ]),
+        ...this.vestingRemainingAccounts(withdrawIncentive.vestingRecord)
+      )
+    }
+
+    return this.program.instruction.withdrawMany(index, this.programAuthority.nonce, {
+      accounts: {
         position,
+        pool,
+        lowerTick,
+        upperTick,
+        tokenX: update.pair.tokenX,
+        tokenY: update.pair.tokenY,
         stakerAuthority: this.programAuthority.authority,
         owner,
-        tokenProgram: TOKEN_PROGRAM_ID
-      }
+        signer: signer ?? owner,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        invariant: market.program.programId,
+        systemProgram: SystemProgram.programId,
+        rent: SYSVAR_RENT_PUBKEY
+      },
+      remainingAccounts
     })
   }
 
@@ -248,7 +464,8 @@ export class Staker {
     incentiveToken,
     incentiveTokenAccount,
     founderTokenAccount,
-    founder
+    founder,
+    rewardAccounts
   }: EndIncentive) {
     return this.program.instruction.endIncentive(this.programAuthority.nonce, {
       accounts: {
@@ -259,16 +476,26 @@ export class Staker {
         stakerAuthority: this.programAuthority.authority,
         founder: founder,
         tokenProgram: TOKEN_PROGRAM_ID
-      }
+      },
+      remainingAccounts: this.rewardRemainingAccounts(rewardAccounts)
     })
   }
 
-  public async removeStakeIx(userStake: PublicKey, incentive: PublicKey, founder: PublicKey) {
+  public async removeStakeIx(
+    userStake: PublicKey,
+    incentive: PublicKey,
+    founder: PublicKey,
+    position: PublicKey,
+    invariant: PublicKey
+  ) {
     return this.program.instruction.removeStake({
       accounts: {
         incentive,
         userStake: userStake,
-        founder: founder
+        position,
+        founder: founder,
+        stakerAuthority: this.programAuthority.authority,
+        invariant
       }
     })
   }
@@ -278,18 +505,97 @@ export class Staker {
     incentive: PublicKey,
     position: PublicKey,
     owner: PublicKey,
-    index: number
+    index: number,
+    invariant: PublicKey
   ) {
     return this.program.instruction.closeStakeByOwner(index, {
       accounts: {
         incentive,
         userStake,
         position,
-        owner
+        owner,
+        stakerAuthority: this.programAuthority.authority,
+        invariant
+      }
+    })
+  }
+
+  public async getPendingRewardIx(
+    market: Market,
+    update: UpdateSecondsPerLiquidity,
+    { incentive, pool, id, position }: GetPendingReward
+  ) {
+    const [userStakeAddress] = await this.getUserStakeAddressAndBump(incentive, pool, id)
+    const { lowerTick, upperTick } = await this.getPositionTicks(market, update)
+
+    return this.program.instruction.getPendingReward({
+      accounts: {
+        incentive,
+        userStake: userStakeAddress,
+        position,
+        pool,
+        lowerTick,
+        upperTick
+      }
+    })
+  }
+
+  public async cancelIncentiveIx({
+    incentive,
+    incentiveTokenAccount,
+    founderTokenAccount,
+    founder,
+    rewardAccounts
+  }: CancelIncentive) {
+    return this.program.instruction.cancelIncentive(this.programAuthority.nonce, {
+      accounts: {
+        incentive,
+        incentiveTokenAccount,
+        founderTokenAccount,
+        stakerAuthority: this.programAuthority.authority,
+        founder,
+        tokenProgram: TOKEN_PROGRAM_ID
+      },
+      remainingAccounts: this.rewardRemainingAccounts(rewardAccounts)
+    })
+  }
+
+  public async createVestingRecordIx({ incentive, owner, signer }: CreateVestingRecord) {
+    const [vestingRecord] = await this.getVestingRecordAddressAndBump(incentive, owner)
+
+    return this.program.instruction.createVestingRecord({
+      accounts: {
+        vestingRecord,
+        incentive,
+        owner,
+        signer: signer ?? owner,
+        systemProgram: SystemProgram.programId,
+        rent: SYSVAR_RENT_PUBKEY
       }
     })
   }
 
+  public async releaseVestedIx({
+    incentive,
+    owner,
+    incentiveTokenAccount,
+    ownerTokenAcc,
+    rewardAccounts
+  }: ReleaseVested) {
+    const [vestingRecord] = await this.getVestingRecordAddressAndBump(incentive, owner)
+
+    return this.program.instruction.releaseVested(this.programAuthority.nonce, {
+      accounts: {
+        vestingRecord,
+        incentiveTokenAccount,
+        ownerTokenAccount: ownerTokenAcc,
+        stakerAuthority: this.programAuthority.authority,
+        tokenProgram: TOKEN_PROGRAM_ID
+      },
+      remainingAccounts: this.rewardRemainingAccounts(rewardAccounts)
+    })
+  }
+
   // getters
   async getProgramAuthority() {
     const [authority, nonce] = await PublicKey.findProgramAddress(
@@ -303,6 +609,16 @@ export class Staker {
     }
   }
 
+  async getPositionTicks(
+    market: Market,
+    { pair, lowerTickIndex, upperTickIndex }: UpdateSecondsPerLiquidity
+  ) {
+    const { tickAddress: lowerTick } = await market.getTickAddress(pair, lowerTickIndex)
+    const { tickAddress: upperTick } = await market.getTickAddress(pair, upperTickIndex)
+
+    return { lowerTick, upperTick }
+  }
+
   public async getIncentive(incentivePubKey: PublicKey) {
     return (await this.program.account.incentive.fetch(incentivePubKey)) as IncentiveStructure
   }
@@ -322,6 +638,55 @@ export class Staker {
     return await this.program.account.userStake.fetch(userStakeAddress)
   }
 
+  // simulated, rewards are in the order of incentive reward tokens
+  public async getPendingReward(
+    market: Market,
+    update: UpdateSecondsPerLiquidity,
+    getPendingReward: GetPendingReward
+  ) {
+    const tx = new Transaction().add(
+      await this.getPendingRewardIx(market, update, getPendingReward)
+    )
+    tx.feePayer = this.wallet.publicKey
+    tx.recentBlockhash = (await this.connection.getRecentBlockhash()).blockhash
+
+    const { value } = await this.connection.simulateTransaction(tx)
+    const logs = value.logs ?? []
+    if (value.err) {
+      const failed = logs.find(log => log.includes('failed'))
+      throw new Error(failed ?? JSON.stringify(value.err))
+    }
+
+    const prefix = `Program return: ${this.programId.toBase58()} `
+    const returnLog = logs.find(log => log.startsWith(prefix))
+    if (returnLog === undefined) {
+      throw new Error('Pending reward was not returned')
+    }
+
+    // borsh Vec<u64>: u32 length followed by the amounts
+    const data = Buffer.from(returnLog.slice(prefix.length), 'base64')
+    const rewards: BN[] = []
+    for (let i = 0; i < data.readUInt32LE(0); i++) {
+      rewards.push(new BN(data.subarray(4 + i * 8, 12 + i * 8), 'le'))
+    }
+
+    return rewards
+  }
+
+  public async getVestingRecordAddressAndBump(incentive: PublicKey, owner: PublicKey) {
+    return await PublicKey.findProgramAddress(
+      [Buffer.from(VESTING_SEED), incentive.toBuffer(), owner.toBuffer()],
+      this.programId
+    )
+  }
+
+  public async getVestingRecord(incentive: PublicKey, owner: PublicKey) {
+    const [vestingRecordAddress] = await this.getVestingRecordAddressAndBump(incentive, owner)
+    return (await this.program.account.vestingRecord.fetch(
+      vestingRecordAddress
+    )) as VestingRecordStructure
+  }
+
   public async getAllIncentiveStakes(incentive: PublicKey) {
     return await this.program.account.userStake.all([
       {
@@ -336,6 +701,22 @@ export class Staker {
     }) as Incentive[]
   }
 
+  // pairs of vault and receiving token account, withdraw passes the first reward token separately
+  private rewardRemainingAccounts(rewardAccounts: RewardAccounts[] = []) {
+    const remainingAccounts: AccountMeta[] = []
+    for (const { incentiveTokenAccount, tokenAccount } of rewardAccounts) {
+      remainingAccounts.push({ pubkey: incentiveTokenAccount, isWritable: true, isSigner: false })
+      remainingAccounts.push({ pubkey: tokenAccount, isWritable: true, isSigner: false })
+    }
+
+    return remainingAccounts
+  }
+
+  // incentives vesting rewards credit them to the vesting record passed last
+  private vestingRemainingAccounts(vestingRecord?: PublicKey): AccountMeta[] {
+    return vestingRecord ? [{ pubkey: vestingRecord, isWritable: true, isSigner: false }] : []
+  }
+
   private async signAndSend(tx: Transaction, signers?: Keypair[], opts?: ConfirmOptions) {
     const blockhash = await this.connection.getRecentBlockhash(
       this.opts?.commitment || Provider.defaultOptions().commitment
@@ -393,6 +774,43 @@ export interface CreateIncentive {
   incentiveToken: PublicKey
   founderTokenAccount: PublicKey
   invariant: PublicKey
+  band?: TickBand
+  vesting?: Vesting
+}
+export interface TickBand {
+  lowerTickIndex: number
+  upperTickIndex: number
+  maxWidth: number
+}
+export interface Vesting {
+  cliff: Decimal
+  duration: Decimal
+}
+export interface AddRewardToken {
+  incentive: PublicKey
+  reward: Decimal
+  founder: PublicKey
+  incentiveToken: PublicKey
+  founderTokenAccount: PublicKey
+}
+export interface AddIncentiveReward {
+  incentive: PublicKey
+  incentiveTokenAccount: PublicKey
+  funderTokenAccount: PublicKey
+  funder?: PublicKey
+  reward: Decimal
+}
+export interface ExtendIncentive {
+  incentive: PublicKey
+  incentiveTokenAccount: PublicKey
+  founderTokenAccount: PublicKey
+  founder?: PublicKey
+  endTime: Decimal
+  rewardAccounts?: RewardAccounts[]
+}
+export interface RewardAccounts {
+  incentiveTokenAccount: PublicKey
+  tokenAccount: PublicKey
 }
 export interface CreateStake {
   pool: PublicKey
@@ -410,6 +828,7 @@ export interface Stake {
   secondsPerLiquidityInitial: Decimal
   liquidity: Decimal
   bump: number
+  locked: boolean
 }
 export interface Withdraw {
   incentive: PublicKey
@@ -419,7 +838,28 @@ export interface Withdraw {
   ownerTokenAcc: PublicKey
   position: PublicKey
   owner: PublicKey
+  signer?: PublicKey
   index: number
+  rewardAccounts?: RewardAccounts[]
+  vestingRecord?: PublicKey
+}
+
+export interface WithdrawMany {
+  pool: PublicKey
+  id: BN
+  position: PublicKey
+  owner: PublicKey
+  signer?: PublicKey
+  index: number
+  incentives: WithdrawManyIncentive[]
+}
+
+export interface WithdrawManyIncentive {
+  incentive: PublicKey
+  incentiveTokenAccount: PublicKey
+  ownerTokenAcc: PublicKey
+  rewardAccounts?: RewardAccounts[]
+  vestingRecord?: PublicKey
 }
 
 export interface EndIncentive {
@@ -428,6 +868,52 @@ export interface EndIncentive {
   incentiveTokenAccount: PublicKey
   founderTokenAccount: PublicKey
   founder: PublicKey
+  rewardAccounts?: RewardAccounts[]
+}
+
+export interface CreateVestingRecord {
+  incentive: PublicKey
+  owner: PublicKey
+  signer?: PublicKey
+}
+
+export interface ReleaseVested {
+  incentive: PublicKey
+  owner: PublicKey
+  incentiveTokenAccount: PublicKey
+  ownerTokenAcc: PublicKey
+  rewardAccounts?: RewardAccounts[]
+}
+
+export interface VestingRecordStructure {
+  incentive: PublicKey
+  owner: PublicKey
+  rewards: VestedReward[]
+  rewardCount: number
+  vesting: Vesting
+  startTime: Decimal
+  bump: number
+}
+
+export interface VestedReward {
+  tokenAccount: PublicKey
+  credited: Decimal
+  released: Decimal
+}
+
+export interface CancelIncentive {
+  incentive: PublicKey
+  incentiveTokenAccount: PublicKey
+  founderTokenAccount: PublicKey
+  founder: PublicKey
+  rewardAccounts?: RewardAccounts[]
+}
+
+export interface GetPendingReward {
+  incentive: PublicKey
+  pool: PublicKey
+  id: BN
+  position: PublicKey
 }
 
 export interface CloseStake {
@@ -437,21 +923,29 @@ export interface CloseStake {
   position: PublicKey
   owner: PublicKey
   index: number
+  invariant: PublicKey
 }
 
 export interface IncentiveStructure {
   founder: PublicKey
-  tokenAccount: PublicKey
-  totalRewardUnclaimed: Decimal
+  rewards: IncentiveReward[]
+  rewardCount: number
   totalSecondsClaimed: Decimal
   startTime: Decimal
   endTime: Decimal
   endClaimTime: Decimal
   numOfStakes: BN
   pool: PublicKey
+  band: TickBand
+  vesting: Vesting
   nonce: number
 }
 
+export interface IncentiveReward {
+  tokenAccount: PublicKey
+  totalRewardUnclaimed: Decimal
+}
+
 export interface Incentive extends IncentiveStructure {
   publicKey: PublicKey
 }
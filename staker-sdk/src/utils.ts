@@ -14,7 +14,20 @@ export enum STAKER_ERRORS {
   START_IN_PAST = '0x1775',
   TO_LONG_DURATION = '0x1774',
   ENDED = '0x1776',
-  DIFFERENT_INCENTIVE_POOL = '0x1786'
+  STAKE_EXIST = '0x177c',
+  INVALID_FOUNDER = '0x177f',
+  INVALID_STAKE = '0x1780',
+  INVALID_TOKEN_ACCOUNT = '0x1781',
+  DIFFERENT_INCENTIVE_POOL = '0x1786',
+  CLAIM_ENDED = '0x1787',
+  TOO_MANY_REWARD_TOKENS = '0x1788',
+  INVALID_END_TIME = '0x1789',
+  INVALID_TICK_BAND = '0x178a',
+  POSITION_OUTSIDE_BAND = '0x178b',
+  INVALID_TICK = '0x178c',
+  ALREADY_STARTED = '0x178d',
+  INVALID_VESTING = '0x178e',
+  EXTENSION_REWARD_OVERFLOW = '0x178f'
 }
 
 export interface Decimal {
@@ -26,6 +39,7 @@ export const toDecimal = (x: number, decimals: number = 0): Decimal => {
 }
 
 export const STAKER_SEED = 'staker'
+export const VESTING_SEED = 'vesting'
 
 export const fromInteger = (integer: number): { v: BN } => {
   return { v: new BN(integer).mul(DENOMINATOR) }
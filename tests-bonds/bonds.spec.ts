@@ -0,0 +1,247 @@
+import * as anchor from '@project-serum/anchor'
+import { Provider, BN } from '@project-serum/anchor'
+import { Market, Pair, sleep } from '@invariant-labs/sdk'
+import { Network } from '../bonds-sdk/src'
+import { Keypair, PublicKey, Transaction } from '@solana/web3.js'
+import { assert } from 'chai'
+import { Bonds, InitBondSale } from '../bonds-sdk/src/bonds'
+import { BONDS_ERRORS, DENOMINATOR } from '../bonds-sdk/src/utils'
+import { assertThrowsAsync, getTime, signAndSend } from '../tests-staker/testUtils'
+import { createToken as createTkn, initMarket } from '../tests/testUtils'
+import { fromFee } from '@invariant-labs/sdk/lib/utils'
+import { FeeTier } from '@invariant-labs/sdk/lib/market'
+import { Token, TOKEN_PROGRAM_ID } from '@solana/spl-token'
+import { tou64 } from '@invariant-labs/sdk/src/utils'
+
+describe('Bonds', () => {
+  const provider = Provider.local()
+  const connection = provider.connection
+  // @ts-expect-error
+  const wallet = provider.wallet.payer as Account
+  const mintAuthority = Keypair.generate()
+  const admin = Keypair.generate()
+  const bondAmount = new BN(1000000)
+  const boughtAmount = new BN(100000)
+  // floor price 2 plus half of the 0.6 jump caused by buying 10% of the bond
+  const quoteAmount = new BN(230000)
+  let bonds: Bonds
+  let market: Market
+  let pool: PublicKey
+  let pair: Pair
+  let tokenBond: Token
+  let tokenQuote: Token
+  let payerBondAccount: PublicKey
+  let payerQuoteAccount: PublicKey
+  let bondSale: PublicKey
+  let tokenBondReserve: PublicKey
+  let tokenQuoteReserve: PublicKey
+  let otherBondSale: PublicKey
+  let bond: PublicKey
+  let endTime: BN
+
+  before(async () => {
+    bonds = await Bonds.build(Network.LOCAL, provider.wallet, connection)
+    market = await Market.build(
+      0,
+      provider.wallet,
+      connection,
+      anchor.workspace.Invariant.programId
+    )
+
+    await Promise.all([
+      connection.requestAirdrop(mintAuthority.publicKey, 1e9),
+      connection.requestAirdrop(admin.publicKey, 1e9)
+    ])
+
+    const tokens = await Promise.all([
+      createTkn(connection, wallet, mintAuthority),
+      createTkn(connection, wallet, mintAuthority)
+    ])
+
+    const feeTier: FeeTier = {
+      fee: fromFee(new BN(600)),
+      tickSpacing: 10
+    }
+
+    pair = new Pair(tokens[0].publicKey, tokens[1].publicKey, feeTier)
+    tokenBond = new Token(connection, pair.tokenX, TOKEN_PROGRAM_ID, wallet)
+    tokenQuote = new Token(connection, pair.tokenY, TOKEN_PROGRAM_ID, wallet)
+
+    await initMarket(market, [pair], admin)
+    pool = await pair.getAddress(anchor.workspace.Invariant.programId)
+
+    payerBondAccount = await tokenBond.createAccount(wallet.publicKey)
+    payerQuoteAccount = await tokenQuote.createAccount(wallet.publicKey)
+    await tokenBond.mintTo(payerBondAccount, mintAuthority, [], tou64(bondAmount.muln(2)))
+    await tokenQuote.mintTo(payerQuoteAccount, mintAuthority, [], tou64(quoteAmount))
+  })
+
+  const initBondSaleVars = (): InitBondSale => {
+    const currentTime = getTime()
+    endTime = currentTime.addn(20)
+
+    return {
+      pool,
+      tokenBond: tokenBond.publicKey,
+      tokenQuote: tokenQuote.publicKey,
+      payerBondAccount,
+      bondAmount: { v: bondAmount },
+      floorPrice: { v: DENOMINATOR.muln(2) },
+      upBound: { v: DENOMINATOR.muln(3) },
+      velocity: { v: DENOMINATOR },
+      startTime: { v: currentTime.subn(1) },
+      endTime: { v: endTime },
+      vestingTime: { v: new BN(2) }
+    }
+  }
+
+  it('#initBondSale()', async () => {
+    const result = await bonds.initBondSale(initBondSaleVars())
+    bondSale = result.bondSale
+    tokenBondReserve = result.tokenBondReserve
+    tokenQuoteReserve = result.tokenQuoteReserve
+
+    const bondSaleState = await bonds.getBondSale(bondSale)
+    assert.ok(bondSaleState.tokenBond.equals(tokenBond.publicKey))
+    assert.ok(bondSaleState.tokenQuote.equals(tokenQuote.publicKey))
+    assert.ok(bondSaleState.payer.equals(wallet.publicKey))
+    assert.ok(bondSaleState.remainingAmount.v.eq(bondAmount))
+
+    // reserves are owned by an authority derived from this bond sale only
+    const { authority } = await bonds.getBondsAuthority(bondSale)
+    const bondReserve = await tokenBond.getAccountInfo(tokenBondReserve)
+    const quoteReserve = await tokenQuote.getAccountInfo(tokenQuoteReserve)
+    assert.ok(bondReserve.owner.equals(authority))
+    assert.ok(quoteReserve.owner.equals(authority))
+    assert.ok(bondReserve.amount.eq(bondAmount))
+  })
+
+  it('#buyBond()', async () => {
+    await assertThrowsAsync(
+      bonds.buyBond({
+        bondSale,
+        ownerQuoteAccount: payerQuoteAccount,
+        amount: { v: boughtAmount },
+        maxQuoteAmount: { v: quoteAmount.subn(1) }
+      }),
+      BONDS_ERRORS.QUOTE_LIMIT_EXCEEDED
+    )
+
+    const result = await bonds.buyBond({
+      bondSale,
+      ownerQuoteAccount: payerQuoteAccount,
+      amount: { v: boughtAmount },
+      maxQuoteAmount: { v: quoteAmount }
+    })
+    bond = result.bond
+
+    const bondState = await bonds.getBond(bond)
+    assert.ok(bondState.bondSale.equals(bondSale))
+    assert.ok(bondState.owner.equals(wallet.publicKey))
+    assert.ok(bondState.tokenBondAmount.v.eq(boughtAmount))
+
+    const bondSaleState = await bonds.getBondSale(bondSale)
+    assert.ok(bondSaleState.remainingAmount.v.eq(bondAmount.sub(boughtAmount)))
+    assert.ok((await tokenQuote.getAccountInfo(tokenQuoteReserve)).amount.eq(quoteAmount))
+    assert.ok((await tokenQuote.getAccountInfo(payerQuoteAccount)).amount.eqn(0))
+  })
+
+  it('Another bond sale cannot use reserves of this one', async () => {
+    const result = await bonds.initBondSale(initBondSaleVars())
+    otherBondSale = result.bondSale
+
+    const { authority, nonce } = await bonds.getBondsAuthority(bondSale)
+    const { authority: otherAuthority, nonce: otherNonce } = await bonds.getBondsAuthority(
+      otherBondSale
+    )
+    assert.ok(!authority.equals(otherAuthority))
+
+    // reserves of the first sale do not belong to the second one
+    const foreignReservesIx = bonds.program.instruction.endBondSale(otherNonce, {
+      accounts: {
+        bondSale: otherBondSale,
+        tokenBondReserve,
+        tokenQuoteReserve,
+        payerBondAccount,
+        payerQuoteAccount,
+        bondsAuthority: otherAuthority,
+        payer: wallet.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID
+      }
+    })
+    await assertThrowsAsync(
+      signAndSend(new Transaction().add(foreignReservesIx), [wallet], connection),
+      BONDS_ERRORS.INVALID_TOKEN_ACCOUNT
+    )
+
+    // authority of the first sale is not derived from the second one
+    const foreignAuthorityIx = bonds.program.instruction.endBondSale(nonce, {
+      accounts: {
+        bondSale: otherBondSale,
+        tokenBondReserve: result.tokenBondReserve,
+        tokenQuoteReserve: result.tokenQuoteReserve,
+        payerBondAccount,
+        payerQuoteAccount,
+        bondsAuthority: authority,
+        payer: wallet.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID
+      }
+    })
+    await assertThrowsAsync(
+      signAndSend(new Transaction().add(foreignAuthorityIx), [wallet], connection)
+    )
+
+    // bond of the first sale cannot be claimed from the second one
+    const foreignBondIx = bonds.program.instruction.claimBond(otherNonce, {
+      accounts: {
+        bond,
+        bondSale: otherBondSale,
+        tokenBondReserve: result.tokenBondReserve,
+        ownerBondAccount: payerBondAccount,
+        bondsAuthority: otherAuthority,
+        owner: wallet.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID
+      }
+    })
+    await assertThrowsAsync(
+      signAndSend(new Transaction().add(foreignBondIx), [wallet], connection),
+      BONDS_ERRORS.INVALID_BOND
+    )
+  })
+
+  it('#claimBond()', async () => {
+    await sleep(3000)
+    const balanceBefore = (await tokenBond.getAccountInfo(payerBondAccount)).amount
+
+    await bonds.claimBond({ bond, ownerBondAccount: payerBondAccount })
+
+    const balanceAfter = (await tokenBond.getAccountInfo(payerBondAccount)).amount
+    assert.ok(balanceAfter.sub(balanceBefore).eq(boughtAmount))
+    // fully claimed bond is closed
+    assert.equal(await connection.getAccountInfo(bond), null)
+  })
+
+  it('#endBondSale()', async () => {
+    await assertThrowsAsync(
+      bonds.endBondSale({ bondSale, payerBondAccount, payerQuoteAccount }),
+      BONDS_ERRORS.TOO_EARLY
+    )
+
+    await sleep(endTime.sub(getTime()).addn(2).muln(1000).toNumber())
+    const balanceBefore = (await tokenBond.getAccountInfo(payerBondAccount)).amount
+
+    await bonds.endBondSale({ bondSale, payerBondAccount, payerQuoteAccount })
+
+    const balanceAfter = (await tokenBond.getAccountInfo(payerBondAccount)).amount
+    assert.ok(balanceAfter.sub(balanceBefore).eq(bondAmount.sub(boughtAmount)))
+    assert.ok((await tokenQuote.getAccountInfo(payerQuoteAccount)).amount.eq(quoteAmount))
+    assert.ok((await tokenBond.getAccountInfo(tokenBondReserve)).amount.eqn(0))
+    assert.ok((await tokenQuote.getAccountInfo(tokenQuoteReserve)).amount.eqn(0))
+
+    // the other sale keeps its own reserve untouched
+    const otherBondSaleState = await bonds.getBondSale(otherBondSale)
+    assert.ok(
+      (await tokenBond.getAccountInfo(otherBondSaleState.tokenBondReserve)).amount.eq(bondAmount)
+    )
+  })
+})
@@ -0,0 +1,249 @@
+import * as anchor from '@project-serum/anchor'
+import { Provider, BN } from '@project-serum/anchor'
+import { Market, Pair, DENOMINATOR, sleep, PRICE_DENOMINATOR } from '@invariant-labs/sdk'
+import { Network } from '../staker-sdk/src'
+import { Keypair, PublicKey, Transaction } from '@solana/web3.js'
+import { assert } from 'chai'
+import {
+  AddRewardToken,
+  CreateIncentive,
+  CreateStake,
+  Decimal,
+  Staker,
+  Withdraw
+} from '../staker-sdk/src/staker'
+import { STAKER_ERRORS } from '../staker-sdk/src/utils'
+import { almostEqual, assertThrowsAsync, createToken, getTime, signAndSend } from './testUtils'
+import { createToken as createTkn, initMarket } from '../tests/testUtils'
+import { fromFee } from '@invariant-labs/sdk/lib/utils'
+import { FeeTier } from '@invariant-labs/sdk/lib/market'
+import { InitPosition, UpdateSecondsPerLiquidity } from '@invariant-labs/sdk/src/market'
+import { Token, TOKEN_PROGRAM_ID } from '@solana/spl-token'
+import { tou64 } from '@invariant-labs/sdk/src/utils'
+
+describe('Add reward token tests', () => {
+  const provider = Provider.local()
+  const connection = provider.connection
+  // @ts-expect-error
+  const wallet = provider.wallet.payer as Account
+  const mintAuthority = Keypair.generate()
+  const founderAccount = Keypair.generate()
+  const positionOwner = Keypair.generate()
+  const admin = Keypair.generate()
+  const lowerTick = -30
+  const upperTick = 10
+  const epsilon = new BN(10)
+  let staker: Staker
+  let market: Market
+  let pool: PublicKey
+  let invariant: PublicKey
+  let rewardTokens: Token[]
+  let founderTokenAccounts: PublicKey[]
+  let pair: Pair
+  let tokenX: Token
+  let tokenY: Token
+
+  before(async () => {
+    staker = await Staker.build(Network.LOCAL, provider.wallet, connection)
+
+    await Promise.all([
+      connection.requestAirdrop(mintAuthority.publicKey, 1e9),
+      connection.requestAirdrop(founderAccount.publicKey, 10e9),
+      connection.requestAirdrop(positionOwner.publicKey, 1e9),
+      connection.requestAirdrop(admin.publicKey, 1e9)
+    ])
+
+    // one more token than an incentive can hold
+    rewardTokens = []
+    founderTokenAccounts = []
+    for (let i = 0; i < 5; i++) {
+      const rewardToken = await createToken(connection, wallet, wallet)
+      const founderTokenAccount = await rewardToken.createAccount(founderAccount.publicKey)
+      await rewardToken.mintTo(founderTokenAccount, wallet, [], tou64(new BN(5000 * 1e6)))
+      rewardTokens.push(rewardToken)
+      founderTokenAccounts.push(founderTokenAccount)
+    }
+
+    market = await Market.build(
+      0,
+      provider.wallet,
+      connection,
+      anchor.workspace.Invariant.programId
+    )
+
+    const tokens = await Promise.all([
+      createTkn(connection, wallet, mintAuthority),
+      createTkn(connection, wallet, mintAuthority)
+    ])
+
+    const feeTier: FeeTier = {
+      fee: fromFee(new BN(600)),
+      tickSpacing: 10
+    }
+
+    pair = new Pair(tokens[0].publicKey, tokens[1].publicKey, feeTier)
+    tokenX = new Token(connection, pair.tokenX, TOKEN_PROGRAM_ID, wallet)
+    tokenY = new Token(connection, pair.tokenY, TOKEN_PROGRAM_ID, wallet)
+    invariant = anchor.workspace.Invariant.programId
+  })
+
+  const createIncentive = async (reward: Decimal, duration: number) => {
+    const incentiveAccount = Keypair.generate()
+    const incentiveTokenAccount = Keypair.generate()
+    const currentTime = getTime()
+
+    const createIncentiveVars: CreateIncentive = {
+      reward,
+      startTime: { v: currentTime },
+      endTime: { v: currentTime.addn(duration) },
+      pool,
+      founder: founderAccount.publicKey,
+      incentiveToken: rewardTokens[0].publicKey,
+      founderTokenAccount: founderTokenAccounts[0],
+      invariant
+    }
+    const createTx = new Transaction().add(
+      await staker.createIncentiveIx(
+        createIncentiveVars,
+        incentiveAccount.publicKey,
+        incentiveTokenAccount.publicKey
+      )
+    )
+    await signAndSend(
+      createTx,
+      [founderAccount, incentiveAccount, incentiveTokenAccount],
+      staker.connection
+    )
+
+    return { incentive: incentiveAccount.publicKey, vault: incentiveTokenAccount.publicKey }
+  }
+
+  const addRewardToken = async (incentive: PublicKey, tokenIndex: number, reward: Decimal) => {
+    const incentiveTokenAccount = Keypair.generate()
+    const addRewardTokenVars: AddRewardToken = {
+      incentive,
+      reward,
+      founder: founderAccount.publicKey,
+      incentiveToken: rewardTokens[tokenIndex].publicKey,
+      founderTokenAccount: founderTokenAccounts[tokenIndex]
+    }
+    const addTx = new Transaction().add(
+      await staker.addRewardTokenIx(addRewardTokenVars, incentiveTokenAccount.publicKey)
+    )
+    await signAndSend(addTx, [founderAccount, incentiveTokenAccount], staker.connection)
+
+    return incentiveTokenAccount.publicKey
+  }
+
+  it('#init()', async () => {
+    await initMarket(market, [pair], admin)
+    pool = await pair.getAddress(anchor.workspace.Invariant.programId)
+
+    const userTokenXAccount = await tokenX.createAccount(positionOwner.publicKey)
+    const userTokenYAccount = await tokenY.createAccount(positionOwner.publicKey)
+    const mintAmount = tou64(new BN(10).pow(new BN(10)))
+    await tokenX.mintTo(userTokenXAccount, mintAuthority.publicKey, [mintAuthority], mintAmount)
+    await tokenY.mintTo(userTokenYAccount, mintAuthority.publicKey, [mintAuthority], mintAmount)
+
+    await market.createPositionList(positionOwner.publicKey, positionOwner)
+    const initPositionVars: InitPosition = {
+      pair,
+      owner: positionOwner.publicKey,
+      userTokenX: userTokenXAccount,
+      userTokenY: userTokenYAccount,
+      lowerTick,
+      upperTick,
+      liquidityDelta: { v: new BN(2000000).mul(DENOMINATOR) },
+      knownPrice: { v: PRICE_DENOMINATOR },
+      slippage: { v: new BN(0) }
+    }
+    await market.initPosition(initPositionVars, positionOwner)
+  })
+
+  it('Withdraw pays every reward token by the same share', async () => {
+    const { incentive, vault } = await createIncentive({ v: new BN(1000) }, 30)
+    const secondVault = await addRewardToken(incentive, 1, { v: new BN(2000) })
+
+    const incentiveStruct = await staker.getIncentive(incentive)
+    assert.equal(incentiveStruct.rewardCount, 2)
+    assert.ok(incentiveStruct.rewards[1].tokenAccount.equals(secondVault))
+    assert.ok(incentiveStruct.rewards[1].totalRewardUnclaimed.v.eqn(2000))
+
+    const index = 0
+    const { positionAddress: position } = await market.getPositionAddress(
+      positionOwner.publicKey,
+      index
+    )
+    const { id } = await market.getPosition(positionOwner.publicKey, index)
+    const update: UpdateSecondsPerLiquidity = {
+      pair,
+      owner: positionOwner.publicKey,
+      lowerTickIndex: lowerTick,
+      upperTickIndex: upperTick,
+      index
+    }
+    const createStake: CreateStake = {
+      pool,
+      id,
+      index,
+      position,
+      incentive,
+      owner: positionOwner.publicKey,
+      invariant
+    }
+    const stakeTx = new Transaction().add(await staker.createStakeIx(market, update, createStake))
+    await signAndSend(stakeTx, [positionOwner], staker.connection)
+
+    await sleep(10000)
+
+    const ownerTokenAccount = await rewardTokens[0].createAccount(positionOwner.publicKey)
+    const ownerSecondTokenAccount = await rewardTokens[1].createAccount(positionOwner.publicKey)
+    const withdraw: Withdraw = {
+      incentive,
+      pool,
+      id,
+      position,
+      owner: positionOwner.publicKey,
+      incentiveTokenAccount: vault,
+      ownerTokenAcc: ownerTokenAccount,
+      index
+    }
+
+    // every reward token has to be passed
+    const partialWithdrawTx = new Transaction().add(
+      await staker.withdrawIx(market, update, withdraw)
+    )
+    await assertThrowsAsync(
+      signAndSend(partialWithdrawTx, [positionOwner], staker.connection),
+      STAKER_ERRORS.INVALID_TOKEN_ACCOUNT
+    )
+
+    const withdrawTx = new Transaction().add(
+      await staker.withdrawIx(market, update, {
+        ...withdraw,
+        rewardAccounts: [
+          { incentiveTokenAccount: secondVault, tokenAccount: ownerSecondTokenAccount }
+        ]
+      })
+    )
+    await signAndSend(withdrawTx, [positionOwner], staker.connection)
+
+    const firstReward = (await rewardTokens[0].getAccountInfo(ownerTokenAccount)).amount
+    const secondReward = (await rewardTokens[1].getAccountInfo(ownerSecondTokenAccount)).amount
+    assert.ok(firstReward.gtn(0))
+    assert.ok(almostEqual(secondReward, firstReward.muln(2), epsilon))
+  })
+
+  it('Fail on more reward tokens than an incentive can hold', async () => {
+    const { incentive } = await createIncentive({ v: new BN(1000) }, 100)
+    for (let i = 1; i < 4; i++) {
+      await addRewardToken(incentive, i, { v: new BN(1000) })
+    }
+    assert.equal((await staker.getIncentive(incentive)).rewardCount, 4)
+
+    await assertThrowsAsync(
+      addRewardToken(incentive, 4, { v: new BN(1000) }),
+      STAKER_ERRORS.TOO_MANY_REWARD_TOKENS
+    )
+  })
+})
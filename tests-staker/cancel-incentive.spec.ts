@@ -0,0 +1,161 @@
+import * as anchor from '@project-serum/anchor'
+import { Provider, BN } from '@project-serum/anchor'
+import { Market, Pair } from '@invariant-labs/sdk'
+import { Network } from '../staker-sdk/src'
+import { Keypair, PublicKey, Transaction } from '@solana/web3.js'
+import { assert } from 'chai'
+import { AddRewardToken, CancelIncentive, CreateIncentive, Staker } from '../staker-sdk/src/staker'
+import { STAKER_ERRORS } from '../staker-sdk/src/utils'
+import { assertThrowsAsync, createToken, getTime, signAndSend } from './testUtils'
+import { createToken as createTkn, initMarket } from '../tests/testUtils'
+import { fromFee } from '@invariant-labs/sdk/lib/utils'
+import { FeeTier } from '@invariant-labs/sdk/lib/market'
+import { Token } from '@solana/spl-token'
+import { tou64 } from '@invariant-labs/sdk/src/utils'
+
+describe('Cancel incentive tests', () => {
+  const provider = Provider.local()
+  const connection = provider.connection
+  // @ts-expect-error
+  const wallet = provider.wallet.payer as Account
+  const mintAuthority = Keypair.generate()
+  const founderAccount = Keypair.generate()
+  const admin = Keypair.generate()
+  const founderBalance = new BN(5000 * 1e6)
+  let staker: Staker
+  let market: Market
+  let pool: PublicKey
+  let invariant: PublicKey
+  let rewardTokens: Token[]
+  let founderTokenAccounts: PublicKey[]
+  let pair: Pair
+
+  before(async () => {
+    staker = await Staker.build(Network.LOCAL, provider.wallet, connection)
+
+    await Promise.all([
+      connection.requestAirdrop(founderAccount.publicKey, 10e9),
+      connection.requestAirdrop(admin.publicKey, 1e9)
+    ])
+
+    rewardTokens = []
+    founderTokenAccounts = []
+    for (let i = 0; i < 2; i++) {
+      const rewardToken = await createToken(connection, wallet, wallet)
+      const founderTokenAccount = await rewardToken.createAccount(founderAccount.publicKey)
+      await rewardToken.mintTo(founderTokenAccount, wallet, [], tou64(founderBalance))
+      rewardTokens.push(rewardToken)
+      founderTokenAccounts.push(founderTokenAccount)
+    }
+
+    market = await Market.build(
+      0,
+      provider.wallet,
+      connection,
+      anchor.workspace.Invariant.programId
+    )
+
+    const tokens = await Promise.all([
+      createTkn(connection, wallet, mintAuthority),
+      createTkn(connection, wallet, mintAuthority)
+    ])
+
+    const feeTier: FeeTier = {
+      fee: fromFee(new BN(600)),
+      tickSpacing: 10
+    }
+
+    pair = new Pair(tokens[0].publicKey, tokens[1].publicKey, feeTier)
+    invariant = anchor.workspace.Invariant.programId
+  })
+
+  const createIncentive = async (startIn: number) => {
+    const incentiveAccount = Keypair.generate()
+    const incentiveTokenAccount = Keypair.generate()
+    const currentTime = getTime()
+
+    const createIncentiveVars: CreateIncentive = {
+      reward: { v: new BN(1000) },
+      startTime: { v: currentTime.addn(startIn) },
+      endTime: { v: currentTime.addn(startIn + 1000) },
+      pool,
+      founder: founderAccount.publicKey,
+      incentiveToken: rewardTokens[0].publicKey,
+      founderTokenAccount: founderTokenAccounts[0],
+      invariant
+    }
+    const createTx = new Transaction().add(
+      await staker.createIncentiveIx(
+        createIncentiveVars,
+        incentiveAccount.publicKey,
+        incentiveTokenAccount.publicKey
+      )
+    )
+    await signAndSend(
+      createTx,
+      [founderAccount, incentiveAccount, incentiveTokenAccount],
+      staker.connection
+    )
+
+    return { incentive: incentiveAccount.publicKey, vault: incentiveTokenAccount.publicKey }
+  }
+
+  it('#init()', async () => {
+    await initMarket(market, [pair], admin)
+    pool = await pair.getAddress(anchor.workspace.Invariant.programId)
+  })
+
+  it('Cancel incentive before the start', async () => {
+    const { incentive, vault } = await createIncentive(100)
+
+    const secondVault = Keypair.generate()
+    const addRewardTokenVars: AddRewardToken = {
+      incentive,
+      reward: { v: new BN(2000) },
+      founder: founderAccount.publicKey,
+      incentiveToken: rewardTokens[1].publicKey,
+      founderTokenAccount: founderTokenAccounts[1]
+    }
+    const addTx = new Transaction().add(
+      await staker.addRewardTokenIx(addRewardTokenVars, secondVault.publicKey)
+    )
+    await signAndSend(addTx, [founderAccount, secondVault], staker.connection)
+
+    const cancelIncentive: CancelIncentive = {
+      incentive,
+      incentiveTokenAccount: vault,
+      founderTokenAccount: founderTokenAccounts[0],
+      founder: founderAccount.publicKey,
+      rewardAccounts: [
+        { incentiveTokenAccount: secondVault.publicKey, tokenAccount: founderTokenAccounts[1] }
+      ]
+    }
+    const cancelTx = new Transaction().add(await staker.cancelIncentiveIx(cancelIncentive))
+    await signAndSend(cancelTx, [founderAccount], staker.connection)
+
+    // whole reward is returned and the incentive with its vaults is closed
+    for (let i = 0; i < 2; i++) {
+      const founderAccountInfo = await rewardTokens[i].getAccountInfo(founderTokenAccounts[i])
+      assert.ok(founderAccountInfo.amount.eq(founderBalance))
+    }
+    assert.equal(await connection.getAccountInfo(incentive), null)
+    assert.equal(await connection.getAccountInfo(vault), null)
+    assert.equal(await connection.getAccountInfo(secondVault.publicKey), null)
+  })
+
+  it('Fail on cancelling incentive after the start', async () => {
+    const { incentive, vault } = await createIncentive(0)
+
+    const cancelIncentive: CancelIncentive = {
+      incentive,
+      incentiveTokenAccount: vault,
+      founderTokenAccount: founderTokenAccounts[0],
+      founder: founderAccount.publicKey
+    }
+    const cancelTx = new Transaction().add(await staker.cancelIncentiveIx(cancelIncentive))
+    await assertThrowsAsync(
+      signAndSend(cancelTx, [founderAccount], staker.connection),
+      STAKER_ERRORS.ALREADY_STARTED
+    )
+  })
+})
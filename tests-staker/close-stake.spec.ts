@@ -200,7 +200,7 @@ describe('Remove Stake tests', () => {
     }
 
     const updateIx = await market.updateSecondsPerLiquidityInstruction(update)
-    const stakeIx = await staker.createStakeIx(createStake)
+    const stakeIx = await staker.createStakeIx(market, update, createStake)
     const stakeTx = new Transaction().add(updateIx).add(stakeIx)
 
     await signAndSend(stakeTx, [positionOwner], staker.connection)
@@ -229,7 +229,8 @@ describe('Remove Stake tests', () => {
       incentiveAccount.publicKey,
       position,
       positionOwner.publicKey,
-      index
+      index,
+      invariant
     )
     const closeTx = new Transaction().add(closeStakeIx)
     await signAndSend(closeTx, [positionOwner], staker.connection)
@@ -112,7 +112,8 @@ describe('Create incentive tests', () => {
     )
 
     const createdIncentive = await staker.getIncentive(incentiveAccount.publicKey)
-    assert.ok(createdIncentive.totalRewardUnclaimed.v.eq(reward.v))
+    assert.equal(createdIncentive.rewardCount, 1)
+    assert.ok(createdIncentive.rewards[0].totalRewardUnclaimed.v.eq(reward.v))
     assert.ok(eqDecimal(createdIncentive.totalSecondsClaimed, totalSecondsClaimed))
     assert.ok(createdIncentive.startTime.v.eq(startTime.v))
     assert.ok(createdIncentive.endTime.v.eq(endTime.v))
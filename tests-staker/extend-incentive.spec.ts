@@ -0,0 +1,331 @@
+import * as anchor from '@project-serum/anchor'
+import { Provider, BN } from '@project-serum/anchor'
+import { Market, Pair, DENOMINATOR, PRICE_DENOMINATOR, sleep } from '@invariant-labs/sdk'
+import { Network } from '../staker-sdk/src'
+import { Keypair, PublicKey, Transaction } from '@solana/web3.js'
+import { assert } from 'chai'
+import {
+  AddIncentiveReward,
+  CreateIncentive,
+  CreateStake,
+  Decimal,
+  ExtendIncentive,
+  Staker,
+  WithdrawMany
+} from '../staker-sdk/src/staker'
+import { STAKER_ERRORS } from '../staker-sdk/src/utils'
+import { assertThrowsAsync, createToken, getTime, signAndSend } from './testUtils'
+import { createToken as createTkn, initMarket } from '../tests/testUtils'
+import { fromFee } from '@invariant-labs/sdk/lib/utils'
+import { FeeTier } from '@invariant-labs/sdk/lib/market'
+import { InitPosition, UpdateSecondsPerLiquidity } from '@invariant-labs/sdk/src/market'
+import { Token, TOKEN_PROGRAM_ID } from '@solana/spl-token'
+import { tou64 } from '@invariant-labs/sdk/src/utils'
+
+describe('Add incentive reward and extend incentive tests', () => {
+  const provider = Provider.local()
+  const connection = provider.connection
+  // @ts-expect-error
+  const wallet = provider.wallet.payer as Account
+  const mintAuthority = Keypair.generate()
+  const founderAccount = Keypair.generate()
+  const funderAccount = Keypair.generate()
+  const positionOwner = Keypair.generate()
+  const admin = Keypair.generate()
+  const incentiveAccount = Keypair.generate()
+  const incentiveTokenAccount = Keypair.generate()
+  const reward: Decimal = { v: new BN(1000) }
+  const lowerTick = -30
+  const upperTick = 10
+  const index = 0
+  let staker: Staker
+  let market: Market
+  let pool: PublicKey
+  let invariant: PublicKey
+  let incentiveToken: Token
+  let founderTokenAccount: PublicKey
+  let funderTokenAccount: PublicKey
+  let pair: Pair
+  let tokenX: Token
+  let tokenY: Token
+
+  before(async () => {
+    staker = await Staker.build(Network.LOCAL, provider.wallet, connection)
+
+    await Promise.all([
+      connection.requestAirdrop(founderAccount.publicKey, 10e9),
+      connection.requestAirdrop(funderAccount.publicKey, 1e9),
+      connection.requestAirdrop(positionOwner.publicKey, 1e9),
+      connection.requestAirdrop(mintAuthority.publicKey, 1e9),
+      connection.requestAirdrop(admin.publicKey, 1e9)
+    ])
+
+    incentiveToken = await createToken(connection, wallet, wallet)
+    founderTokenAccount = await incentiveToken.createAccount(founderAccount.publicKey)
+    funderTokenAccount = await incentiveToken.createAccount(funderAccount.publicKey)
+    await incentiveToken.mintTo(founderTokenAccount, wallet, [], tou64(new BN(5000 * 1e6)))
+    await incentiveToken.mintTo(funderTokenAccount, wallet, [], tou64(new BN(5000 * 1e6)))
+
+    market = await Market.build(
+      0,
+      provider.wallet,
+      connection,
+      anchor.workspace.Invariant.programId
+    )
+
+    const tokens = await Promise.all([
+      createTkn(connection, wallet, mintAuthority),
+      createTkn(connection, wallet, mintAuthority)
+    ])
+
+    const feeTier: FeeTier = {
+      fee: fromFee(new BN(600)),
+      tickSpacing: 10
+    }
+
+    pair = new Pair(tokens[0].publicKey, tokens[1].publicKey, feeTier)
+    tokenX = new Token(connection, pair.tokenX, TOKEN_PROGRAM_ID, wallet)
+    tokenY = new Token(connection, pair.tokenY, TOKEN_PROGRAM_ID, wallet)
+    invariant = anchor.workspace.Invariant.programId
+  })
+
+  it('#init()', async () => {
+    await initMarket(market, [pair], admin)
+    pool = await pair.getAddress(anchor.workspace.Invariant.programId)
+
+    const currentTime = getTime()
+    const createIncentiveVars: CreateIncentive = {
+      reward,
+      startTime: { v: currentTime },
+      endTime: { v: currentTime.addn(1000) },
+      pool,
+      founder: founderAccount.publicKey,
+      incentiveToken: incentiveToken.publicKey,
+      founderTokenAccount,
+      invariant
+    }
+    const createTx = new Transaction().add(
+      await staker.createIncentiveIx(
+        createIncentiveVars,
+        incentiveAccount.publicKey,
+        incentiveTokenAccount.publicKey
+      )
+    )
+    await signAndSend(
+      createTx,
+      [founderAccount, incentiveAccount, incentiveTokenAccount],
+      staker.connection
+    )
+  })
+
+  it('Add incentive reward', async () => {
+    const addIncentiveReward: AddIncentiveReward = {
+      incentive: incentiveAccount.publicKey,
+      incentiveTokenAccount: incentiveTokenAccount.publicKey,
+      funderTokenAccount,
+      funder: funderAccount.publicKey,
+      reward: { v: new BN(500) }
+    }
+    const addTx = new Transaction().add(await staker.addIncentiveRewardIx(addIncentiveReward))
+    await signAndSend(addTx, [funderAccount], staker.connection)
+
+    const incentive = await staker.getIncentive(incentiveAccount.publicKey)
+    assert.ok(incentive.rewards[0].totalRewardUnclaimed.v.eqn(1500))
+    const vault = await incentiveToken.getAccountInfo(incentiveTokenAccount.publicKey)
+    assert.ok(vault.amount.eqn(1500))
+  })
+
+  it('Fail on adding reward to a vault outside of the incentive', async () => {
+    const otherVault = await incentiveToken.createAccount(staker.programAuthority.authority)
+    const addIncentiveReward: AddIncentiveReward = {
+      incentive: incentiveAccount.publicKey,
+      incentiveTokenAccount: otherVault,
+      funderTokenAccount,
+      funder: funderAccount.publicKey,
+      reward: { v: new BN(500) }
+    }
+    const addTx = new Transaction().add(await staker.addIncentiveRewardIx(addIncentiveReward))
+    await assertThrowsAsync(
+      signAndSend(addTx, [funderAccount], staker.connection),
+      STAKER_ERRORS.INVALID_TOKEN_ACCOUNT
+    )
+  })
+
+  it('Extend incentive', async () => {
+    const incentiveBefore = await staker.getIncentive(incentiveAccount.publicKey)
+    const endTime = { v: incentiveBefore.endTime.v.addn(1000) }
+
+    const founderBalanceBefore = (await incentiveToken.getAccountInfo(founderTokenAccount)).amount
+
+    const extendIncentive: ExtendIncentive = {
+      incentive: incentiveAccount.publicKey,
+      incentiveTokenAccount: incentiveTokenAccount.publicKey,
+      founderTokenAccount,
+      founder: founderAccount.publicKey,
+      endTime
+    }
+    const extendTx = new Transaction().add(await staker.extendIncentiveIx(extendIncentive))
+    await signAndSend(extendTx, [founderAccount], staker.connection)
+
+    // duration is doubled, so the founder deposits the whole unclaimed reward again
+    const incentive = await staker.getIncentive(incentiveAccount.publicKey)
+    assert.ok(incentive.endTime.v.eq(endTime.v))
+    assert.ok(incentive.endClaimTime.v.eq(endTime.v.addn(604800)))
+    assert.ok(incentive.rewards[0].totalRewardUnclaimed.v.eqn(3000))
+    const founderBalance = (await incentiveToken.getAccountInfo(founderTokenAccount)).amount
+    assert.ok(founderBalanceBefore.sub(founderBalance).eqn(1500))
+    const vault = await incentiveToken.getAccountInfo(incentiveTokenAccount.publicKey)
+    assert.ok(vault.amount.eqn(3000))
+  })
+
+  it('Fail on shortening or extending by someone else than founder', async () => {
+    const incentive = await staker.getIncentive(incentiveAccount.publicKey)
+
+    const shortenTx = new Transaction().add(
+      await staker.extendIncentiveIx({
+        incentive: incentiveAccount.publicKey,
+        incentiveTokenAccount: incentiveTokenAccount.publicKey,
+        founderTokenAccount,
+        founder: founderAccount.publicKey,
+        endTime: { v: incentive.endTime.v.subn(1) }
+      })
+    )
+    await assertThrowsAsync(
+      signAndSend(shortenTx, [founderAccount], staker.connection),
+      STAKER_ERRORS.INVALID_END_TIME
+    )
+
+    const extendByFunderTx = new Transaction().add(
+      await staker.extendIncentiveIx({
+        incentive: incentiveAccount.publicKey,
+        incentiveTokenAccount: incentiveTokenAccount.publicKey,
+        founderTokenAccount: funderTokenAccount,
+        founder: funderAccount.publicKey,
+        endTime: { v: incentive.endTime.v.addn(1000) }
+      })
+    )
+    await assertThrowsAsync(
+      signAndSend(extendByFunderTx, [funderAccount], staker.connection),
+      STAKER_ERRORS.INVALID_FOUNDER
+    )
+  })
+
+  it('Extending keeps rewards earned before the extension', async () => {
+    const userTokenXAccount = await tokenX.createAccount(positionOwner.publicKey)
+    const userTokenYAccount = await tokenY.createAccount(positionOwner.publicKey)
+    const mintAmount = tou64(new BN(10).pow(new BN(10)))
+    await tokenX.mintTo(userTokenXAccount, mintAuthority.publicKey, [mintAuthority], mintAmount)
+    await tokenY.mintTo(userTokenYAccount, mintAuthority.publicKey, [mintAuthority], mintAmount)
+
+    await market.createPositionList(positionOwner.publicKey, positionOwner)
+    const initPositionVars: InitPosition = {
+      pair,
+      owner: positionOwner.publicKey,
+      userTokenX: userTokenXAccount,
+      userTokenY: userTokenYAccount,
+      lowerTick,
+      upperTick,
+      liquidityDelta: { v: new BN(2000000).mul(DENOMINATOR) },
+      knownPrice: { v: PRICE_DENOMINATOR },
+      slippage: { v: new BN(0) }
+    }
+    await market.initPosition(initPositionVars, positionOwner)
+    const { positionAddress: position } = await market.getPositionAddress(
+      positionOwner.publicKey,
+      index
+    )
+    const { id } = await market.getPosition(positionOwner.publicKey, index)
+
+    // two identical incentives, only the second one is extended
+    const currentTime = getTime()
+    const incentives = [0, 1].map(() => ({
+      incentive: Keypair.generate(),
+      vault: Keypair.generate()
+    }))
+    const createTx = new Transaction()
+    for (const { incentive, vault } of incentives) {
+      const createIncentiveVars: CreateIncentive = {
+        reward,
+        startTime: { v: currentTime },
+        endTime: { v: currentTime.addn(100) },
+        pool,
+        founder: founderAccount.publicKey,
+        incentiveToken: incentiveToken.publicKey,
+        founderTokenAccount,
+        invariant
+      }
+      createTx.add(
+        await staker.createIncentiveIx(createIncentiveVars, incentive.publicKey, vault.publicKey)
+      )
+    }
+    await signAndSend(
+      createTx,
+      [founderAccount, ...incentives.flatMap(({ incentive, vault }) => [incentive, vault])],
+      staker.connection
+    )
+
+    // both stakes are created at the same time
+    const update: UpdateSecondsPerLiquidity = {
+      pair,
+      owner: positionOwner.publicKey,
+      lowerTickIndex: lowerTick,
+      upperTickIndex: upperTick,
+      index
+    }
+    const stakeTx = new Transaction()
+    for (const { incentive } of incentives) {
+      const createStake: CreateStake = {
+        pool,
+        id,
+        index,
+        position,
+        incentive: incentive.publicKey,
+        owner: positionOwner.publicKey,
+        invariant
+      }
+      stakeTx.add(await staker.createStakeIx(market, update, createStake))
+    }
+    await signAndSend(stakeTx, [positionOwner], staker.connection)
+
+    await sleep(10000)
+
+    const extendTx = new Transaction().add(
+      await staker.extendIncentiveIx({
+        incentive: incentives[1].incentive.publicKey,
+        incentiveTokenAccount: incentives[1].vault.publicKey,
+        founderTokenAccount,
+        founder: founderAccount.publicKey,
+        endTime: { v: currentTime.addn(200) }
+      })
+    )
+    await signAndSend(extendTx, [founderAccount], staker.connection)
+
+    // rewards of both incentives are claimed at the same time
+    const ownerTokenAccounts = [
+      await incentiveToken.createAccount(positionOwner.publicKey),
+      await incentiveToken.createAccount(positionOwner.publicKey)
+    ]
+    const withdrawMany: WithdrawMany = {
+      pool,
+      id,
+      position,
+      owner: positionOwner.publicKey,
+      index,
+      incentives: incentives.map(({ incentive, vault }, i) => ({
+        incentive: incentive.publicKey,
+        incentiveTokenAccount: vault.publicKey,
+        ownerTokenAcc: ownerTokenAccounts[i]
+      }))
+    }
+    const withdrawTx = new Transaction().add(
+      await staker.withdrawManyIx(market, update, withdrawMany)
+    )
+    await signAndSend(withdrawTx, [positionOwner], staker.connection)
+
+    const [payout, extendedPayout] = await Promise.all(
+      ownerTokenAccounts.map(async account => (await incentiveToken.getAccountInfo(account)).amount)
+    )
+    assert.ok(payout.gtn(0))
+    assert.ok(extendedPayout.eq(payout))
+  })
+})
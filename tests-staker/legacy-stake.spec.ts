@@ -0,0 +1,275 @@
+import * as anchor from '@project-serum/anchor'
+import { Provider, BN } from '@project-serum/anchor'
+import {
+  Market,
+  Pair,
+  DENOMINATOR,
+  PRICE_DENOMINATOR,
+  INVARIANT_ERRORS
+} from '@invariant-labs/sdk'
+import { Network } from '../staker-sdk/src'
+import { Keypair, PublicKey, SystemProgram, Transaction } from '@solana/web3.js'
+import { assert } from 'chai'
+import { CreateIncentive, CreateStake, Staker } from '../staker-sdk/src/staker'
+import { assertThrowsAsync, createToken, getTime, signAndSend } from './testUtils'
+import { initMarket } from '../tests/testUtils'
+import { fromFee } from '@invariant-labs/sdk/lib/utils'
+import { FeeTier } from '@invariant-labs/sdk/lib/market'
+import {
+  InitPosition,
+  RemovePosition,
+  UpdateSecondsPerLiquidity
+} from '@invariant-labs/sdk/src/market'
+import { MintLayout, Token, TOKEN_PROGRAM_ID } from '@solana/spl-token'
+import { tou64 } from '@invariant-labs/sdk/src/utils'
+import { readFileSync } from 'fs'
+
+// Addresses of the legacy stake fixture loaded by the test validator (see Anchor.toml) depend on
+// these keypairs, the fee tier and the id of the first position in the pool
+const loadKeypair = (name: string) =>
+  Keypair.fromSecretKey(
+    Uint8Array.from(
+      JSON.parse(readFileSync(`tests-staker/fixtures/legacy-stake/${name}.json`, 'utf-8'))
+    )
+  )
+
+describe('Legacy stake tests', () => {
+  const provider = Provider.local()
+  const connection = provider.connection
+  // @ts-expect-error
+  const wallet = provider.wallet.payer as Account
+  const mintAuthority = Keypair.generate()
+  const founderAccount = Keypair.generate()
+  const positionOwner = loadKeypair('owner')
+  const admin = Keypair.generate()
+  const incentiveAccount = Keypair.generate()
+  const incentiveTokenAccount = Keypair.generate()
+  // incentive of the legacy stake, ended and closed before the upgrade
+  const legacyIncentive = new PublicKey('49VisWnVHdMhwTVA7hU6cf4DNBwMs1QMXqHwZEZyuLPA')
+  const legacyStake = new PublicKey('DwiJdKwS7u9Ep4kT5ANgnVtGsSPNft2yft7qr8bvdkob')
+  const lowerTick = -30
+  const upperTick = 10
+  const index = 0
+  let staker: Staker
+  let market: Market
+  let pool: PublicKey
+  let invariant: PublicKey
+  let incentiveToken: Token
+  let founderTokenAccount: PublicKey
+  let pair: Pair
+  let tokenX: Token
+  let tokenY: Token
+  let userTokenXAccount: PublicKey
+  let userTokenYAccount: PublicKey
+  let position: PublicKey
+  let positionId: BN
+
+  const createMint = async (mint: Keypair) => {
+    const tx = new Transaction().add(
+      SystemProgram.createAccount({
+        fromPubkey: wallet.publicKey,
+        newAccountPubkey: mint.publicKey,
+        lamports: await Token.getMinBalanceRentForExemptMint(connection),
+        space: MintLayout.span,
+        programId: TOKEN_PROGRAM_ID
+      }),
+      Token.createInitMintInstruction(
+        TOKEN_PROGRAM_ID,
+        mint.publicKey,
+        6,
+        mintAuthority.publicKey,
+        null
+      )
+    )
+    await signAndSend(tx, [wallet, mint], connection)
+    return mint.publicKey
+  }
+
+  before(async () => {
+    staker = await Staker.build(Network.LOCAL, provider.wallet, connection)
+
+    await Promise.all([
+      connection.requestAirdrop(mintAuthority.publicKey, 1e9),
+      connection.requestAirdrop(founderAccount.publicKey, 10e9),
+      connection.requestAirdrop(positionOwner.publicKey, 1e9),
+      connection.requestAirdrop(admin.publicKey, 1e9)
+    ])
+
+    incentiveToken = await createToken(connection, wallet, wallet)
+    founderTokenAccount = await incentiveToken.createAccount(founderAccount.publicKey)
+    await incentiveToken.mintTo(founderTokenAccount, wallet, [], tou64(new BN(5000 * 1e6)))
+
+    market = await Market.build(
+      0,
+      provider.wallet,
+      connection,
+      anchor.workspace.Invariant.programId
+    )
+
+    const tokens = [
+      await createMint(loadKeypair('token-a')),
+      await createMint(loadKeypair('token-b'))
+    ]
+
+    const feeTier: FeeTier = {
+      fee: fromFee(new BN(600)),
+      tickSpacing: 10
+    }
+
+    pair = new Pair(tokens[0], tokens[1], feeTier)
+    tokenX = new Token(connection, pair.tokenX, TOKEN_PROGRAM_ID, wallet)
+    tokenY = new Token(connection, pair.tokenY, TOKEN_PROGRAM_ID, wallet)
+    invariant = anchor.workspace.Invariant.programId
+  })
+
+  it('#init()', async () => {
+    await initMarket(market, [pair], admin)
+    pool = await pair.getAddress(anchor.workspace.Invariant.programId)
+
+    userTokenXAccount = await tokenX.createAccount(positionOwner.publicKey)
+    userTokenYAccount = await tokenY.createAccount(positionOwner.publicKey)
+    const mintAmount = tou64(new BN(10).pow(new BN(10)))
+    await tokenX.mintTo(userTokenXAccount, mintAuthority.publicKey, [mintAuthority], mintAmount)
+    await tokenY.mintTo(userTokenYAccount, mintAuthority.publicKey, [mintAuthority], mintAmount)
+
+    await market.createPositionList(positionOwner.publicKey, positionOwner)
+    const initPositionVars: InitPosition = {
+      pair,
+      owner: positionOwner.publicKey,
+      userTokenX: userTokenXAccount,
+      userTokenY: userTokenYAccount,
+      lowerTick,
+      upperTick,
+      liquidityDelta: { v: new BN(2000000).mul(DENOMINATOR) },
+      knownPrice: { v: PRICE_DENOMINATOR },
+      slippage: { v: new BN(0) }
+    }
+    await market.initPosition(initPositionVars, positionOwner)
+
+    const { positionAddress } = await market.getPositionAddress(positionOwner.publicKey, index)
+    position = positionAddress
+    positionId = (await market.getPosition(positionOwner.publicKey, index)).id
+
+    // legacy stake of the position was created before the upgrade and never locked it
+    const [legacyStakeAddress] = await staker.getUserStakeAddressAndBump(
+      legacyIncentive,
+      pool,
+      positionId
+    )
+    assert.ok(legacyStakeAddress.equals(legacyStake))
+    assert.equal((await market.getPosition(positionOwner.publicKey, index)).stakeCount, 0)
+  })
+
+  it('Stake the position after the upgrade', async () => {
+    const currentTime = getTime()
+    const createIncentiveVars: CreateIncentive = {
+      reward: { v: new BN(1000) },
+      startTime: { v: currentTime },
+      endTime: { v: currentTime.addn(1000) },
+      pool,
+      founder: founderAccount.publicKey,
+      incentiveToken: incentiveToken.publicKey,
+      founderTokenAccount,
+      invariant
+    }
+    const createTx = new Transaction().add(
+      await staker.createIncentiveIx(
+        createIncentiveVars,
+        incentiveAccount.publicKey,
+        incentiveTokenAccount.publicKey
+      )
+    )
+    await signAndSend(
+      createTx,
+      [founderAccount, incentiveAccount, incentiveTokenAccount],
+      staker.connection
+    )
+
+    const update: UpdateSecondsPerLiquidity = {
+      pair,
+      owner: positionOwner.publicKey,
+      lowerTickIndex: lowerTick,
+      upperTickIndex: upperTick,
+      index
+    }
+    const createStake: CreateStake = {
+      pool,
+      id: positionId,
+      index,
+      position,
+      incentive: incentiveAccount.publicKey,
+      owner: positionOwner.publicKey,
+      invariant
+    }
+    const stakeTx = new Transaction().add(await staker.createStakeIx(market, update, createStake))
+    await signAndSend(stakeTx, [positionOwner], staker.connection)
+
+    const userStake = await staker.getStake(incentiveAccount.publicKey, pool, positionId)
+    assert.ok(userStake.locked)
+    assert.equal((await market.getPosition(positionOwner.publicKey, index)).stakeCount, 1)
+  })
+
+  it('Closing the legacy stake keeps the position locked', async () => {
+    await staker.migrateStake(legacyStake)
+    const migratedStake = await staker.getStake(legacyIncentive, pool, positionId)
+    assert.ok(migratedStake.position.equals(position))
+    assert.ok(!migratedStake.locked)
+
+    // stake is already in the current layout
+    await assertThrowsAsync(staker.migrateStake(legacyStake))
+
+    const closeStakeIx = await staker.closeStakeByOwnerIx(
+      legacyStake,
+      legacyIncentive,
+      position,
+      positionOwner.publicKey,
+      index,
+      invariant
+    )
+    await signAndSend(new Transaction().add(closeStakeIx), [positionOwner], staker.connection)
+    assert.equal(await connection.getAccountInfo(legacyStake), null)
+    assert.equal((await market.getPosition(positionOwner.publicKey, index)).stakeCount, 1)
+
+    const removePositionVars: RemovePosition = {
+      pair,
+      owner: positionOwner.publicKey,
+      index,
+      userTokenX: userTokenXAccount,
+      userTokenY: userTokenYAccount
+    }
+    await assertThrowsAsync(
+      market.removePosition(removePositionVars, positionOwner),
+      INVARIANT_ERRORS.POSITION_LOCKED
+    )
+  })
+
+  it('Remove the position after closing the stake created after the upgrade', async () => {
+    const [userStakeAddress] = await staker.getUserStakeAddressAndBump(
+      incentiveAccount.publicKey,
+      pool,
+      positionId
+    )
+    const closeStakeIx = await staker.closeStakeByOwnerIx(
+      userStakeAddress,
+      incentiveAccount.publicKey,
+      position,
+      positionOwner.publicKey,
+      index,
+      invariant
+    )
+    await signAndSend(new Transaction().add(closeStakeIx), [positionOwner], staker.connection)
+    assert.equal((await market.getPosition(positionOwner.publicKey, index)).stakeCount, 0)
+
+    await market.removePosition(
+      {
+        pair,
+        owner: positionOwner.publicKey,
+        index,
+        userTokenX: userTokenXAccount,
+        userTokenY: userTokenYAccount
+      },
+      positionOwner
+    )
+    assert.equal((await market.getPositionList(positionOwner.publicKey)).head, 0)
+  })
+})
@@ -286,7 +286,7 @@ describe('Multicall test', () => {
       invariant: anchor.workspace.Invariant.programId
     }
 
-    const firstStakeIx = await staker.createStakeIx(firstCreateStake)
+    const firstStakeIx = await staker.createStakeIx(market, firstUpdate, firstCreateStake)
     const firstTx = new Transaction().add(firstUpdateIx).add(firstStakeIx)
     await signAndSend(firstTx, [firstPositionOwner], staker.connection)
 
@@ -302,7 +302,7 @@ describe('Multicall test', () => {
       invariant
     }
 
-    const secondStakeIx = await staker.createStakeIx(secondCreateStake)
+    const secondStakeIx = await staker.createStakeIx(market, secondUpdate, secondCreateStake)
     const secondTx = new Transaction().add(secondUpdateIx).add(secondStakeIx)
     await signAndSend(secondTx, [secondPositionOwner], staker.connection)
 
@@ -318,7 +318,7 @@ describe('Multicall test', () => {
       invariant
     }
 
-    const thirdStakeIx = await staker.createStakeIx(thirdCreateStake)
+    const thirdStakeIx = await staker.createStakeIx(market, firstUpdate, thirdCreateStake)
     const thirdTx = new Transaction().add(firstUpdateIx).add(thirdStakeIx)
     await signAndSend(thirdTx, [firstPositionOwner], staker.connection)
 
@@ -334,7 +334,7 @@ describe('Multicall test', () => {
       invariant
     }
 
-    const fourthStakeIx = await staker.createStakeIx(fourthCreateStake)
+    const fourthStakeIx = await staker.createStakeIx(market, secondUpdate, fourthCreateStake)
     const fourthTx = new Transaction().add(secondUpdateIx).add(fourthStakeIx)
     await signAndSend(fourthTx, [secondPositionOwner], staker.connection)
 
@@ -373,7 +373,7 @@ describe('Multicall test', () => {
       index
     }
 
-    const firstWithdrawIx = await staker.withdrawIx(firstWithdraw)
+    const firstWithdrawIx = await staker.withdrawIx(market, firstUpdate, firstWithdraw)
     const firstWithdrawTx = new Transaction().add(firstUpdateIx).add(firstWithdrawIx)
     await signAndSend(firstWithdrawTx, [firstPositionOwner], staker.connection)
 
@@ -392,7 +392,7 @@ describe('Multicall test', () => {
       index
     }
 
-    const secondWithdrawIx = await staker.withdrawIx(secondWithdraw)
+    const secondWithdrawIx = await staker.withdrawIx(market, secondUpdate, secondWithdraw)
     const secondWithdrawTx = new Transaction().add(secondUpdateIx).add(secondWithdrawIx)
     await signAndSend(secondWithdrawTx, [secondPositionOwner], staker.connection)
 
@@ -410,7 +410,7 @@ describe('Multicall test', () => {
       index
     }
 
-    const thirdWithdrawIx = await staker.withdrawIx(thirdWithdraw)
+    const thirdWithdrawIx = await staker.withdrawIx(market, firstUpdate, thirdWithdraw)
     const thirdWithdrawTx = new Transaction().add(firstUpdateIx).add(thirdWithdrawIx)
     await signAndSend(thirdWithdrawTx, [firstPositionOwner], staker.connection)
 
@@ -428,7 +428,7 @@ describe('Multicall test', () => {
       index
     }
 
-    const fourthWithdrawIx = await staker.withdrawIx(fourthWithdraw)
+    const fourthWithdrawIx = await staker.withdrawIx(market, secondUpdate, fourthWithdraw)
     const fourthWithdrawTx = new Transaction().add(secondUpdateIx).add(fourthWithdrawIx)
     await signAndSend(fourthWithdrawTx, [secondPositionOwner], staker.connection)
 
@@ -0,0 +1,214 @@
+import * as anchor from '@project-serum/anchor'
+import { Provider, BN } from '@project-serum/anchor'
+import { Market, Pair, DENOMINATOR, sleep, PRICE_DENOMINATOR } from '@invariant-labs/sdk'
+import { Network } from '../staker-sdk/src'
+import { Keypair, PublicKey, Transaction } from '@solana/web3.js'
+import { assert } from 'chai'
+import {
+  CreateIncentive,
+  CreateStake,
+  Decimal,
+  GetPendingReward,
+  Staker,
+  Withdraw
+} from '../staker-sdk/src/staker'
+import { STAKER_ERRORS } from '../staker-sdk/src/utils'
+import { assertThrowsAsync, createToken, getTime, signAndSend } from './testUtils'
+import { createToken as createTkn, initMarket } from '../tests/testUtils'
+import { fromFee } from '@invariant-labs/sdk/lib/utils'
+import { FeeTier } from '@invariant-labs/sdk/lib/market'
+import { InitPosition, UpdateSecondsPerLiquidity } from '@invariant-labs/sdk/src/market'
+import { Token, TOKEN_PROGRAM_ID } from '@solana/spl-token'
+import { tou64 } from '@invariant-labs/sdk/src/utils'
+
+describe('Pending reward tests', () => {
+  const provider = Provider.local()
+  const connection = provider.connection
+  // @ts-expect-error
+  const wallet = provider.wallet.payer as Account
+  const mintAuthority = Keypair.generate()
+  const founderAccount = Keypair.generate()
+  const positionOwner = Keypair.generate()
+  const admin = Keypair.generate()
+  const incentiveAccount = Keypair.generate()
+  const incentiveTokenAccount = Keypair.generate()
+  const lowerTick = -30
+  const upperTick = 10
+  const index = 0
+  let staker: Staker
+  let market: Market
+  let pool: PublicKey
+  let invariant: PublicKey
+  let incentiveToken: Token
+  let founderTokenAccount: PublicKey
+  let pair: Pair
+  let tokenX: Token
+  let tokenY: Token
+  let position: PublicKey
+  let positionId: BN
+  let update: UpdateSecondsPerLiquidity
+
+  before(async () => {
+    staker = await Staker.build(Network.LOCAL, provider.wallet, connection)
+
+    await Promise.all([
+      connection.requestAirdrop(mintAuthority.publicKey, 1e9),
+      connection.requestAirdrop(founderAccount.publicKey, 10e9),
+      connection.requestAirdrop(positionOwner.publicKey, 1e9),
+      connection.requestAirdrop(admin.publicKey, 1e9)
+    ])
+
+    incentiveToken = await createToken(connection, wallet, wallet)
+    founderTokenAccount = await incentiveToken.createAccount(founderAccount.publicKey)
+    await incentiveToken.mintTo(founderTokenAccount, wallet, [], tou64(new BN(5000 * 1e6)))
+
+    market = await Market.build(
+      0,
+      provider.wallet,
+      connection,
+      anchor.workspace.Invariant.programId
+    )
+
+    const tokens = await Promise.all([
+      createTkn(connection, wallet, mintAuthority),
+      createTkn(connection, wallet, mintAuthority)
+    ])
+
+    const feeTier: FeeTier = {
+      fee: fromFee(new BN(600)),
+      tickSpacing: 10
+    }
+
+    pair = new Pair(tokens[0].publicKey, tokens[1].publicKey, feeTier)
+    tokenX = new Token(connection, pair.tokenX, TOKEN_PROGRAM_ID, wallet)
+    tokenY = new Token(connection, pair.tokenY, TOKEN_PROGRAM_ID, wallet)
+    invariant = anchor.workspace.Invariant.programId
+  })
+
+  it('#init()', async () => {
+    await initMarket(market, [pair], admin)
+    pool = await pair.getAddress(anchor.workspace.Invariant.programId)
+
+    const currentTime = getTime()
+    const reward: Decimal = { v: new BN(1000) }
+    const createIncentiveVars: CreateIncentive = {
+      reward,
+      startTime: { v: currentTime },
+      endTime: { v: currentTime.addn(100) },
+      pool,
+      founder: founderAccount.publicKey,
+      incentiveToken: incentiveToken.publicKey,
+      founderTokenAccount,
+      invariant
+    }
+    const createTx = new Transaction().add(
+      await staker.createIncentiveIx(
+        createIncentiveVars,
+        incentiveAccount.publicKey,
+        incentiveTokenAccount.publicKey
+      )
+    )
+    await signAndSend(
+      createTx,
+      [founderAccount, incentiveAccount, incentiveTokenAccount],
+      staker.connection
+    )
+
+    const userTokenXAccount = await tokenX.createAccount(positionOwner.publicKey)
+    const userTokenYAccount = await tokenY.createAccount(positionOwner.publicKey)
+    const mintAmount = tou64(new BN(10).pow(new BN(10)))
+    await tokenX.mintTo(userTokenXAccount, mintAuthority.publicKey, [mintAuthority], mintAmount)
+    await tokenY.mintTo(userTokenYAccount, mintAuthority.publicKey, [mintAuthority], mintAmount)
+
+    await market.createPositionList(positionOwner.publicKey, positionOwner)
+    const initPositionVars: InitPosition = {
+      pair,
+      owner: positionOwner.publicKey,
+      userTokenX: userTokenXAccount,
+      userTokenY: userTokenYAccount,
+      lowerTick,
+      upperTick,
+      liquidityDelta: { v: new BN(2000000).mul(DENOMINATOR) },
+      knownPrice: { v: PRICE_DENOMINATOR },
+      slippage: { v: new BN(0) }
+    }
+    await market.initPosition(initPositionVars, positionOwner)
+
+    const { positionAddress } = await market.getPositionAddress(positionOwner.publicKey, index)
+    position = positionAddress
+    positionId = (await market.getPosition(positionOwner.publicKey, index)).id
+    update = {
+      pair,
+      owner: positionOwner.publicKey,
+      lowerTickIndex: lowerTick,
+      upperTickIndex: upperTick,
+      index
+    }
+
+    const createStake: CreateStake = {
+      pool,
+      id: positionId,
+      index,
+      position,
+      incentive: incentiveAccount.publicKey,
+      owner: positionOwner.publicKey,
+      invariant
+    }
+    const stakeTx = new Transaction().add(await staker.createStakeIx(market, update, createStake))
+    await signAndSend(stakeTx, [positionOwner], staker.connection)
+  })
+
+  it('Get pending reward without changing the stake', async () => {
+    await sleep(5000)
+
+    const getPendingReward: GetPendingReward = {
+      incentive: incentiveAccount.publicKey,
+      pool,
+      id: positionId,
+      position
+    }
+    const stakeBefore = await staker.getStake(incentiveAccount.publicKey, pool, positionId)
+    const [pending] = await staker.getPendingReward(market, update, getPendingReward)
+    const stakeAfter = await staker.getStake(incentiveAccount.publicKey, pool, positionId)
+    assert.ok(pending.gtn(0))
+    assert.ok(
+      stakeAfter.secondsPerLiquidityInitial.v.eq(stakeBefore.secondsPerLiquidityInitial.v)
+    )
+
+    // withdraw happens later, so it pays at least the pending amount
+    const ownerTokenAcc = await incentiveToken.createAccount(positionOwner.publicKey)
+    const withdraw: Withdraw = {
+      incentive: incentiveAccount.publicKey,
+      pool,
+      id: positionId,
+      position,
+      owner: positionOwner.publicKey,
+      incentiveTokenAccount: incentiveTokenAccount.publicKey,
+      ownerTokenAcc,
+      index
+    }
+    const withdrawTx = new Transaction().add(await staker.withdrawIx(market, update, withdraw))
+    await signAndSend(withdrawTx, [positionOwner], staker.connection)
+
+    const withdrawn = (await incentiveToken.getAccountInfo(ownerTokenAcc)).amount
+    assert.ok(withdrawn.gte(pending))
+  })
+
+  it('Fail on ticks not matching the position', async () => {
+    const getPendingReward: GetPendingReward = {
+      incentive: incentiveAccount.publicKey,
+      pool,
+      id: positionId,
+      position
+    }
+    const swappedTicks: UpdateSecondsPerLiquidity = {
+      ...update,
+      lowerTickIndex: upperTick,
+      upperTickIndex: lowerTick
+    }
+    await assertThrowsAsync(
+      staker.getPendingReward(market, swappedTicks, getPendingReward),
+      STAKER_ERRORS.INVALID_TICK
+    )
+  })
+})
@@ -189,7 +189,7 @@ describe('Withdraw with transfer position ownership', () => {
     }
 
     const updateIx = await market.updateSecondsPerLiquidityInstruction(update)
-    const stakeIx = await staker.createStakeIx(createStake)
+    const stakeIx = await staker.createStakeIx(market, update, createStake)
     const tx = new Transaction().add(updateIx).add(stakeIx)
 
     await signAndSend(tx, [positionOwner], staker.connection)
@@ -265,7 +265,7 @@ describe('Withdraw with transfer position ownership', () => {
       index
     }
     const updateRecipientIx = await market.updateSecondsPerLiquidityInstruction(updateRecipient)
-    const withdrawIx = await staker.withdrawIx(withdraw)
+    const withdrawIx = await staker.withdrawIx(market, updateRecipient, withdraw)
     const withdrawTx = new Transaction().add(updateRecipientIx).add(withdrawIx)
     await signAndSend(withdrawTx, [positionRecipient], staker.connection)
 
@@ -183,7 +183,9 @@ describe('Remove all takes', () => {
       const removeIx = await staker.removeStakeIx(
         stakes[i].publicKey,
         incentiveAccount.publicKey,
-        founderAccount.publicKey
+        founderAccount.publicKey,
+        stakes[i].account.position,
+        invariant
       )
       tx.add(removeIx)
       if ((i + 1) % 18 === 0 || i + 1 === stakes.length) {
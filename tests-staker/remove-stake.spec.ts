@@ -200,7 +200,7 @@ describe('Remove Stake tests', () => {
     }
 
     const updateIx = await market.updateSecondsPerLiquidityInstruction(update)
-    const stakeIx = await staker.createStakeIx(createStake)
+    const stakeIx = await staker.createStakeIx(market, update, createStake)
     const stakeTx = new Transaction().add(updateIx).add(stakeIx)
 
     await signAndSend(stakeTx, [positionOwner], staker.connection)
@@ -229,7 +229,9 @@ describe('Remove Stake tests', () => {
     const removeIx = await staker.removeStakeIx(
       userStakeAddress,
       incentiveAccount.publicKey,
-      founderAccount.publicKey
+      founderAccount.publicKey,
+      position,
+      invariant
     )
     const removeTx = new Transaction().add(removeIx)
     await signAndSend(removeTx, [founderAccount], staker.connection)
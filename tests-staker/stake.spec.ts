@@ -195,7 +195,7 @@ describe('Stake tests', () => {
     }
 
     const updateIx = await market.updateSecondsPerLiquidityInstruction(update)
-    const stakeIx = await staker.createStakeIx(createStake)
+    const stakeIx = await staker.createStakeIx(market, update, createStake)
     const tx = new Transaction().add(updateIx).add(stakeIx)
 
     await signAndSend(tx, [positionOwner], staker.connection)
@@ -269,7 +269,7 @@ describe('Stake tests', () => {
     }
 
     const updateIx = await market.updateSecondsPerLiquidityInstruction(update)
-    const stakeIx = await staker.createStakeIx(createStake)
+    const stakeIx = await staker.createStakeIx(market, update, createStake)
     const tx = new Transaction().add(updateIx).add(stakeIx)
 
     await assertThrowsAsync(
@@ -366,7 +366,7 @@ describe('Stake tests', () => {
     }
 
     const updateIx = await market.updateSecondsPerLiquidityInstruction(update)
-    const stakeIx = await staker.createStakeIx(createStake)
+    const stakeIx = await staker.createStakeIx(market, update, createStake)
     const tx = new Transaction().add(updateIx).add(stakeIx)
 
     await signAndSend(tx, [admin], staker.connection)
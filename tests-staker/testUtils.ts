@@ -153,7 +153,7 @@ export const createSomePositionsAndStakes = async (
     }
 
     const updateIx = await market.updateSecondsPerLiquidityInstruction(update)
-    const stakeIx = await staker.createStakeIx(createStake)
+    const stakeIx = await staker.createStakeIx(market, update, createStake)
     const tx = new Transaction().add(updateIx).add(stakeIx)
 
     await signAndSend(tx, [positionOwner], staker.connection)
@@ -0,0 +1,204 @@
+import * as anchor from '@project-serum/anchor'
+import { Provider, BN } from '@project-serum/anchor'
+import { Market, Pair, DENOMINATOR, PRICE_DENOMINATOR } from '@invariant-labs/sdk'
+import { Network } from '../staker-sdk/src'
+import { Keypair, PublicKey, Transaction } from '@solana/web3.js'
+import { assert } from 'chai'
+import { CreateIncentive, CreateStake, Staker, TickBand } from '../staker-sdk/src/staker'
+import { STAKER_ERRORS } from '../staker-sdk/src/utils'
+import { assertThrowsAsync, createToken, getTime, signAndSend } from './testUtils'
+import { createToken as createTkn, initMarket } from '../tests/testUtils'
+import { fromFee } from '@invariant-labs/sdk/lib/utils'
+import { FeeTier } from '@invariant-labs/sdk/lib/market'
+import { InitPosition, UpdateSecondsPerLiquidity } from '@invariant-labs/sdk/src/market'
+import { Token, TOKEN_PROGRAM_ID } from '@solana/spl-token'
+import { tou64 } from '@invariant-labs/sdk/src/utils'
+
+describe('Tick band tests', () => {
+  const provider = Provider.local()
+  const connection = provider.connection
+  // @ts-expect-error
+  const wallet = provider.wallet.payer as Account
+  const mintAuthority = Keypair.generate()
+  const founderAccount = Keypair.generate()
+  const positionOwner = Keypair.generate()
+  const admin = Keypair.generate()
+  // index of the position in the owner list and its ticks
+  const widePosition = { index: 0, lowerTick: -30, upperTick: 10 }
+  const narrowPosition = { index: 1, lowerTick: -10, upperTick: 10 }
+  let staker: Staker
+  let market: Market
+  let pool: PublicKey
+  let invariant: PublicKey
+  let incentiveToken: Token
+  let founderTokenAccount: PublicKey
+  let pair: Pair
+  let tokenX: Token
+  let tokenY: Token
+
+  before(async () => {
+    staker = await Staker.build(Network.LOCAL, provider.wallet, connection)
+
+    await Promise.all([
+      connection.requestAirdrop(mintAuthority.publicKey, 1e9),
+      connection.requestAirdrop(founderAccount.publicKey, 10e9),
+      connection.requestAirdrop(positionOwner.publicKey, 1e9),
+      connection.requestAirdrop(admin.publicKey, 1e9)
+    ])
+
+    incentiveToken = await createToken(connection, wallet, wallet)
+    founderTokenAccount = await incentiveToken.createAccount(founderAccount.publicKey)
+    await incentiveToken.mintTo(founderTokenAccount, wallet, [], tou64(new BN(5000 * 1e6)))
+
+    market = await Market.build(
+      0,
+      provider.wallet,
+      connection,
+      anchor.workspace.Invariant.programId
+    )
+
+    const tokens = await Promise.all([
+      createTkn(connection, wallet, mintAuthority),
+      createTkn(connection, wallet, mintAuthority)
+    ])
+
+    const feeTier: FeeTier = {
+      fee: fromFee(new BN(600)),
+      tickSpacing: 10
+    }
+
+    pair = new Pair(tokens[0].publicKey, tokens[1].publicKey, feeTier)
+    tokenX = new Token(connection, pair.tokenX, TOKEN_PROGRAM_ID, wallet)
+    tokenY = new Token(connection, pair.tokenY, TOKEN_PROGRAM_ID, wallet)
+    invariant = anchor.workspace.Invariant.programId
+  })
+
+  const createIncentive = async (band: TickBand) => {
+    const incentiveAccount = Keypair.generate()
+    const incentiveTokenAccount = Keypair.generate()
+    const currentTime = getTime()
+
+    const createIncentiveVars: CreateIncentive = {
+      reward: { v: new BN(1000) },
+      startTime: { v: currentTime },
+      endTime: { v: currentTime.addn(1000) },
+      pool,
+      founder: founderAccount.publicKey,
+      incentiveToken: incentiveToken.publicKey,
+      founderTokenAccount,
+      invariant,
+      band
+    }
+    const createTx = new Transaction().add(
+      await staker.createIncentiveIx(
+        createIncentiveVars,
+        incentiveAccount.publicKey,
+        incentiveTokenAccount.publicKey
+      )
+    )
+    await signAndSend(
+      createTx,
+      [founderAccount, incentiveAccount, incentiveTokenAccount],
+      staker.connection
+    )
+
+    return incentiveAccount.publicKey
+  }
+
+  const stake = async (
+    incentive: PublicKey,
+    { index, lowerTick, upperTick }: typeof widePosition
+  ) => {
+    const { positionAddress: position } = await market.getPositionAddress(
+      positionOwner.publicKey,
+      index
+    )
+    const { id } = await market.getPosition(positionOwner.publicKey, index)
+    const update: UpdateSecondsPerLiquidity = {
+      pair,
+      owner: positionOwner.publicKey,
+      lowerTickIndex: lowerTick,
+      upperTickIndex: upperTick,
+      index
+    }
+    const createStake: CreateStake = {
+      pool,
+      id,
+      index,
+      position,
+      incentive,
+      owner: positionOwner.publicKey,
+      invariant
+    }
+    const stakeTx = new Transaction().add(await staker.createStakeIx(market, update, createStake))
+    await signAndSend(stakeTx, [positionOwner], staker.connection)
+
+    return id
+  }
+
+  it('#init()', async () => {
+    await initMarket(market, [pair], admin)
+    pool = await pair.getAddress(anchor.workspace.Invariant.programId)
+
+    const userTokenXAccount = await tokenX.createAccount(positionOwner.publicKey)
+    const userTokenYAccount = await tokenY.createAccount(positionOwner.publicKey)
+    const mintAmount = tou64(new BN(10).pow(new BN(10)))
+    await tokenX.mintTo(userTokenXAccount, mintAuthority.publicKey, [mintAuthority], mintAmount)
+    await tokenY.mintTo(userTokenYAccount, mintAuthority.publicKey, [mintAuthority], mintAmount)
+
+    await market.createPositionList(positionOwner.publicKey, positionOwner)
+    for (const { lowerTick, upperTick } of [widePosition, narrowPosition]) {
+      const initPositionVars: InitPosition = {
+        pair,
+        owner: positionOwner.publicKey,
+        userTokenX: userTokenXAccount,
+        userTokenY: userTokenYAccount,
+        lowerTick,
+        upperTick,
+        liquidityDelta: { v: new BN(2000000).mul(DENOMINATOR) },
+        knownPrice: { v: PRICE_DENOMINATOR },
+        slippage: { v: new BN(0) }
+      }
+      await market.initPosition(initPositionVars, positionOwner)
+    }
+  })
+
+  it('Stake only positions inside the band', async () => {
+    const incentive = await createIncentive({
+      lowerTickIndex: -20,
+      upperTickIndex: 20,
+      maxWidth: 0
+    })
+
+    const incentiveStruct = await staker.getIncentive(incentive)
+    assert.equal(incentiveStruct.band.lowerTickIndex, -20)
+    assert.equal(incentiveStruct.band.upperTickIndex, 20)
+
+    await assertThrowsAsync(stake(incentive, widePosition), STAKER_ERRORS.POSITION_OUTSIDE_BAND)
+
+    const id = await stake(incentive, narrowPosition)
+    const userStake = await staker.getStake(incentive, pool, id)
+    assert.ok(userStake.incentive.equals(incentive))
+  })
+
+  it('Stake only positions not wider than the max width', async () => {
+    const incentive = await createIncentive({
+      lowerTickIndex: -100,
+      upperTickIndex: 100,
+      maxWidth: 20
+    })
+
+    await assertThrowsAsync(stake(incentive, widePosition), STAKER_ERRORS.POSITION_OUTSIDE_BAND)
+
+    const id = await stake(incentive, narrowPosition)
+    const userStake = await staker.getStake(incentive, pool, id)
+    assert.ok(userStake.incentive.equals(incentive))
+  })
+
+  it('Fail on creating an incentive with an invalid band', async () => {
+    await assertThrowsAsync(
+      createIncentive({ lowerTickIndex: 20, upperTickIndex: -20, maxWidth: 0 }),
+      STAKER_ERRORS.INVALID_TICK_BAND
+    )
+  })
+})
@@ -0,0 +1,256 @@
+import * as anchor from '@project-serum/anchor'
+import { Provider, BN } from '@project-serum/anchor'
+import { Market, Pair, DENOMINATOR, sleep, PRICE_DENOMINATOR } from '@invariant-labs/sdk'
+import { Network } from '../staker-sdk/src'
+import { Keypair, PublicKey, Transaction } from '@solana/web3.js'
+import { assert } from 'chai'
+import {
+  CreateIncentive,
+  CreateStake,
+  ReleaseVested,
+  Staker,
+  Vesting,
+  Withdraw
+} from '../staker-sdk/src/staker'
+import { STAKER_ERRORS } from '../staker-sdk/src/utils'
+import { assertThrowsAsync, createToken, getTime, signAndSend } from './testUtils'
+import { createToken as createTkn, initMarket } from '../tests/testUtils'
+import { fromFee } from '@invariant-labs/sdk/lib/utils'
+import { FeeTier } from '@invariant-labs/sdk/lib/market'
+import { InitPosition, UpdateSecondsPerLiquidity } from '@invariant-labs/sdk/src/market'
+import { Token, TOKEN_PROGRAM_ID } from '@solana/spl-token'
+import { tou64 } from '@invariant-labs/sdk/src/utils'
+
+describe('Vesting tests', () => {
+  const provider = Provider.local()
+  const connection = provider.connection
+  // @ts-expect-error
+  const wallet = provider.wallet.payer as Account
+  const mintAuthority = Keypair.generate()
+  const founderAccount = Keypair.generate()
+  const positionOwner = Keypair.generate()
+  const admin = Keypair.generate()
+  const lowerTick = -30
+  const upperTick = 10
+  const index = 0
+  // seconds counted from the end of the incentive
+  const vesting: Vesting = { cliff: { v: new BN(20) }, duration: { v: new BN(10) } }
+  let staker: Staker
+  let market: Market
+  let pool: PublicKey
+  let invariant: PublicKey
+  let incentiveToken: Token
+  let founderTokenAccount: PublicKey
+  let pair: Pair
+  let tokenX: Token
+  let tokenY: Token
+  let position: PublicKey
+  let positionId: BN
+  let update: UpdateSecondsPerLiquidity
+
+  before(async () => {
+    staker = await Staker.build(Network.LOCAL, provider.wallet, connection)
+
+    await Promise.all([
+      connection.requestAirdrop(mintAuthority.publicKey, 1e9),
+      connection.requestAirdrop(founderAccount.publicKey, 10e9),
+      connection.requestAirdrop(positionOwner.publicKey, 1e9),
+      connection.requestAirdrop(admin.publicKey, 1e9)
+    ])
+
+    incentiveToken = await createToken(connection, wallet, wallet)
+    founderTokenAccount = await incentiveToken.createAccount(founderAccount.publicKey)
+    await incentiveToken.mintTo(founderTokenAccount, wallet, [], tou64(new BN(5000 * 1e6)))
+
+    market = await Market.build(
+      0,
+      provider.wallet,
+      connection,
+      anchor.workspace.Invariant.programId
+    )
+
+    const tokens = await Promise.all([
+      createTkn(connection, wallet, mintAuthority),
+      createTkn(connection, wallet, mintAuthority)
+    ])
+
+    const feeTier: FeeTier = {
+      fee: fromFee(new BN(600)),
+      tickSpacing: 10
+    }
+
+    pair = new Pair(tokens[0].publicKey, tokens[1].publicKey, feeTier)
+    tokenX = new Token(connection, pair.tokenX, TOKEN_PROGRAM_ID, wallet)
+    tokenY = new Token(connection, pair.tokenY, TOKEN_PROGRAM_ID, wallet)
+    invariant = anchor.workspace.Invariant.programId
+  })
+
+  const createIncentive = async (duration: number, incentiveVesting?: Vesting) => {
+    const incentiveAccount = Keypair.generate()
+    const incentiveTokenAccount = Keypair.generate()
+    const currentTime = getTime()
+
+    const createIncentiveVars: CreateIncentive = {
+      reward: { v: new BN(1000) },
+      startTime: { v: currentTime },
+      endTime: { v: currentTime.addn(duration) },
+      pool,
+      founder: founderAccount.publicKey,
+      incentiveToken: incentiveToken.publicKey,
+      founderTokenAccount,
+      invariant,
+      vesting: incentiveVesting
+    }
+    const createTx = new Transaction().add(
+      await staker.createIncentiveIx(
+        createIncentiveVars,
+        incentiveAccount.publicKey,
+        incentiveTokenAccount.publicKey
+      )
+    )
+    await signAndSend(
+      createTx,
+      [founderAccount, incentiveAccount, incentiveTokenAccount],
+      staker.connection
+    )
+
+    return { incentive: incentiveAccount.publicKey, vault: incentiveTokenAccount.publicKey }
+  }
+
+  it('#init()', async () => {
+    await initMarket(market, [pair], admin)
+    pool = await pair.getAddress(anchor.workspace.Invariant.programId)
+
+    const userTokenXAccount = await tokenX.createAccount(positionOwner.publicKey)
+    const userTokenYAccount = await tokenY.createAccount(positionOwner.publicKey)
+    const mintAmount = tou64(new BN(10).pow(new BN(10)))
+    await tokenX.mintTo(userTokenXAccount, mintAuthority.publicKey, [mintAuthority], mintAmount)
+    await tokenY.mintTo(userTokenYAccount, mintAuthority.publicKey, [mintAuthority], mintAmount)
+
+    await market.createPositionList(positionOwner.publicKey, positionOwner)
+    const initPositionVars: InitPosition = {
+      pair,
+      owner: positionOwner.publicKey,
+      userTokenX: userTokenXAccount,
+      userTokenY: userTokenYAccount,
+      lowerTick,
+      upperTick,
+      liquidityDelta: { v: new BN(2000000).mul(DENOMINATOR) },
+      knownPrice: { v: PRICE_DENOMINATOR },
+      slippage: { v: new BN(0) }
+    }
+    await market.initPosition(initPositionVars, positionOwner)
+
+    const { positionAddress } = await market.getPositionAddress(positionOwner.publicKey, index)
+    position = positionAddress
+    positionId = (await market.getPosition(positionOwner.publicKey, index)).id
+    update = {
+      pair,
+      owner: positionOwner.publicKey,
+      lowerTickIndex: lowerTick,
+      upperTickIndex: upperTick,
+      index
+    }
+  })
+
+  it('Withdraw credits rewards that are released after the cliff', async () => {
+    const { incentive, vault } = await createIncentive(20, vesting)
+    const createRecordTx = new Transaction().add(
+      await staker.createVestingRecordIx({ incentive, owner: positionOwner.publicKey })
+    )
+    await signAndSend(createRecordTx, [positionOwner], staker.connection)
+    const [vestingRecord] = await staker.getVestingRecordAddressAndBump(
+      incentive,
+      positionOwner.publicKey
+    )
+
+    const createStake: CreateStake = {
+      pool,
+      id: positionId,
+      index,
+      position,
+      incentive,
+      owner: positionOwner.publicKey,
+      invariant
+    }
+    const stakeTx = new Transaction().add(await staker.createStakeIx(market, update, createStake))
+    await signAndSend(stakeTx, [positionOwner], staker.connection)
+
+    const ownerTokenAcc = await incentiveToken.createAccount(positionOwner.publicKey)
+    const releaseVested: ReleaseVested = {
+      incentive,
+      owner: positionOwner.publicKey,
+      incentiveTokenAccount: vault,
+      ownerTokenAcc
+    }
+    // nothing was credited yet, so the record doesn't know the vault
+    await assertThrowsAsync(
+      signAndSend(
+        new Transaction().add(await staker.releaseVestedIx(releaseVested)),
+        [positionOwner],
+        staker.connection
+      ),
+      STAKER_ERRORS.INVALID_TOKEN_ACCOUNT
+    )
+
+    await sleep(22000)
+
+    const withdraw: Withdraw = {
+      incentive,
+      pool,
+      id: positionId,
+      position,
+      owner: positionOwner.publicKey,
+      incentiveTokenAccount: vault,
+      ownerTokenAcc,
+      index
+    }
+    // vesting record has to be passed
+    const withdrawWithoutRecordTx = new Transaction().add(
+      await staker.withdrawIx(market, update, withdraw)
+    )
+    await assertThrowsAsync(
+      signAndSend(withdrawWithoutRecordTx, [positionOwner], staker.connection),
+      STAKER_ERRORS.INVALID_TOKEN_ACCOUNT
+    )
+
+    const withdrawTx = new Transaction().add(
+      await staker.withdrawIx(market, update, { ...withdraw, vestingRecord })
+    )
+    await signAndSend(withdrawTx, [positionOwner], staker.connection)
+
+    const record = await staker.getVestingRecord(incentive, positionOwner.publicKey)
+    const credited = record.rewards[0].credited.v
+    assert.ok(credited.gtn(0))
+    assert.ok((await incentiveToken.getAccountInfo(ownerTokenAcc)).amount.eqn(0))
+
+    // before the cliff nothing is released
+    const releaseBeforeCliffTx = new Transaction().add(await staker.releaseVestedIx(releaseVested))
+    await signAndSend(releaseBeforeCliffTx, [positionOwner], staker.connection)
+    assert.ok((await incentiveToken.getAccountInfo(ownerTokenAcc)).amount.eqn(0))
+
+    await sleep(30000)
+
+    const releaseTx = new Transaction().add(await staker.releaseVestedIx(releaseVested))
+    await signAndSend(releaseTx, [positionOwner], staker.connection)
+    assert.ok((await incentiveToken.getAccountInfo(ownerTokenAcc)).amount.eq(credited))
+  })
+
+  it('Fail on vesting record for an incentive without vesting', async () => {
+    const { incentive } = await createIncentive(100)
+    const createRecordTx = new Transaction().add(
+      await staker.createVestingRecordIx({ incentive, owner: positionOwner.publicKey })
+    )
+    await assertThrowsAsync(
+      signAndSend(createRecordTx, [positionOwner], staker.connection),
+      STAKER_ERRORS.INVALID_VESTING
+    )
+  })
+
+  it('Fail on vesting longer than the max duration', async () => {
+    await assertThrowsAsync(
+      createIncentive(100, { cliff: { v: new BN(31556927) }, duration: { v: new BN(0) } }),
+      STAKER_ERRORS.INVALID_VESTING
+    )
+  })
+})
@@ -0,0 +1,234 @@
+import * as anchor from '@project-serum/anchor'
+import { Provider, BN } from '@project-serum/anchor'
+import { Market, Pair, DENOMINATOR, sleep, PRICE_DENOMINATOR } from '@invariant-labs/sdk'
+import { Network } from '../staker-sdk/src'
+import { Keypair, PublicKey, Transaction } from '@solana/web3.js'
+import { assert } from 'chai'
+import { CreateIncentive, CreateStake, Staker, WithdrawMany } from '../staker-sdk/src/staker'
+import { STAKER_ERRORS } from '../staker-sdk/src/utils'
+import { assertThrowsAsync, createToken, getTime, signAndSend } from './testUtils'
+import { createToken as createTkn, initMarket } from '../tests/testUtils'
+import { fromFee } from '@invariant-labs/sdk/lib/utils'
+import { FeeTier } from '@invariant-labs/sdk/lib/market'
+import { InitPosition, UpdateSecondsPerLiquidity } from '@invariant-labs/sdk/src/market'
+import { Token, TOKEN_PROGRAM_ID } from '@solana/spl-token'
+import { tou64 } from '@invariant-labs/sdk/src/utils'
+
+describe('Withdraw many tests', () => {
+  const provider = Provider.local()
+  const connection = provider.connection
+  // @ts-expect-error
+  const wallet = provider.wallet.payer as Account
+  const mintAuthority = Keypair.generate()
+  const founderAccount = Keypair.generate()
+  const positionOwner = Keypair.generate()
+  const admin = Keypair.generate()
+  const lowerTick = -30
+  const upperTick = 10
+  let staker: Staker
+  let market: Market
+  let pool: PublicKey
+  let invariant: PublicKey
+  let incentiveToken: Token
+  let founderTokenAccount: PublicKey
+  let pair: Pair
+  let tokenX: Token
+  let tokenY: Token
+  let incentives: { incentive: PublicKey; vault: PublicKey }[]
+  let positions: { position: PublicKey; id: BN }[]
+
+  before(async () => {
+    staker = await Staker.build(Network.LOCAL, provider.wallet, connection)
+
+    await Promise.all([
+      connection.requestAirdrop(mintAuthority.publicKey, 1e9),
+      connection.requestAirdrop(founderAccount.publicKey, 10e9),
+      connection.requestAirdrop(positionOwner.publicKey, 1e9),
+      connection.requestAirdrop(admin.publicKey, 1e9)
+    ])
+
+    incentiveToken = await createToken(connection, wallet, wallet)
+    founderTokenAccount = await incentiveToken.createAccount(founderAccount.publicKey)
+    await incentiveToken.mintTo(founderTokenAccount, wallet, [], tou64(new BN(5000 * 1e6)))
+
+    market = await Market.build(
+      0,
+      provider.wallet,
+      connection,
+      anchor.workspace.Invariant.programId
+    )
+
+    const tokens = await Promise.all([
+      createTkn(connection, wallet, mintAuthority),
+      createTkn(connection, wallet, mintAuthority)
+    ])
+
+    const feeTier: FeeTier = {
+      fee: fromFee(new BN(600)),
+      tickSpacing: 10
+    }
+
+    pair = new Pair(tokens[0].publicKey, tokens[1].publicKey, feeTier)
+    tokenX = new Token(connection, pair.tokenX, TOKEN_PROGRAM_ID, wallet)
+    tokenY = new Token(connection, pair.tokenY, TOKEN_PROGRAM_ID, wallet)
+    invariant = anchor.workspace.Invariant.programId
+  })
+
+  const update = (index: number): UpdateSecondsPerLiquidity => ({
+    pair,
+    owner: positionOwner.publicKey,
+    lowerTickIndex: lowerTick,
+    upperTickIndex: upperTick,
+    index
+  })
+
+  it('#init()', async () => {
+    await initMarket(market, [pair], admin)
+    pool = await pair.getAddress(anchor.workspace.Invariant.programId)
+
+    incentives = []
+    for (let i = 0; i < 2; i++) {
+      const incentiveAccount = Keypair.generate()
+      const incentiveTokenAccount = Keypair.generate()
+      const currentTime = getTime()
+      const createIncentiveVars: CreateIncentive = {
+        reward: { v: new BN(1000) },
+        startTime: { v: currentTime },
+        endTime: { v: currentTime.addn(1000) },
+        pool,
+        founder: founderAccount.publicKey,
+        incentiveToken: incentiveToken.publicKey,
+        founderTokenAccount,
+        invariant
+      }
+      const createTx = new Transaction().add(
+        await staker.createIncentiveIx(
+          createIncentiveVars,
+          incentiveAccount.publicKey,
+          incentiveTokenAccount.publicKey
+        )
+      )
+      await signAndSend(
+        createTx,
+        [founderAccount, incentiveAccount, incentiveTokenAccount],
+        staker.connection
+      )
+      incentives.push({
+        incentive: incentiveAccount.publicKey,
+        vault: incentiveTokenAccount.publicKey
+      })
+    }
+
+    const userTokenXAccount = await tokenX.createAccount(positionOwner.publicKey)
+    const userTokenYAccount = await tokenY.createAccount(positionOwner.publicKey)
+    const mintAmount = tou64(new BN(10).pow(new BN(10)))
+    await tokenX.mintTo(userTokenXAccount, mintAuthority.publicKey, [mintAuthority], mintAmount)
+    await tokenY.mintTo(userTokenYAccount, mintAuthority.publicKey, [mintAuthority], mintAmount)
+
+    await market.createPositionList(positionOwner.publicKey, positionOwner)
+    positions = []
+    for (let index = 0; index < 2; index++) {
+      const initPositionVars: InitPosition = {
+        pair,
+        owner: positionOwner.publicKey,
+        userTokenX: userTokenXAccount,
+        userTokenY: userTokenYAccount,
+        lowerTick,
+        upperTick,
+        liquidityDelta: { v: new BN(2000000).mul(DENOMINATOR) },
+        knownPrice: { v: PRICE_DENOMINATOR },
+        slippage: { v: new BN(0) }
+      }
+      await market.initPosition(initPositionVars, positionOwner)
+
+      const { positionAddress: position } = await market.getPositionAddress(
+        positionOwner.publicKey,
+        index
+      )
+      const { id } = await market.getPosition(positionOwner.publicKey, index)
+      positions.push({ position, id })
+    }
+
+    // first position is staked in both incentives, second one only in the first incentive
+    const stakes = [
+      { index: 0, incentive: incentives[0].incentive },
+      { index: 0, incentive: incentives[1].incentive },
+      { index: 1, incentive: incentives[0].incentive }
+    ]
+    for (const { index, incentive } of stakes) {
+      const createStake: CreateStake = {
+        pool,
+        id: positions[index].id,
+        index,
+        position: positions[index].position,
+        incentive,
+        owner: positionOwner.publicKey,
+        invariant
+      }
+      const stakeTx = new Transaction().add(
+        await staker.createStakeIx(market, update(index), createStake)
+      )
+      await signAndSend(stakeTx, [positionOwner], staker.connection)
+    }
+  })
+
+  it('Withdraw from every incentive of the position at once', async () => {
+    await sleep(10000)
+
+    const ownerTokenAccounts = [
+      await incentiveToken.createAccount(positionOwner.publicKey),
+      await incentiveToken.createAccount(positionOwner.publicKey)
+    ]
+    const withdrawMany: WithdrawMany = {
+      pool,
+      id: positions[0].id,
+      position: positions[0].position,
+      owner: positionOwner.publicKey,
+      index: 0,
+      incentives: incentives.map(({ incentive, vault }, i) => ({
+        incentive,
+        incentiveTokenAccount: vault,
+        ownerTokenAcc: ownerTokenAccounts[i]
+      }))
+    }
+    const withdrawTx = new Transaction().add(
+      await staker.withdrawManyIx(market, update(0), withdrawMany)
+    )
+    await signAndSend(withdrawTx, [positionOwner], staker.connection)
+
+    for (const ownerTokenAccount of ownerTokenAccounts) {
+      assert.ok((await incentiveToken.getAccountInfo(ownerTokenAccount)).amount.gtn(0))
+    }
+    // incentives did not end, so both stakes stay open
+    for (const { incentive } of incentives) {
+      const stake = await staker.getStake(incentive, pool, positions[0].id)
+      assert.ok(stake.position.equals(positions[0].position))
+    }
+  })
+
+  it('Fail on a stake of another position', async () => {
+    const ownerTokenAccount = await incentiveToken.createAccount(positionOwner.publicKey)
+    // stake of the second position passed for the first one
+    const withdrawMany: WithdrawMany = {
+      pool,
+      id: positions[1].id,
+      position: positions[0].position,
+      owner: positionOwner.publicKey,
+      index: 0,
+      incentives: [
+        {
+          incentive: incentives[0].incentive,
+          incentiveTokenAccount: incentives[0].vault,
+          ownerTokenAcc: ownerTokenAccount
+        }
+      ]
+    }
+    const withdrawTx = new Transaction().add(
+      await staker.withdrawManyIx(market, update(0), withdrawMany)
+    )
+    await assertThrowsAsync(
+      signAndSend(withdrawTx, [positionOwner], staker.connection),
+      STAKER_ERRORS.INVALID_STAKE
+    )
+  })
+})
@@ -198,7 +198,7 @@ describe('Withdraw tests', () => {
     }
 
     const updateIx = await market.updateSecondsPerLiquidityInstruction(update)
-    const stakeIx = await staker.createStakeIx(createStake)
+    const stakeIx = await staker.createStakeIx(market, update, createStake)
     const tx = new Transaction().add(updateIx).add(stakeIx)
 
     await signAndSend(tx, [positionOwner], staker.connection)
@@ -244,7 +244,7 @@ describe('Withdraw tests', () => {
       index
     }
 
-    const withdrawIx = await staker.withdrawIx(withdraw)
+    const withdrawIx = await staker.withdrawIx(market, update, withdraw)
     const withdrawTx = new Transaction().add(updateIx).add(withdrawIx)
     await signAndSend(withdrawTx, [positionOwner], staker.connection)
 
@@ -360,7 +360,7 @@ describe('Withdraw tests', () => {
     }
 
     const updateIx = await market.updateSecondsPerLiquidityInstruction(update)
-    const stakeIx = await staker.createStakeIx(createStake)
+    const stakeIx = await staker.createStakeIx(market, update, createStake)
     const tx = new Transaction().add(updateIx).add(stakeIx)
 
     await signAndSend(tx, [positionOwner], staker.connection)
@@ -416,7 +416,7 @@ describe('Withdraw tests', () => {
       index
     }
 
-    const withdrawIx = await staker.withdrawIx(withdraw)
+    const withdrawIx = await staker.withdrawIx(market, update, withdraw)
     const withdrawTx = new Transaction().add(updateIx).add(withdrawIx)
 
     await assertThrowsAsync(
@@ -552,7 +552,7 @@ describe('Withdraw tests', () => {
     }
 
     const updateIx = await market.updateSecondsPerLiquidityInstruction(update)
-    const stakeIx = await staker.createStakeIx(createStake)
+    const stakeIx = await staker.createStakeIx(market, update, createStake)
     const tx = new Transaction().add(updateIx).add(stakeIx)
 
     await signAndSend(tx, [positionOwner], staker.connection)
@@ -627,7 +627,7 @@ describe('Withdraw tests', () => {
     }
     const updateAfterIx = await market.updateSecondsPerLiquidityInstruction(updateAfter)
 
-    const withdrawIx = await staker.withdrawIx(withdraw)
+    const withdrawIx = await staker.withdrawIx(market, updateAfter, withdraw)
     const withdrawTx = new Transaction().add(updateAfterIx).add(withdrawIx)
     await signAndSend(withdrawTx, [positionOwner], staker.connection)
   })
@@ -759,7 +759,7 @@ describe('Withdraw tests', () => {
     }
 
     const updateIx = await market.updateSecondsPerLiquidityInstruction(update)
-    const stakeIx = await staker.createStakeIx(createStake)
+    const stakeIx = await staker.createStakeIx(market, update, createStake)
     const tx = new Transaction().add(updateIx).add(stakeIx)
 
     await signAndSend(tx, [positionOwner], staker.connection)
@@ -834,7 +834,7 @@ describe('Withdraw tests', () => {
     }
     const updateAfterIx = await market.updateSecondsPerLiquidityInstruction(updateAfter)
 
-    const withdrawIx = await staker.withdrawIx(withdraw)
+    const withdrawIx = await staker.withdrawIx(market, updateAfter, withdraw)
     const withdrawTx = new Transaction().add(updateAfterIx).add(withdrawIx)
     await signAndSend(withdrawTx, [founderAccount], staker.connection)
   })
@@ -1,6 +1,11 @@
 import { Network, sleep } from '@invariant-labs/sdk'
 import { Market, Pair } from '@invariant-labs/sdk/src'
-import { ChangeProtocolFee, Decimal, FeeTier } from '@invariant-labs/sdk/src/market'
+import {
+  ChangeDefaultProtocolFee,
+  ChangeProtocolFee,
+  Decimal,
+  FeeTier
+} from '@invariant-labs/sdk/src/market'
 import { assertThrowsAsync, fromFee } from '@invariant-labs/sdk/src/utils'
 import * as anchor from '@project-serum/anchor'
 import { Provider, BN } from '@project-serum/anchor'
@@ -48,6 +53,11 @@ describe('change-protocol-fee', () => {
   })
   it('#init()', async () => {
     await initMarket(market, [pair], admin)
+
+    // without the default protocol fee account pools get the initial 1%
+    const { address } = await market.getDefaultProtocolFeeAddress()
+    assert.equal(await connection.getAccountInfo(address), null)
+    assert.ok((await market.getPool(pair)).protocolFee.v.eq(fromFee(new BN(1000))))
   })
 
   it('#change-protocol-fee() state admin', async () => {
@@ -89,4 +99,38 @@ describe('change-protocol-fee', () => {
     }
     await assertThrowsAsync(market.changeProtocolFee(changeProtocolFeeVars, user))
   })
+
+  it('#create-default-protocol-fee()', async () => {
+    const protocolFee: Decimal = { v: fromFee(new BN(5000)) }
+    const createDefaultProtocolFeeVars: ChangeDefaultProtocolFee = {
+      protocolFee,
+      admin: admin.publicKey
+    }
+    await market.createDefaultProtocolFee(createDefaultProtocolFeeVars, admin)
+    assert.ok((await market.getDefaultProtocolFee()).protocolFee.v.eq(protocolFee.v))
+
+    const newPair = new Pair(pair.tokenX, pair.tokenY, {
+      fee: fromFee(new BN(1000)),
+      tickSpacing: 20
+    })
+    await market.createFeeTier({ feeTier: newPair.feeTier, admin: admin.publicKey }, admin)
+    await market.createPool({ pair: newPair, payer: admin })
+
+    assert.ok((await market.getPool(newPair)).protocolFee.v.eq(protocolFee.v))
+  })
+
+  it('#change-default-protocol-fee()', async () => {
+    const protocolFee: Decimal = { v: fromFee(new BN(20000)) }
+
+    const user = Keypair.generate()
+    await connection.requestAirdrop(user.publicKey, 1e10)
+    await sleep(500)
+
+    await assertThrowsAsync(
+      market.changeDefaultProtocolFee({ protocolFee, admin: user.publicKey }, user)
+    )
+
+    await market.changeDefaultProtocolFee({ protocolFee, admin: admin.publicKey }, admin)
+    assert.ok((await market.getDefaultProtocolFee()).protocolFee.v.eq(protocolFee.v))
+  })
 })
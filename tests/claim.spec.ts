@@ -187,7 +187,8 @@ describe('claim', () => {
           reserveX: poolDataAfter.tokenXReserve,
           reserveY: poolDataAfter.tokenYReserve,
           programAuthority: market.programAuthority,
-          tokenProgram: TOKEN_PROGRAM_ID
+          tokenXProgram: TOKEN_PROGRAM_ID,
+          tokenYProgram: TOKEN_PROGRAM_ID
         }
       }
     )
@@ -0,0 +1,212 @@
+import * as anchor from '@project-serum/anchor'
+import { Provider, BN } from '@project-serum/anchor'
+import { Token, TOKEN_PROGRAM_ID } from '@solana/spl-token'
+import { Keypair, PublicKey } from '@solana/web3.js'
+import { assert } from 'chai'
+import { assertThrowsAsync, createToken, initMarket } from './testUtils'
+import {
+  Market,
+  Pair,
+  LIQUIDITY_DENOMINATOR,
+  Network,
+  INVARIANT_ERRORS,
+  TICK_LIMIT,
+  calculatePriceSqrt
+} from '@invariant-labs/sdk'
+import { FeeTier } from '@invariant-labs/sdk/lib/market'
+import { fromFee } from '@invariant-labs/sdk/lib/utils'
+import { toDecimal, tou64 } from '@invariant-labs/sdk/src/utils'
+import { CrossLimit, InitPosition, Swap } from '@invariant-labs/sdk/src/market'
+import { PRICE_DENOMINATOR } from '@invariant-labs/sdk'
+
+describe('cross limit', () => {
+  const provider = Provider.local()
+  const connection = provider.connection
+  // @ts-expect-error
+  const wallet = provider.wallet.payer as Keypair
+  const mintAuthority = Keypair.generate()
+  const admin = Keypair.generate()
+  const feeTier: FeeTier = {
+    fee: fromFee(new BN(600)), // 0.6%
+    tickSpacing: 10
+  }
+  const virtualFeeTier: FeeTier = {
+    fee: fromFee(new BN(100)), // 0.1%
+    tickSpacing: 1
+  }
+  const liquidityDelta = { v: new BN(1000000).mul(LIQUIDITY_DENOMINATOR) }
+  let market: Market
+  let pair: Pair
+  let virtualPair: Pair
+  let tokenX: Token
+  let tokenY: Token
+
+  const unlimited = 65535
+
+  before(async () => {
+    market = await Market.build(
+      Network.LOCAL,
+      provider.wallet,
+      connection,
+      anchor.workspace.Invariant.programId
+    )
+
+    await Promise.all([
+      connection.requestAirdrop(mintAuthority.publicKey, 1e9),
+      connection.requestAirdrop(admin.publicKey, 1e9)
+    ])
+    const tokens = await Promise.all([
+      createToken(connection, wallet, mintAuthority),
+      createToken(connection, wallet, mintAuthority)
+    ])
+
+    pair = new Pair(tokens[0].publicKey, tokens[1].publicKey, feeTier)
+    virtualPair = new Pair(tokens[0].publicKey, tokens[1].publicKey, virtualFeeTier)
+    tokenX = new Token(connection, pair.tokenX, TOKEN_PROGRAM_ID, wallet)
+    tokenY = new Token(connection, pair.tokenY, TOKEN_PROGRAM_ID, wallet)
+  })
+
+  const providePositions = async (pair: Pair, ranges: Array<[number, number]>) => {
+    const positionOwner = Keypair.generate()
+    await connection.requestAirdrop(positionOwner.publicKey, 1e9)
+    const userTokenXAccount = await tokenX.createAccount(positionOwner.publicKey)
+    const userTokenYAccount = await tokenY.createAccount(positionOwner.publicKey)
+
+    const mintAmount = tou64(new BN(10).pow(new BN(10)))
+    await tokenX.mintTo(userTokenXAccount, mintAuthority.publicKey, [mintAuthority], mintAmount)
+    await tokenY.mintTo(userTokenYAccount, mintAuthority.publicKey, [mintAuthority], mintAmount)
+
+    for (const [lowerTick, upperTick] of ranges) {
+      const initPositionVars: InitPosition = {
+        pair,
+        owner: positionOwner.publicKey,
+        userTokenX: userTokenXAccount,
+        userTokenY: userTokenYAccount,
+        lowerTick,
+        upperTick,
+        liquidityDelta,
+        knownPrice: { v: PRICE_DENOMINATOR },
+        slippage: { v: new BN(0) }
+      }
+      await market.initPosition(initPositionVars, positionOwner)
+    }
+  }
+
+  const createSwapper = async (amount: BN) => {
+    const owner = Keypair.generate()
+    await connection.requestAirdrop(owner.publicKey, 1e9)
+    const accountX = await tokenX.createAccount(owner.publicKey)
+    const accountY = await tokenY.createAccount(owner.publicKey)
+    await tokenX.mintTo(accountX, mintAuthority.publicKey, [mintAuthority], tou64(amount))
+
+    return { owner, accountX, accountY }
+  }
+
+  const swapVars = (
+    pair: Pair,
+    owner: PublicKey,
+    accountX: PublicKey,
+    accountY: PublicKey,
+    amount: BN,
+    crossLimit: CrossLimit,
+    estimatedPriceAfterSwap = calculatePriceSqrt(-40000)
+  ): Swap => {
+    return {
+      pair,
+      owner,
+      xToY: true,
+      amount,
+      estimatedPriceAfterSwap,
+      slippage: toDecimal(0),
+      accountX,
+      accountY,
+      byAmountIn: true,
+      crossLimit
+    }
+  }
+
+  it('#init()', async () => {
+    await initMarket(market, [pair, virtualPair], admin)
+  })
+
+  it('#swapWithCrossLimit() initialized ticks', async () => {
+    // ticks -10 and -20 are initialized below the current price
+    await providePositions(pair, [
+      [-20, 10],
+      [-40, -10]
+    ])
+    assert.ok((await market.getPool(pair)).liquidity.v.eq(liquidityDelta.v))
+
+    const amount = new BN(1000)
+    const { owner, accountX, accountY } = await createSwapper(amount)
+
+    // crossing tick -10 is not allowed
+    const noCross = { maxInitialized: 0, maxVirtual: unlimited, partialFill: false }
+    await assertThrowsAsync(
+      market.swap(swapVars(pair, owner.publicKey, accountX, accountY, amount, noCross), owner),
+      INVARIANT_ERRORS.TOO_MANY_TICK_CROSSES
+    )
+
+    // partial fill stops at the price of tick -10 without crossing it
+    const partialFill = { maxInitialized: 0, maxVirtual: unlimited, partialFill: true }
+    await market.swap(
+      swapVars(pair, owner.publicKey, accountX, accountY, amount, partialFill),
+      owner
+    )
+
+    const poolAfterPartial = await market.getPool(pair)
+    assert.equal(poolAfterPartial.currentTickIndex, -10)
+    assert.ok(poolAfterPartial.sqrtPrice.v.eq(calculatePriceSqrt(-10).v))
+    assert.ok(poolAfterPartial.liquidity.v.eq(liquidityDelta.v))
+
+    const amountXLeft = (await tokenX.getAccountInfo(accountX)).amount
+    assert.ok(amountXLeft.gtn(0))
+    assert.ok(amountXLeft.lt(amount))
+
+    // a single cross is allowed by the limit of one
+    const oneCross = { maxInitialized: 1, maxVirtual: unlimited, partialFill: false }
+    await market.swap(
+      swapVars(pair, owner.publicKey, accountX, accountY, new BN(200), oneCross),
+      owner
+    )
+
+    const poolAfterCross = await market.getPool(pair)
+    assert.equal(poolAfterCross.currentTickIndex, -20)
+    assert.ok(poolAfterCross.liquidity.v.eq(liquidityDelta.v.muln(2)))
+  })
+
+  it('#swapWithCrossLimit() virtual ticks', async () => {
+    // search reaches the whole tickmap, so the only virtual tick is at its edge
+    await providePositions(virtualPair, [[-10, 10]])
+    const edgeTick = -(TICK_LIMIT - 1)
+    const belowEdge = calculatePriceSqrt(edgeTick - 1)
+
+    const amount = new BN(10).pow(new BN(7))
+    const { owner, accountX, accountY } = await createSwapper(amount)
+
+    const noCross = { maxInitialized: unlimited, maxVirtual: 0, partialFill: false }
+    await assertThrowsAsync(
+      market.swap(
+        swapVars(virtualPair, owner.publicKey, accountX, accountY, amount, noCross, belowEdge),
+        owner
+      ),
+      INVARIANT_ERRORS.TOO_MANY_TICK_CROSSES
+    )
+
+    // partial fill crosses the initialized tick -10 and stops at the edge of the tickmap
+    const partialFill = { maxInitialized: unlimited, maxVirtual: 0, partialFill: true }
+    await market.swap(
+      swapVars(virtualPair, owner.publicKey, accountX, accountY, amount, partialFill, belowEdge),
+      owner
+    )
+
+    const poolAfterPartial = await market.getPool(virtualPair)
+    assert.equal(poolAfterPartial.currentTickIndex, edgeTick)
+    assert.ok(poolAfterPartial.sqrtPrice.v.eq(calculatePriceSqrt(edgeTick).v))
+    assert.ok(poolAfterPartial.liquidity.v.eqn(0))
+
+    const amountXLeft = (await tokenX.getAccountInfo(accountX)).amount
+    assert.ok(amountXLeft.gtn(0))
+    assert.ok(amountXLeft.lt(amount))
+  })
+})
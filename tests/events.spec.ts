@@ -0,0 +1,141 @@
+import * as anchor from '@project-serum/anchor'
+import { Provider, BN } from '@project-serum/anchor'
+import { Token, TOKEN_PROGRAM_ID } from '@solana/spl-token'
+import { Keypair, PublicKey } from '@solana/web3.js'
+import { assert } from 'chai'
+import { createToken, initMarket } from './testUtils'
+import { Market, Pair, LIQUIDITY_DENOMINATOR, Network } from '@invariant-labs/sdk'
+import { FeeTier } from '@invariant-labs/sdk/lib/market'
+import { fromFee } from '@invariant-labs/sdk/lib/utils'
+import { toDecimal, tou64 } from '@invariant-labs/sdk/src/utils'
+import { InitPosition, Swap, SwapEvent, TickCrossedEvent } from '@invariant-labs/sdk/src/market'
+
+describe('events', () => {
+  const provider = Provider.local()
+  const connection = provider.connection
+  // @ts-expect-error
+  const wallet = provider.wallet.payer as Keypair
+  const mintAuthority = Keypair.generate()
+  const admin = Keypair.generate()
+  const positionOwner = Keypair.generate()
+  const swapper = Keypair.generate()
+  const feeTier: FeeTier = {
+    fee: fromFee(new BN(600)),
+    tickSpacing: 10
+  }
+  const liquidityDelta = { v: new BN(1000000).mul(LIQUIDITY_DENOMINATOR) }
+  let market: Market
+  let pair: Pair
+  let tokenX: Token
+  let tokenY: Token
+  let swapperX: PublicKey
+  let swapperY: PublicKey
+
+  before(async () => {
+    market = await Market.build(
+      Network.LOCAL,
+      provider.wallet,
+      connection,
+      anchor.workspace.Invariant.programId
+    )
+
+    await Promise.all([
+      connection.requestAirdrop(mintAuthority.publicKey, 1e9),
+      connection.requestAirdrop(admin.publicKey, 1e9),
+      connection.requestAirdrop(positionOwner.publicKey, 1e9),
+      connection.requestAirdrop(swapper.publicKey, 1e9)
+    ])
+
+    const tokens = await Promise.all([
+      createToken(connection, wallet, mintAuthority),
+      createToken(connection, wallet, mintAuthority)
+    ])
+
+    pair = new Pair(tokens[0].publicKey, tokens[1].publicKey, feeTier)
+    tokenX = new Token(connection, pair.tokenX, TOKEN_PROGRAM_ID, wallet)
+    tokenY = new Token(connection, pair.tokenY, TOKEN_PROGRAM_ID, wallet)
+
+    swapperX = await tokenX.createAccount(swapper.publicKey)
+    swapperY = await tokenY.createAccount(swapper.publicKey)
+    await tokenX.mintTo(swapperX, mintAuthority.publicKey, [mintAuthority], tou64(new BN(1e6)))
+  })
+
+  it('#init()', async () => {
+    await initMarket(market, [pair], admin)
+
+    const ownerX = await tokenX.createAccount(positionOwner.publicKey)
+    const ownerY = await tokenY.createAccount(positionOwner.publicKey)
+    const mintAmount = tou64(new BN(10).pow(new BN(10)))
+    await tokenX.mintTo(ownerX, mintAuthority.publicKey, [mintAuthority], mintAmount)
+    await tokenY.mintTo(ownerY, mintAuthority.publicKey, [mintAuthority], mintAmount)
+
+    await market.createPositionList(positionOwner.publicKey, positionOwner)
+    for (const index of [10, -10, -30]) {
+      await market.createTick({ pair, index, payer: admin.publicKey }, admin)
+    }
+    // tick -10 ends one position and starts the other
+    for (const [lowerTick, upperTick] of [
+      [-10, 10],
+      [-30, -10]
+    ]) {
+      const initPositionVars: InitPosition = {
+        pair,
+        owner: positionOwner.publicKey,
+        userTokenX: ownerX,
+        userTokenY: ownerY,
+        lowerTick,
+        upperTick,
+        liquidityDelta,
+        knownPrice: (await market.getPool(pair)).sqrtPrice,
+        slippage: { v: new BN(0) }
+      }
+      await market.initPosition(initPositionVars, positionOwner)
+    }
+  })
+
+  it('#swap() emits swap and tick cross events', async () => {
+    const amount = new BN(800)
+    const poolBefore = await market.getPool(pair)
+    const poolAddress = await pair.getAddress(market.program.programId)
+
+    const swapVars: Swap = {
+      pair,
+      owner: swapper.publicKey,
+      xToY: true,
+      amount,
+      estimatedPriceAfterSwap: poolBefore.sqrtPrice,
+      slippage: toDecimal(1, 2),
+      accountX: swapperX,
+      accountY: swapperY,
+      byAmountIn: true
+    }
+    const signature = await market.swap(swapVars, swapper)
+
+    const poolAfter = await market.getPool(pair)
+    const received = (await tokenY.getAccountInfo(swapperY)).amount
+    const events = await market.getTransactionEvents(signature)
+
+    // crossed ticks are emitted in order, before the swap summary
+    assert.deepEqual(events.map(event => event.name), ['TickCrossedEvent', 'SwapEvent'])
+
+    const cross = events[0].data as TickCrossedEvent
+    assert.ok(cross.pool.equals(poolAddress))
+    assert.equal(cross.index, -10)
+    assert.ok(cross.xToY)
+    assert.ok(cross.liquidity.v.eq(liquidityDelta.v))
+
+    const swap = events[1].data as SwapEvent
+    assert.ok(swap.pool.equals(poolAddress))
+    assert.ok(swap.swapper.equals(swapper.publicKey))
+    assert.ok(swap.xToY)
+    assert.ok(swap.amountIn.eq(amount))
+    assert.ok(swap.amountOut.eq(received))
+    assert.ok(swap.fee.gtn(0))
+    assert.ok(swap.referralFee.eqn(0))
+    assert.ok(swap.priceBefore.v.eq(poolBefore.sqrtPrice.v))
+    assert.ok(swap.priceAfter.v.eq(poolAfter.sqrtPrice.v))
+    assert.equal(swap.currentTickIndex, poolAfter.currentTickIndex)
+    assert.ok(swap.currentTickIndex < -10)
+    assert.ok(swap.liquidity.v.eq(poolAfter.liquidity.v))
+  })
+})
@@ -0,0 +1,169 @@
+import * as anchor from '@project-serum/anchor'
+import { Provider, BN } from '@project-serum/anchor'
+import { Token, TOKEN_PROGRAM_ID } from '@solana/spl-token'
+import { Keypair, PublicKey, Transaction } from '@solana/web3.js'
+import { assert } from 'chai'
+import { assertThrowsAsync, createToken, initMarket } from './testUtils'
+import {
+  Market,
+  Pair,
+  LIQUIDITY_DENOMINATOR,
+  Network,
+  INVARIANT_ERRORS,
+  signAndSend
+} from '@invariant-labs/sdk'
+import { FeeTier } from '@invariant-labs/sdk/lib/market'
+import { fromFee } from '@invariant-labs/sdk/lib/utils'
+import { tou64 } from '@invariant-labs/sdk/src/utils'
+import { FlashLoan, InitPosition } from '@invariant-labs/sdk/src/market'
+import { PRICE_DENOMINATOR } from '@invariant-labs/sdk'
+
+describe('flash loan', () => {
+  const provider = Provider.local()
+  const connection = provider.connection
+  // @ts-expect-error
+  const wallet = provider.wallet.payer as Keypair
+  const mintAuthority = Keypair.generate()
+  const admin = Keypair.generate()
+  const borrower = Keypair.generate()
+  const feeTier: FeeTier = {
+    fee: fromFee(new BN(600)), // 0.6%
+    tickSpacing: 10
+  }
+  let market: Market
+  let pair: Pair
+  let tokenX: Token
+  let tokenY: Token
+  let accountX: PublicKey
+  let accountY: PublicKey
+
+  before(async () => {
+    market = await Market.build(
+      Network.LOCAL,
+      provider.wallet,
+      connection,
+      anchor.workspace.Invariant.programId
+    )
+
+    await Promise.all([
+      connection.requestAirdrop(mintAuthority.publicKey, 1e9),
+      connection.requestAirdrop(admin.publicKey, 1e9),
+      connection.requestAirdrop(borrower.publicKey, 1e9)
+    ])
+    const tokens = await Promise.all([
+      createToken(connection, wallet, mintAuthority),
+      createToken(connection, wallet, mintAuthority)
+    ])
+
+    pair = new Pair(tokens[0].publicKey, tokens[1].publicKey, feeTier)
+    tokenX = new Token(connection, pair.tokenX, TOKEN_PROGRAM_ID, wallet)
+    tokenY = new Token(connection, pair.tokenY, TOKEN_PROGRAM_ID, wallet)
+  })
+
+  it('#init()', async () => {
+    await initMarket(market, [pair], admin)
+
+    const positionOwner = Keypair.generate()
+    await connection.requestAirdrop(positionOwner.publicKey, 1e9)
+    const userTokenXAccount = await tokenX.createAccount(positionOwner.publicKey)
+    const userTokenYAccount = await tokenY.createAccount(positionOwner.publicKey)
+
+    const mintAmount = tou64(new BN(10).pow(new BN(10)))
+    await tokenX.mintTo(userTokenXAccount, mintAuthority.publicKey, [mintAuthority], mintAmount)
+    await tokenY.mintTo(userTokenYAccount, mintAuthority.publicKey, [mintAuthority], mintAmount)
+
+    const initPositionVars: InitPosition = {
+      pair,
+      owner: positionOwner.publicKey,
+      userTokenX: userTokenXAccount,
+      userTokenY: userTokenYAccount,
+      lowerTick: -1000,
+      upperTick: 1000,
+      liquidityDelta: { v: new BN(1000000).mul(LIQUIDITY_DENOMINATOR) },
+      knownPrice: { v: PRICE_DENOMINATOR },
+      slippage: { v: new BN(0) }
+    }
+    await market.initPosition(initPositionVars, positionOwner)
+
+    // borrower only holds enough to cover the fee
+    accountX = await tokenX.createAccount(borrower.publicKey)
+    accountY = await tokenY.createAccount(borrower.publicKey)
+    await tokenX.mintTo(accountX, mintAuthority.publicKey, [mintAuthority], 100)
+    await tokenY.mintTo(accountY, mintAuthority.publicKey, [mintAuthority], 100)
+  })
+
+  it('#flashLoan() repaid in the same transaction', async () => {
+    const flashLoanVars: FlashLoan = {
+      pair,
+      borrower: borrower.publicKey,
+      accountX,
+      accountY,
+      amountX: new BN(10000),
+      amountY: new BN(5000)
+    }
+
+    const reservesBefore = await market.getReserveBalances(pair, tokenX, tokenY)
+    await market.flashLoan(flashLoanVars, borrower)
+    const reservesAfter = await market.getReserveBalances(pair, tokenX, tokenY)
+
+    // 0.6% fee rounded up
+    const feeX = new BN(60)
+    const feeY = new BN(30)
+    assert.ok(reservesAfter.x.eq(reservesBefore.x.add(feeX)))
+    assert.ok(reservesAfter.y.eq(reservesBefore.y.add(feeY)))
+    assert.ok((await tokenX.getAccountInfo(accountX)).amount.eq(new BN(100).sub(feeX)))
+    assert.ok((await tokenY.getAccountInfo(accountY)).amount.eq(new BN(100).sub(feeY)))
+
+    const pool = await market.getPool(pair)
+    assert.ok(pool.feeGrowthGlobalX.v.gtn(0))
+    assert.ok(pool.feeGrowthGlobalY.v.gtn(0))
+    assert.ok(pool.feeProtocolTokenX.gtn(0))
+    assert.ok(pool.feeProtocolTokenY.gtn(0))
+  })
+
+  it('#flashLoan() without repay', async () => {
+    const flashLoanVars: FlashLoan = {
+      pair,
+      borrower: borrower.publicKey,
+      accountX,
+      accountY,
+      amountX: new BN(1000),
+      amountY: new BN(0)
+    }
+
+    const loanTx = new Transaction().add(await market.flashLoanInstruction(flashLoanVars))
+    await assertThrowsAsync(
+      signAndSend(loanTx, [borrower], connection),
+      INVARIANT_ERRORS.FLASH_LOAN_NOT_REPAID
+    )
+
+    // repay of a different amount doesn't count
+    const repayIx = await market.flashRepayInstruction({ ...flashLoanVars, amountX: new BN(1) })
+    const partialRepayTx = new Transaction()
+      .add(await market.flashLoanInstruction(flashLoanVars))
+      .add(repayIx)
+    await assertThrowsAsync(
+      signAndSend(partialRepayTx, [borrower], connection),
+      INVARIANT_ERRORS.FLASH_LOAN_NOT_REPAID
+    )
+  })
+
+  it('#flashLoan() twice before a single repay', async () => {
+    const flashLoanVars: FlashLoan = {
+      pair,
+      borrower: borrower.publicKey,
+      accountX,
+      accountY,
+      amountX: new BN(1000),
+      amountY: new BN(0)
+    }
+
+    const tx = await market.flashLoanTransaction(flashLoanVars, [
+      await market.flashLoanInstruction(flashLoanVars)
+    ])
+    await assertThrowsAsync(
+      signAndSend(tx, [borrower], connection),
+      INVARIANT_ERRORS.FLASH_LOAN_NOT_REPAID
+    )
+  })
+})
@@ -4,7 +4,6 @@ import {
   calculatePriceSqrt,
   DENOMINATOR,
   TICK_LIMIT,
-  TICK_SEARCH_RANGE,
   MAX_TICK,
   MIN_TICK
 } from '@invariant-labs/sdk'
@@ -1174,51 +1173,26 @@ describe('Math', () => {
     })
   })
   describe('test getSearchLimit', () => {
-    it('Simple up', async () => {
-      const limit = getSearchLimit(new BN(0), new BN(1), true)
-      assert.ok(limit.eq(new BN(TICK_SEARCH_RANGE)))
-    })
-    it('Simple down', async () => {
-      const limit = getSearchLimit(new BN(0), new BN(1), false)
-      assert.ok(limit.eq(new BN(-TICK_SEARCH_RANGE)))
-    })
-    it('Less simple up', async () => {
-      const start = new BN(60)
-      const step = new BN(12)
-      const limit = getSearchLimit(start, step, true)
-      const expected = new BN(TICK_SEARCH_RANGE).mul(step).add(start)
-      assert.ok(limit.eq(expected))
-    })
-    it('Less simple down', async () => {
-      const start = new BN(60)
-      const step = new BN(12)
-      const limit = getSearchLimit(start, step, false)
-      const expected = new BN(-TICK_SEARCH_RANGE).mul(step).add(start)
-      assert.ok(limit.eq(expected))
-    })
     it('Up to array limit', async () => {
-      const step = new BN(2)
-      const limit = getSearchLimit(step.mul(new BN(TICK_LIMIT)).subn(10), step, true)
-      const expected = step.mul(new BN(TICK_LIMIT - 1))
-      assert.ok(limit.eq(expected))
+      const limit = getSearchLimit(new BN(1), true)
+      assert.ok(limit.eq(new BN(TICK_LIMIT - 1)))
     })
     it('Down to array limit', async () => {
+      const limit = getSearchLimit(new BN(1), false)
+      assert.ok(limit.eq(new BN(-(TICK_LIMIT - 1))))
+    })
+    it('Array limit scaled by spacing', async () => {
       const step = new BN(2)
-      const limit = getSearchLimit(step.mul(new BN(-TICK_LIMIT + 1)), step, false)
-      const expected = step.mul(new BN(-(TICK_LIMIT - 1)))
-      assert.ok(limit.eq(expected))
+      assert.ok(getSearchLimit(step, true).eq(step.mul(new BN(TICK_LIMIT - 1))))
+      assert.ok(getSearchLimit(step, false).eq(step.mul(new BN(-(TICK_LIMIT - 1)))))
     })
     it('Up to price limit', async () => {
-      const step = new BN(5)
-      const limit = getSearchLimit(new BN(MAX_TICK - 22), step, true)
-      const expected = new BN(MAX_TICK - 3)
-      assert.ok(limit.eq(expected))
-    })
-    it('At the price limit', async () => {
-      const step = new BN(5)
-      const limit = getSearchLimit(new BN(MAX_TICK - 3), step, true)
-      const expected = new BN(MAX_TICK - 3)
-      assert.ok(limit.eq(expected))
+      const limit = getSearchLimit(new BN(5), true)
+      assert.ok(limit.eq(new BN(MAX_TICK - 3)))
+    })
+    it('Down to price limit', async () => {
+      const limit = getSearchLimit(new BN(5), false)
+      assert.ok(limit.eq(new BN(-MAX_TICK + 3)))
     })
   })
   describe('test getCloserLimit', () => {
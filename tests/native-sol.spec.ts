@@ -0,0 +1,219 @@
+import * as anchor from '@project-serum/anchor'
+import { Provider, BN } from '@project-serum/anchor'
+import { NATIVE_MINT, Token } from '@solana/spl-token'
+import { Keypair, PublicKey } from '@solana/web3.js'
+import { assert } from 'chai'
+import { createToken, initMarket } from './testUtils'
+import { Market, Pair, LIQUIDITY_DENOMINATOR, Network } from '@invariant-labs/sdk'
+import { FeeTier } from '@invariant-labs/sdk/lib/market'
+import { fromFee } from '@invariant-labs/sdk/lib/utils'
+import { toDecimal, tou64 } from '@invariant-labs/sdk/src/utils'
+import { ClaimFee, InitPosition, RemovePosition, Swap } from '@invariant-labs/sdk/src/market'
+
+describe('native sol', () => {
+  const provider = Provider.local()
+  const connection = provider.connection
+  // @ts-expect-error
+  const wallet = provider.wallet.payer as Keypair
+  const mintAuthority = Keypair.generate()
+  const admin = Keypair.generate()
+  const owner = Keypair.generate()
+  const feeTier: FeeTier = {
+    fee: fromFee(new BN(600)),
+    tickSpacing: 10
+  }
+  const lowerTick = -1000
+  const upperTick = 1000
+  let market: Market
+  let pair: Pair
+  let token: Token
+  let solIsX: boolean
+  let wrappedSol: PublicKey
+  let ownerToken: PublicKey
+  let solReserve: PublicKey
+  let lamportsPerSignature: number
+
+  const getLamports = async (address: PublicKey) => {
+    return new BN((await connection.getAccountInfo(address))?.lamports ?? 0)
+  }
+  const getSolReserveBalance = async () => {
+    return new BN((await connection.getTokenAccountBalance(solReserve)).value.amount)
+  }
+  const assertWrappedSolClosed = async () => {
+    assert.equal(await connection.getAccountInfo(wrappedSol), null)
+  }
+
+  before(async () => {
+    market = await Market.build(
+      Network.LOCAL,
+      provider.wallet,
+      connection,
+      anchor.workspace.Invariant.programId
+    )
+
+    await Promise.all([
+      connection.requestAirdrop(mintAuthority.publicKey, 1e9),
+      connection.requestAirdrop(admin.publicKey, 1e9),
+      connection.requestAirdrop(owner.publicKey, 10e9)
+    ])
+
+    token = await createToken(connection, wallet, mintAuthority)
+    pair = new Pair(NATIVE_MINT, token.publicKey, feeTier)
+    solIsX = pair.tokenX.equals(NATIVE_MINT)
+
+    ownerToken = await token.createAccount(owner.publicKey)
+    await token.mintTo(ownerToken, mintAuthority.publicKey, [mintAuthority], tou64(new BN(1e9)))
+
+    wrappedSol = (await market.getWrappedSolAddress(owner.publicKey)).address
+    const { feeCalculator } = await connection.getRecentBlockhash()
+    lamportsPerSignature = feeCalculator.lamportsPerSignature
+  })
+
+  it('#init()', async () => {
+    await initMarket(market, [pair], admin)
+
+    const pool = await market.getPool(pair)
+    solReserve = solIsX ? pool.tokenXReserve : pool.tokenYReserve
+
+    await market.createTick({ pair, index: lowerTick, payer: admin.publicKey }, admin)
+    await market.createTick({ pair, index: upperTick, payer: admin.publicKey }, admin)
+    await market.createPositionList(owner.publicKey, owner)
+  })
+
+  it('#initPositionNative()', async () => {
+    const ownerBefore = await getLamports(owner.publicKey)
+    const reserveBefore = await getSolReserveBalance()
+
+    const initPositionVars: InitPosition = {
+      pair,
+      owner: owner.publicKey,
+      userTokenX: ownerToken,
+      userTokenY: ownerToken,
+      lowerTick,
+      upperTick,
+      liquidityDelta: { v: new BN(1000000).mul(LIQUIDITY_DENOMINATOR) },
+      knownPrice: (await market.getPool(pair)).sqrtPrice,
+      slippage: { v: new BN(0) }
+    }
+    // wraps far more than needed, the rest has to come back
+    await market.initPositionNative(initPositionVars, new BN(1e9), owner)
+
+    const ownerAfter = await getLamports(owner.publicKey)
+    const deposit = (await getSolReserveBalance()).sub(reserveBefore)
+    const { positionAddress } = await market.getPositionAddress(owner.publicKey, 0)
+    const positionRent = await getLamports(positionAddress)
+
+    await assertWrappedSolClosed()
+    assert.ok(deposit.gtn(0))
+    assert.ok(ownerBefore.sub(ownerAfter).eq(deposit.add(positionRent).addn(lamportsPerSignature)))
+  })
+
+  it('#swapNative() with SOL in', async () => {
+    const amount = new BN(10000)
+    const ownerBefore = await getLamports(owner.publicKey)
+    const reserveBefore = await getSolReserveBalance()
+    const tokenBefore = (await token.getAccountInfo(ownerToken)).amount
+
+    const swapVars: Swap = {
+      pair,
+      owner: owner.publicKey,
+      xToY: solIsX,
+      amount,
+      estimatedPriceAfterSwap: (await market.getPool(pair)).sqrtPrice,
+      slippage: toDecimal(1, 2),
+      accountX: ownerToken,
+      accountY: ownerToken,
+      byAmountIn: true
+    }
+    await market.swapNative(swapVars, amount.muln(2), owner)
+
+    const ownerAfter = await getLamports(owner.publicKey)
+    const reserveDelta = (await getSolReserveBalance()).sub(reserveBefore)
+    const tokenAfter = (await token.getAccountInfo(ownerToken)).amount
+
+    await assertWrappedSolClosed()
+    assert.ok(reserveDelta.eq(amount))
+    assert.ok(ownerBefore.sub(ownerAfter).eq(amount.addn(lamportsPerSignature)))
+    assert.ok(tokenAfter.gt(tokenBefore))
+  })
+
+  it('#swapNative() with SOL out', async () => {
+    const amount = new BN(5000)
+    const ownerBefore = await getLamports(owner.publicKey)
+    const reserveBefore = await getSolReserveBalance()
+    const tokenBefore = (await token.getAccountInfo(ownerToken)).amount
+
+    const swapVars: Swap = {
+      pair,
+      owner: owner.publicKey,
+      xToY: !solIsX,
+      amount,
+      estimatedPriceAfterSwap: (await market.getPool(pair)).sqrtPrice,
+      slippage: toDecimal(1, 2),
+      accountX: ownerToken,
+      accountY: ownerToken,
+      byAmountIn: true
+    }
+    await market.swapNative(swapVars, new BN(0), owner)
+
+    const ownerAfter = await getLamports(owner.publicKey)
+    const received = reserveBefore.sub(await getSolReserveBalance())
+    const tokenAfter = (await token.getAccountInfo(ownerToken)).amount
+
+    await assertWrappedSolClosed()
+    assert.ok(received.gtn(0))
+    assert.ok(tokenBefore.sub(tokenAfter).eq(amount))
+    assert.ok(ownerAfter.sub(ownerBefore).eq(received.subn(lamportsPerSignature)))
+  })
+
+  it('#claimFeeNative()', async () => {
+    const ownerBefore = await getLamports(owner.publicKey)
+    const reserveBefore = await getSolReserveBalance()
+
+    const claimFeeVars: ClaimFee = {
+      pair,
+      owner: owner.publicKey,
+      userTokenX: ownerToken,
+      userTokenY: ownerToken,
+      index: 0
+    }
+    await market.claimFeeNative(claimFeeVars, owner)
+
+    const ownerAfter = await getLamports(owner.publicKey)
+    const claimed = reserveBefore.sub(await getSolReserveBalance())
+
+    // fee from the swap with SOL in
+    await assertWrappedSolClosed()
+    assert.ok(claimed.gtn(0))
+    assert.ok(ownerAfter.sub(ownerBefore).eq(claimed.subn(lamportsPerSignature)))
+  })
+
+  it('#removePositionNative()', async () => {
+    const ownerBefore = await getLamports(owner.publicKey)
+    const reserveBefore = await getSolReserveBalance()
+    const { positionAddress } = await market.getPositionAddress(owner.publicKey, 0)
+    const { tickAddress: lowerTickAddress } = await market.getTickAddress(pair, lowerTick)
+    const { tickAddress: upperTickAddress } = await market.getTickAddress(pair, upperTick)
+    // position and both ticks are closed to the owner
+    const rent = (await getLamports(positionAddress))
+      .add(await getLamports(lowerTickAddress))
+      .add(await getLamports(upperTickAddress))
+
+    const removePositionVars: RemovePosition = {
+      pair,
+      owner: owner.publicKey,
+      index: 0,
+      userTokenX: ownerToken,
+      userTokenY: ownerToken
+    }
+    await market.removePositionNative(removePositionVars, owner)
+
+    const ownerAfter = await getLamports(owner.publicKey)
+    const withdrawn = reserveBefore.sub(await getSolReserveBalance())
+
+    await assertWrappedSolClosed()
+    assert.ok(withdrawn.gtn(0))
+    assert.ok(ownerAfter.sub(ownerBefore).eq(withdrawn.add(rent).subn(lamportsPerSignature)))
+    assert.equal(await connection.getAccountInfo(positionAddress), null)
+  })
+})
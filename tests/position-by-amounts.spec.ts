@@ -0,0 +1,139 @@
+import * as anchor from '@project-serum/anchor'
+import { Provider, BN } from '@project-serum/anchor'
+import { Token, TOKEN_PROGRAM_ID } from '@solana/spl-token'
+import { Keypair, PublicKey } from '@solana/web3.js'
+import { assert } from 'chai'
+import { createToken, initMarket } from './testUtils'
+import { Market, Pair, Network } from '@invariant-labs/sdk'
+import { FeeTier } from '@invariant-labs/sdk/lib/market'
+import { fromFee } from '@invariant-labs/sdk/lib/utils'
+import { tou64 } from '@invariant-labs/sdk/src/utils'
+import { InitPositionByAmounts } from '@invariant-labs/sdk/src/market'
+
+describe('position by amounts', () => {
+  const provider = Provider.local()
+  const connection = provider.connection
+  // @ts-expect-error
+  const wallet = provider.wallet.payer as Keypair
+  const mintAuthority = Keypair.generate()
+  const admin = Keypair.generate()
+  const owner = Keypair.generate()
+  const feeTier: FeeTier = {
+    fee: fromFee(new BN(600)),
+    tickSpacing: 10
+  }
+  const mintAmount = new BN(10).pow(new BN(12))
+  let market: Market
+  let pair: Pair
+  let tokenX: Token
+  let tokenY: Token
+  let ownerX: PublicKey
+  let ownerY: PublicKey
+
+  const getBalances = async () => {
+    return {
+      x: (await tokenX.getAccountInfo(ownerX)).amount,
+      y: (await tokenY.getAccountInfo(ownerY)).amount
+    }
+  }
+
+  before(async () => {
+    market = await Market.build(
+      Network.LOCAL,
+      provider.wallet,
+      connection,
+      anchor.workspace.Invariant.programId
+    )
+
+    await Promise.all([
+      connection.requestAirdrop(mintAuthority.publicKey, 1e9),
+      connection.requestAirdrop(admin.publicKey, 1e9),
+      connection.requestAirdrop(owner.publicKey, 1e9)
+    ])
+
+    const tokens = await Promise.all([
+      createToken(connection, wallet, mintAuthority),
+      createToken(connection, wallet, mintAuthority)
+    ])
+
+    pair = new Pair(tokens[0].publicKey, tokens[1].publicKey, feeTier)
+    tokenX = new Token(connection, pair.tokenX, TOKEN_PROGRAM_ID, wallet)
+    tokenY = new Token(connection, pair.tokenY, TOKEN_PROGRAM_ID, wallet)
+
+    ownerX = await tokenX.createAccount(owner.publicKey)
+    ownerY = await tokenY.createAccount(owner.publicKey)
+    await tokenX.mintTo(ownerX, mintAuthority.publicKey, [mintAuthority], tou64(mintAmount))
+    await tokenY.mintTo(ownerY, mintAuthority.publicKey, [mintAuthority], tou64(mintAmount))
+  })
+
+  it('#init()', async () => {
+    await initMarket(market, [pair], admin)
+
+    await market.createPositionList(owner.publicKey, owner)
+    for (const index of [-1000, 1000, 2000]) {
+      await market.createTick({ pair, index, payer: owner.publicKey }, owner)
+    }
+  })
+
+  it('#initPositionByAmounts() in range takes the limiting token fully', async () => {
+    const maxAmountX = new BN(1000000)
+    const maxAmountY = new BN(2000000)
+    const before = await getBalances()
+
+    const initPositionVars: InitPositionByAmounts = {
+      pair,
+      owner: owner.publicKey,
+      userTokenX: ownerX,
+      userTokenY: ownerY,
+      lowerTick: -1000,
+      upperTick: 1000,
+      maxAmountX,
+      maxAmountY
+    }
+    await market.initPositionByAmounts(initPositionVars, owner)
+
+    const after = await getBalances()
+    const spentX = before.x.sub(after.x)
+    const spentY = before.y.sub(after.y)
+    const position = await market.getPosition(owner.publicKey, 0)
+    const pool = await market.getPool(pair)
+
+    assert.ok(position.liquidity.v.gtn(0))
+    assert.ok(pool.liquidity.v.eq(position.liquidity.v))
+    // range is symmetric around the price, so x runs out first
+    assert.ok(spentX.lte(maxAmountX))
+    assert.ok(spentX.gte(maxAmountX.subn(10)))
+    assert.ok(spentY.gtn(0))
+    assert.ok(spentY.lt(maxAmountY))
+  })
+
+  it('#initPositionByAmounts() above the price takes only x', async () => {
+    const maxAmountX = new BN(1000000)
+    const maxAmountY = new BN(1000000)
+    const poolBefore = await market.getPool(pair)
+    const before = await getBalances()
+
+    const initPositionVars: InitPositionByAmounts = {
+      pair,
+      owner: owner.publicKey,
+      userTokenX: ownerX,
+      userTokenY: ownerY,
+      lowerTick: 1000,
+      upperTick: 2000,
+      maxAmountX,
+      maxAmountY
+    }
+    await market.initPositionByAmounts(initPositionVars, owner)
+
+    const after = await getBalances()
+    const spentX = before.x.sub(after.x)
+    const position = await market.getPosition(owner.publicKey, 1)
+    const poolAfter = await market.getPool(pair)
+
+    assert.ok(position.liquidity.v.gtn(0))
+    assert.ok(poolAfter.liquidity.v.eq(poolBefore.liquidity.v))
+    assert.ok(spentX.lte(maxAmountX))
+    assert.ok(spentX.gte(maxAmountX.subn(10)))
+    assert.ok(after.y.eq(before.y))
+  })
+})
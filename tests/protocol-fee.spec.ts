@@ -13,7 +13,13 @@ import {
   toDecimal,
   tou64
 } from '@invariant-labs/sdk/src/utils'
-import { CreateTick, InitPosition, Swap, WithdrawProtocolFee } from '@invariant-labs/sdk/src/market'
+import {
+  CreateFeeDistribution,
+  CreateTick,
+  InitPosition,
+  Swap,
+  WithdrawProtocolFee
+} from '@invariant-labs/sdk/src/market'
 import { PRICE_DENOMINATOR } from '@invariant-labs/sdk'
 
 describe('protocol-fee', () => {
@@ -35,6 +41,8 @@ describe('protocol-fee', () => {
   let tokenY: Token
   let userTokenXAccount: PublicKey
   let userTokenYAccount: PublicKey
+  const recipients = [Keypair.generate(), Keypair.generate()]
+  let recipientAccounts: Array<{ accountX: PublicKey; accountY: PublicKey }>
 
   before(async () => {
     market = await Market.build(
@@ -212,4 +220,149 @@ describe('protocol-fee', () => {
       INVARIANT_ERRORS.INVALID_AUTHORITY
     )
   })
+  it('#distributeProtocolFee()', async () => {
+    recipientAccounts = await Promise.all(
+      recipients.map(async recipient => {
+        return {
+          accountX: await tokenX.createAccount(recipient.publicKey),
+          accountY: await tokenY.createAccount(recipient.publicKey)
+        }
+      })
+    )
+
+    const createFeeDistributionVars: CreateFeeDistribution = {
+      owners: recipients.map(recipient => recipient.publicKey),
+      weights: [6000, 4000],
+      admin: admin.publicKey
+    }
+    await market.createFeeDistribution(createFeeDistributionVars, admin)
+
+    const feeDistribution = await market.getFeeDistribution()
+    assert.equal(feeDistribution.count, 2)
+    assert.ok(feeDistribution.recipients[0].owner.equals(recipients[0].publicKey))
+    assert.equal(feeDistribution.recipients[1].weight, 4000)
+
+    // accrue protocol fee in token y
+    const swapper = Keypair.generate()
+    await connection.requestAirdrop(swapper.publicKey, 1e9)
+    const amount = new BN(1000)
+    const accountX = await tokenX.createAccount(swapper.publicKey)
+    const accountY = await tokenY.createAccount(swapper.publicKey)
+    await tokenY.mintTo(accountY, mintAuthority.publicKey, [mintAuthority], tou64(amount))
+
+    const poolDataBefore = await market.getPool(pair)
+    const swapVars: Swap = {
+      pair,
+      xToY: false,
+      owner: swapper.publicKey,
+      amount,
+      estimatedPriceAfterSwap: poolDataBefore.sqrtPrice, // ignore price impact using high slippage tolerance
+      slippage: toDecimal(1, 2),
+      accountX,
+      accountY,
+      byAmountIn: true
+    }
+    await market.swap(swapVars, swapper)
+
+    const protocolFeeY = (await market.getPool(pair)).feeProtocolTokenY
+    assert.ok(protocolFeeY.gtn(0))
+
+    // fee receiver can't bypass the distribution
+    const adminAccountX = await tokenX.createAccount(admin.publicKey)
+    const adminAccountY = await tokenY.createAccount(admin.publicKey)
+    const withdrawProtocolFeeVars: WithdrawProtocolFee = {
+      pair,
+      accountX: adminAccountX,
+      accountY: adminAccountY,
+      admin: admin.publicKey
+    }
+    await assertThrowsAsync(
+      market.withdrawProtocolFee(withdrawProtocolFeeVars, admin),
+      INVARIANT_ERRORS.FEE_DISTRIBUTION_EXISTS
+    )
+
+    // accounts passed out of order belong to other recipients, so their shares stay in the pool
+    await market.distributeProtocolFee(
+      { pair, recipientAccounts: [...recipientAccounts].reverse() },
+      wallet
+    )
+    assert.ok((await market.getPool(pair)).feeProtocolTokenY.eq(protocolFeeY))
+
+    await market.distributeProtocolFee({ pair, recipientAccounts }, wallet)
+
+    const received = await Promise.all(
+      recipientAccounts.map(async ({ accountY }) => (await tokenY.getAccountInfo(accountY)).amount)
+    )
+    const poolData = await market.getPool(pair)
+    assert.ok(received[0].add(received[1]).eq(protocolFeeY))
+    assert.ok(received[0].gte(received[1]))
+    assert.ok(poolData.feeProtocolTokenX.eqn(0))
+    assert.ok(poolData.feeProtocolTokenY.eqn(0))
+  })
+  it('#distributeProtocolFee() skips a missing recipient', async () => {
+    // deeper liquidity, so that the protocol fee is big enough to be split between both recipients
+    for (const index of [-1000, 1000]) {
+      await market.createTick({ pair, index, payer: admin.publicKey }, admin)
+    }
+    const initPositionVars: InitPosition = {
+      pair,
+      owner: positionOwner.publicKey,
+      userTokenX: userTokenXAccount,
+      userTokenY: userTokenYAccount,
+      lowerTick: -1000,
+      upperTick: 1000,
+      liquidityDelta: { v: new BN(10).pow(new BN(9)).mul(LIQUIDITY_DENOMINATOR) },
+      knownPrice: (await market.getPool(pair)).sqrtPrice,
+      slippage: { v: new BN(0) }
+    }
+    await market.initPosition(initPositionVars, positionOwner)
+
+    const swapper = Keypair.generate()
+    await connection.requestAirdrop(swapper.publicKey, 1e9)
+    const amount = new BN(100000)
+    const accountX = await tokenX.createAccount(swapper.publicKey)
+    const accountY = await tokenY.createAccount(swapper.publicKey)
+    await tokenY.mintTo(accountY, mintAuthority.publicKey, [mintAuthority], tou64(amount))
+
+    const swapVars: Swap = {
+      pair,
+      xToY: false,
+      owner: swapper.publicKey,
+      amount,
+      estimatedPriceAfterSwap: (await market.getPool(pair)).sqrtPrice,
+      slippage: toDecimal(1, 2),
+      accountX,
+      accountY,
+      byAmountIn: true
+    }
+    await market.swap(swapVars, swapper)
+
+    // second recipient closes its account
+    const [first, second] = recipientAccounts
+    const secondBalance = (await tokenY.getAccountInfo(second.accountY)).amount
+    if (secondBalance.gtn(0)) {
+      await tokenY.transfer(
+        second.accountY,
+        first.accountY,
+        recipients[1],
+        [],
+        tou64(secondBalance)
+      )
+    }
+    await tokenY.closeAccount(second.accountY, first.accountY, recipients[1], [])
+    assert.equal(await connection.getAccountInfo(second.accountY), null)
+
+    const protocolFeeY = (await market.getPool(pair)).feeProtocolTokenY
+    const secondShare = protocolFeeY.muln(4000).divn(10000)
+    const firstShare = protocolFeeY.sub(secondShare)
+    assert.ok(secondShare.gtn(0))
+    const firstBefore = (await tokenY.getAccountInfo(first.accountY)).amount
+
+    await market.distributeProtocolFee({ pair, recipientAccounts }, wallet)
+
+    const firstAfter = (await tokenY.getAccountInfo(first.accountY)).amount
+    const poolData = await market.getPool(pair)
+    assert.ok(firstAfter.sub(firstBefore).eq(firstShare))
+    assert.ok(poolData.feeProtocolTokenY.eq(secondShare))
+  })
 })
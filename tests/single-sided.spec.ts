@@ -0,0 +1,175 @@
+import * as anchor from '@project-serum/anchor'
+import { Provider, BN } from '@project-serum/anchor'
+import { Token, TOKEN_PROGRAM_ID } from '@solana/spl-token'
+import { Keypair, PublicKey } from '@solana/web3.js'
+import { assert } from 'chai'
+import { createToken, initMarket } from './testUtils'
+import {
+  Market,
+  Pair,
+  LIQUIDITY_DENOMINATOR,
+  Network,
+  calculatePriceSqrt
+} from '@invariant-labs/sdk'
+import { FeeTier } from '@invariant-labs/sdk/lib/market'
+import { fromFee } from '@invariant-labs/sdk/lib/utils'
+import { tou64 } from '@invariant-labs/sdk/src/utils'
+import { InitPosition, InitPositionSingleSided } from '@invariant-labs/sdk/src/market'
+
+describe('single sided position', () => {
+  const provider = Provider.local()
+  const connection = provider.connection
+  // @ts-expect-error
+  const wallet = provider.wallet.payer as Keypair
+  const mintAuthority = Keypair.generate()
+  const admin = Keypair.generate()
+  const liquidityProvider = Keypair.generate()
+  const owner = Keypair.generate()
+  const feeTier: FeeTier = {
+    fee: fromFee(new BN(600)),
+    tickSpacing: 10
+  }
+  const otherFeeTier: FeeTier = {
+    fee: fromFee(new BN(1000)),
+    tickSpacing: 10
+  }
+  const mintAmount = new BN(10).pow(new BN(12))
+  const amount = new BN(1000000)
+  let market: Market
+  let pair: Pair
+  let limitedPair: Pair
+  let tokenX: Token
+  let tokenY: Token
+  let providerX: PublicKey
+  let providerY: PublicKey
+  let ownerX: PublicKey
+  let ownerY: PublicKey
+
+  const provideLiquidity = async (
+    pair: Pair,
+    lowerTick: number,
+    upperTick: number,
+    liquidity: BN
+  ) => {
+    const initPositionVars: InitPosition = {
+      pair,
+      owner: liquidityProvider.publicKey,
+      userTokenX: providerX,
+      userTokenY: providerY,
+      lowerTick,
+      upperTick,
+      liquidityDelta: { v: liquidity.mul(LIQUIDITY_DENOMINATOR) },
+      knownPrice: (await market.getPool(pair)).sqrtPrice,
+      slippage: { v: new BN(0) }
+    }
+    await market.initPosition(initPositionVars, liquidityProvider)
+  }
+
+  before(async () => {
+    market = await Market.build(
+      Network.LOCAL,
+      provider.wallet,
+      connection,
+      anchor.workspace.Invariant.programId
+    )
+
+    await Promise.all([
+      connection.requestAirdrop(mintAuthority.publicKey, 1e9),
+      connection.requestAirdrop(admin.publicKey, 1e9),
+      connection.requestAirdrop(liquidityProvider.publicKey, 1e9),
+      connection.requestAirdrop(owner.publicKey, 1e9)
+    ])
+
+    const tokens = await Promise.all([
+      createToken(connection, wallet, mintAuthority),
+      createToken(connection, wallet, mintAuthority)
+    ])
+
+    pair = new Pair(tokens[0].publicKey, tokens[1].publicKey, feeTier)
+    limitedPair = new Pair(tokens[0].publicKey, tokens[1].publicKey, otherFeeTier)
+    tokenX = new Token(connection, pair.tokenX, TOKEN_PROGRAM_ID, wallet)
+    tokenY = new Token(connection, pair.tokenY, TOKEN_PROGRAM_ID, wallet)
+
+    providerX = await tokenX.createAccount(liquidityProvider.publicKey)
+    providerY = await tokenY.createAccount(liquidityProvider.publicKey)
+    ownerX = await tokenX.createAccount(owner.publicKey)
+    ownerY = await tokenY.createAccount(owner.publicKey)
+    await tokenX.mintTo(providerX, mintAuthority.publicKey, [mintAuthority], tou64(mintAmount))
+    await tokenY.mintTo(providerY, mintAuthority.publicKey, [mintAuthority], tou64(mintAmount))
+    await tokenX.mintTo(ownerX, mintAuthority.publicKey, [mintAuthority], tou64(mintAmount))
+  })
+
+  it('#init()', async () => {
+    await initMarket(market, [pair, limitedPair], admin)
+
+    await provideLiquidity(pair, -1000, 1000, new BN(10).pow(new BN(10)))
+    // initialized ticks close to the price stop the swap of a single sided deposit
+    await provideLiquidity(limitedPair, -10, 10, new BN(1000000))
+
+    await market.createPositionList(owner.publicKey, owner)
+    for (const p of [pair, limitedPair]) {
+      await market.createTick({ pair: p, index: -500, payer: owner.publicKey }, owner)
+      await market.createTick({ pair: p, index: 500, payer: owner.publicKey }, owner)
+    }
+  })
+
+  it('#initPositionSingleSided() swaps part of the deposit', async () => {
+    const poolBefore = await market.getPool(pair)
+    const ownerXBefore = (await tokenX.getAccountInfo(ownerX)).amount
+
+    const initPositionVars: InitPositionSingleSided = {
+      pair,
+      owner: owner.publicKey,
+      userTokenX: ownerX,
+      userTokenY: ownerY,
+      lowerTick: -500,
+      upperTick: 500,
+      amount,
+      xToY: true,
+      knownPrice: poolBefore.sqrtPrice,
+      slippage: { v: new BN(0) }
+    }
+    await market.initPositionSingleSided(initPositionVars, owner)
+
+    const poolAfter = await market.getPool(pair)
+    const position = await market.getPosition(owner.publicKey, 0)
+    const spent = ownerXBefore.sub((await tokenX.getAccountInfo(ownerX)).amount)
+
+    assert.ok(position.liquidity.v.gtn(0))
+    assert.ok(poolAfter.sqrtPrice.v.lt(poolBefore.sqrtPrice.v))
+    // whole deposit is used up to rounding dust
+    assert.ok(spent.lte(amount))
+    assert.ok(spent.gte(amount.subn(10)))
+  })
+
+  it('#initPositionSingleSided() stops the swap before an initialized tick', async () => {
+    const poolBefore = await market.getPool(limitedPair)
+    const ownerXBefore = (await tokenX.getAccountInfo(ownerX)).amount
+
+    const initPositionVars: InitPositionSingleSided = {
+      pair: limitedPair,
+      owner: owner.publicKey,
+      userTokenX: ownerX,
+      userTokenY: ownerY,
+      lowerTick: -500,
+      upperTick: 500,
+      amount,
+      xToY: true,
+      knownPrice: poolBefore.sqrtPrice,
+      slippage: { v: new BN(0) }
+    }
+    await market.initPositionSingleSided(initPositionVars, owner)
+
+    const poolAfter = await market.getPool(limitedPair)
+    const position = await market.getPosition(owner.publicKey, 1)
+    const spent = ownerXBefore.sub((await tokenX.getAccountInfo(ownerX)).amount)
+
+    // price stays above the tick -10, so nothing was crossed
+    assert.ok(poolAfter.sqrtPrice.v.lt(poolBefore.sqrtPrice.v))
+    assert.ok(poolAfter.sqrtPrice.v.gt(calculatePriceSqrt(-10).v))
+    assert.ok(poolAfter.liquidity.v.eq(poolBefore.liquidity.v.add(position.liquidity.v)))
+    // position is limited by the received y, so most of the deposit stays with the owner
+    assert.ok(position.liquidity.v.gtn(0))
+    assert.ok(spent.lt(amount.divn(2)))
+  })
+})
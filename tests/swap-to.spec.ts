@@ -0,0 +1,157 @@
+import * as anchor from '@project-serum/anchor'
+import { Provider, BN } from '@project-serum/anchor'
+import { Token, TOKEN_PROGRAM_ID } from '@solana/spl-token'
+import { Keypair } from '@solana/web3.js'
+import { assert } from 'chai'
+import { assertThrowsAsync, createToken, initMarket } from './testUtils'
+import { Market, Pair, LIQUIDITY_DENOMINATOR, Network, INVARIANT_ERRORS } from '@invariant-labs/sdk'
+import { FeeTier } from '@invariant-labs/sdk/lib/market'
+import { fromFee } from '@invariant-labs/sdk/lib/utils'
+import { toDecimal, tou64 } from '@invariant-labs/sdk/src/utils'
+import { InitPosition, Swap } from '@invariant-labs/sdk/src/market'
+import { PRICE_DENOMINATOR } from '@invariant-labs/sdk'
+
+describe('swap to', () => {
+  const provider = Provider.local()
+  const connection = provider.connection
+  // @ts-expect-error
+  const wallet = provider.wallet.payer as Keypair
+  const mintAuthority = Keypair.generate()
+  const admin = Keypair.generate()
+  const feeTier: FeeTier = {
+    fee: fromFee(new BN(600)), // 0.6%
+    tickSpacing: 10
+  }
+  let market: Market
+  let pair: Pair
+  let tokenX: Token
+  let tokenY: Token
+
+  before(async () => {
+    market = await Market.build(
+      Network.LOCAL,
+      provider.wallet,
+      connection,
+      anchor.workspace.Invariant.programId
+    )
+
+    await Promise.all([
+      connection.requestAirdrop(mintAuthority.publicKey, 1e9),
+      connection.requestAirdrop(admin.publicKey, 1e9)
+    ])
+    const tokens = await Promise.all([
+      createToken(connection, wallet, mintAuthority),
+      createToken(connection, wallet, mintAuthority)
+    ])
+
+    pair = new Pair(tokens[0].publicKey, tokens[1].publicKey, feeTier)
+    tokenX = new Token(connection, pair.tokenX, TOKEN_PROGRAM_ID, wallet)
+    tokenY = new Token(connection, pair.tokenY, TOKEN_PROGRAM_ID, wallet)
+  })
+
+  it('#init()', async () => {
+    await initMarket(market, [pair], admin)
+
+    const positionOwner = Keypair.generate()
+    await connection.requestAirdrop(positionOwner.publicKey, 1e9)
+    const userTokenXAccount = await tokenX.createAccount(positionOwner.publicKey)
+    const userTokenYAccount = await tokenY.createAccount(positionOwner.publicKey)
+
+    const mintAmount = tou64(new BN(10).pow(new BN(10)))
+    await tokenX.mintTo(userTokenXAccount, mintAuthority.publicKey, [mintAuthority], mintAmount)
+    await tokenY.mintTo(userTokenYAccount, mintAuthority.publicKey, [mintAuthority], mintAmount)
+
+    const initPositionVars: InitPosition = {
+      pair,
+      owner: positionOwner.publicKey,
+      userTokenX: userTokenXAccount,
+      userTokenY: userTokenYAccount,
+      lowerTick: -1000,
+      upperTick: 1000,
+      liquidityDelta: { v: new BN(1000000).mul(LIQUIDITY_DENOMINATOR) },
+      knownPrice: { v: PRICE_DENOMINATOR },
+      slippage: { v: new BN(0) }
+    }
+    await market.initPosition(initPositionVars, positionOwner)
+  })
+
+  it('#swapTo() recipient of a different owner', async () => {
+    const owner = Keypair.generate()
+    const recipientOwner = Keypair.generate()
+    await connection.requestAirdrop(owner.publicKey, 1e9)
+
+    const amount = new BN(1000)
+    const accountX = await tokenX.createAccount(owner.publicKey)
+    await tokenX.mintTo(accountX, mintAuthority.publicKey, [mintAuthority], tou64(amount))
+    const recipient = await tokenY.createAccount(recipientOwner.publicKey)
+
+    const poolDataBefore = await market.getPool(pair)
+    const swapVars: Swap = {
+      pair,
+      owner: owner.publicKey,
+      xToY: true,
+      amount,
+      estimatedPriceAfterSwap: poolDataBefore.sqrtPrice, // ignore price impact using high slippage tolerance
+      slippage: toDecimal(1, 2),
+      accountX,
+      // owner doesn't need an account of the output token
+      accountY: recipient,
+      byAmountIn: true,
+      recipient
+    }
+    await market.swap(swapVars, owner)
+
+    assert.ok((await tokenX.getAccountInfo(accountX)).amount.eqn(0))
+    assert.ok((await tokenY.getAccountInfo(recipient)).amount.gtn(0))
+  })
+
+  it('#swapTo() recipient of the input token', async () => {
+    const owner = Keypair.generate()
+    await connection.requestAirdrop(owner.publicKey, 1e9)
+
+    const amount = new BN(1000)
+    const accountX = await tokenX.createAccount(owner.publicKey)
+    const accountY = await tokenY.createAccount(owner.publicKey)
+    await tokenX.mintTo(accountX, mintAuthority.publicKey, [mintAuthority], tou64(amount))
+    const recipient = await tokenX.createAccount(Keypair.generate().publicKey)
+
+    const poolDataBefore = await market.getPool(pair)
+    const swapVars: Swap = {
+      pair,
+      owner: owner.publicKey,
+      xToY: true,
+      amount,
+      estimatedPriceAfterSwap: poolDataBefore.sqrtPrice, // ignore price impact using high slippage tolerance
+      slippage: toDecimal(1, 2),
+      accountX,
+      accountY,
+      byAmountIn: true,
+      recipient
+    }
+    await assertThrowsAsync(market.swap(swapVars, owner), INVARIANT_ERRORS.INVALID_MINT)
+  })
+
+  it('#swap() output account of a different owner', async () => {
+    const owner = Keypair.generate()
+    await connection.requestAirdrop(owner.publicKey, 1e9)
+
+    const amount = new BN(1000)
+    const accountX = await tokenX.createAccount(owner.publicKey)
+    await tokenX.mintTo(accountX, mintAuthority.publicKey, [mintAuthority], tou64(amount))
+    const accountY = await tokenY.createAccount(Keypair.generate().publicKey)
+
+    const poolDataBefore = await market.getPool(pair)
+    const swapVars: Swap = {
+      pair,
+      owner: owner.publicKey,
+      xToY: true,
+      amount,
+      estimatedPriceAfterSwap: poolDataBefore.sqrtPrice, // ignore price impact using high slippage tolerance
+      slippage: toDecimal(1, 2),
+      accountX,
+      accountY,
+      byAmountIn: true
+    }
+    await assertThrowsAsync(market.swap(swapVars, owner), INVARIANT_ERRORS.INVALID_OWNER)
+  })
+})
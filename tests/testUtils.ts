@@ -1,4 +1,12 @@
-import { Connection, Keypair, PublicKey } from '@solana/web3.js'
+import {
+  Connection,
+  Keypair,
+  PublicKey,
+  sendAndConfirmTransaction,
+  SystemProgram,
+  Transaction,
+  TransactionInstruction
+} from '@solana/web3.js'
 import { TokenInstructions } from '@project-serum/serum'
 import { Token, TOKEN_PROGRAM_ID } from '@solana/spl-token'
 import { FeeTier, Market, Position, Tick } from '@invariant-labs/sdk/lib/market'
@@ -14,7 +22,8 @@ import {
   feeToTickSpacing,
   FEE_TIERS,
   generateTicksArray,
-  tou64
+  tou64,
+  TOKEN_2022_PROGRAM_ID
 } from '@invariant-labs/sdk/src/utils'
 import BN from 'bn.js'
 import { Pair, TICK_LIMIT, calculatePriceSqrt, LIQUIDITY_DENOMINATOR } from '@invariant-labs/sdk'
@@ -64,6 +73,150 @@ export const createToken = async (
   return token
 }
 
+// Token-2022 layouts, the mint and accounts carry only the transfer fee extensions
+const TRANSFER_FEE_MINT_LEN = 165 + 1 + 4 + 108
+const TRANSFER_FEE_ACCOUNT_LEN = 165 + 1 + 4 + 8
+const ONE_IN_BASIS_POINTS = 10000
+
+export interface TransferFeeMint {
+  mint: PublicKey
+  basisPoints: number
+  maximumFee: BN
+}
+
+export const createTransferFeeToken = async (
+  connection: Connection,
+  payer: Keypair,
+  mintAuthority: Keypair,
+  basisPoints: number,
+  maximumFee: BN,
+  decimals = 6
+): Promise<TransferFeeMint> => {
+  const mint = Keypair.generate()
+
+  const initTransferFeeData = Buffer.concat([
+    Buffer.from([26, 0, 1]),
+    mintAuthority.publicKey.toBuffer(),
+    Buffer.from([1]),
+    mintAuthority.publicKey.toBuffer(),
+    new BN(basisPoints).toArrayLike(Buffer, 'le', 2),
+    maximumFee.toArrayLike(Buffer, 'le', 8)
+  ])
+  const initMintData = Buffer.concat([
+    Buffer.from([20, decimals]),
+    mintAuthority.publicKey.toBuffer(),
+    Buffer.from([0])
+  ])
+
+  const tx = new Transaction()
+    .add(
+      SystemProgram.createAccount({
+        fromPubkey: payer.publicKey,
+        newAccountPubkey: mint.publicKey,
+        space: TRANSFER_FEE_MINT_LEN,
+        lamports: await connection.getMinimumBalanceForRentExemption(TRANSFER_FEE_MINT_LEN),
+        programId: TOKEN_2022_PROGRAM_ID
+      })
+    )
+    .add(
+      new TransactionInstruction({
+        keys: [{ pubkey: mint.publicKey, isSigner: false, isWritable: true }],
+        programId: TOKEN_2022_PROGRAM_ID,
+        data: initTransferFeeData
+      })
+    )
+    .add(
+      new TransactionInstruction({
+        keys: [{ pubkey: mint.publicKey, isSigner: false, isWritable: true }],
+        programId: TOKEN_2022_PROGRAM_ID,
+        data: initMintData
+      })
+    )
+  await sendAndConfirmTransaction(connection, tx, [payer, mint])
+
+  return { mint: mint.publicKey, basisPoints, maximumFee }
+}
+
+export const createTransferFeeAccount = async (
+  connection: Connection,
+  payer: Keypair,
+  mint: PublicKey,
+  owner: PublicKey
+) => {
+  const account = Keypair.generate()
+
+  const tx = new Transaction()
+    .add(
+      SystemProgram.createAccount({
+        fromPubkey: payer.publicKey,
+        newAccountPubkey: account.publicKey,
+        space: TRANSFER_FEE_ACCOUNT_LEN,
+        lamports: await connection.getMinimumBalanceForRentExemption(TRANSFER_FEE_ACCOUNT_LEN),
+        programId: TOKEN_2022_PROGRAM_ID
+      })
+    )
+    .add(
+      new TransactionInstruction({
+        keys: [
+          { pubkey: account.publicKey, isSigner: false, isWritable: true },
+          { pubkey: mint, isSigner: false, isWritable: false }
+        ],
+        programId: TOKEN_2022_PROGRAM_ID,
+        data: Buffer.concat([Buffer.from([18]), owner.toBuffer()])
+      })
+    )
+  await sendAndConfirmTransaction(connection, tx, [payer, account])
+
+  return account.publicKey
+}
+
+export const mintTransferFeeToken = async (
+  connection: Connection,
+  payer: Keypair,
+  mint: PublicKey,
+  account: PublicKey,
+  mintAuthority: Keypair,
+  amount: BN
+) => {
+  const tx = new Transaction().add(
+    Token.createMintToInstruction(
+      TOKEN_2022_PROGRAM_ID,
+      mint,
+      account,
+      mintAuthority.publicKey,
+      [],
+      tou64(amount)
+    )
+  )
+  await sendAndConfirmTransaction(connection, tx, [payer, mintAuthority])
+}
+
+// base layout is shared by both token programs, so it works for any token account
+export const getTokenBalance = async (connection: Connection, account: PublicKey) => {
+  const info = await connection.getAccountInfo(account)
+  if (info === null) {
+    throw new Error('Token account does not exist')
+  }
+  return new BN(info.data.slice(64, 72), 'le')
+}
+
+export const getTransferFee = ({ basisPoints, maximumFee }: TransferFeeMint, amount: BN) => {
+  const fee = amount
+    .muln(basisPoints)
+    .addn(ONE_IN_BASIS_POINTS - 1)
+    .divn(ONE_IN_BASIS_POINTS)
+  return BN.min(fee, maximumFee)
+}
+
+export const getPreFeeAmount = ({ basisPoints, maximumFee }: TransferFeeMint, amount: BN) => {
+  const denominator = ONE_IN_BASIS_POINTS - basisPoints
+  const preFeeAmount = amount
+    .muln(ONE_IN_BASIS_POINTS)
+    .addn(denominator - 1)
+    .divn(denominator)
+  return preFeeAmount.sub(amount).gte(maximumFee) ? amount.add(maximumFee) : preFeeAmount
+}
+
 // do not compare bump
 export const positionEquals = (a: Position, b: Position) => {
   return positionWithoutOwnerEquals(a, b) && a.owner.equals(b.owner)
@@ -1,4 +1,4 @@
-import { TICK_SEARCH_RANGE, TICK_LIMIT, MAX_TICK } from '@invariant-labs/sdk'
+import { TICK_LIMIT, MAX_TICK } from '@invariant-labs/sdk'
 import { Tickmap } from '@invariant-labs/sdk/lib/market'
 import {
   findTickmapChanges,
@@ -40,28 +40,19 @@ describe('tickmap', () => {
       assert.ok(getNextTick(tickmap, 0, 10) === null)
     })
 
-    it('just below limit', async () => {
+    it('across the whole bitmap', async () => {
       const tickmap: Tickmap = { bitmap: new Array<number>(25000).map(i => (i = 0)) }
-      const { byte, bit } = tickToPosition(new BN(0), new BN(1))
+      const { byte, bit } = tickToPosition(new BN(TICK_LIMIT - 1), new BN(1))
       tickmap.bitmap[byte] ^= 1 << bit
 
-      assert.ok(getNextTick(tickmap, -TICK_SEARCH_RANGE, 1) === 0)
+      assert.ok(getNextTick(tickmap, -TICK_LIMIT + 1, 1) === TICK_LIMIT - 1)
     })
 
-    it('at limit', async () => {
+    it('past the edge of the bitmap', async () => {
       const tickmap: Tickmap = { bitmap: new Array<number>(25000).map(i => (i = 0)) }
-      const { byte, bit } = tickToPosition(new BN(0), new BN(1))
-      tickmap.bitmap[byte] ^= 1 << bit
-
-      assert.ok(getNextTick(tickmap, -TICK_SEARCH_RANGE - 1, 1) === null)
-    })
-
-    it('further than limit', async () => {
-      const tickmap: Tickmap = { bitmap: new Array<number>(25000).map(i => (i = 0)) }
-      const { byte, bit } = tickToPosition(new BN(TICK_LIMIT - 10), new BN(1))
-      tickmap.bitmap[byte] ^= 1 << bit
 
       assert.ok(getNextTick(tickmap, -TICK_LIMIT + 1, 1) === null)
+      assert.ok(getNextTick(tickmap, TICK_LIMIT - 2, 1) === null)
     })
 
     it('hitting the limit', async () => {
@@ -114,28 +105,19 @@ describe('tickmap', () => {
       assert.ok(getPreviousTick(tickmap, 0, 10) === null)
     })
 
-    it('just below limit', async () => {
-      const tickmap: Tickmap = { bitmap: new Array<number>(25000).map(i => (i = 0)) }
-      const { byte, bit } = tickToPosition(new BN(0), new BN(1))
-      tickmap.bitmap[byte] ^= 1 << bit
-
-      assert.ok(getPreviousTick(tickmap, TICK_SEARCH_RANGE, 1) === 0)
-    })
-
-    it('at limit', async () => {
+    it('across the whole bitmap', async () => {
       const tickmap: Tickmap = { bitmap: new Array<number>(25000).map(i => (i = 0)) }
-      const { byte, bit } = tickToPosition(new BN(0), new BN(1))
+      const { byte, bit } = tickToPosition(new BN(-TICK_LIMIT + 1), new BN(1))
       tickmap.bitmap[byte] ^= 1 << bit
 
-      assert.ok(getPreviousTick(tickmap, TICK_SEARCH_RANGE + 1, 1) === null)
+      assert.ok(getPreviousTick(tickmap, TICK_LIMIT - 1, 1) === -TICK_LIMIT + 1)
     })
 
-    it('further than limit', async () => {
+    it('past the edge of the bitmap', async () => {
       const tickmap: Tickmap = { bitmap: new Array<number>(25000).map(i => (i = 0)) }
-      const { byte, bit } = tickToPosition(new BN(-TICK_LIMIT + 1), new BN(1))
-      tickmap.bitmap[byte] ^= 1 << bit
 
       assert.ok(getPreviousTick(tickmap, TICK_LIMIT - 1, 1) === null)
+      assert.ok(getPreviousTick(tickmap, -TICK_LIMIT + 1, 1) === null)
     })
   })
   describe('findTickmapChanges', () => {
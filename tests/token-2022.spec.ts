@@ -0,0 +1,233 @@
+import * as anchor from '@project-serum/anchor'
+import { Provider, BN } from '@project-serum/anchor'
+import { Keypair, PublicKey } from '@solana/web3.js'
+import { assert } from 'chai'
+import {
+  createTransferFeeAccount,
+  createTransferFeeToken,
+  getPreFeeAmount,
+  getTokenBalance,
+  getTransferFee,
+  initMarket,
+  mintTransferFeeToken,
+  TransferFeeMint
+} from './testUtils'
+import { Market, Pair, LIQUIDITY_DENOMINATOR, Network } from '@invariant-labs/sdk'
+import { FeeTier } from '@invariant-labs/sdk/lib/market'
+import { fromFee } from '@invariant-labs/sdk/lib/utils'
+import { toDecimal, TOKEN_2022_PROGRAM_ID } from '@invariant-labs/sdk/src/utils'
+import { InitPosition, RemovePosition, Swap } from '@invariant-labs/sdk/src/market'
+
+describe('token-2022', () => {
+  const provider = Provider.local()
+  const connection = provider.connection
+  // @ts-expect-error
+  const wallet = provider.wallet.payer as Keypair
+  const mintAuthority = Keypair.generate()
+  const admin = Keypair.generate()
+  const owner = Keypair.generate()
+  const feeTier: FeeTier = {
+    fee: fromFee(new BN(600)),
+    tickSpacing: 10
+  }
+  const mintAmount = new BN(10).pow(new BN(10))
+  let market: Market
+  let pair: Pair
+  let feeX: TransferFeeMint
+  let feeY: TransferFeeMint
+  let accountX: PublicKey
+  let accountY: PublicKey
+  let reserveX: PublicKey
+  let reserveY: PublicKey
+
+  before(async () => {
+    market = await Market.build(
+      Network.LOCAL,
+      provider.wallet,
+      connection,
+      anchor.workspace.Invariant.programId
+    )
+
+    await Promise.all([
+      connection.requestAirdrop(mintAuthority.publicKey, 1e9),
+      connection.requestAirdrop(admin.publicKey, 1e9),
+      connection.requestAirdrop(owner.publicKey, 1e9)
+    ])
+
+    // 1% and 2% transfer fees, maximum high enough to never apply
+    const mints = await Promise.all([
+      createTransferFeeToken(connection, wallet, mintAuthority, 100, new BN(1e9)),
+      createTransferFeeToken(connection, wallet, mintAuthority, 200, new BN(1e9))
+    ])
+
+    pair = new Pair(mints[0].mint, mints[1].mint, feeTier)
+    feeX = mints[0].mint.equals(pair.tokenX) ? mints[0] : mints[1]
+    feeY = mints[0].mint.equals(pair.tokenX) ? mints[1] : mints[0]
+
+    accountX = await createTransferFeeAccount(connection, wallet, pair.tokenX, owner.publicKey)
+    accountY = await createTransferFeeAccount(connection, wallet, pair.tokenY, owner.publicKey)
+    await mintTransferFeeToken(connection, wallet, pair.tokenX, accountX, mintAuthority, mintAmount)
+    await mintTransferFeeToken(connection, wallet, pair.tokenY, accountY, mintAuthority, mintAmount)
+  })
+
+  it('#init()', async () => {
+    await initMarket(market, [pair], admin)
+
+    const pool = await market.getPool(pair)
+    reserveX = pool.tokenXReserve
+    reserveY = pool.tokenYReserve
+
+    const { tokenXProgram, tokenYProgram } = await market.getTokenPrograms(pair)
+    assert.ok(tokenXProgram.equals(TOKEN_2022_PROGRAM_ID))
+    assert.ok(tokenYProgram.equals(TOKEN_2022_PROGRAM_ID))
+    assert.ok((await connection.getAccountInfo(reserveX))?.owner.equals(TOKEN_2022_PROGRAM_ID))
+    assert.ok((await connection.getAccountInfo(reserveY))?.owner.equals(TOKEN_2022_PROGRAM_ID))
+  })
+
+  it('#initPosition() grosses up deposits by the transfer fee', async () => {
+    const [userXBefore, userYBefore] = await Promise.all([
+      getTokenBalance(connection, accountX),
+      getTokenBalance(connection, accountY)
+    ])
+
+    await market.createPositionList(owner.publicKey, owner)
+    const initPositionVars: InitPosition = {
+      pair,
+      owner: owner.publicKey,
+      userTokenX: accountX,
+      userTokenY: accountY,
+      lowerTick: -1000,
+      upperTick: 1000,
+      liquidityDelta: { v: new BN(10000000).mul(LIQUIDITY_DENOMINATOR) },
+      knownPrice: (await market.getPool(pair)).sqrtPrice,
+      slippage: { v: new BN(0) }
+    }
+    await market.initPosition(initPositionVars, owner)
+
+    const [userXAfter, userYAfter, depositX, depositY] = await Promise.all([
+      getTokenBalance(connection, accountX),
+      getTokenBalance(connection, accountY),
+      getTokenBalance(connection, reserveX),
+      getTokenBalance(connection, reserveY)
+    ])
+
+    // reserves receive the whole required amount, user pays the fee on top of it
+    assert.ok(depositX.gtn(0))
+    assert.ok(depositY.gtn(0))
+    assert.ok(userXBefore.sub(userXAfter).eq(getPreFeeAmount(feeX, depositX)))
+    assert.ok(userYBefore.sub(userYAfter).eq(getPreFeeAmount(feeY, depositY)))
+  })
+
+  it('#swap() x to y by amount in', async () => {
+    const amount = new BN(10000)
+    const [userXBefore, userYBefore, reserveXBefore, reserveYBefore] = await Promise.all([
+      getTokenBalance(connection, accountX),
+      getTokenBalance(connection, accountY),
+      getTokenBalance(connection, reserveX),
+      getTokenBalance(connection, reserveY)
+    ])
+
+    const swapVars: Swap = {
+      pair,
+      xToY: true,
+      amount,
+      estimatedPriceAfterSwap: (await market.getPool(pair)).sqrtPrice,
+      slippage: toDecimal(1, 2),
+      accountX,
+      accountY,
+      byAmountIn: true,
+      owner: owner.publicKey
+    }
+    await market.swap(swapVars, owner)
+
+    const [userXAfter, userYAfter, reserveXAfter, reserveYAfter] = await Promise.all([
+      getTokenBalance(connection, accountX),
+      getTokenBalance(connection, accountY),
+      getTokenBalance(connection, reserveX),
+      getTokenBalance(connection, reserveY)
+    ])
+    const reserveXDelta = reserveXAfter.sub(reserveXBefore)
+    const reserveYDelta = reserveYBefore.sub(reserveYAfter)
+
+    // swap is computed on the amount left after the input fee, 10000 - 1% = 9900
+    // grossing 9900 up again takes exactly the 10000 specified by the user
+    assert.ok(reserveXDelta.eq(amount.sub(getTransferFee(feeX, amount))))
+    assert.ok(userXBefore.sub(userXAfter).eq(amount))
+    // output arrives reduced by the fee withheld by the mint
+    assert.ok(reserveYDelta.gtn(0))
+    assert.ok(
+      userYAfter.sub(userYBefore).eq(reserveYDelta.sub(getTransferFee(feeY, reserveYDelta)))
+    )
+  })
+
+  it('#swap() y to x by amount out', async () => {
+    const amount = new BN(5000)
+    const [userXBefore, userYBefore, reserveXBefore, reserveYBefore] = await Promise.all([
+      getTokenBalance(connection, accountX),
+      getTokenBalance(connection, accountY),
+      getTokenBalance(connection, reserveX),
+      getTokenBalance(connection, reserveY)
+    ])
+
+    const swapVars: Swap = {
+      pair,
+      xToY: false,
+      amount,
+      estimatedPriceAfterSwap: (await market.getPool(pair)).sqrtPrice,
+      slippage: toDecimal(1, 2),
+      accountX,
+      accountY,
+      byAmountIn: false,
+      owner: owner.publicKey
+    }
+    await market.swap(swapVars, owner)
+
+    const [userXAfter, userYAfter, reserveXAfter, reserveYAfter] = await Promise.all([
+      getTokenBalance(connection, accountX),
+      getTokenBalance(connection, accountY),
+      getTokenBalance(connection, reserveX),
+      getTokenBalance(connection, reserveY)
+    ])
+    const reserveXDelta = reserveXBefore.sub(reserveXAfter)
+    const reserveYDelta = reserveYAfter.sub(reserveYBefore)
+
+    // pool sends the pre-fee amount, ceil(5000 / 0.99) = 5051, so that exactly 5000 arrives
+    assert.ok(reserveXDelta.eq(getPreFeeAmount(feeX, amount)))
+    assert.ok(userXAfter.sub(userXBefore).eq(amount))
+    // input is grossed up, reserve receives the whole amount used by the swap
+    assert.ok(reserveYDelta.gtn(0))
+    assert.ok(userYBefore.sub(userYAfter).eq(getPreFeeAmount(feeY, reserveYDelta)))
+  })
+
+  it('#removePosition() withdraws amounts reduced by the transfer fee', async () => {
+    const [userXBefore, userYBefore, reserveXBefore, reserveYBefore] = await Promise.all([
+      getTokenBalance(connection, accountX),
+      getTokenBalance(connection, accountY),
+      getTokenBalance(connection, reserveX),
+      getTokenBalance(connection, reserveY)
+    ])
+
+    const removePositionVars: RemovePosition = {
+      pair,
+      owner: owner.publicKey,
+      index: 0,
+      userTokenX: accountX,
+      userTokenY: accountY
+    }
+    await market.removePosition(removePositionVars, owner)
+
+    const [userXAfter, userYAfter, reserveXAfter, reserveYAfter] = await Promise.all([
+      getTokenBalance(connection, accountX),
+      getTokenBalance(connection, accountY),
+      getTokenBalance(connection, reserveX),
+      getTokenBalance(connection, reserveY)
+    ])
+    const withdrawX = reserveXBefore.sub(reserveXAfter)
+    const withdrawY = reserveYBefore.sub(reserveYAfter)
+
+    assert.ok(withdrawX.gtn(0))
+    assert.ok(withdrawY.gtn(0))
+    assert.ok(userXAfter.sub(userXBefore).eq(withdrawX.sub(getTransferFee(feeX, withdrawX))))
+    assert.ok(userYAfter.sub(userYBefore).eq(withdrawY.sub(getTransferFee(feeY, withdrawY))))
+  })
+})
@@ -184,7 +184,8 @@ describe('withdraw', () => {
           reserveX: pool.tokenXReserve,
           reserveY: pool.tokenYReserve,
           programAuthority: market.programAuthority,
-          tokenProgram: TOKEN_PROGRAM_ID
+          tokenXProgram: TOKEN_PROGRAM_ID,
+          tokenYProgram: TOKEN_PROGRAM_ID
         }
       }
     )